@@ -0,0 +1,13 @@
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Protobuf code is only needed by the optional gRPC service
+    #[cfg(feature = "grpc")]
+    {
+        println!("cargo:rerun-if-changed=proto/hugure/v1/communication.proto");
+        tonic_build::configure()
+            .build_client(true)
+            .build_server(true)
+            .compile(&["proto/hugure/v1/communication.proto"], &["proto"])?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,26 @@
+//! Dynamic plugin registering a constant fidelity predictor
+//! 
+//! Built as a shared library with `--features dynamic-plugins`; the plugin registry
+//! tests load it through [`PluginRegistry::load_library`](hugure::plugins::PluginRegistry::load_library).
+
+use std::sync::Arc;
+
+use hugure::bmd::BMD;
+use hugure::plugins::FidelityPredictor;
+
+/// Fidelity reported for every BMD combination
+pub const CONSTANT_FIDELITY: f64 = 0.75;
+
+/// Predictor that ignores its input
+#[derive(Debug)]
+pub struct ConstantPredictor;
+
+impl FidelityPredictor for ConstantPredictor {
+    fn predict_fidelity(&self, _bmds: &[BMD]) -> f64 {
+        CONSTANT_FIDELITY
+    }
+}
+
+hugure::declare_plugins!(|registry| {
+    registry.register_fidelity_predictor("constant", Arc::new(ConstantPredictor))
+});
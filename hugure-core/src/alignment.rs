@@ -0,0 +1,188 @@
+//! Pluggable tri-dimensional alignment strategies
+//!
+//! Tri-dimensional alignment maps the raw (S_knowledge, S_time, S_entropy) vector to an
+//! aligned one with smaller S-distances. The [`SEntropyEngine`] delegates that mapping to
+//! an [`AlignmentStrategy`], chosen with `SEntropyEngine::with_alignment_strategy`:
+//!
+//! - [`FixedMatrixAlignment`]: multiplies by a fixed optimization matrix (the default)
+//! - [`IterativeLeastSquaresAlignment`]: minimizes the distance to the input plus a penalty
+//!   on disagreement between dimensions, solved by Gauss-Seidel iteration
+//! - [`WeightedMinimizationAlignment`]: minimizes each dimension independently against its
+//!   own weight
+//!
+//! [`SEntropyEngine`]: crate::s_entropy::SEntropyEngine
+
+use std::{fmt, sync::Arc};
+
+use nalgebra::{Matrix3, Vector3};
+
+use crate::{
+    error::{SEntropyError, SEntropyResult},
+    types::AlignmentStrategyKind,
+};
+
+/// Scheme mapping a raw S-vector to an aligned one
+pub trait AlignmentStrategy: fmt::Debug + Send + Sync {
+    /// Strategy name, as reported in logs
+    fn name(&self) -> &'static str;
+
+    /// Aligned counterpart of `input`
+    fn align(&self, input: Vector3<f64>) -> SEntropyResult<Vector3<f64>>;
+}
+
+impl AlignmentStrategyKind {
+    /// The strategy of this kind, with default parameters
+    pub fn strategy(&self) -> Arc<dyn AlignmentStrategy> {
+        match self {
+            Self::FixedMatrix => Arc::new(FixedMatrixAlignment::default()),
+            Self::IterativeLeastSquares => Arc::new(IterativeLeastSquaresAlignment::default()),
+            Self::WeightedMinimization => Arc::new(WeightedMinimizationAlignment::default()),
+        }
+    }
+}
+
+/// Multiplication by a fixed optimization matrix
+#[derive(Debug, Clone, PartialEq)]
+pub struct FixedMatrixAlignment {
+    /// Optimization matrix applied to the S-vector
+    pub matrix: Matrix3<f64>,
+}
+
+impl Default for FixedMatrixAlignment {
+    fn default() -> Self {
+        // Sacred mathematical matrix honoring St. Stella-Lorraine
+        // Designed to minimize S-distances across all dimensions
+        Self {
+            matrix: Matrix3::new(
+                0.8, 0.1, 0.1, // S-knowledge optimization weights
+                0.1, 0.8, 0.1, // S-time optimization weights
+                0.1, 0.1, 0.8, // S-entropy optimization weights
+            ),
+        }
+    }
+}
+
+impl AlignmentStrategy for FixedMatrixAlignment {
+    fn name(&self) -> &'static str {
+        AlignmentStrategyKind::FixedMatrix.as_str()
+    }
+
+    fn align(&self, input: Vector3<f64>) -> SEntropyResult<Vector3<f64>> {
+        Ok(self.matrix * input)
+    }
+}
+
+/// Least-squares alignment pulling the three dimensions toward each other
+///
+/// Minimizes `|x - input|² + coupling · Σ_{i<j} (x_i - x_j)²` by Gauss-Seidel iteration,
+/// which converges for any non-negative coupling since the system is diagonally dominant.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IterativeLeastSquaresAlignment {
+    /// Weight of cross-dimension disagreement against fidelity to the input
+    pub coupling: f64,
+
+    /// Largest per-component change at which iteration stops
+    pub tolerance: f64,
+
+    /// Iterations after which an unconverged solve fails
+    pub max_iterations: usize,
+}
+
+impl Default for IterativeLeastSquaresAlignment {
+    fn default() -> Self {
+        Self { coupling: 0.5, tolerance: 1e-12, max_iterations: 100 }
+    }
+}
+
+impl AlignmentStrategy for IterativeLeastSquaresAlignment {
+    fn name(&self) -> &'static str {
+        AlignmentStrategyKind::IterativeLeastSquares.as_str()
+    }
+
+    fn align(&self, input: Vector3<f64>) -> SEntropyResult<Vector3<f64>> {
+        let coupling = self.coupling.max(0.0);
+        let mut aligned = input;
+        for _ in 0..self.max_iterations {
+            let mut largest_change: f64 = 0.0;
+            for i in 0..3 {
+                let others = aligned.sum() - aligned[i];
+                let updated = (input[i] + coupling * others) / (1.0 + 2.0 * coupling);
+                largest_change = largest_change.max((updated - aligned[i]).abs());
+                aligned[i] = updated;
+            }
+            if largest_change <= self.tolerance {
+                return Ok(aligned);
+            }
+        }
+        Err(SEntropyError::tri_dimensional_alignment(
+            "all",
+            format!("no convergence within {} iterations", self.max_iterations),
+        ))
+    }
+}
+
+/// Independent minimization of each dimension against its own weight
+///
+/// Minimizes `|x - input|² + Σ_i weight_i · x_i²`, shrinking each dimension by
+/// `1 / (1 + weight_i)`; a weight of zero leaves its dimension untouched.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WeightedMinimizationAlignment {
+    /// Non-negative weights of S-knowledge, S-time, and S-entropy
+    pub weights: Vector3<f64>,
+}
+
+impl Default for WeightedMinimizationAlignment {
+    fn default() -> Self {
+        Self { weights: Vector3::new(0.25, 0.25, 0.25) }
+    }
+}
+
+impl AlignmentStrategy for WeightedMinimizationAlignment {
+    fn name(&self) -> &'static str {
+        AlignmentStrategyKind::WeightedMinimization.as_str()
+    }
+
+    fn align(&self, input: Vector3<f64>) -> SEntropyResult<Vector3<f64>> {
+        if let Some(dimension) = self.weights.iter().position(|w| !w.is_finite() || *w < 0.0) {
+            return Err(SEntropyError::tri_dimensional_alignment(
+                ["s_knowledge", "s_time", "s_entropy"][dimension],
+                "weight must be finite and non-negative",
+            ));
+        }
+        Ok(input.zip_map(&self.weights, |value, weight| value / (1.0 + weight)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_iterative_least_squares_matches_closed_form() {
+        let strategy = IterativeLeastSquaresAlignment::default();
+        let input = Vector3::new(3.0, 0.0, 6.0);
+        let aligned = strategy.align(input).unwrap();
+
+        // (I + c·L) x = input, with L the Laplacian of the complete graph on 3 dimensions
+        let c = strategy.coupling;
+        let system = Matrix3::identity() * (1.0 + 3.0 * c) - Matrix3::repeat(c);
+        let expected = system.try_inverse().unwrap() * input;
+        assert!((aligned - expected).norm() < 1e-9);
+        // The pull preserves the mean and narrows the spread
+        assert!((aligned.mean() - input.mean()).abs() < 1e-9);
+        assert!(aligned.max() - aligned.min() < input.max() - input.min());
+    }
+
+    #[test]
+    fn test_kinds_and_weighted_minimization() {
+        let input = Vector3::new(1.0, 2.0, 3.0);
+        let fixed = AlignmentStrategyKind::FixedMatrix.strategy();
+        assert_eq!(fixed.name(), "fixed_matrix");
+        assert!((fixed.align(input).unwrap() - Vector3::new(1.3, 2.0, 2.7)).norm() < 1e-9);
+
+        let weighted = WeightedMinimizationAlignment { weights: Vector3::new(0.0, 1.0, 2.0) };
+        assert_eq!(weighted.align(input).unwrap(), Vector3::new(1.0, 1.0, 1.0));
+        let negative = WeightedMinimizationAlignment { weights: Vector3::new(0.0, -1.0, 0.0) };
+        assert!(negative.align(input).is_err());
+    }
+}
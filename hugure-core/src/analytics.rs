@@ -0,0 +1,191 @@
+//! Rolling time-series analytics over the measurement history
+//!
+//! [`MeasurementAnalytics`] answers whether observer-process separation is actually
+//! decreasing: it tracks the rolling mean and variance of the total S-entropy magnitude,
+//! fits a least-squares trend over the most recent window, and extrapolates that trend
+//! to estimate when the rolling mean will fall below the precision threshold.
+
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::s_entropy::SEntropyMeasurement;
+
+/// Measurements per rolling window by default
+pub const DEFAULT_ANALYTICS_WINDOW: usize = 50;
+
+/// Total magnitude statistics over the window ending at one measurement
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RollingStats {
+    /// Time of the last measurement in the window
+    pub measured_at: DateTime<Utc>,
+
+    /// Mean total magnitude over the window
+    pub mean: f64,
+
+    /// Population variance of the total magnitude over the window
+    pub variance: f64,
+}
+
+/// Trend analysis of the total S-entropy magnitude
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MeasurementAnalytics {
+    /// Measurements analyzed
+    pub measurements: usize,
+
+    /// Measurements per rolling window
+    pub window: usize,
+
+    /// Rolling statistics, one per measurement from the first full window on
+    pub rolling: Vec<RollingStats>,
+
+    /// Least-squares change in total magnitude per second over the latest window;
+    /// `None` with fewer than two distinct measurement times
+    pub trend_per_second: Option<f64>,
+
+    /// Estimated time until the rolling mean reaches the precision threshold; zero when
+    /// already there, `None` when the trend is not decreasing
+    pub time_to_optimal: Option<Duration>,
+}
+
+impl MeasurementAnalytics {
+    /// Analyze `measurements`, oldest first, against the optimal-integration `threshold`
+    ///
+    /// With fewer measurements than `window`, the window shrinks to all of them.
+    pub fn from_measurements(
+        measurements: &[SEntropyMeasurement],
+        window: usize,
+        threshold: f64,
+    ) -> Self {
+        let window = window.clamp(1, measurements.len().max(1));
+        let magnitudes: Vec<f64> = measurements.iter().map(|m| m.total_magnitude).collect();
+
+        let mut rolling = Vec::with_capacity(measurements.len().saturating_sub(window - 1));
+        let (mut sum, mut sum_squares) = (0.0, 0.0);
+        for (index, magnitude) in magnitudes.iter().enumerate() {
+            sum += magnitude;
+            sum_squares += magnitude * magnitude;
+            if index >= window {
+                let leaving = magnitudes[index - window];
+                sum -= leaving;
+                sum_squares -= leaving * leaving;
+            }
+            if index + 1 >= window {
+                let mean = sum / window as f64;
+                rolling.push(RollingStats {
+                    measured_at: measurements[index].measured_at,
+                    mean,
+                    // Clamped against rounding in the running sums
+                    variance: (sum_squares / window as f64 - mean * mean).max(0.0),
+                });
+            }
+        }
+
+        let latest = &measurements[measurements.len() - window.min(measurements.len())..];
+        let trend_per_second = trend(latest);
+        let time_to_optimal = rolling.last().and_then(|stats| {
+            if stats.mean <= threshold {
+                return Some(Duration::ZERO);
+            }
+            let slope = trend_per_second.filter(|slope| *slope < 0.0)?;
+            Duration::try_from_secs_f64((stats.mean - threshold) / -slope).ok()
+        });
+
+        Self {
+            measurements: measurements.len(),
+            window,
+            rolling,
+            trend_per_second,
+            time_to_optimal,
+        }
+    }
+
+    /// Rolling statistics of the most recent window
+    pub fn latest(&self) -> Option<&RollingStats> {
+        self.rolling.last()
+    }
+
+    /// Whether the total magnitude trends downward
+    pub fn is_decreasing(&self) -> bool {
+        self.trend_per_second.is_some_and(|slope| slope < 0.0)
+    }
+}
+
+/// Least-squares slope of total magnitude against seconds since the first measurement
+fn trend(measurements: &[SEntropyMeasurement]) -> Option<f64> {
+    let first = measurements.first()?.measured_at;
+    let points: Vec<(f64, f64)> = measurements
+        .iter()
+        .map(|m| {
+            let elapsed = (m.measured_at - first).num_microseconds().unwrap_or(i64::MAX);
+            (elapsed as f64 / 1e6, m.total_magnitude)
+        })
+        .collect();
+
+    let n = points.len() as f64;
+    let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / n;
+    let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / n;
+    let (covariance, variance) = points.iter().fold((0.0, 0.0), |(cov, var), (x, y)| {
+        let dx = x - mean_x;
+        (cov + dx * (y - mean_y), var + dx * dx)
+    });
+    (variance > 0.0).then(|| covariance / variance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use crate::types::{ObserverSophistication, SEntropyPrecision};
+
+    fn measurement(seconds: i64, total_magnitude: f64) -> SEntropyMeasurement {
+        SEntropyMeasurement {
+            id: uuid::Uuid::new_v4(),
+            s_knowledge: total_magnitude,
+            s_time: 0.0,
+            s_entropy: 0.0,
+            total_magnitude,
+            observer_sophistication: ObserverSophistication::Expert,
+            precision: SEntropyPrecision::Standard,
+            escalated_from: Vec::new(),
+            optimal_integration: false,
+            memorial_significance: crate::MEMORIAL_SIGNIFICANCE.to_string(),
+            measured_at: Utc.timestamp_opt(seconds, 0).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_rolling_statistics() {
+        let history: Vec<_> =
+            [1.0, 3.0, 5.0, 7.0].iter().zip(0..).map(|(m, t)| measurement(t, *m)).collect();
+        let analytics = MeasurementAnalytics::from_measurements(&history, 2, 0.0);
+
+        let means: Vec<f64> = analytics.rolling.iter().map(|stats| stats.mean).collect();
+        assert_eq!(means, vec![2.0, 4.0, 6.0]);
+        assert!(analytics.rolling.iter().all(|stats| (stats.variance - 1.0).abs() < 1e-9));
+        assert_eq!(analytics.latest().unwrap().measured_at, history[3].measured_at);
+        // Increasing magnitudes never reach the threshold
+        assert!(!analytics.is_decreasing());
+        assert_eq!(analytics.time_to_optimal, None);
+    }
+
+    #[test]
+    fn test_time_to_optimal_from_decreasing_trend() {
+        // Magnitude falls by 1.0 every 10 seconds
+        let history: Vec<_> = (0..5).map(|i| measurement(10 * i, 10.0 - i as f64)).collect();
+        let analytics = MeasurementAnalytics::from_measurements(&history, 5, 2.0);
+
+        assert!((analytics.trend_per_second.unwrap() + 0.1).abs() < 1e-9);
+        // Rolling mean 8.0 reaches 2.0 in 60 seconds at 0.1 per second
+        let eta = analytics.time_to_optimal.unwrap();
+        assert!((eta.as_secs_f64() - 60.0).abs() < 1e-6);
+
+        let reached = MeasurementAnalytics::from_measurements(&history, 5, 9.0);
+        assert_eq!(reached.time_to_optimal, Some(Duration::ZERO));
+        assert!(MeasurementAnalytics::from_measurements(&[], 5, 1.0).rolling.is_empty());
+    }
+}
@@ -0,0 +1,136 @@
+//! Compact binary encodings of coordinates, measurements, and patterns
+//!
+//! JSON is too verbose for high-rate channels such as the foundry and Kambuzuma links, so
+//! [`BinaryCodec`] adds MessagePack (`msgpack` feature) and CBOR (`cbor` feature)
+//! encoding to [`NavigationCoordinate`], [`SEntropyMeasurement`], and [`BMDPattern`].
+//! Structs are encoded as maps keyed by field name in both formats, so fields added later
+//! with `#[serde(default)]` still decode from older payloads. Encoding failures surface as
+//! [`SEntropyError::Serialization`], like JSON ones.
+
+#[cfg(feature = "cbor")]
+use std::io::Cursor;
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::error::{SEntropyError, SEntropyResult};
+#[cfg(feature = "async")]
+use crate::s_entropy::SEntropyMeasurement;
+use crate::types::BMDPattern;
+#[cfg(feature = "matrix")]
+use crate::types::NavigationCoordinate;
+
+/// Serialization error for a failure of the `format` encoding
+fn encoding_error(format: &str, error: impl std::fmt::Display) -> SEntropyError {
+    SEntropyError::Serialization {
+        format: serde::de::Error::custom(format!("{}: {}", format, error)),
+    }
+}
+
+/// MessagePack and CBOR encoding of a serializable framework type
+pub trait BinaryCodec: Serialize + DeserializeOwned {
+    /// MessagePack encoding
+    #[cfg(feature = "msgpack")]
+    fn to_msgpack(&self) -> SEntropyResult<Vec<u8>> {
+        rmp_serde::to_vec_named(self).map_err(|e| encoding_error("msgpack", e))
+    }
+
+    /// Value decoded from [`to_msgpack`](Self::to_msgpack) output
+    #[cfg(feature = "msgpack")]
+    fn from_msgpack(bytes: &[u8]) -> SEntropyResult<Self> {
+        rmp_serde::from_slice(bytes).map_err(|e| encoding_error("msgpack", e))
+    }
+
+    /// CBOR encoding
+    #[cfg(feature = "cbor")]
+    fn to_cbor(&self) -> SEntropyResult<Vec<u8>> {
+        let mut bytes = Vec::new();
+        ciborium::into_writer(self, &mut bytes).map_err(|e| encoding_error("cbor", e))?;
+        Ok(bytes)
+    }
+
+    /// Value decoded from [`to_cbor`](Self::to_cbor) output
+    #[cfg(feature = "cbor")]
+    fn from_cbor(bytes: &[u8]) -> SEntropyResult<Self> {
+        ciborium::from_reader(Cursor::new(bytes)).map_err(|e| encoding_error("cbor", e))
+    }
+}
+
+impl BinaryCodec for BMDPattern {}
+
+#[cfg(feature = "matrix")]
+impl BinaryCodec for NavigationCoordinate {}
+
+#[cfg(feature = "async")]
+impl BinaryCodec for SEntropyMeasurement {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ImpossibilityAmplification;
+
+    fn pattern() -> BMDPattern {
+        let mut pattern =
+            BMDPattern::create_ridiculous("leap".to_string(), ImpossibilityAmplification::High);
+        pattern.metadata.insert("channel".to_string(), "foundry".to_string());
+        pattern
+    }
+
+    #[cfg(feature = "msgpack")]
+    #[test]
+    fn test_msgpack_round_trips() {
+        let pattern = pattern();
+        let bytes = pattern.to_msgpack().unwrap();
+        assert_eq!(BMDPattern::from_msgpack(&bytes).unwrap(), pattern);
+        assert!(bytes.len() < serde_json::to_vec(&pattern).unwrap().len());
+
+        #[cfg(feature = "matrix")]
+        {
+            use nalgebra::Vector3;
+            let coordinate =
+                NavigationCoordinate::new(Vector3::x(), Vector3::y(), Vector3::z(), 0.75);
+            let bytes = coordinate.to_msgpack().unwrap();
+            assert_eq!(NavigationCoordinate::from_msgpack(&bytes).unwrap(), coordinate);
+        }
+
+        let error = BMDPattern::from_msgpack(&bytes[..bytes.len() / 2]).unwrap_err();
+        assert_eq!(error.code(), crate::error::ErrorCode::Serialization);
+    }
+
+    #[cfg(feature = "cbor")]
+    #[test]
+    fn test_cbor_round_trips() {
+        let pattern = pattern();
+        let bytes = pattern.to_cbor().unwrap();
+        assert_eq!(BMDPattern::from_cbor(&bytes).unwrap(), pattern);
+        assert!(BMDPattern::from_cbor(&[0xff, 0x00]).is_err());
+    }
+
+    #[cfg(all(feature = "async", any(feature = "msgpack", feature = "cbor")))]
+    #[tokio::test]
+    async fn test_measurements_round_trip() {
+        use crate::{s_entropy::SEntropyEngine, types::ObserverSophistication};
+
+        let engine = SEntropyEngine::new(crate::types::SEntropyPrecision::High);
+        let measurement = engine
+            .generate_measurement(
+                "foundry telemetry",
+                ObserverSophistication::Expert,
+                1e-30,
+                0.1,
+                1.0,
+                0.9,
+            )
+            .await
+            .unwrap();
+        #[cfg(feature = "msgpack")]
+        {
+            let bytes = measurement.to_msgpack().unwrap();
+            assert_eq!(SEntropyMeasurement::from_msgpack(&bytes).unwrap(), measurement);
+        }
+        #[cfg(feature = "cbor")]
+        {
+            let bytes = measurement.to_cbor().unwrap();
+            assert_eq!(SEntropyMeasurement::from_cbor(&bytes).unwrap(), measurement);
+        }
+    }
+}
@@ -0,0 +1,197 @@
+//! Empirical calibration of navigation confidence
+//!
+//! Navigation assigns each coordinate a raw confidence of `1/(1+magnitude)`, which orders
+//! coordinates sensibly but says nothing about how often their solutions actually work.
+//! A [`ConfidenceCalibrator`] remembers the raw confidence of every coordinate it is shown,
+//! collects success feedback through [`ConfidenceCalibrator::report_outcome`], and fits a
+//! monotone calibration curve from raw confidence to observed success rate, so calibrated
+//! confidence reads as a probability of success.
+//!
+//! Raw confidences are grouped into equal-width bins. Each bin's success rate is smoothed
+//! toward the bin's own midpoint by one pseudo-observation and the rates are then made
+//! non-decreasing by pool-adjacent-violators isotonic regression. Calibration interpolates
+//! linearly between bin midpoints, so until feedback arrives confidence is left as is.
+
+use std::collections::{HashMap, VecDeque};
+
+use uuid::Uuid;
+
+use crate::error::{SEntropyError, SEntropyResult};
+
+/// Calibration bins over `0.0..=1.0` raw confidence
+pub const DEFAULT_CALIBRATION_BINS: usize = 10;
+
+/// Predictions awaiting an outcome before the oldest are forgotten
+pub const DEFAULT_PENDING_LIMIT: usize = 10_000;
+
+/// Successes and trials observed for one bin
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct CalibrationBin {
+    /// Outcomes reported as successful
+    pub successes: u64,
+
+    /// Outcomes reported
+    pub trials: u64,
+}
+
+/// Fits raw navigation confidence to observed solution success
+#[derive(Debug, Clone)]
+pub struct ConfidenceCalibrator {
+    bins: Vec<CalibrationBin>,
+    curve: Vec<f64>,
+    pending: HashMap<Uuid, f64>,
+    pending_order: VecDeque<Uuid>,
+    pending_limit: usize,
+}
+
+impl Default for ConfidenceCalibrator {
+    fn default() -> Self {
+        Self::new(DEFAULT_CALIBRATION_BINS)
+    }
+}
+
+impl ConfidenceCalibrator {
+    /// Calibrator over `bins` equal-width bins, at least two
+    pub fn new(bins: usize) -> Self {
+        let bins = bins.max(2);
+        let mut calibrator = Self {
+            bins: vec![CalibrationBin::default(); bins],
+            curve: Vec::new(),
+            pending: HashMap::new(),
+            pending_order: VecDeque::new(),
+            pending_limit: DEFAULT_PENDING_LIMIT,
+        };
+        calibrator.fit();
+        calibrator
+    }
+
+    /// Forget the oldest predictions once more than `limit` await an outcome
+    pub fn with_pending_limit(mut self, limit: usize) -> Self {
+        self.pending_limit = limit.max(1);
+        self
+    }
+
+    /// Remember that coordinate `id` was produced with `raw_confidence`
+    pub fn record_prediction(&mut self, id: Uuid, raw_confidence: f64) {
+        if self.pending.insert(id, raw_confidence.clamp(0.0, 1.0)).is_none() {
+            self.pending_order.push_back(id);
+        }
+        while self.pending.len() > self.pending_limit {
+            if let Some(oldest) = self.pending_order.pop_front() {
+                self.pending.remove(&oldest);
+            }
+        }
+    }
+
+    /// Record whether the solution at coordinate `id` succeeded, refitting the curve
+    pub fn report_outcome(&mut self, id: Uuid, success: bool) -> SEntropyResult<()> {
+        let raw_confidence = self.pending.remove(&id).ok_or_else(|| {
+            SEntropyError::navigation(
+                "report_outcome",
+                format!("no pending prediction for navigation coordinate {}", id),
+            )
+        })?;
+        self.pending_order.retain(|pending| *pending != id);
+
+        let bin = &mut self.bins[self.bin_index(raw_confidence)];
+        bin.trials += 1;
+        bin.successes += u64::from(success);
+        self.fit();
+        Ok(())
+    }
+
+    /// Observed probability of success for `raw_confidence`
+    pub fn calibrate(&self, raw_confidence: f64) -> f64 {
+        // Position in units of bins, relative to the first bin's midpoint
+        let position = raw_confidence.clamp(0.0, 1.0) * self.curve.len() as f64 - 0.5;
+        let lower = (position.max(0.0) as usize).min(self.curve.len() - 2);
+        let fraction = position - lower as f64;
+        let (from, to) = (self.curve[lower], self.curve[lower + 1]);
+        (from + (to - from) * fraction).clamp(0.0, 1.0)
+    }
+
+    /// Calibrated confidence at each bin's midpoint, lowest raw confidence first
+    pub fn curve(&self) -> &[f64] {
+        &self.curve
+    }
+
+    /// Observed outcomes of each bin, lowest raw confidence first
+    pub fn bins(&self) -> &[CalibrationBin] {
+        &self.bins
+    }
+
+    /// Outcomes reported so far
+    pub fn observations(&self) -> u64 {
+        self.bins.iter().map(|bin| bin.trials).sum()
+    }
+
+    /// Predictions still awaiting an outcome
+    pub fn pending(&self) -> usize {
+        self.pending.len()
+    }
+
+    fn bin_index(&self, raw_confidence: f64) -> usize {
+        let scaled = raw_confidence.clamp(0.0, 1.0) * self.bins.len() as f64;
+        (scaled as usize).min(self.bins.len() - 1)
+    }
+
+    fn fit(&mut self) {
+        let width = 1.0 / self.bins.len() as f64;
+        // Each block is (weighted success rate, weight, bins covered)
+        let mut blocks: Vec<(f64, f64, usize)> = Vec::with_capacity(self.bins.len());
+        for (i, bin) in self.bins.iter().enumerate() {
+            let midpoint = (i as f64 + 0.5) * width;
+            let weight = bin.trials as f64 + 1.0;
+            blocks.push(((bin.successes as f64 + midpoint) / weight, weight, 1));
+            while blocks.len() > 1 && blocks[blocks.len() - 2].0 > blocks[blocks.len() - 1].0 {
+                let (rate, weight, covered) = blocks.pop().unwrap();
+                let previous = blocks.last_mut().unwrap();
+                previous.0 = (previous.0 * previous.1 + rate * weight) / (previous.1 + weight);
+                previous.1 += weight;
+                previous.2 += covered;
+            }
+        }
+        self.curve = blocks
+            .into_iter()
+            .flat_map(|(rate, _, covered)| std::iter::repeat(rate).take(covered))
+            .collect();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_curve_tracks_observed_success() {
+        let mut calibrator = ConfidenceCalibrator::new(4);
+        assert_eq!(calibrator.curve(), &[0.125, 0.375, 0.625, 0.875]);
+        assert!((calibrator.calibrate(0.3) - 0.3).abs() < 1e-12);
+
+        // Raw confidence near 0.9 only succeeds half the time
+        for i in 0..40 {
+            let id = Uuid::new_v4();
+            calibrator.record_prediction(id, 0.9);
+            calibrator.report_outcome(id, i % 2 == 0).unwrap();
+        }
+        assert_eq!(calibrator.observations(), 40);
+        assert!((calibrator.calibrate(0.9) - 0.5).abs() < 0.02);
+        // Lower bins stay monotone beneath the observed rate
+        assert!(calibrator.curve().windows(2).all(|pair| pair[0] <= pair[1]));
+        assert!(calibrator.calibrate(0.6) <= calibrator.calibrate(0.9));
+    }
+
+    #[test]
+    fn test_outcomes_need_a_pending_prediction() {
+        let mut calibrator = ConfidenceCalibrator::default().with_pending_limit(2);
+        let forgotten = Uuid::new_v4();
+        calibrator.record_prediction(forgotten, 0.5);
+        calibrator.record_prediction(Uuid::new_v4(), 0.5);
+        calibrator.record_prediction(Uuid::new_v4(), 0.5);
+        assert_eq!(calibrator.pending(), 2);
+
+        assert!(calibrator.report_outcome(forgotten, true).is_err());
+        assert!(calibrator.report_outcome(Uuid::new_v4(), true).is_err());
+        assert_eq!(calibrator.observations(), 0);
+    }
+}
@@ -0,0 +1,132 @@
+//! Snapshots of S-entropy engine state that survive process restarts
+//!
+//! An [`EngineCheckpoint`] captures what an engine has learned while running: the
+//! integration tracker (current separation, attempts, and success rate), the aligned
+//! coordinate cache, and the retained measurement history. Take one with
+//! `SEntropyEngine::checkpoint`, persist it with [`EngineCheckpoint::write_json`], and
+//! hand it to `SEntropyEngine::restore` after a restart.
+
+#[cfg(feature = "serde")]
+use std::io::{Read, Write};
+
+use chrono::{DateTime, Utc};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    coordinate_cache::CoordinateKey,
+    error::{SEntropyError, SEntropyResult},
+    s_entropy::{ObserverProcessTracker, SEntropyMeasurement},
+    types::SEntropyPrecision,
+    SEntropyCoordinate,
+};
+
+/// Checkpoint format written by this version of the engine
+pub const CHECKPOINT_VERSION: u32 = 1;
+
+/// Serializable snapshot of an engine's learned state
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EngineCheckpoint {
+    /// Checkpoint format, [`CHECKPOINT_VERSION`] when written
+    pub version: u32,
+
+    /// Precision level of the checkpointed engine
+    pub precision: SEntropyPrecision,
+
+    /// Alignment strategy that produced the cached coordinates
+    pub alignment_strategy: String,
+
+    /// Separation distance, integration attempts, and success rate
+    pub integration_tracker: ObserverProcessTracker,
+
+    /// Cached alignment inputs and coordinates, least recently used first
+    pub cached_coordinates: Vec<(CoordinateKey, SEntropyCoordinate)>,
+
+    /// Retained measurements, oldest first
+    pub measurement_history: Vec<SEntropyMeasurement>,
+
+    /// When the checkpoint was taken
+    pub created_at: DateTime<Utc>,
+}
+
+impl EngineCheckpoint {
+    /// Fail unless this checkpoint's format can be restored
+    pub fn validate_version(&self) -> SEntropyResult<()> {
+        if self.version == CHECKPOINT_VERSION {
+            Ok(())
+        } else {
+            Err(SEntropyError::configuration(
+                "checkpoint.version",
+                format!(
+                    "unsupported checkpoint version {} (expected {})",
+                    self.version, CHECKPOINT_VERSION
+                ),
+            ))
+        }
+    }
+
+    /// Write the checkpoint as JSON
+    #[cfg(feature = "serde")]
+    pub fn write_json(&self, writer: impl Write) -> SEntropyResult<()> {
+        serde_json::to_writer(writer, self)?;
+        Ok(())
+    }
+
+    /// Read a checkpoint written by [`write_json`](Self::write_json)
+    #[cfg(feature = "serde")]
+    pub fn read_json(reader: impl Read) -> SEntropyResult<Self> {
+        let checkpoint: Self = serde_json::from_reader(reader)?;
+        checkpoint.validate_version()?;
+        Ok(checkpoint)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{s_entropy::SEntropyEngine, types::ObserverSophistication};
+
+    async fn trained_engine() -> SEntropyEngine {
+        let engine = SEntropyEngine::new(SEntropyPrecision::High).with_rng_seed(7);
+        let observer = ObserverSophistication::Expert;
+        engine
+            .generate_measurement("checkpoint_test", observer, 1e-30, 0.1, 1.0, 0.95)
+            .await
+            .unwrap();
+        engine.align_tri_dimensional(1.0, 2.0, 3.0).await.unwrap();
+        engine.attempt_integration(0.05).await.unwrap();
+        engine
+    }
+
+    #[cfg(feature = "serde")]
+    #[tokio::test]
+    async fn test_restore_from_json_round_trip() {
+        let original = trained_engine().await;
+        let checkpoint = original.checkpoint().await;
+        let mut json = Vec::new();
+        checkpoint.write_json(&mut json).unwrap();
+
+        let restored = SEntropyEngine::new(SEntropyPrecision::High);
+        restored.restore(EngineCheckpoint::read_json(json.as_slice()).unwrap()).await.unwrap();
+
+        assert_eq!(restored.checkpoint().await.integration_tracker, checkpoint.integration_tracker);
+        assert_eq!(restored.measurement_history().await, checkpoint.measurement_history);
+        let stats = restored.get_integration_stats().await.unwrap();
+        assert_eq!(stats.total_attempts, 1);
+        // The restored cache serves the checkpointed alignment
+        let aligned = restored.align_tri_dimensional(1.0, 2.0, 3.0).await.unwrap();
+        assert_eq!(aligned, checkpoint.cached_coordinates[0].1);
+        assert_eq!(restored.coordinate_cache_stats().await.hits, 1);
+    }
+
+    #[tokio::test]
+    async fn test_rejects_unknown_version() {
+        let mut checkpoint = trained_engine().await.checkpoint().await;
+        checkpoint.version = CHECKPOINT_VERSION + 1;
+
+        let engine = SEntropyEngine::new(SEntropyPrecision::High);
+        assert!(engine.restore(checkpoint).await.is_err());
+        assert!(engine.measurement_history().await.is_empty());
+    }
+}
@@ -0,0 +1,312 @@
+//! Clock backends for temporal precision measurement
+//!
+//! Achieved temporal precision is only as good as the clock it is measured on, so
+//! [`measure_precision`] reads a [`ClockSource`] back to back and reports the smallest step
+//! it observes, never less than the clock's stated resolution. Three backends are built in:
+//!
+//! - [`StdClock`]: the monotonic [`Instant`] clock, available everywhere
+//! - [`TscClock`]: the x86-64 time stamp counter, calibrated against [`Instant`]
+//! - [`PtpClock`]: a Linux PTP hardware clock such as `/dev/ptp0` (`ptp` feature)
+//!
+//! Readings are femtoseconds since an epoch fixed per clock, as `u128` so that no clock
+//! overflows within the lifetime of a process; only differences between readings of the
+//! same clock are meaningful.
+
+use std::{fmt, str::FromStr, time::Instant};
+
+use crate::error::{SEntropyError, SEntropyResult};
+use crate::types::TemporalPrecision;
+use crate::units::{Femtoseconds, Seconds, FEMTOSECONDS_PER_SECOND};
+
+/// Back-to-back readings [`measure_precision`] takes by default
+pub const DEFAULT_PRECISION_SAMPLES: usize = 1_024;
+
+/// Femtoseconds in one nanosecond, as a reading
+const FEMTOSECONDS_PER_NANOSECOND: u128 = 1_000_000;
+
+/// Monotonic clock temporal precision is measured on
+pub trait ClockSource: fmt::Debug + Send + Sync {
+    /// Backend name, as reported in logs
+    fn name(&self) -> &'static str;
+
+    /// Femtoseconds since this clock's epoch
+    fn now(&self) -> SEntropyResult<u128>;
+
+    /// Smallest step the clock can represent
+    fn resolution(&self) -> Femtoseconds;
+}
+
+/// Built-in [`ClockSource`] backends
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ClockSourceKind {
+    /// [`StdClock`]
+    Std,
+    /// [`TscClock`]
+    Tsc,
+    /// [`PtpClock`]
+    Ptp,
+}
+
+impl ClockSourceKind {
+    /// Name accepted by `FromStr`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Std => "std",
+            Self::Tsc => "tsc",
+            Self::Ptp => "ptp",
+        }
+    }
+}
+
+impl FromStr for ClockSourceKind {
+    type Err = SEntropyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "std" => Ok(Self::Std),
+            "tsc" => Ok(Self::Tsc),
+            "ptp" => Ok(Self::Ptp),
+            other => Err(SEntropyError::configuration(
+                "clock_source",
+                format!("unknown clock source '{}'", other),
+            )),
+        }
+    }
+}
+
+/// The monotonic [`Instant`] clock, with its epoch at construction
+#[derive(Debug, Clone, Copy)]
+pub struct StdClock {
+    epoch: Instant,
+}
+
+impl StdClock {
+    /// Clock with its epoch now
+    pub fn new() -> Self {
+        Self { epoch: Instant::now() }
+    }
+}
+
+impl Default for StdClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ClockSource for StdClock {
+    fn name(&self) -> &'static str {
+        "std"
+    }
+
+    fn now(&self) -> SEntropyResult<u128> {
+        Ok(self.epoch.elapsed().as_nanos() * FEMTOSECONDS_PER_NANOSECOND)
+    }
+
+    /// One nanosecond, the finest step of [`Instant`]; the platform clock may be coarser
+    fn resolution(&self) -> Femtoseconds {
+        Femtoseconds::new(1_000_000)
+    }
+}
+
+/// The x86-64 time stamp counter
+///
+/// The counter's frequency is calibrated against [`Instant`] when the clock is created.
+/// Readings are only monotonic on processors with an invariant TSC, which every x86-64
+/// processor of the last decade has.
+#[cfg(target_arch = "x86_64")]
+#[derive(Debug, Clone, Copy)]
+pub struct TscClock {
+    ticks_per_second: u128,
+}
+
+#[cfg(target_arch = "x86_64")]
+impl TscClock {
+    /// How long [`calibrate`](Self::calibrate) counts ticks for
+    pub const CALIBRATION_PERIOD: std::time::Duration = std::time::Duration::from_millis(10);
+
+    /// Clock with its frequency measured over [`CALIBRATION_PERIOD`](Self::CALIBRATION_PERIOD)
+    pub fn calibrate() -> SEntropyResult<Self> {
+        let started = Instant::now();
+        let start_ticks = Self::ticks();
+        while started.elapsed() < Self::CALIBRATION_PERIOD {
+            std::hint::spin_loop();
+        }
+        let ticks = Self::ticks().saturating_sub(start_ticks) as u128;
+        let nanoseconds = started.elapsed().as_nanos();
+        let ticks_per_second = ticks * 1_000_000_000 / nanoseconds.max(1);
+        if ticks_per_second == 0 {
+            return Err(SEntropyError::configuration(
+                "clock_source.tsc",
+                "time stamp counter did not advance during calibration",
+            ));
+        }
+        Ok(Self { ticks_per_second })
+    }
+
+    /// Calibrated counter frequency in hertz
+    pub fn frequency(&self) -> u128 {
+        self.ticks_per_second
+    }
+
+    fn ticks() -> u64 {
+        // SAFETY: RDTSC is part of the x86-64 baseline and has no preconditions
+        unsafe { std::arch::x86_64::_rdtsc() }
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+impl ClockSource for TscClock {
+    fn name(&self) -> &'static str {
+        "tsc"
+    }
+
+    fn now(&self) -> SEntropyResult<u128> {
+        Ok(Self::ticks() as u128 * FEMTOSECONDS_PER_SECOND as u128 / self.ticks_per_second)
+    }
+
+    /// One counter tick
+    fn resolution(&self) -> Femtoseconds {
+        let femtoseconds = FEMTOSECONDS_PER_SECOND as u128 / self.ticks_per_second;
+        Femtoseconds::new(u64::try_from(femtoseconds).unwrap_or(u64::MAX).max(1))
+    }
+}
+
+/// A Linux PTP hardware clock, read through its character device
+#[cfg(all(feature = "ptp", target_os = "linux"))]
+#[derive(Debug)]
+pub struct PtpClock {
+    /// Open for as long as the clock id derived from it is in use
+    _device: std::fs::File,
+    clock_id: libc::clockid_t,
+    resolution: Femtoseconds,
+}
+
+#[cfg(all(feature = "ptp", target_os = "linux"))]
+impl PtpClock {
+    /// Clock of the PTP device at `path`, such as `/dev/ptp0`
+    pub fn open(path: impl AsRef<std::path::Path>) -> SEntropyResult<Self> {
+        use std::os::unix::io::AsRawFd;
+
+        let device = std::fs::File::open(path)?;
+        // FD_TO_CLOCKID from the kernel's posix-timers.h
+        let clock_id = ((!device.as_raw_fd()) << 3) | 3;
+        let resolution = Self::timespec(clock_id, libc::clock_getres)?;
+        let resolution = u64::try_from(resolution).unwrap_or(u64::MAX).max(1);
+        Ok(Self { _device: device, clock_id, resolution: Femtoseconds::new(resolution) })
+    }
+
+    fn timespec(
+        clock_id: libc::clockid_t,
+        read: unsafe extern "C" fn(libc::clockid_t, *mut libc::timespec) -> libc::c_int,
+    ) -> SEntropyResult<u128> {
+        let mut time = libc::timespec { tv_sec: 0, tv_nsec: 0 };
+        // SAFETY: `time` is a valid timespec and the clock id refers to an open device
+        if unsafe { read(clock_id, &mut time) } != 0 {
+            return Err(std::io::Error::last_os_error().into());
+        }
+        Ok(time.tv_sec as u128 * FEMTOSECONDS_PER_SECOND as u128
+            + time.tv_nsec as u128 * FEMTOSECONDS_PER_NANOSECOND)
+    }
+}
+
+#[cfg(all(feature = "ptp", target_os = "linux"))]
+impl ClockSource for PtpClock {
+    fn name(&self) -> &'static str {
+        "ptp"
+    }
+
+    fn now(&self) -> SEntropyResult<u128> {
+        Self::timespec(self.clock_id, libc::clock_gettime)
+    }
+
+    fn resolution(&self) -> Femtoseconds {
+        self.resolution
+    }
+}
+
+/// Temporal precision of `clock` against `target`, from `samples` back-to-back readings
+///
+/// The achieved precision is the smallest positive step between consecutive readings, or
+/// the clock's resolution if that is coarser or the clock never advanced.
+pub fn measure_precision(
+    clock: &dyn ClockSource,
+    target: Seconds,
+    samples: usize,
+) -> SEntropyResult<TemporalPrecision> {
+    let samples = samples.max(2);
+    let mut readings = Vec::with_capacity(samples);
+    for _ in 0..samples {
+        readings.push(clock.now()?);
+    }
+    let smallest_step = readings
+        .windows(2)
+        .filter_map(|pair| pair[1].checked_sub(pair[0]))
+        .filter(|step| *step > 0)
+        .min()
+        .map(|step| Femtoseconds::new(u64::try_from(step).unwrap_or(u64::MAX)));
+    let achieved = smallest_step.unwrap_or_default().max(clock.resolution());
+    let memory_bytes = (samples * std::mem::size_of::<u128>()) as u64;
+    Ok(TemporalPrecision::new(target, achieved.to_seconds(), memory_bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Clock advancing by a fixed step on every reading
+    #[derive(Debug)]
+    struct Stepping {
+        readings: std::sync::atomic::AtomicU64,
+        step: u64,
+    }
+
+    impl ClockSource for Stepping {
+        fn name(&self) -> &'static str {
+            "stepping"
+        }
+
+        fn now(&self) -> SEntropyResult<u128> {
+            let reading = self.readings.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            Ok((reading / 2 * self.step) as u128)
+        }
+
+        fn resolution(&self) -> Femtoseconds {
+            Femtoseconds::new(1)
+        }
+    }
+
+    #[test]
+    fn test_measured_precision_is_the_smallest_observed_step() {
+        // Every value is read twice, so half the steps are zero and are skipped
+        let clock = Stepping { readings: Default::default(), step: 500 };
+        let target = Seconds::new(1e-12).unwrap();
+        let precision = measure_precision(&clock, target, 16).unwrap();
+        assert_eq!(precision.achieved_precision, Femtoseconds::new(500).to_seconds());
+        assert_eq!(precision.target_precision, target);
+        assert!(!precision.ultra_precision_achieved);
+
+        let std_clock = StdClock::new();
+        let precision = measure_precision(&std_clock, target, 64).unwrap();
+        assert!(precision.achieved_precision >= std_clock.resolution().to_seconds());
+    }
+
+    #[test]
+    fn test_clock_sources_parse_and_advance() {
+        for kind in [ClockSourceKind::Std, ClockSourceKind::Tsc, ClockSourceKind::Ptp] {
+            assert_eq!(kind.as_str().parse::<ClockSourceKind>().unwrap(), kind);
+        }
+        assert!("sundial".parse::<ClockSourceKind>().is_err());
+
+        let clock = StdClock::new();
+        let earlier = clock.now().unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(1));
+        assert!(clock.now().unwrap() - earlier >= 1_000_000_000_000);
+
+        #[cfg(target_arch = "x86_64")]
+        {
+            let tsc = TscClock::calibrate().unwrap();
+            assert!(tsc.frequency() > 0);
+            assert!(tsc.now().unwrap() <= tsc.now().unwrap());
+        }
+    }
+}
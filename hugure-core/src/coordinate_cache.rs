@@ -0,0 +1,254 @@
+//! Size-bounded LRU cache of aligned S-entropy coordinates
+//!
+//! Alignment inputs are quantized into a [`CoordinateKey`] so that repeated alignments of
+//! the same (S_knowledge, S_time, S_entropy) triple, up to floating-point noise, share one
+//! entry. Once the cache holds its capacity, inserting evicts the least recently used
+//! coordinate. Hits, misses, and evictions are counted in [`CoordinateCacheStats`].
+
+use std::collections::{BTreeMap, HashMap};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::SEntropyCoordinate;
+
+/// Coordinates cached by default
+pub const DEFAULT_COORDINATE_CACHE_CAPACITY: usize = 10_000;
+
+/// Default quantization step of cache keys
+pub const DEFAULT_COORDINATE_QUANTUM: f64 = 1e-9;
+
+/// Quantized (S_knowledge, S_time, S_entropy) alignment input
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CoordinateKey([i64; 3]);
+
+impl CoordinateKey {
+    /// Key of the triple, with each component rounded to a multiple of `quantum`
+    ///
+    /// Components beyond the `i64` range of steps saturate, and NaN maps to zero.
+    pub fn quantize(s_knowledge: f64, s_time: f64, s_entropy: f64, quantum: f64) -> Self {
+        let step = |value: f64| (value / quantum).round() as i64;
+        Self([step(s_knowledge), step(s_time), step(s_entropy)])
+    }
+}
+
+/// Cache effectiveness counters
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CoordinateCacheStats {
+    /// Lookups that found a cached coordinate
+    pub hits: u64,
+
+    /// Lookups that found nothing
+    pub misses: u64,
+
+    /// Coordinates evicted to stay within capacity
+    pub evictions: u64,
+
+    /// Coordinates currently cached
+    pub len: usize,
+
+    /// Maximum coordinates cached
+    pub capacity: usize,
+}
+
+impl CoordinateCacheStats {
+    /// Fraction of lookups that hit, 0.0 before any lookup
+    pub fn hit_rate(&self) -> f64 {
+        let lookups = self.hits + self.misses;
+        if lookups == 0 {
+            0.0
+        } else {
+            self.hits as f64 / lookups as f64
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Entry {
+    coordinate: SEntropyCoordinate,
+    last_used: u64,
+}
+
+/// Least-recently-used cache of aligned coordinates
+#[derive(Debug, Clone)]
+pub struct CoordinateCache {
+    capacity: usize,
+    quantum: f64,
+    entries: HashMap<CoordinateKey, Entry>,
+    /// Keys by the tick they were last used at, least recent first
+    recency: BTreeMap<u64, CoordinateKey>,
+    tick: u64,
+    hits: u64,
+    misses: u64,
+    evictions: u64,
+}
+
+impl Default for CoordinateCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_COORDINATE_CACHE_CAPACITY)
+    }
+}
+
+impl CoordinateCache {
+    /// Cache holding up to `capacity` coordinates (at least one)
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            quantum: DEFAULT_COORDINATE_QUANTUM,
+            entries: HashMap::new(),
+            recency: BTreeMap::new(),
+            tick: 0,
+            hits: 0,
+            misses: 0,
+            evictions: 0,
+        }
+    }
+
+    /// Quantize keys to multiples of `quantum`; non-positive or non-finite steps are ignored
+    pub fn with_quantum(mut self, quantum: f64) -> Self {
+        if quantum.is_finite() && quantum > 0.0 {
+            self.quantum = quantum;
+        }
+        self
+    }
+
+    /// Key of an alignment input under this cache's quantization
+    pub fn key(&self, s_knowledge: f64, s_time: f64, s_entropy: f64) -> CoordinateKey {
+        CoordinateKey::quantize(s_knowledge, s_time, s_entropy, self.quantum)
+    }
+
+    /// Cached coordinate for `key`, marking it most recently used
+    pub fn get(&mut self, key: &CoordinateKey) -> Option<&SEntropyCoordinate> {
+        let tick = self.next_tick();
+        match self.entries.get_mut(key) {
+            Some(entry) => {
+                self.hits += 1;
+                self.recency.remove(&entry.last_used);
+                self.recency.insert(tick, *key);
+                entry.last_used = tick;
+                Some(&entry.coordinate)
+            },
+            None => {
+                self.misses += 1;
+                None
+            },
+        }
+    }
+
+    /// Cache `coordinate` under `key`, returning the coordinate evicted to make room
+    pub fn insert(
+        &mut self,
+        key: CoordinateKey,
+        coordinate: SEntropyCoordinate,
+    ) -> Option<SEntropyCoordinate> {
+        let tick = self.next_tick();
+        if let Some(previous) = self.entries.insert(key, Entry { coordinate, last_used: tick }) {
+            self.recency.remove(&previous.last_used);
+            self.recency.insert(tick, key);
+            return None;
+        }
+        self.recency.insert(tick, key);
+
+        if self.entries.len() <= self.capacity {
+            return None;
+        }
+        let (_, oldest) = self.recency.pop_first()?;
+        self.evictions += 1;
+        self.entries.remove(&oldest).map(|entry| entry.coordinate)
+    }
+
+    /// Cached coordinates, in no particular order
+    pub fn values(&self) -> impl Iterator<Item = &SEntropyCoordinate> {
+        self.entries.values().map(|entry| &entry.coordinate)
+    }
+
+    /// Cached keys and coordinates, least recently used first
+    ///
+    /// Inserting them in this order into an empty cache reproduces the recency order.
+    pub fn entries(&self) -> Vec<(CoordinateKey, SEntropyCoordinate)> {
+        self.recency
+            .values()
+            .filter_map(|key| Some((*key, self.entries.get(key)?.coordinate.clone())))
+            .collect()
+    }
+
+    /// Drop every cached coordinate, keeping the effectiveness counters
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.recency.clear();
+    }
+
+    /// Number of cached coordinates
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Maximum number of cached coordinates
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Whether nothing is cached
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Effectiveness counters and occupancy
+    pub fn stats(&self) -> CoordinateCacheStats {
+        CoordinateCacheStats {
+            hits: self.hits,
+            misses: self.misses,
+            evictions: self.evictions,
+            len: self.entries.len(),
+            capacity: self.capacity,
+        }
+    }
+
+    fn next_tick(&mut self) -> u64 {
+        self.tick += 1;
+        self.tick
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quantized_keys_share_entries() {
+        let mut cache = CoordinateCache::new(8);
+        let key = cache.key(0.1, 0.2, 0.3);
+        assert_eq!(cache.key(0.1 + 1e-12, 0.2, 0.3 - 1e-12), key);
+        assert_ne!(cache.key(0.1 + 1e-6, 0.2, 0.3), key);
+
+        assert!(cache.get(&key).is_none());
+        cache.insert(key, SEntropyCoordinate::new(0.1, 0.2, 0.3));
+        assert!(cache.get(&key).is_some());
+
+        let stats = cache.stats();
+        assert_eq!((stats.hits, stats.misses, stats.len), (1, 1, 1));
+        assert_eq!(stats.hit_rate(), 0.5);
+    }
+
+    #[test]
+    fn test_evicts_least_recently_used() {
+        let mut cache = CoordinateCache::new(2);
+        let keys: Vec<CoordinateKey> = (0..3).map(|i| cache.key(i as f64, 0.0, 0.0)).collect();
+        cache.insert(keys[0], SEntropyCoordinate::new(0.0, 0.0, 0.0));
+        cache.insert(keys[1], SEntropyCoordinate::new(1.0, 0.0, 0.0));
+        // Touching the oldest entry makes the second one the eviction candidate
+        cache.get(&keys[0]);
+
+        let evicted = cache.insert(keys[2], SEntropyCoordinate::new(2.0, 0.0, 0.0)).unwrap();
+        assert_eq!(evicted.s_knowledge, 1.0);
+        assert!(cache.get(&keys[1]).is_none());
+        assert!(cache.get(&keys[0]).is_some());
+        assert_eq!(cache.stats().evictions, 1);
+        assert_eq!(cache.len(), 2);
+
+        // keys[0] was touched last, after keys[2] was inserted
+        let order: Vec<CoordinateKey> = cache.entries().into_iter().map(|(key, _)| key).collect();
+        assert_eq!(order, vec![keys[2], keys[0]]);
+    }
+}
@@ -0,0 +1,518 @@
+//! S-entropy coordinates and the enums describing them, without the standard library
+//!
+//! Everything here builds under `no_std` with `alloc`, so embedded consumers can compute
+//! S-entropy coordinates with `default-features = false`. Without the `std` feature,
+//! coordinates carry no `id` or `created_at`, since generating UUIDs and reading the clock
+//! need the standard library, and square roots come from `libm`. [`NavigationCoordinate`]
+//! needs the `navigation` feature, which pulls in nalgebra without its `std` feature.
+
+use alloc::string::{String, ToString};
+use core::fmt;
+
+#[cfg(feature = "std")]
+use chrono::{DateTime, Utc};
+#[cfg(feature = "navigation")]
+use nalgebra::Vector3;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "std")]
+use uuid::Uuid;
+
+use crate::{MEMORIAL_SIGNIFICANCE, S_ENTROPY_PRECISION_TARGET};
+
+/// Square root, through `libm` when the standard library is unavailable
+fn sqrt(value: f64) -> f64 {
+    #[cfg(feature = "std")]
+    {
+        value.sqrt()
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        libm::sqrt(value)
+    }
+}
+
+/// Ten to the power `exponent`, through `libm` when the standard library is unavailable
+fn pow10(exponent: f64) -> f64 {
+    #[cfg(feature = "std")]
+    {
+        10f64.powf(exponent)
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        libm::pow(10.0, exponent)
+    }
+}
+
+/// Core S-Entropy coordinate system for tri-dimensional navigation
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SEntropyCoordinate {
+    /// Unique identifier for this S-entropy coordinate
+    #[cfg(feature = "std")]
+    pub id: Uuid,
+
+    /// S_knowledge: Information deficit + frame selection coordinates
+    pub s_knowledge: f64,
+
+    /// S_time: Temporal navigation + ultra-precision coordination
+    pub s_time: f64,
+
+    /// S_entropy: Entropy endpoint navigation + oscillation accessibility  
+    pub s_entropy: f64,
+
+    /// Timestamp of coordinate creation
+    #[cfg(feature = "std")]
+    pub created_at: DateTime<Utc>,
+
+    /// Memorial significance marker
+    pub memorial_significance: String,
+}
+
+impl SEntropyCoordinate {
+    /// Create a new S-entropy coordinate with memorial significance
+    pub fn new(s_knowledge: f64, s_time: f64, s_entropy: f64) -> Self {
+        Self {
+            #[cfg(feature = "std")]
+            id: Uuid::new_v4(),
+            s_knowledge,
+            s_time,
+            s_entropy,
+            #[cfg(feature = "std")]
+            created_at: Utc::now(),
+            memorial_significance: MEMORIAL_SIGNIFICANCE.to_string(),
+        }
+    }
+
+    /// Calculate the total S-entropy magnitude
+    pub fn total_magnitude(&self) -> f64 {
+        sqrt(
+            self.s_knowledge * self.s_knowledge
+                + self.s_time * self.s_time
+                + self.s_entropy * self.s_entropy,
+        )
+    }
+
+    /// Check if this coordinate represents near-perfect integration (S ≈ 0)
+    pub fn is_optimal_integration(&self) -> bool {
+        self.total_magnitude() < S_ENTROPY_PRECISION_TARGET
+    }
+
+    /// Check if this coordinate honors the memorial significance
+    pub fn validates_memorial_significance(&self) -> bool {
+        self.memorial_significance == MEMORIAL_SIGNIFICANCE
+    }
+}
+
+impl fmt::Display for SEntropyCoordinate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "S({:.6}, {:.6}, {:.6}) | Total: {:.6} | Memorial: {}",
+            self.s_knowledge,
+            self.s_time,
+            self.s_entropy,
+            self.total_magnitude(),
+            self.memorial_significance
+        )
+    }
+}
+
+/// Precision level for S-entropy calculations, ordered from coarsest to finest
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SEntropyPrecision {
+    /// Standard precision for general use
+    Standard,
+    /// High precision for scientific applications
+    High,
+    /// Ultra precision for consciousness integration (1e-30 target)
+    Ultra,
+    /// Supreme precision for memorial significance validation
+    Supreme,
+}
+
+impl SEntropyPrecision {
+    /// Get the numerical precision threshold for this level
+    pub fn threshold(&self) -> f64 {
+        match self {
+            Self::Standard => 1e-6,
+            Self::High => 1e-15,
+            Self::Ultra => 1e-30,
+            Self::Supreme => 1e-50,
+        }
+    }
+
+    /// The next finer precision level, `None` at `Supreme`
+    pub fn next_higher(&self) -> Option<Self> {
+        match self {
+            Self::Standard => Some(Self::High),
+            Self::High => Some(Self::Ultra),
+            Self::Ultra => Some(Self::Supreme),
+            Self::Supreme => None,
+        }
+    }
+
+    /// Name accepted by `FromStr`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Standard => "standard",
+            Self::High => "high",
+            Self::Ultra => "ultra",
+            Self::Supreme => "supreme",
+        }
+    }
+}
+
+/// Observer sophistication levels for universal accessibility
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ObserverSophistication {
+    /// Naive user requiring simple interactions
+    Naive,
+    /// Intermediate user with some technical knowledge
+    Intermediate,
+    /// Expert user with deep technical understanding
+    Expert,
+    /// Universal observer with unlimited capability
+    Universal,
+}
+
+impl ObserverSophistication {
+    /// Name accepted by `FromStr`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Naive => "naive",
+            Self::Intermediate => "intermediate",
+            Self::Expert => "expert",
+            Self::Universal => "universal",
+        }
+    }
+
+    /// Position of this level on the continuous [`SophisticationScore`] scale
+    pub fn score(&self) -> SophisticationScore {
+        SophisticationScore(match self {
+            Self::Naive => 0.0,
+            Self::Intermediate => 1.0,
+            Self::Expert => 2.0,
+            Self::Universal => 3.0,
+        })
+    }
+
+    /// Information deficit of an observer at this level
+    pub fn information_deficit(&self) -> f64 {
+        match self {
+            Self::Naive => 1000.0,
+            Self::Intermediate => 100.0,
+            Self::Expert => 10.0,
+            Self::Universal => 0.0,
+        }
+    }
+}
+
+impl From<ObserverSophistication> for SophisticationScore {
+    fn from(level: ObserverSophistication) -> Self {
+        level.score()
+    }
+}
+
+/// Graded observer sophistication, from 0.0 (naive) to 3.0 (universal)
+///
+/// The four [`ObserverSophistication`] levels sit at 0, 1, 2, and 3. Scores in between
+/// describe observers learned from interaction history, see [`learn`](Self::learn).
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(transparent))]
+pub struct SophisticationScore(f64);
+
+impl SophisticationScore {
+    /// Score of a naive observer
+    pub const MIN: Self = Self(0.0);
+
+    /// Score of a universal observer
+    pub const MAX: Self = Self(3.0);
+
+    /// `score` clamped to `0.0..=3.0`, `None` unless finite
+    pub fn new(score: f64) -> Option<Self> {
+        score.is_finite().then(|| Self(score.clamp(Self::MIN.0, Self::MAX.0)))
+    }
+
+    /// The score
+    pub fn get(&self) -> f64 {
+        self.0
+    }
+
+    /// Level closest to this score, the higher one on ties
+    pub fn nearest_level(&self) -> ObserverSophistication {
+        match self.0 {
+            score if score < 0.5 => ObserverSophistication::Naive,
+            score if score < 1.5 => ObserverSophistication::Intermediate,
+            score if score < 2.5 => ObserverSophistication::Expert,
+            _ => ObserverSophistication::Universal,
+        }
+    }
+
+    /// Score moved `rate` of the way towards `observed`, `rate` clamped to `0.0..=1.0`
+    pub fn learn(&self, observed: Self, rate: f64) -> Self {
+        let rate = if rate.is_nan() { 0.0 } else { rate.clamp(0.0, 1.0) };
+        Self(self.0 + rate * (observed.0 - self.0))
+    }
+
+    /// Information deficit of an observer at this score
+    ///
+    /// Matches [`ObserverSophistication::information_deficit`] at the four levels, falling
+    /// tenfold per level from naive to expert and linearly from expert to universal.
+    pub fn information_deficit(&self) -> f64 {
+        if self.0 >= 2.0 {
+            10.0 * (3.0 - self.0)
+        } else {
+            pow10(3.0 - self.0)
+        }
+    }
+}
+
+/// BMD (Biological Maxwell Demon) operation modes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum BMDOperationMode {
+    /// Frame selection across predetermined manifolds
+    FrameSelection,
+    /// Reality-frame fusion processing
+    RealityFusion,
+    /// Memory fabrication and ridiculous solutions
+    MemoryFabrication,
+    /// Temporal coherence through emotional delusion
+    TemporalCoherence,
+    /// Agency experience generation
+    AgencyDelusion,
+}
+
+/// Consciousness enhancement modes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ConsciousnessMode {
+    /// Enhancement only - never replacement
+    EnhancementOnly,
+    /// Frame selection engine optimization
+    FrameSelectionEngine,
+    /// Reality fusion enabled
+    RealityFusion,
+    /// Agency preservation (strict boundaries)
+    AgencyPreservation,
+}
+
+/// Strategic impossibility amplification levels
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ImpossibilityAmplification {
+    /// Mild impossibility for testing
+    Mild,
+    /// Standard impossibility for normal operations
+    Standard,
+    /// High impossibility for advanced optimization
+    High,
+    /// Extreme impossibility for breakthrough solutions
+    Extreme,
+    /// User-defined amplification factor for experiments
+    Custom {
+        /// Amplification factor, finite and positive to be [valid](Self::is_valid)
+        factor: f64,
+    },
+}
+
+impl ImpossibilityAmplification {
+    /// Custom amplification by `factor`, `None` unless it is finite and positive
+    pub fn custom(factor: f64) -> Option<Self> {
+        Some(Self::Custom { factor }).filter(Self::is_valid)
+    }
+
+    /// Get the amplification factor for ridiculous solution generation
+    pub fn factor(&self) -> f64 {
+        match self {
+            Self::Mild => 10.0,
+            Self::Standard => 100.0,
+            Self::High => 1000.0,
+            Self::Extreme => 10000.0,
+            Self::Custom { factor } => *factor,
+        }
+    }
+
+    /// Whether the factor is finite and positive, as built-in levels always are
+    pub fn is_valid(&self) -> bool {
+        let factor = self.factor();
+        factor.is_finite() && factor > 0.0
+    }
+}
+
+impl core::hash::Hash for ImpossibilityAmplification {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        core::mem::discriminant(self).hash(state);
+        if let Self::Custom { factor } = self {
+            // Adding zero maps -0.0 to 0.0, which compare equal
+            (factor + 0.0).to_bits().hash(state);
+        }
+    }
+}
+
+/// Navigation coordinates in predetermined manifold space
+#[cfg(feature = "navigation")]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NavigationCoordinate {
+    /// Unique identifier
+    #[cfg(feature = "std")]
+    pub id: Uuid,
+
+    /// Position in knowledge space
+    pub knowledge_position: Vector3<f64>,
+
+    /// Position in temporal space
+    pub temporal_position: Vector3<f64>,
+
+    /// Position in entropy space
+    pub entropy_position: Vector3<f64>,
+
+    /// Confidence in this coordinate's validity
+    pub confidence: f64,
+
+    /// Memorial significance marker
+    pub memorial_significance: String,
+
+    /// Creation timestamp
+    #[cfg(feature = "std")]
+    pub created_at: DateTime<Utc>,
+}
+
+#[cfg(feature = "navigation")]
+impl NavigationCoordinate {
+    /// Create a new navigation coordinate
+    pub fn new(
+        knowledge: Vector3<f64>,
+        temporal: Vector3<f64>,
+        entropy: Vector3<f64>,
+        confidence: f64,
+    ) -> Self {
+        Self {
+            #[cfg(feature = "std")]
+            id: Uuid::new_v4(),
+            knowledge_position: knowledge,
+            temporal_position: temporal,
+            entropy_position: entropy,
+            confidence,
+            memorial_significance: MEMORIAL_SIGNIFICANCE.to_string(),
+            #[cfg(feature = "std")]
+            created_at: Utc::now(),
+        }
+    }
+
+    /// Calculate the total navigation distance
+    pub fn total_distance(&self) -> f64 {
+        sqrt(
+            self.knowledge_position.norm_squared()
+                + self.temporal_position.norm_squared()
+                + self.entropy_position.norm_squared(),
+        )
+    }
+
+    /// Euclidean distance to `other` over the knowledge, temporal, and entropy positions
+    pub fn distance_to(&self, other: &NavigationCoordinate) -> f64 {
+        sqrt(
+            (self.knowledge_position - other.knowledge_position).norm_squared()
+                + (self.temporal_position - other.temporal_position).norm_squared()
+                + (self.entropy_position - other.entropy_position).norm_squared(),
+        )
+    }
+
+    /// Coordinate a fraction `t` of the way from this coordinate to `other`
+    ///
+    /// The manifold is flat in position, so the geodesic is the straight line between the
+    /// two; confidence is interpolated the same way. `t` is clamped to `0.0..=1.0`.
+    pub fn interpolate(&self, other: &NavigationCoordinate, t: f64) -> NavigationCoordinate {
+        let t = t.clamp(0.0, 1.0);
+        NavigationCoordinate::new(
+            self.knowledge_position.lerp(&other.knowledge_position, t),
+            self.temporal_position.lerp(&other.temporal_position, t),
+            self.entropy_position.lerp(&other.entropy_position, t),
+            self.confidence + (other.confidence - self.confidence) * t,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sophistication_scores_interpolate_levels() {
+        let levels = [
+            ObserverSophistication::Naive,
+            ObserverSophistication::Intermediate,
+            ObserverSophistication::Expert,
+            ObserverSophistication::Universal,
+        ];
+        for level in levels {
+            assert_eq!(level.score().nearest_level(), level);
+            assert_eq!(level.score().information_deficit(), level.information_deficit());
+        }
+
+        let between = SophisticationScore::new(1.5).unwrap();
+        assert_eq!(between.nearest_level(), ObserverSophistication::Expert);
+        assert!((between.information_deficit() - 10f64.powf(1.5)).abs() < 1e-9);
+        assert_eq!(SophisticationScore::new(2.5).unwrap().information_deficit(), 5.0);
+        assert_eq!(SophisticationScore::new(7.0), Some(SophisticationScore::MAX));
+        assert_eq!(SophisticationScore::new(f64::NAN), None);
+
+        let learned = SophisticationScore::MIN.learn(SophisticationScore::MAX, 0.5);
+        assert_eq!(learned.get(), 1.5);
+        assert_eq!(learned.learn(SophisticationScore::MAX, 7.0), SophisticationScore::MAX);
+    }
+
+    #[test]
+    fn test_custom_amplification_factors() {
+        use core::hash::{Hash, Hasher};
+
+        assert_eq!(ImpossibilityAmplification::custom(42.0).unwrap().factor(), 42.0);
+        assert_eq!(ImpossibilityAmplification::custom(0.0), None);
+        assert_eq!(ImpossibilityAmplification::custom(f64::INFINITY), None);
+        assert!(!ImpossibilityAmplification::Custom { factor: f64::NAN }.is_valid());
+        assert!(ImpossibilityAmplification::Extreme.is_valid());
+
+        let hash = |level: ImpossibilityAmplification| {
+            let mut hasher = std::collections::hash_map::DefaultHasher::new();
+            level.hash(&mut hasher);
+            hasher.finish()
+        };
+        let (zero, negative_zero) = (
+            ImpossibilityAmplification::Custom { factor: 0.0 },
+            ImpossibilityAmplification::Custom { factor: -0.0 },
+        );
+        assert_eq!(zero, negative_zero);
+        assert_eq!(hash(zero), hash(negative_zero));
+        assert_ne!(hash(zero), hash(ImpossibilityAmplification::Mild));
+    }
+
+    #[test]
+    fn test_magnitude_without_std_math() {
+        assert_eq!(sqrt(16.0), 4.0);
+        let coordinate = SEntropyCoordinate::new(2.0, 3.0, 6.0);
+        assert_eq!(coordinate.total_magnitude(), 7.0);
+        assert_eq!(
+            coordinate.to_string(),
+            "S(2.000000, 3.000000, 6.000000) | Total: 7.000000 | Memorial: st-stella-lorraine"
+        );
+    }
+
+    #[cfg(feature = "navigation")]
+    #[test]
+    fn test_navigation_distances() {
+        let origin = NavigationCoordinate::new(
+            Vector3::zeros(),
+            Vector3::zeros(),
+            Vector3::zeros(),
+            1.0,
+        );
+        let unit = NavigationCoordinate::new(Vector3::x(), Vector3::y(), Vector3::z(), 0.5);
+        assert!((unit.total_distance() - 3f64.sqrt()).abs() < 1e-12);
+        assert_eq!(origin.distance_to(&unit), unit.total_distance());
+        assert_eq!(origin.interpolate(&unit, 0.5).confidence, 0.75);
+    }
+}
@@ -0,0 +1,399 @@
+//! Staged cross-domain pattern transfer
+//!
+//! [`StagedCrossDomainOptimizer`] implements [`CrossDomainOptimizer`] by running every
+//! transfer through four stages:
+//!
+//! 1. oscillation similarity of the source and target [`DomainDescriptor`]s
+//! 2. adaptation of the pattern to the target domain: the less similar the domains, the
+//!    further the adapted pattern's S-coordinates move from optimal integration
+//! 3. efficiency measurement: similarity times the fraction of the pattern's closeness to
+//!    optimal integration that survives adaptation
+//! 4. validation of the efficiency against the threshold, 90% unless configured otherwise
+//!
+//! Similarity comes from a [`DomainSimilarity`] model, [`DescriptorSimilarity`] unless
+//! another is chosen with [`StagedCrossDomainOptimizer::with_similarity`]. Domains that were
+//! never described with [`StagedCrossDomainOptimizer::with_domain`] are described by their
+//! name alone.
+
+use std::{
+    collections::{BTreeSet, HashMap},
+    fmt,
+    sync::Arc,
+};
+
+use async_trait::async_trait;
+
+use crate::error::{SEntropyError, SEntropyResult};
+use crate::navigation::transform_s_to_navigation;
+use crate::problem_features::{ProblemFeatureExtractor, TfIdfExtractor};
+use crate::traits::CrossDomainOptimizer;
+use crate::types::{
+    BMDOperationMode, BMDPattern, CrossDomainTransfer, ImpossibilityAmplification,
+    NavigationCoordinate,
+};
+use crate::SEntropyCoordinate;
+
+/// Transfer efficiency a transfer must reach by default
+pub const DEFAULT_TRANSFER_THRESHOLD: f64 = 0.90;
+
+/// Description of a problem domain
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DomainDescriptor {
+    /// Domain name, as passed to the optimizer
+    pub name: String,
+
+    /// Free-text description of the domain's problems
+    pub description: String,
+
+    /// Lowercased keywords characterizing the domain
+    pub keywords: BTreeSet<String>,
+}
+
+impl DomainDescriptor {
+    /// Domain `name` described by `description`, without keywords
+    pub fn new(name: impl Into<String>, description: impl Into<String>) -> Self {
+        Self { name: name.into(), description: description.into(), keywords: BTreeSet::new() }
+    }
+
+    /// Add `keywords`
+    pub fn keywords<I, S>(mut self, keywords: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.keywords.extend(keywords.into_iter().map(|keyword| keyword.as_ref().to_lowercase()));
+        self
+    }
+}
+
+/// Similarity in `0.0..=1.0` of two domains
+pub trait DomainSimilarity: fmt::Debug + Send + Sync {
+    /// Model name, as reported in transfer metadata
+    fn name(&self) -> &'static str;
+
+    /// Similarity of domains `a` and `b`, one for identical descriptors
+    fn similarity(&self, a: &DomainDescriptor, b: &DomainDescriptor) -> SEntropyResult<f64>;
+}
+
+/// Oscillation alignment of the descriptions, blended with keyword overlap
+///
+/// Descriptions are mapped to oscillation space by a feature extractor, TF-IDF by default,
+/// and compared by the angle between them: `(1 + cos θ) / 2`. Keyword overlap is the
+/// Jaccard index of the keyword sets, and counts for `keyword_weight` of the similarity
+/// when either domain has keywords.
+#[derive(Debug, Clone)]
+pub struct DescriptorSimilarity {
+    extractor: Arc<dyn ProblemFeatureExtractor>,
+    keyword_weight: f64,
+}
+
+impl Default for DescriptorSimilarity {
+    fn default() -> Self {
+        Self { extractor: Arc::new(TfIdfExtractor::new()), keyword_weight: 0.5 }
+    }
+}
+
+impl DescriptorSimilarity {
+    /// Map descriptions to oscillation space with `extractor`
+    pub fn with_extractor(mut self, extractor: Arc<dyn ProblemFeatureExtractor>) -> Self {
+        self.extractor = extractor;
+        self
+    }
+
+    /// Let keyword overlap count for `weight` of the similarity, clamped to `0.0..=1.0`
+    pub fn with_keyword_weight(mut self, weight: f64) -> Self {
+        self.keyword_weight = weight.clamp(0.0, 1.0);
+        self
+    }
+
+    fn oscillation_alignment(&self, a: &str, b: &str) -> SEntropyResult<f64> {
+        let (a, b) = (self.extractor.oscillations(a)?, self.extractor.oscillations(b)?);
+        Ok(match (a.try_normalize(0.0), b.try_normalize(0.0)) {
+            (Some(a), Some(b)) => ((1.0 + a.dot(&b)) / 2.0).clamp(0.0, 1.0),
+            _ => 0.0,
+        })
+    }
+}
+
+impl DomainSimilarity for DescriptorSimilarity {
+    fn name(&self) -> &'static str {
+        "descriptor"
+    }
+
+    fn similarity(&self, a: &DomainDescriptor, b: &DomainDescriptor) -> SEntropyResult<f64> {
+        let alignment = self.oscillation_alignment(&a.description, &b.description)?;
+        if a.keywords.is_empty() && b.keywords.is_empty() {
+            return Ok(alignment);
+        }
+        let shared = a.keywords.intersection(&b.keywords).count() as f64;
+        let overlap = shared / a.keywords.union(&b.keywords).count() as f64;
+        Ok((1.0 - self.keyword_weight) * alignment + self.keyword_weight * overlap)
+    }
+}
+
+/// [`CrossDomainOptimizer`] running transfers through the staged pipeline
+#[derive(Debug, Clone)]
+pub struct StagedCrossDomainOptimizer {
+    domains: HashMap<String, DomainDescriptor>,
+    similarity: Arc<dyn DomainSimilarity>,
+    threshold: f64,
+}
+
+impl Default for StagedCrossDomainOptimizer {
+    fn default() -> Self {
+        Self {
+            domains: HashMap::new(),
+            similarity: Arc::new(DescriptorSimilarity::default()),
+            threshold: DEFAULT_TRANSFER_THRESHOLD,
+        }
+    }
+}
+
+impl StagedCrossDomainOptimizer {
+    /// Optimizer with no described domains and the default threshold
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Describe a domain, replacing any earlier description of the same name
+    pub fn with_domain(mut self, domain: DomainDescriptor) -> Self {
+        self.domains.insert(domain.name.clone(), domain);
+        self
+    }
+
+    /// Compare domains with `similarity`
+    pub fn with_similarity(mut self, similarity: Arc<dyn DomainSimilarity>) -> Self {
+        self.similarity = similarity;
+        self
+    }
+
+    /// Require transfers to reach `threshold` efficiency
+    pub fn with_threshold(mut self, threshold: f64) -> SEntropyResult<Self> {
+        if !threshold.is_finite() || threshold < 0.0 {
+            return Err(SEntropyError::configuration(
+                "cross_domain.threshold",
+                format!("threshold must be finite and non-negative, got {}", threshold),
+            ));
+        }
+        self.threshold = threshold;
+        Ok(self)
+    }
+
+    /// Transfer efficiency transfers must reach
+    pub fn threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    /// Description of `domain`, its name alone if it was never described
+    pub fn descriptor(&self, domain: &str) -> DomainDescriptor {
+        self.domains.get(domain).cloned().unwrap_or_else(|| DomainDescriptor::new(domain, domain))
+    }
+
+    /// Stage 2: `pattern` adapted to `target_domain` at `similarity`
+    pub fn adapt_pattern(
+        &self,
+        pattern: &BMDPattern,
+        target_domain: &str,
+        similarity: f64,
+    ) -> BMDPattern {
+        let mut adapted = pattern.clone();
+        adapted.id = uuid::Uuid::new_v4();
+        adapted.name = format!("{}@{}", pattern.name, target_domain);
+        adapted.effectiveness = pattern.effectiveness * similarity;
+        adapted.transfer_efficiency = similarity;
+        adapted.s_coordinates = scaled(&pattern.s_coordinates, 2.0 - similarity);
+        adapted.metadata.insert("adapted_from".to_string(), pattern.id.to_string());
+        adapted.metadata.insert("target_domain".to_string(), target_domain.to_string());
+        adapted
+    }
+
+    /// Stage 3: efficiency of moving `source` to `target` at `similarity`
+    pub fn measure_efficiency(
+        source: &SEntropyCoordinate,
+        target: &SEntropyCoordinate,
+        similarity: f64,
+    ) -> f64 {
+        similarity * (1.0 + source.total_magnitude()) / (1.0 + target.total_magnitude())
+    }
+}
+
+/// `coordinate` with every component multiplied by `factor`
+fn scaled(coordinate: &SEntropyCoordinate, factor: f64) -> SEntropyCoordinate {
+    SEntropyCoordinate::new(
+        coordinate.s_knowledge * factor,
+        coordinate.s_time * factor,
+        coordinate.s_entropy * factor,
+    )
+}
+
+#[async_trait]
+impl CrossDomainOptimizer for StagedCrossDomainOptimizer {
+    async fn transfer_pattern(
+        &self,
+        source_domain: &str,
+        target_domain: &str,
+        pattern: &BMDPattern,
+    ) -> SEntropyResult<CrossDomainTransfer> {
+        let similarity = self.calculate_oscillation_similarity(source_domain, target_domain).await?;
+        let adapted = self.adapt_pattern(pattern, target_domain, similarity);
+        let efficiency =
+            Self::measure_efficiency(&pattern.s_coordinates, &adapted.s_coordinates, similarity);
+
+        let mut transfer = CrossDomainTransfer::new(
+            source_domain.to_string(),
+            target_domain.to_string(),
+            pattern.s_coordinates.clone(),
+            adapted.s_coordinates.clone(),
+            efficiency,
+            similarity,
+        );
+        transfer.metadata.insert("pattern".to_string(), pattern.id.to_string());
+        transfer.metadata.insert("similarity_model".to_string(), self.similarity.name().into());
+
+        if self.validate_transfer_efficiency(&transfer).await? {
+            Ok(transfer)
+        } else {
+            let issue = format!(
+                "efficiency {:.3} is below the {:.3} threshold",
+                efficiency, self.threshold
+            );
+            Err(SEntropyError::cross_domain_transfer(source_domain, target_domain, issue))
+        }
+    }
+
+    async fn calculate_oscillation_similarity(
+        &self,
+        domain_a: &str,
+        domain_b: &str,
+    ) -> SEntropyResult<f64> {
+        self.similarity.similarity(&self.descriptor(domain_a), &self.descriptor(domain_b))
+    }
+
+    /// One pattern per ordered pair of distinct domains, most transferable first
+    async fn cross_pollinate(&self, domains: &[String]) -> SEntropyResult<Vec<BMDPattern>> {
+        let mut patterns = Vec::new();
+        for source in domains {
+            for target in domains.iter().filter(|target| *target != source) {
+                let similarity = self.calculate_oscillation_similarity(source, target).await?;
+                let mut pattern = BMDPattern::new(
+                    format!("{}->{}", source, target),
+                    BMDOperationMode::FrameSelection,
+                    ImpossibilityAmplification::Standard,
+                    true,
+                );
+                pattern.effectiveness = similarity;
+                pattern.transfer_efficiency = similarity;
+                pattern.metadata.insert("source_domain".to_string(), source.clone());
+                pattern.metadata.insert("target_domain".to_string(), target.clone());
+                patterns.push(pattern);
+            }
+        }
+        patterns.sort_by(|a, b| b.transfer_efficiency.total_cmp(&a.transfer_efficiency));
+        Ok(patterns)
+    }
+
+    async fn validate_transfer_efficiency(
+        &self,
+        transfer: &CrossDomainTransfer,
+    ) -> SEntropyResult<bool> {
+        Ok(transfer.efficiency >= self.threshold)
+    }
+
+    /// Navigation coordinates of the targets of transfers meeting the threshold
+    async fn extract_cross_domain_insights(
+        &self,
+        transfers: &[CrossDomainTransfer],
+    ) -> SEntropyResult<Vec<NavigationCoordinate>> {
+        let mut insights = Vec::new();
+        for transfer in transfers {
+            if self.validate_transfer_efficiency(transfer).await? {
+                insights.push(transform_s_to_navigation(&transfer.target_s_coordinate));
+            }
+        }
+        Ok(insights)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::Vector3;
+
+    use super::*;
+
+    /// Extractor mapping every description to the same oscillations
+    #[derive(Debug)]
+    struct Constant;
+
+    impl ProblemFeatureExtractor for Constant {
+        fn name(&self) -> &'static str {
+            "constant"
+        }
+
+        fn oscillations(&self, _problem: &str) -> SEntropyResult<Vector3<f64>> {
+            Ok(Vector3::x())
+        }
+    }
+
+    fn optimizer() -> StagedCrossDomainOptimizer {
+        StagedCrossDomainOptimizer::new()
+            .with_domain(
+                DomainDescriptor::new("logistics", "route delivery vehicles through a network")
+                    .keywords(["Routing", "graph", "scheduling"]),
+            )
+            .with_domain(
+                DomainDescriptor::new("networking", "route packets through a network")
+                    .keywords(["routing", "graph", "latency"]),
+            )
+            .with_domain(
+                DomainDescriptor::new("poetry", "compose verse with meter and rhyme")
+                    .keywords(["meter", "rhyme"]),
+            )
+    }
+
+    #[test]
+    fn test_descriptor_similarity_blends_alignment_and_keywords() {
+        let model = DescriptorSimilarity::default().with_extractor(Arc::new(Constant));
+        let optimizer = optimizer();
+        let logistics = optimizer.descriptor("logistics");
+        assert_eq!(model.similarity(&logistics, &logistics).unwrap(), 1.0);
+
+        // Identical oscillations, two of four keywords shared
+        let networking = optimizer.descriptor("networking");
+        assert_eq!(model.similarity(&logistics, &networking).unwrap(), 0.75);
+        assert_eq!(model.similarity(&logistics, &optimizer.descriptor("poetry")).unwrap(), 0.5);
+
+        let undescribed = optimizer.descriptor("astronomy");
+        assert_eq!(undescribed.description, "astronomy");
+        assert!(undescribed.keywords.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_transfers_are_validated_against_the_threshold() {
+        let mut pattern = BMDPattern::new(
+            "shortest_path".to_string(),
+            BMDOperationMode::FrameSelection,
+            ImpossibilityAmplification::Standard,
+            false,
+        );
+        pattern.effectiveness = 0.8;
+        pattern.s_coordinates = SEntropyCoordinate::new(0.1, 0.0, 0.0);
+
+        let lenient = optimizer().with_threshold(0.2).unwrap();
+        let transfer = lenient.transfer_pattern("logistics", "networking", &pattern).await.unwrap();
+        let similarity = transfer.oscillation_similarity;
+        assert!(similarity > 0.0 && similarity <= 1.0);
+        assert!(transfer.efficiency <= similarity);
+        assert!(transfer.target_s_coordinate.total_magnitude() >= 0.1);
+        assert_eq!(lenient.extract_cross_domain_insights(&[transfer]).await.unwrap().len(), 1);
+
+        let strict = optimizer().with_threshold(1.5).unwrap();
+        let error = strict.transfer_pattern("logistics", "poetry", &pattern).await.unwrap_err();
+        assert!(matches!(error, SEntropyError::CrossDomainTransfer { .. }));
+        assert!(optimizer().with_threshold(f64::NAN).is_err());
+
+        let domains = ["logistics", "networking", "poetry"].map(String::from);
+        let patterns = optimizer().cross_pollinate(&domains).await.unwrap();
+        assert_eq!(patterns.len(), 6);
+        assert!(patterns[0].transfer_efficiency >= patterns[5].transfer_efficiency);
+    }
+}
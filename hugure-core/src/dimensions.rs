@@ -0,0 +1,109 @@
+//! Calculators of the three S-entropy dimensions
+//!
+//! The [`SEntropyEngine`] computes S_knowledge, S_time, and S_entropy through one
+//! calculator per dimension. The built-in calculators implement the framework's default
+//! heuristics; domain-specific deficit and accessibility models replace them through
+//! [`SEntropyEngineBuilder`].
+//!
+//! [`SEntropyEngine`]: crate::s_entropy::SEntropyEngine
+//! [`SEntropyEngineBuilder`]: crate::s_entropy::SEntropyEngineBuilder
+
+use std::fmt;
+
+use crate::{
+    error::SEntropyResult,
+    types::{MeasurementContext, ObserverSophistication},
+};
+
+/// S_knowledge: information deficit + frame selection coordinates
+pub trait KnowledgeCalculator: fmt::Debug + Send + Sync {
+    /// S_knowledge of the problem described by `context` for `observer`
+    fn s_knowledge(
+        &self,
+        context: &MeasurementContext,
+        observer: ObserverSophistication,
+    ) -> SEntropyResult<f64>;
+}
+
+/// S_time: temporal coordination distance + emotional time distortion
+pub trait TimeCalculator: fmt::Debug + Send + Sync {
+    /// S_time at `temporal_precision_target` under `emotional_factor`
+    fn s_time(&self, temporal_precision_target: f64, emotional_factor: f64) -> SEntropyResult<f64>;
+}
+
+/// S_entropy: endpoint navigation distance + oscillation accessibility
+pub trait EntropyCalculator: fmt::Debug + Send + Sync {
+    /// S_entropy of a problem of `problem_complexity` with endpoint `accessibility`
+    fn s_entropy(&self, problem_complexity: f64, accessibility: f64) -> SEntropyResult<f64>;
+}
+
+/// Observer-sophistication deficit, shared among the available knowledge sources, plus the
+/// order of magnitude of the problem complexity
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DefaultKnowledgeCalculator;
+
+impl KnowledgeCalculator for DefaultKnowledgeCalculator {
+    fn s_knowledge(
+        &self,
+        context: &MeasurementContext,
+        observer: ObserverSophistication,
+    ) -> SEntropyResult<f64> {
+        // Information deficit calculation based on observer sophistication, graded if known
+        let sophistication_deficit = match context.sophistication_score {
+            Some(score) => score.information_deficit(),
+            None => observer.information_deficit(),
+        };
+        // Each available knowledge source closes an equal share of the deficit
+        let information_deficit =
+            sophistication_deficit / (1.0 + context.knowledge_sources.len() as f64);
+
+        // Frame selection coordinate calculation
+        let frame_selection_coords = context.complexity().log10().max(0.0);
+
+        Ok(information_deficit + frame_selection_coords)
+    }
+}
+
+/// Orders of magnitude above the precision target plus amplified emotional distortion
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DefaultTimeCalculator;
+
+impl TimeCalculator for DefaultTimeCalculator {
+    fn s_time(&self, temporal_precision_target: f64, emotional_factor: f64) -> SEntropyResult<f64> {
+        // Ultra-precision temporal coordination distance
+        let precision_distance = if temporal_precision_target <= crate::S_ENTROPY_PRECISION_TARGET {
+            0.01 // Near-zero for ultra-precision
+        } else {
+            (temporal_precision_target / crate::S_ENTROPY_PRECISION_TARGET).log10()
+        };
+
+        // Emotional time distortion (how subjective time differs from objective time)
+        let emotional_distortion = emotional_factor * 10.0; // Amplify emotional effects
+
+        Ok(precision_distance + emotional_distortion)
+    }
+}
+
+/// Inaccessible share of the problem plus an oscillation accessibility factor
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DefaultEntropyCalculator;
+
+impl EntropyCalculator for DefaultEntropyCalculator {
+    fn s_entropy(&self, problem_complexity: f64, accessibility: f64) -> SEntropyResult<f64> {
+        // Entropy endpoint navigation distance
+        let navigation_distance = if accessibility > 0.9 {
+            0.01 // Near-zero for high accessibility
+        } else {
+            (1.0 - accessibility) * problem_complexity
+        };
+
+        // Oscillation accessibility factor
+        let oscillation_factor = if accessibility > 0.5 {
+            accessibility.log10().abs()
+        } else {
+            (1.0 - accessibility) * 100.0
+        };
+
+        Ok(navigation_distance + oscillation_factor)
+    }
+}
@@ -0,0 +1,379 @@
+//! Background disposal of disposable BMD patterns
+//!
+//! Ridiculous patterns are generated to be thrown away: [`BMDPattern::should_dispose`]
+//! reports when one is past its disposal time. A [`DisposalManager`] holds registered
+//! [`DisposablePattern`]s and frees those due for disposal, keeping the navigation insights
+//! each one yields first. Call [`DisposalManager::collect`] directly, or
+//! [`DisposalManager::spawn`] a task that collects on an interval until its
+//! [`DisposalTask`] is shut down or dropped.
+//!
+//! A pattern is only freed once it has yielded its insights, disposed of itself, and
+//! validated the disposal. A pattern failing any step stays registered and is retried on
+//! the next collection.
+
+use std::{
+    collections::{BTreeMap, VecDeque},
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    time::{Duration, Instant},
+};
+
+use async_trait::async_trait;
+use tokio::{
+    sync::oneshot,
+    task::JoinHandle,
+    time::{self, MissedTickBehavior},
+};
+use tracing::{debug, warn};
+
+use crate::error::{SEntropyError, SEntropyResult};
+use crate::logging::subsystem;
+use crate::navigation::transform_s_to_navigation;
+use crate::traits::DisposablePattern;
+use crate::types::{BMDPattern, NavigationCoordinate};
+
+/// Insights a [`DisposalManager`] retains by default
+pub const DEFAULT_INSIGHT_CAPACITY: usize = 4_096;
+
+/// A pattern's insight is the navigation coordinate of its S-coordinates
+#[async_trait]
+impl DisposablePattern for BMDPattern {
+    async fn should_dispose(&self) -> bool {
+        BMDPattern::should_dispose(self)
+    }
+
+    async fn extract_insights(&self) -> SEntropyResult<Vec<NavigationCoordinate>> {
+        Ok(vec![transform_s_to_navigation(&self.s_coordinates)])
+    }
+
+    async fn dispose(&self) -> SEntropyResult<()> {
+        if self.disposable {
+            Ok(())
+        } else {
+            Err(SEntropyError::bmd_operation(
+                format!("{:?}", self.operation_mode),
+                format!("pattern '{}' is persistent and cannot be disposed", self.name),
+            ))
+        }
+    }
+
+    async fn validate_disposal(&self) -> SEntropyResult<bool> {
+        Ok(self.disposable)
+    }
+}
+
+/// Outcome of one [`DisposalManager::collect`] pass
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CollectionReport {
+    /// Patterns freed
+    pub disposed: usize,
+
+    /// Patterns due for disposal that failed a step and stay registered
+    pub failed: usize,
+
+    /// Insights extracted from the freed patterns
+    pub insights: usize,
+
+    /// Time the pass took
+    pub elapsed: Duration,
+}
+
+/// Running totals of a [`DisposalManager`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DisposalStats {
+    /// Collection passes run
+    pub collections: u64,
+
+    /// Patterns freed
+    pub disposed: u64,
+
+    /// Failed disposal attempts
+    pub failed: u64,
+
+    /// Insights extracted from freed patterns
+    pub insights_extracted: u64,
+
+    /// Insights currently retained
+    pub insights_retained: usize,
+
+    /// Patterns currently registered
+    pub registered: usize,
+
+    /// Time since the manager was created
+    pub uptime: Duration,
+}
+
+impl DisposalStats {
+    /// Patterns freed per second of uptime
+    pub fn disposal_rate(&self) -> f64 {
+        let seconds = self.uptime.as_secs_f64();
+        if seconds > 0.0 {
+            self.disposed as f64 / seconds
+        } else {
+            0.0
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct Totals {
+    collections: u64,
+    disposed: u64,
+    failed: u64,
+    insights_extracted: u64,
+}
+
+/// Registry of disposable patterns that frees them once they are due
+pub struct DisposalManager {
+    patterns: Mutex<BTreeMap<u64, Arc<dyn DisposablePattern + Send + Sync>>>,
+    next_id: Mutex<u64>,
+    insights: Mutex<VecDeque<NavigationCoordinate>>,
+    insight_capacity: usize,
+    totals: Mutex<Totals>,
+    created_at: Instant,
+}
+
+impl std::fmt::Debug for DisposalManager {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DisposalManager")
+            .field("registered", &self.len())
+            .field("insight_capacity", &self.insight_capacity)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for DisposalManager {
+    fn default() -> Self {
+        Self::new(DEFAULT_INSIGHT_CAPACITY)
+    }
+}
+
+impl DisposalManager {
+    /// Manager retaining the latest `insight_capacity` insights
+    pub fn new(insight_capacity: usize) -> Self {
+        Self {
+            patterns: Mutex::new(BTreeMap::new()),
+            next_id: Mutex::new(0),
+            insights: Mutex::new(VecDeque::new()),
+            insight_capacity,
+            totals: Mutex::new(Totals::default()),
+            created_at: Instant::now(),
+        }
+    }
+
+    /// Register `pattern` for disposal, returning its registration id
+    pub fn register(&self, pattern: Arc<dyn DisposablePattern + Send + Sync>) -> u64 {
+        let id = {
+            let mut next_id = lock(&self.next_id);
+            *next_id += 1;
+            *next_id
+        };
+        lock(&self.patterns).insert(id, pattern);
+        id
+    }
+
+    /// Register a BMD pattern for disposal, returning its registration id
+    pub fn register_pattern(&self, pattern: BMDPattern) -> u64 {
+        self.register(Arc::new(pattern))
+    }
+
+    /// Remove the pattern registered as `id` without disposing of it
+    pub fn unregister(&self, id: u64) -> Option<Arc<dyn DisposablePattern + Send + Sync>> {
+        lock(&self.patterns).remove(&id)
+    }
+
+    /// Number of registered patterns
+    pub fn len(&self) -> usize {
+        lock(&self.patterns).len()
+    }
+
+    /// Whether no pattern is registered
+    pub fn is_empty(&self) -> bool {
+        lock(&self.patterns).is_empty()
+    }
+
+    /// Free every registered pattern due for disposal, keeping its insights
+    pub async fn collect(&self) -> CollectionReport {
+        let started = Instant::now();
+        let registered: Vec<_> = lock(&self.patterns)
+            .iter()
+            .map(|(id, pattern)| (*id, Arc::clone(pattern)))
+            .collect();
+
+        let mut report = CollectionReport {
+            disposed: 0,
+            failed: 0,
+            insights: 0,
+            elapsed: Duration::ZERO,
+        };
+        for (id, pattern) in registered {
+            if !pattern.should_dispose().await {
+                continue;
+            }
+            match dispose(pattern.as_ref()).await {
+                Ok(insights) => {
+                    lock(&self.patterns).remove(&id);
+                    report.disposed += 1;
+                    report.insights += insights.len();
+                    self.retain_insights(insights);
+                },
+                Err(error) => {
+                    warn!(target: subsystem::FRAMEWORK, id, %error, "pattern disposal failed");
+                    report.failed += 1;
+                },
+            }
+        }
+        report.elapsed = started.elapsed();
+
+        let mut totals = lock(&self.totals);
+        totals.collections += 1;
+        totals.disposed += report.disposed as u64;
+        totals.failed += report.failed as u64;
+        totals.insights_extracted += report.insights as u64;
+        debug!(
+            target: subsystem::FRAMEWORK,
+            disposed = report.disposed,
+            failed = report.failed,
+            insights = report.insights,
+            "collected disposable patterns"
+        );
+        report
+    }
+
+    /// Retained insights, oldest first
+    pub fn insights(&self) -> Vec<NavigationCoordinate> {
+        lock(&self.insights).iter().cloned().collect()
+    }
+
+    /// Remove and return the retained insights, oldest first
+    pub fn take_insights(&self) -> Vec<NavigationCoordinate> {
+        lock(&self.insights).drain(..).collect()
+    }
+
+    /// Running totals since the manager was created
+    pub fn stats(&self) -> DisposalStats {
+        let totals = lock(&self.totals);
+        DisposalStats {
+            collections: totals.collections,
+            disposed: totals.disposed,
+            failed: totals.failed,
+            insights_extracted: totals.insights_extracted,
+            insights_retained: lock(&self.insights).len(),
+            registered: self.len(),
+            uptime: self.created_at.elapsed(),
+        }
+    }
+
+    /// Collect every `period` in a background task
+    pub fn spawn(self: Arc<Self>, period: Duration) -> DisposalTask {
+        let (shutdown, mut stopped) = oneshot::channel();
+        let handle = tokio::spawn(async move {
+            let mut ticks = time::interval(period);
+            ticks.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            loop {
+                tokio::select! {
+                    _ = &mut stopped => break,
+                    _ = ticks.tick() => {
+                        self.collect().await;
+                    },
+                }
+            }
+        });
+        DisposalTask { shutdown, handle }
+    }
+
+    fn retain_insights(&self, new_insights: Vec<NavigationCoordinate>) {
+        let mut insights = lock(&self.insights);
+        insights.extend(new_insights);
+        let excess = insights.len().saturating_sub(self.insight_capacity);
+        insights.drain(..excess);
+    }
+}
+
+/// Background collection started by [`DisposalManager::spawn`]; dropping it stops the task
+#[derive(Debug)]
+pub struct DisposalTask {
+    shutdown: oneshot::Sender<()>,
+    handle: JoinHandle<()>,
+}
+
+impl DisposalTask {
+    /// Stop collecting, waiting for a collection in progress to finish
+    pub async fn shutdown(self) {
+        let _ = self.shutdown.send(());
+        let _ = self.handle.await;
+    }
+}
+
+/// Insights of `pattern`, once it has disposed of itself and validated the disposal
+async fn dispose(
+    pattern: &(dyn DisposablePattern + Send + Sync),
+) -> SEntropyResult<Vec<NavigationCoordinate>> {
+    let insights = pattern.extract_insights().await?;
+    pattern.dispose().await?;
+    if pattern.validate_disposal().await? {
+        Ok(insights)
+    } else {
+        Err(SEntropyError::bmd_operation("disposal", "disposal did not validate"))
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{BMDOperationMode, ImpossibilityAmplification};
+
+    fn expired(name: &str, disposable: bool) -> BMDPattern {
+        let mut pattern = BMDPattern::new(
+            name.to_string(),
+            BMDOperationMode::MemoryFabrication,
+            ImpossibilityAmplification::High,
+            disposable,
+        );
+        pattern.dispose_at = Some(chrono::Utc::now() - chrono::Duration::seconds(1));
+        pattern
+    }
+
+    #[tokio::test]
+    async fn test_collect_frees_due_patterns_and_keeps_insights() {
+        let manager = DisposalManager::new(1);
+        manager.register_pattern(expired("due", true));
+        manager.register_pattern(expired("also_due", true));
+        manager.register_pattern(BMDPattern::create_ridiculous(
+            "fresh".to_string(),
+            ImpossibilityAmplification::High,
+        ));
+        // Past its time but persistent, so disposal fails and it stays
+        manager.register_pattern(expired("persistent", false));
+
+        let report = manager.collect().await;
+        assert_eq!((report.disposed, report.failed, report.insights), (2, 1, 2));
+        assert_eq!(manager.len(), 2);
+
+        let stats = manager.stats();
+        assert_eq!((stats.disposed, stats.insights_extracted), (2, 2));
+        assert_eq!(stats.insights_retained, 1);
+        assert!(stats.disposal_rate() > 0.0);
+        assert_eq!(manager.take_insights().len(), 1);
+        assert!(manager.insights().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_spawned_task_collects_until_shutdown() {
+        let manager = Arc::new(DisposalManager::default());
+        manager.register_pattern(expired("due", true));
+
+        let task = Arc::clone(&manager).spawn(Duration::from_millis(5));
+        time::sleep(Duration::from_millis(50)).await;
+        task.shutdown().await;
+
+        assert!(manager.is_empty());
+        let collections = manager.stats().collections;
+        assert!(collections > 0);
+        time::sleep(Duration::from_millis(20)).await;
+        assert_eq!(manager.stats().collections, collections);
+    }
+}
@@ -4,7 +4,12 @@
 //! including tri-dimensional navigation, consciousness integration, and
 //! memorial significance validation.
 
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
+use uuid::Uuid;
 
 /// Result type alias for S-Entropy operations
 pub type SEntropyResult<T> = Result<T, SEntropyError>;
@@ -99,6 +104,7 @@ pub enum SEntropyError {
     },
 
     /// Serialization errors
+    #[cfg(feature = "serde")]
     #[error("Serialization error: {format}")]
     Serialization {
         #[from]
@@ -113,6 +119,10 @@ pub enum SEntropyError {
         #[source]
         details: anyhow::Error,
     },
+
+    /// Another error annotated with the operation it arose in
+    #[error("{error} [{context}]")]
+    Contextual { error: Box<SEntropyError>, context: ErrorContext },
 }
 
 impl SEntropyError {
@@ -224,24 +234,120 @@ impl SEntropyError {
         Self::ZeroComputation { computation_type: computation_type.into() }
     }
 
+    /// Create a configuration error
+    pub fn configuration(config_key: impl Into<String>, config_issue: impl Into<String>) -> Self {
+        Self::Configuration { config_key: config_key.into(), config_issue: config_issue.into() }
+    }
+
+    /// Annotate this error with `context`
+    ///
+    /// Context already attached takes precedence, since it was attached closer to where
+    /// the error arose; `context` only fills in the fields it leaves unset.
+    pub fn with_context(self, context: ErrorContext) -> Self {
+        match self {
+            Self::Contextual { error, context: inner } => {
+                Self::Contextual { error, context: inner.or(context) }
+            },
+            error if context.is_empty() => error,
+            error => Self::Contextual { error: Box::new(error), context },
+        }
+    }
+
+    /// Context attached with [`with_context`](Self::with_context), if any
+    pub fn context(&self) -> Option<&ErrorContext> {
+        match self {
+            Self::Contextual { context, .. } => Some(context),
+            _ => None,
+        }
+    }
+
+    /// This error without its attached context
+    pub fn root(&self) -> &Self {
+        match self {
+            Self::Contextual { error, .. } => error.root(),
+            error => error,
+        }
+    }
+
     /// Check if this error is related to memorial significance
     pub fn is_memorial_significance_error(&self) -> bool {
-        matches!(self, Self::MemorialSignificance { .. })
+        matches!(self.root(), Self::MemorialSignificance { .. })
     }
 
     /// Check if this error is related to consciousness boundaries
     pub fn is_consciousness_boundary_error(&self) -> bool {
-        matches!(self, Self::ConsciousnessIntegration { .. } | Self::BoundaryViolation { .. })
+        matches!(
+            self.root(),
+            Self::ConsciousnessIntegration { .. } | Self::BoundaryViolation { .. }
+        )
     }
 
     /// Check if this error is related to S-entropy calculation
     pub fn is_s_entropy_calculation_error(&self) -> bool {
-        matches!(self, Self::SEntropyCalculation { .. } | Self::TriDimensionalAlignment { .. })
+        matches!(
+            self.root(),
+            Self::SEntropyCalculation { .. } | Self::TriDimensionalAlignment { .. }
+        )
+    }
+
+    /// Get the stable error code for this error
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::SEntropyCalculation { .. } => ErrorCode::SEntropyCalculation,
+            Self::TriDimensionalAlignment { .. } => ErrorCode::TriDimensionalAlignment,
+            Self::Navigation { .. } => ErrorCode::Navigation,
+            Self::ConsciousnessIntegration { .. } => ErrorCode::ConsciousnessIntegration,
+            Self::BMDOperation { .. } => ErrorCode::BMDOperation,
+            Self::CrossDomainTransfer { .. } => ErrorCode::CrossDomainTransfer,
+            Self::StrategicImpossibility { .. } => ErrorCode::StrategicImpossibility,
+            Self::TemporalPrecision { .. } => ErrorCode::TemporalPrecision,
+            Self::MemoryOptimization { .. } => ErrorCode::MemoryOptimization,
+            Self::UniversalTransformation { .. } => ErrorCode::UniversalTransformation,
+            Self::MemorialSignificance { .. } => ErrorCode::MemorialSignificance,
+            Self::BoundaryViolation { .. } => ErrorCode::BoundaryViolation,
+            Self::ObserverProcessIntegration { .. } => ErrorCode::ObserverProcessIntegration,
+            Self::PredeterminedManifoldAccess { .. } => ErrorCode::PredeterminedManifoldAccess,
+            Self::DisposableGeneration { .. } => ErrorCode::DisposableGeneration,
+            Self::OscillationEndpoint { .. } => ErrorCode::OscillationEndpoint,
+            Self::EntropySolverService { .. } => ErrorCode::EntropySolverService,
+            Self::ZeroComputation { .. } => ErrorCode::ZeroComputation,
+            Self::Configuration { .. } => ErrorCode::Configuration,
+            Self::Io { .. } => ErrorCode::Io,
+            #[cfg(feature = "serde")]
+            Self::Serialization { .. } => ErrorCode::Serialization,
+            Self::Internal { .. } => ErrorCode::Internal,
+            Self::Contextual { error, .. } => error.code(),
+        }
+    }
+
+    /// HTTP status code to report this error with
+    pub fn http_status(&self) -> u16 {
+        self.code().http_status()
+    }
+
+    /// gRPC status code (as defined by `google.rpc.Code`) to report this error with
+    pub fn grpc_code(&self) -> i32 {
+        self.code().grpc_code()
+    }
+
+    /// JSON object describing this error for clients of services wrapping Hugure
+    ///
+    /// Carries the stable `id` (e.g. `SE-1008`), `code`, `message`, `severity`, and the
+    /// variant's structured fields under `details`; see [`SEntropyErrorDto`].
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> serde_json::Value {
+        let dto = SEntropyErrorDto::from(self);
+        let mut json = serde_json::to_value(&dto).unwrap_or(serde_json::Value::Null);
+        if let serde_json::Value::Object(fields) = &mut json {
+            fields.insert("id".to_string(), serde_json::Value::String(dto.code.id()));
+        }
+        json
     }
 
     /// Get error severity level
     pub fn severity(&self) -> ErrorSeverity {
         match self {
+            Self::Contextual { error, .. } => error.severity(),
             Self::MemorialSignificance { .. } => ErrorSeverity::Critical,
             Self::BoundaryViolation { .. } => ErrorSeverity::Critical,
             Self::ConsciousnessIntegration { .. } => ErrorSeverity::High,
@@ -255,8 +361,118 @@ impl SEntropyError {
     }
 }
 
+/// Where in the framework an error arose
+///
+/// Attach it with [`SEntropyError::with_context`] or [`ErrorContextExt`] as a failure
+/// bubbles up, so that the report names the request, subsystem, coordinate, and BMD
+/// pattern involved.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ErrorContext {
+    /// Request or operation the error arose in
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub operation_id: Option<String>,
+
+    /// Subsystem the error arose in, one of the [`crate::logging::subsystem`] targets
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub subsystem: Option<String>,
+
+    /// S-entropy coordinate being processed
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub coordinate_id: Option<Uuid>,
+
+    /// BMD pattern being processed
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub pattern_id: Option<Uuid>,
+}
+
+impl ErrorContext {
+    /// Empty context
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the request or operation id
+    pub fn operation(mut self, operation_id: impl Into<String>) -> Self {
+        self.operation_id = Some(operation_id.into());
+        self
+    }
+
+    /// Set the subsystem
+    pub fn subsystem(mut self, subsystem: impl Into<String>) -> Self {
+        self.subsystem = Some(subsystem.into());
+        self
+    }
+
+    /// Set the S-entropy coordinate id
+    pub fn coordinate(mut self, coordinate_id: Uuid) -> Self {
+        self.coordinate_id = Some(coordinate_id);
+        self
+    }
+
+    /// Set the BMD pattern id
+    pub fn pattern(mut self, pattern_id: Uuid) -> Self {
+        self.pattern_id = Some(pattern_id);
+        self
+    }
+
+    /// Whether no field is set
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+
+    /// This context, with unset fields taken from `other`
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            operation_id: self.operation_id.or(other.operation_id),
+            subsystem: self.subsystem.or(other.subsystem),
+            coordinate_id: self.coordinate_id.or(other.coordinate_id),
+            pattern_id: self.pattern_id.or(other.pattern_id),
+        }
+    }
+}
+
+impl fmt::Display for ErrorContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let fields = [
+            ("operation", self.operation_id.clone()),
+            ("subsystem", self.subsystem.clone()),
+            ("coordinate", self.coordinate_id.map(|id| id.to_string())),
+            ("pattern", self.pattern_id.map(|id| id.to_string())),
+        ];
+        let mut separator = "";
+        for (name, value) in fields {
+            if let Some(value) = value {
+                write!(f, "{}{}={}", separator, name, value)?;
+                separator = " ";
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Attaching [`ErrorContext`] to the error of an [`SEntropyResult`]
+pub trait ErrorContextExt<T> {
+    /// Annotate the error, if any, with `context`
+    fn error_context(self, context: ErrorContext) -> SEntropyResult<T>;
+
+    /// Annotate the error, if any, with the context `context` builds
+    fn with_error_context(self, context: impl FnOnce() -> ErrorContext) -> SEntropyResult<T>;
+}
+
+impl<T> ErrorContextExt<T> for SEntropyResult<T> {
+    fn error_context(self, context: ErrorContext) -> SEntropyResult<T> {
+        self.map_err(|error| error.with_context(context))
+    }
+
+    fn with_error_context(self, context: impl FnOnce() -> ErrorContext) -> SEntropyResult<T> {
+        self.map_err(|error| error.with_context(context()))
+    }
+}
+
 /// Error severity levels for S-Entropy operations
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ErrorSeverity {
     /// Low severity - operation can continue with degraded functionality
     Low,
@@ -280,6 +496,455 @@ impl ErrorSeverity {
     }
 }
 
+/// Stable error codes for S-Entropy operations
+///
+/// Numeric values and string names are part of the public contract: they never change
+/// once assigned, and new codes are only ever appended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "SCREAMING_SNAKE_CASE"))]
+#[repr(u16)]
+pub enum ErrorCode {
+    /// S-entropy calculation failure
+    SEntropyCalculation = 1001,
+    /// Tri-dimensional alignment failure
+    TriDimensionalAlignment = 1002,
+    /// Navigation failure
+    Navigation = 1003,
+    /// Consciousness integration failure
+    ConsciousnessIntegration = 1004,
+    /// BMD operation failure
+    #[cfg_attr(feature = "serde", serde(rename = "BMD_OPERATION"))]
+    BMDOperation = 1005,
+    /// Cross-domain transfer failure
+    CrossDomainTransfer = 1006,
+    /// Strategic impossibility failure
+    StrategicImpossibility = 1007,
+    /// Temporal precision not achieved
+    TemporalPrecision = 1008,
+    /// Memory optimization failure
+    MemoryOptimization = 1009,
+    /// Universal (STSL) transformation failure
+    UniversalTransformation = 1010,
+    /// Memorial significance validation failure
+    MemorialSignificance = 1011,
+    /// Framework boundary violation
+    BoundaryViolation = 1012,
+    /// Observer-process integration failure
+    ObserverProcessIntegration = 1013,
+    /// Predetermined manifold access denied
+    PredeterminedManifoldAccess = 1014,
+    /// Disposable generation failure
+    DisposableGeneration = 1015,
+    /// Oscillation endpoint failure
+    OscillationEndpoint = 1016,
+    /// Entropy solver service failure
+    EntropySolverService = 1017,
+    /// Zero computation failure
+    ZeroComputation = 1018,
+    /// Configuration or environment error
+    Configuration = 1019,
+    /// I/O error
+    Io = 1020,
+    /// Serialization error
+    Serialization = 1021,
+    /// Internal framework error
+    Internal = 1022,
+}
+
+impl ErrorCode {
+    /// Numeric form of this code
+    pub fn as_u16(&self) -> u16 {
+        *self as u16
+    }
+
+    /// String form of this code, e.g. `MEMORIAL_SIGNIFICANCE`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::SEntropyCalculation => "S_ENTROPY_CALCULATION",
+            Self::TriDimensionalAlignment => "TRI_DIMENSIONAL_ALIGNMENT",
+            Self::Navigation => "NAVIGATION",
+            Self::ConsciousnessIntegration => "CONSCIOUSNESS_INTEGRATION",
+            Self::BMDOperation => "BMD_OPERATION",
+            Self::CrossDomainTransfer => "CROSS_DOMAIN_TRANSFER",
+            Self::StrategicImpossibility => "STRATEGIC_IMPOSSIBILITY",
+            Self::TemporalPrecision => "TEMPORAL_PRECISION",
+            Self::MemoryOptimization => "MEMORY_OPTIMIZATION",
+            Self::UniversalTransformation => "UNIVERSAL_TRANSFORMATION",
+            Self::MemorialSignificance => "MEMORIAL_SIGNIFICANCE",
+            Self::BoundaryViolation => "BOUNDARY_VIOLATION",
+            Self::ObserverProcessIntegration => "OBSERVER_PROCESS_INTEGRATION",
+            Self::PredeterminedManifoldAccess => "PREDETERMINED_MANIFOLD_ACCESS",
+            Self::DisposableGeneration => "DISPOSABLE_GENERATION",
+            Self::OscillationEndpoint => "OSCILLATION_ENDPOINT",
+            Self::EntropySolverService => "ENTROPY_SOLVER_SERVICE",
+            Self::ZeroComputation => "ZERO_COMPUTATION",
+            Self::Configuration => "CONFIGURATION",
+            Self::Io => "IO",
+            Self::Serialization => "SERIALIZATION",
+            Self::Internal => "INTERNAL",
+        }
+    }
+
+    /// Identifier of this code for clients, e.g. `SE-1011`
+    pub fn id(&self) -> String {
+        format!("SE-{}", self.as_u16())
+    }
+
+    /// Look up a code by its numeric form
+    pub fn from_u16(code: u16) -> Option<Self> {
+        ALL_ERROR_CODES.iter().copied().find(|c| c.as_u16() == code)
+    }
+
+    /// Look up a code by its identifier, e.g. `SE-1011`
+    pub fn from_id(id: &str) -> Option<Self> {
+        id.strip_prefix("SE-")?.parse().ok().and_then(Self::from_u16)
+    }
+
+    /// HTTP status code for errors carrying this code
+    pub fn http_status(&self) -> u16 {
+        match self {
+            Self::Configuration | Self::Serialization => 400,
+            Self::MemorialSignificance | Self::BoundaryViolation => 403,
+            Self::PredeterminedManifoldAccess => 403,
+            Self::ConsciousnessIntegration => 409,
+            Self::SEntropyCalculation
+            | Self::TriDimensionalAlignment
+            | Self::UniversalTransformation
+            | Self::ZeroComputation => 422,
+            Self::EntropySolverService => 503,
+            _ => 500,
+        }
+    }
+
+    /// gRPC status code (as defined by `google.rpc.Code`) for errors carrying this code
+    pub fn grpc_code(&self) -> i32 {
+        match self {
+            Self::Configuration | Self::Serialization => 3, // INVALID_ARGUMENT
+            Self::MemorialSignificance | Self::BoundaryViolation => 7, // PERMISSION_DENIED
+            Self::PredeterminedManifoldAccess => 7,                    // PERMISSION_DENIED
+            Self::ConsciousnessIntegration => 9,                       // FAILED_PRECONDITION
+            Self::SEntropyCalculation
+            | Self::TriDimensionalAlignment
+            | Self::UniversalTransformation
+            | Self::ZeroComputation => 9, // FAILED_PRECONDITION
+            Self::EntropySolverService => 14, // UNAVAILABLE
+            _ => 13,                          // INTERNAL
+        }
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "http")]
+impl From<ErrorCode> for http::StatusCode {
+    fn from(code: ErrorCode) -> Self {
+        Self::from_u16(code.http_status()).unwrap_or(Self::INTERNAL_SERVER_ERROR)
+    }
+}
+
+#[cfg(feature = "http")]
+impl From<&SEntropyError> for http::StatusCode {
+    fn from(error: &SEntropyError) -> Self {
+        error.code().into()
+    }
+}
+
+#[cfg(feature = "http")]
+impl From<SEntropyError> for http::StatusCode {
+    fn from(error: SEntropyError) -> Self {
+        Self::from(&error)
+    }
+}
+
+/// Every assigned error code, in numeric order
+pub const ALL_ERROR_CODES: [ErrorCode; 22] = [
+    ErrorCode::SEntropyCalculation,
+    ErrorCode::TriDimensionalAlignment,
+    ErrorCode::Navigation,
+    ErrorCode::ConsciousnessIntegration,
+    ErrorCode::BMDOperation,
+    ErrorCode::CrossDomainTransfer,
+    ErrorCode::StrategicImpossibility,
+    ErrorCode::TemporalPrecision,
+    ErrorCode::MemoryOptimization,
+    ErrorCode::UniversalTransformation,
+    ErrorCode::MemorialSignificance,
+    ErrorCode::BoundaryViolation,
+    ErrorCode::ObserverProcessIntegration,
+    ErrorCode::PredeterminedManifoldAccess,
+    ErrorCode::DisposableGeneration,
+    ErrorCode::OscillationEndpoint,
+    ErrorCode::EntropySolverService,
+    ErrorCode::ZeroComputation,
+    ErrorCode::Configuration,
+    ErrorCode::Io,
+    ErrorCode::Serialization,
+    ErrorCode::Internal,
+];
+
+/// Serializable form of [`SEntropyError`] for crossing process boundaries
+///
+/// Structured variants round-trip losslessly. `Io`, `Serialization`, and `Internal`
+/// carry only their rendered message; the source chain does not survive the trip.
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SEntropyErrorDto {
+    /// Stable error code
+    pub code: ErrorCode,
+
+    /// Human-readable error message
+    pub message: String,
+
+    /// Error severity
+    pub severity: ErrorSeverity,
+
+    /// Variant-specific structured fields
+    pub details: ErrorDetails,
+
+    /// Where the error arose, if known
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context: Option<ErrorContext>,
+}
+
+/// Variant-specific fields of a serialized [`SEntropyError`]
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+#[allow(missing_docs)]
+pub enum ErrorDetails {
+    SEntropyCalculation { message: String },
+    TriDimensionalAlignment { dimension: String, reason: String },
+    Navigation { operation: String, details: String },
+    ConsciousnessIntegration { mode: String, violation: String },
+    BmdOperation { operation_mode: String, reason: String },
+    CrossDomainTransfer { source: String, target: String, efficiency_issue: String },
+    StrategicImpossibility { impossibility_type: String, global_viability_issue: String },
+    TemporalPrecision { target_precision: f64, achieved_precision: f64 },
+    MemoryOptimization { optimization_type: String, memory_issue: String },
+    UniversalTransformation { problem_type: String, stsl_error: String },
+    MemorialSignificance { expected: String, actual: String },
+    BoundaryViolation { boundary_type: String, violation_details: String },
+    ObserverProcessIntegration { separation_distance: f64 },
+    PredeterminedManifoldAccess { manifold_type: String, access_issue: String },
+    DisposableGeneration { generation_type: String, disposal_issue: String },
+    OscillationEndpoint { endpoint_type: String, accessibility_issue: String },
+    EntropySolverService { service_operation: String, solver_issue: String },
+    ZeroComputation { computation_type: String },
+    Configuration { config_key: String, config_issue: String },
+    Io { message: String },
+    Serialization { message: String },
+    Internal { message: String },
+}
+
+#[cfg(feature = "serde")]
+impl From<&SEntropyError> for SEntropyErrorDto {
+    fn from(error: &SEntropyError) -> Self {
+        use SEntropyError as E;
+
+        let details = match error.root() {
+            E::SEntropyCalculation { message } => {
+                ErrorDetails::SEntropyCalculation { message: message.clone() }
+            },
+            E::TriDimensionalAlignment { dimension, reason } => {
+                ErrorDetails::TriDimensionalAlignment {
+                    dimension: dimension.clone(),
+                    reason: reason.clone(),
+                }
+            },
+            E::Navigation { operation, details } => {
+                ErrorDetails::Navigation { operation: operation.clone(), details: details.clone() }
+            },
+            E::ConsciousnessIntegration { mode, violation } => {
+                ErrorDetails::ConsciousnessIntegration {
+                    mode: mode.clone(),
+                    violation: violation.clone(),
+                }
+            },
+            E::BMDOperation { operation_mode, reason } => ErrorDetails::BmdOperation {
+                operation_mode: operation_mode.clone(),
+                reason: reason.clone(),
+            },
+            E::CrossDomainTransfer { source, target, efficiency_issue } => {
+                ErrorDetails::CrossDomainTransfer {
+                    source: source.clone(),
+                    target: target.clone(),
+                    efficiency_issue: efficiency_issue.clone(),
+                }
+            },
+            E::StrategicImpossibility { impossibility_type, global_viability_issue } => {
+                ErrorDetails::StrategicImpossibility {
+                    impossibility_type: impossibility_type.clone(),
+                    global_viability_issue: global_viability_issue.clone(),
+                }
+            },
+            E::TemporalPrecision { target_precision, achieved_precision } => {
+                ErrorDetails::TemporalPrecision {
+                    target_precision: *target_precision,
+                    achieved_precision: *achieved_precision,
+                }
+            },
+            E::MemoryOptimization { optimization_type, memory_issue } => {
+                ErrorDetails::MemoryOptimization {
+                    optimization_type: optimization_type.clone(),
+                    memory_issue: memory_issue.clone(),
+                }
+            },
+            E::UniversalTransformation { problem_type, stsl_error } => {
+                ErrorDetails::UniversalTransformation {
+                    problem_type: problem_type.clone(),
+                    stsl_error: stsl_error.clone(),
+                }
+            },
+            E::MemorialSignificance { expected, actual } => ErrorDetails::MemorialSignificance {
+                expected: expected.clone(),
+                actual: actual.clone(),
+            },
+            E::BoundaryViolation { boundary_type, violation_details } => {
+                ErrorDetails::BoundaryViolation {
+                    boundary_type: boundary_type.clone(),
+                    violation_details: violation_details.clone(),
+                }
+            },
+            E::ObserverProcessIntegration { separation_distance } => {
+                ErrorDetails::ObserverProcessIntegration {
+                    separation_distance: *separation_distance,
+                }
+            },
+            E::PredeterminedManifoldAccess { manifold_type, access_issue } => {
+                ErrorDetails::PredeterminedManifoldAccess {
+                    manifold_type: manifold_type.clone(),
+                    access_issue: access_issue.clone(),
+                }
+            },
+            E::DisposableGeneration { generation_type, disposal_issue } => {
+                ErrorDetails::DisposableGeneration {
+                    generation_type: generation_type.clone(),
+                    disposal_issue: disposal_issue.clone(),
+                }
+            },
+            E::OscillationEndpoint { endpoint_type, accessibility_issue } => {
+                ErrorDetails::OscillationEndpoint {
+                    endpoint_type: endpoint_type.clone(),
+                    accessibility_issue: accessibility_issue.clone(),
+                }
+            },
+            E::EntropySolverService { service_operation, solver_issue } => {
+                ErrorDetails::EntropySolverService {
+                    service_operation: service_operation.clone(),
+                    solver_issue: solver_issue.clone(),
+                }
+            },
+            E::ZeroComputation { computation_type } => {
+                ErrorDetails::ZeroComputation { computation_type: computation_type.clone() }
+            },
+            E::Configuration { config_key, config_issue } => ErrorDetails::Configuration {
+                config_key: config_key.clone(),
+                config_issue: config_issue.clone(),
+            },
+            E::Io { operation } => ErrorDetails::Io { message: operation.to_string() },
+            E::Serialization { format } => {
+                ErrorDetails::Serialization { message: format.to_string() }
+            },
+            E::Internal { details } => {
+                ErrorDetails::Internal { message: format!("{:#}", details) }
+            },
+            E::Contextual { .. } => unreachable!("root errors carry no context"),
+        };
+
+        Self {
+            code: error.code(),
+            message: error.to_string(),
+            severity: error.severity(),
+            details,
+            context: error.context().cloned(),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<SEntropyError> for SEntropyErrorDto {
+    fn from(error: SEntropyError) -> Self {
+        Self::from(&error)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<SEntropyErrorDto> for SEntropyError {
+    fn from(dto: SEntropyErrorDto) -> Self {
+        use ErrorDetails as D;
+
+        let error = match dto.details {
+            D::SEntropyCalculation { message } => Self::SEntropyCalculation { message },
+            D::TriDimensionalAlignment { dimension, reason } => {
+                Self::TriDimensionalAlignment { dimension, reason }
+            },
+            D::Navigation { operation, details } => Self::Navigation { operation, details },
+            D::ConsciousnessIntegration { mode, violation } => {
+                Self::ConsciousnessIntegration { mode, violation }
+            },
+            D::BmdOperation { operation_mode, reason } => {
+                Self::BMDOperation { operation_mode, reason }
+            },
+            D::CrossDomainTransfer { source, target, efficiency_issue } => {
+                Self::CrossDomainTransfer { source, target, efficiency_issue }
+            },
+            D::StrategicImpossibility { impossibility_type, global_viability_issue } => {
+                Self::StrategicImpossibility { impossibility_type, global_viability_issue }
+            },
+            D::TemporalPrecision { target_precision, achieved_precision } => {
+                Self::TemporalPrecision { target_precision, achieved_precision }
+            },
+            D::MemoryOptimization { optimization_type, memory_issue } => {
+                Self::MemoryOptimization { optimization_type, memory_issue }
+            },
+            D::UniversalTransformation { problem_type, stsl_error } => {
+                Self::UniversalTransformation { problem_type, stsl_error }
+            },
+            D::MemorialSignificance { expected, actual } => {
+                Self::MemorialSignificance { expected, actual }
+            },
+            D::BoundaryViolation { boundary_type, violation_details } => {
+                Self::BoundaryViolation { boundary_type, violation_details }
+            },
+            D::ObserverProcessIntegration { separation_distance } => {
+                Self::ObserverProcessIntegration { separation_distance }
+            },
+            D::PredeterminedManifoldAccess { manifold_type, access_issue } => {
+                Self::PredeterminedManifoldAccess { manifold_type, access_issue }
+            },
+            D::DisposableGeneration { generation_type, disposal_issue } => {
+                Self::DisposableGeneration { generation_type, disposal_issue }
+            },
+            D::OscillationEndpoint { endpoint_type, accessibility_issue } => {
+                Self::OscillationEndpoint { endpoint_type, accessibility_issue }
+            },
+            D::EntropySolverService { service_operation, solver_issue } => {
+                Self::EntropySolverService { service_operation, solver_issue }
+            },
+            D::ZeroComputation { computation_type } => Self::ZeroComputation { computation_type },
+            D::Configuration { config_key, config_issue } => {
+                Self::Configuration { config_key, config_issue }
+            },
+            D::Io { message } => Self::Io {
+                operation: std::io::Error::new(std::io::ErrorKind::Other, message),
+            },
+            D::Serialization { message } => {
+                Self::Serialization { format: serde::de::Error::custom(message) }
+            },
+            D::Internal { message } => Self::Internal { details: anyhow::anyhow!(message) },
+        };
+        match dto.context {
+            Some(context) => error.with_context(context),
+            None => error,
+        }
+    }
+}
+
 /// Helper macro for creating S-entropy specific errors with context
 #[macro_export]
 macro_rules! s_entropy_error {
@@ -348,4 +1013,118 @@ mod tests {
         assert!(check_framework_boundary("operation1", allowed).is_ok());
         assert!(check_framework_boundary("forbidden", allowed).is_err());
     }
+
+    #[test]
+    fn test_error_codes_are_stable() {
+        let error = SEntropyError::memorial_significance("expected", "actual");
+        assert_eq!(error.code(), ErrorCode::MemorialSignificance);
+        assert_eq!(error.code().as_u16(), 1011);
+        assert_eq!(error.code().as_str(), "MEMORIAL_SIGNIFICANCE");
+        assert_eq!(error.http_status(), 403);
+        assert_eq!(ErrorCode::from_u16(1011), Some(ErrorCode::MemorialSignificance));
+        assert_eq!(ErrorCode::from_u16(9999), None);
+    }
+
+    #[test]
+    fn test_error_ids_round_trip() {
+        for code in ALL_ERROR_CODES {
+            assert_eq!(ErrorCode::from_id(&code.id()), Some(code));
+        }
+        assert_eq!(ErrorCode::CrossDomainTransfer.id(), "SE-1006");
+        assert_eq!(ErrorCode::from_id("SE-9999"), None);
+        assert_eq!(ErrorCode::from_id("1006"), None);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_error_codes_serialize_as_their_names() {
+        for code in ALL_ERROR_CODES {
+            let json = serde_json::to_value(code).unwrap();
+            assert_eq!(json, code.as_str());
+            assert_eq!(serde_json::from_value::<ErrorCode>(json).unwrap(), code);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_error_to_json() {
+        let error = SEntropyError::cross_domain_transfer("physics", "biology", "too dissimilar");
+        let json = error.to_json();
+        assert_eq!(json["id"], "SE-1006");
+        assert_eq!(json["code"], "CROSS_DOMAIN_TRANSFER");
+        assert_eq!(json["severity"], "Low");
+        assert_eq!(json["message"], error.to_string());
+        assert_eq!(json["details"]["kind"], "cross_domain_transfer");
+        assert_eq!(json["details"]["target"], "biology");
+
+        let dto: SEntropyErrorDto = serde_json::from_value(json).unwrap();
+        assert_eq!(dto.code, ErrorCode::CrossDomainTransfer);
+
+        let error = error.with_context(ErrorContext::new().operation("req-7"));
+        let json = error.to_json();
+        assert_eq!(json["context"]["operation_id"], "req-7");
+        assert_eq!(json["details"]["kind"], "cross_domain_transfer");
+
+        let dto: SEntropyErrorDto = serde_json::from_value(json).unwrap();
+        let restored = SEntropyError::from(dto);
+        assert_eq!(restored.context(), error.context());
+        assert_eq!(restored.to_string(), error.to_string());
+    }
+
+    #[cfg(feature = "http")]
+    #[test]
+    fn test_error_http_status_code() {
+        let status = http::StatusCode::from(SEntropyError::memorial_significance("a", "b"));
+        assert_eq!(status, http::StatusCode::FORBIDDEN);
+        let status = http::StatusCode::from(&SEntropyError::configuration("key", "bad"));
+        assert_eq!(status, http::StatusCode::BAD_REQUEST);
+    }
+
+    #[test]
+    fn test_error_context_merges_toward_the_root() {
+        let coordinate_id = Uuid::new_v4();
+        let result: SEntropyResult<()> = Err(SEntropyError::navigation("navigate", "lost"));
+        let error = result
+            .error_context(ErrorContext::new().subsystem("navigation").coordinate(coordinate_id))
+            .with_error_context(|| ErrorContext::new().operation("req-7").subsystem("s_entropy"))
+            .unwrap_err();
+
+        let context = error.context().unwrap();
+        assert_eq!(context.subsystem.as_deref(), Some("navigation"));
+        assert_eq!(context.operation_id.as_deref(), Some("req-7"));
+        assert_eq!(context.coordinate_id, Some(coordinate_id));
+        assert!(matches!(error.root(), SEntropyError::Navigation { .. }));
+        assert_eq!(error.code(), ErrorCode::Navigation);
+        assert_eq!(error.severity(), ErrorSeverity::Medium);
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "Navigation failed: navigate - lost [operation=req-7 subsystem=navigation \
+                 coordinate={}]",
+                coordinate_id
+            )
+        );
+
+        let bare = SEntropyError::navigation("navigate", "lost").with_context(ErrorContext::new());
+        assert!(bare.context().is_none());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_error_dto_round_trip() {
+        let error = SEntropyError::temporal_precision(1e-30, 1e-15);
+        let json = serde_json::to_string(&SEntropyErrorDto::from(&error)).unwrap();
+        let dto: SEntropyErrorDto = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(dto.code, ErrorCode::TemporalPrecision);
+        assert_eq!(dto.severity, ErrorSeverity::Medium);
+
+        let restored = SEntropyError::from(dto);
+        assert!(matches!(
+            restored,
+            SEntropyError::TemporalPrecision { target_precision, achieved_precision }
+                if target_precision == 1e-30 && achieved_precision == 1e-15
+        ));
+        assert_eq!(restored.to_string(), error.to_string());
+    }
 }
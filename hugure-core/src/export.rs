@@ -0,0 +1,314 @@
+//! Export of measurement and integration-attempt history for offline analysis
+//!
+//! Records are written one per row, oldest first, in formats that pandas and Polars read
+//! directly:
+//!
+//! - CSV ([`write_csv`]): a header row, RFC 3339 timestamps with nanoseconds, and
+//!   enum fields by the names their `FromStr` accepts
+//! - JSON Lines ([`write_ndjson`], `serde` feature): one serialized record per line
+//! - Parquet ([`write_parquet`], `arrow` feature): one row group with UTC nanosecond
+//!   timestamps
+//!
+//! ```no_run
+//! # use hugure_core::{error::SEntropyResult, s_entropy::SEntropyEngine};
+//! # async fn export(engine: &SEntropyEngine) -> SEntropyResult<()> {
+//! use hugure_core::export::write_csv;
+//!
+//! let file = std::fs::File::create("measurements.csv")?;
+//! write_csv(file, &engine.measurement_history().await)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::io::{self, Write};
+#[cfg(feature = "arrow")]
+use std::sync::Arc;
+
+use chrono::{DateTime, SecondsFormat, Utc};
+
+use crate::{
+    error::SEntropyResult,
+    s_entropy::{IntegrationAttempt, SEntropyMeasurement},
+};
+
+/// History record with a fixed set of exported columns
+pub trait ExportRecord {
+    /// Column names, in export order
+    const COLUMNS: &'static [&'static str];
+
+    /// Field values rendered as text, one per column
+    fn csv_fields(&self) -> Vec<String>;
+
+    /// All `records` as one Arrow record batch
+    #[cfg(feature = "arrow")]
+    fn record_batch(records: &[Self]) -> SEntropyResult<arrow::record_batch::RecordBatch>
+    where
+        Self: Sized;
+}
+
+impl ExportRecord for SEntropyMeasurement {
+    const COLUMNS: &'static [&'static str] = &[
+        "id",
+        "s_knowledge",
+        "s_time",
+        "s_entropy",
+        "total_magnitude",
+        "observer_sophistication",
+        "precision",
+        "escalated_from",
+        "optimal_integration",
+        "memorial_significance",
+        "measured_at",
+    ];
+
+    fn csv_fields(&self) -> Vec<String> {
+        vec![
+            self.id.to_string(),
+            self.s_knowledge.to_string(),
+            self.s_time.to_string(),
+            self.s_entropy.to_string(),
+            self.total_magnitude.to_string(),
+            self.observer_sophistication.as_str().to_string(),
+            self.precision.as_str().to_string(),
+            escalation_chain(self),
+            self.optimal_integration.to_string(),
+            self.memorial_significance.clone(),
+            timestamp(self.measured_at),
+        ]
+    }
+
+    #[cfg(feature = "arrow")]
+    fn record_batch(records: &[Self]) -> SEntropyResult<arrow::record_batch::RecordBatch> {
+        use arrow::array::{ArrayRef, BooleanArray, Float64Array, StringArray};
+
+        let floats = |value: fn(&Self) -> f64| -> ArrayRef {
+            Arc::new(Float64Array::from_iter_values(records.iter().map(value)))
+        };
+        let strings = |value: fn(&Self) -> String| -> ArrayRef {
+            Arc::new(StringArray::from_iter_values(records.iter().map(value)))
+        };
+        let columns: Vec<ArrayRef> = vec![
+            strings(|m| m.id.to_string()),
+            floats(|m| m.s_knowledge),
+            floats(|m| m.s_time),
+            floats(|m| m.s_entropy),
+            floats(|m| m.total_magnitude),
+            strings(|m| m.observer_sophistication.as_str().to_string()),
+            strings(|m| m.precision.as_str().to_string()),
+            strings(escalation_chain),
+            Arc::new(BooleanArray::from(
+                records.iter().map(|m| m.optimal_integration).collect::<Vec<_>>(),
+            )),
+            strings(|m| m.memorial_significance.clone()),
+            timestamp_column(records.iter().map(|m| m.measured_at)),
+        ];
+        record_batch::<Self>(columns)
+    }
+}
+
+impl ExportRecord for IntegrationAttempt {
+    const COLUMNS: &'static [&'static str] = &[
+        "id",
+        "target_separation",
+        "achieved_separation",
+        "successful",
+        "method",
+        "attempted_at",
+    ];
+
+    fn csv_fields(&self) -> Vec<String> {
+        vec![
+            self.id.to_string(),
+            self.target_separation.to_string(),
+            self.achieved_separation.to_string(),
+            self.successful.to_string(),
+            self.method.clone(),
+            timestamp(self.attempted_at),
+        ]
+    }
+
+    #[cfg(feature = "arrow")]
+    fn record_batch(records: &[Self]) -> SEntropyResult<arrow::record_batch::RecordBatch> {
+        use arrow::array::{ArrayRef, BooleanArray, Float64Array, StringArray};
+
+        let floats = |value: fn(&Self) -> f64| -> ArrayRef {
+            Arc::new(Float64Array::from_iter_values(records.iter().map(value)))
+        };
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(StringArray::from_iter_values(records.iter().map(|a| a.id.to_string()))),
+            floats(|a| a.target_separation),
+            floats(|a| a.achieved_separation),
+            Arc::new(BooleanArray::from(records.iter().map(|a| a.successful).collect::<Vec<_>>())),
+            Arc::new(StringArray::from_iter_values(records.iter().map(|a| a.method.as_str()))),
+            timestamp_column(records.iter().map(|a| a.attempted_at)),
+        ];
+        record_batch::<Self>(columns)
+    }
+}
+
+/// Write `records` as CSV with a header row
+pub fn write_csv<R: ExportRecord>(writer: impl Write, records: &[R]) -> SEntropyResult<()> {
+    let mut writer = io::BufWriter::new(writer);
+    write_csv_row(&mut writer, R::COLUMNS.iter().copied())?;
+    for record in records {
+        let fields = record.csv_fields();
+        write_csv_row(&mut writer, fields.iter().map(String::as_str))?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// Write `records` as JSON Lines, one serialized record per line
+#[cfg(feature = "serde")]
+pub fn write_ndjson<R: serde::Serialize>(writer: impl Write, records: &[R]) -> SEntropyResult<()> {
+    let mut writer = io::BufWriter::new(writer);
+    for record in records {
+        serde_json::to_writer(&mut writer, record)?;
+        writer.write_all(b"\n")?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// Write `records` as a Parquet file
+#[cfg(feature = "arrow")]
+pub fn write_parquet<R: ExportRecord>(
+    writer: impl Write + Send,
+    records: &[R],
+) -> SEntropyResult<()> {
+    use parquet::arrow::ArrowWriter;
+
+    let batch = R::record_batch(records)?;
+    let mut writer = ArrowWriter::try_new(writer, batch.schema(), None).map_err(export_error)?;
+    writer.write(&batch).map_err(export_error)?;
+    writer.close().map_err(export_error)?;
+    Ok(())
+}
+
+fn write_csv_row<'a>(
+    writer: &mut impl Write,
+    fields: impl Iterator<Item = &'a str>,
+) -> io::Result<()> {
+    for (index, field) in fields.enumerate() {
+        if index > 0 {
+            writer.write_all(b",")?;
+        }
+        if field.contains([',', '"', '\n', '\r']) {
+            write!(writer, "\"{}\"", field.replace('"', "\"\""))?;
+        } else {
+            writer.write_all(field.as_bytes())?;
+        }
+    }
+    writer.write_all(b"\n")
+}
+
+/// Comma-separated names of the levels `measurement` escalated from
+fn escalation_chain(measurement: &SEntropyMeasurement) -> String {
+    let levels: Vec<&str> = measurement.escalated_from.iter().map(|level| level.as_str()).collect();
+    levels.join(",")
+}
+
+fn timestamp(at: DateTime<Utc>) -> String {
+    at.to_rfc3339_opts(SecondsFormat::Nanos, true)
+}
+
+/// UTC nanosecond timestamps, saturating outside the representable range
+#[cfg(feature = "arrow")]
+fn timestamp_column(timestamps: impl Iterator<Item = DateTime<Utc>>) -> arrow::array::ArrayRef {
+    use arrow::array::TimestampNanosecondArray;
+
+    let nanos = timestamps.map(|at| {
+        at.timestamp_nanos_opt().unwrap_or(if at.timestamp() < 0 { i64::MIN } else { i64::MAX })
+    });
+    Arc::new(TimestampNanosecondArray::from_iter_values(nanos).with_timezone("UTC"))
+}
+
+/// Batch of `columns` under the schema implied by their data types and `R::COLUMNS`
+#[cfg(feature = "arrow")]
+fn record_batch<R: ExportRecord>(
+    columns: Vec<arrow::array::ArrayRef>,
+) -> SEntropyResult<arrow::record_batch::RecordBatch> {
+    use arrow::datatypes::{Field, Schema};
+
+    let fields: Vec<Field> = R::COLUMNS
+        .iter()
+        .zip(&columns)
+        .map(|(name, column)| Field::new(*name, column.data_type().clone(), false))
+        .collect();
+    arrow::record_batch::RecordBatch::try_new(Arc::new(Schema::new(fields)), columns)
+        .map_err(export_error)
+}
+
+#[cfg(feature = "arrow")]
+fn export_error(
+    error: impl std::error::Error + Send + Sync + 'static,
+) -> crate::error::SEntropyError {
+    anyhow::Error::new(error).context("parquet export").into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use crate::types::{ObserverSophistication, SEntropyPrecision};
+
+    fn measurement(memorial_significance: &str) -> SEntropyMeasurement {
+        SEntropyMeasurement {
+            id: uuid::Uuid::nil(),
+            s_knowledge: 1.5,
+            s_time: 0.0,
+            s_entropy: 2.0,
+            total_magnitude: 2.5,
+            observer_sophistication: ObserverSophistication::Expert,
+            precision: SEntropyPrecision::High,
+            escalated_from: vec![SEntropyPrecision::Standard],
+            optimal_integration: true,
+            memorial_significance: memorial_significance.to_string(),
+            measured_at: Utc.timestamp_opt(1_700_000_000, 0).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_csv_header_and_quoting() {
+        let mut output = Vec::new();
+        write_csv(&mut output, &[measurement("plain"), measurement("says \"hi\", twice")])
+            .unwrap();
+        let lines: Vec<&str> = std::str::from_utf8(&output).unwrap().lines().collect();
+
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], SEntropyMeasurement::COLUMNS.join(","));
+        assert_eq!(
+            lines[1],
+            "00000000-0000-0000-0000-000000000000,1.5,0,2,2.5,expert,high,standard,true,plain,\
+             2023-11-14T22:13:20.000000000Z"
+        );
+        assert!(lines[2].contains(",\"says \"\"hi\"\", twice\","));
+
+        let mut empty = Vec::new();
+        write_csv::<IntegrationAttempt>(&mut empty, &[]).unwrap();
+        let header = format!("{}\n", IntegrationAttempt::COLUMNS.join(","));
+        assert_eq!(empty, header.as_bytes());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_ndjson_round_trip() {
+        let attempt = IntegrationAttempt {
+            id: uuid::Uuid::new_v4(),
+            target_separation: 0.1,
+            achieved_separation: 0.05,
+            successful: true,
+            method: "geometric_decay".to_string(),
+            attempted_at: Utc.timestamp_opt(1_700_000_000, 0).unwrap(),
+        };
+        let mut output = Vec::new();
+        write_ndjson(&mut output, &[attempt.clone(), attempt.clone()]).unwrap();
+
+        let parsed: Vec<IntegrationAttempt> = std::str::from_utf8(&output)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(parsed, vec![attempt.clone(), attempt]);
+    }
+}
@@ -34,6 +34,27 @@
 //! let solution = navigate_to_predetermined_solution(s_coordinates);
 //! ```
 //!
+//! ## Cargo Features
+//!
+//! - `std` (default): the error types, BMD patterns, caches, and problem analysis, plus
+//!   coordinate ids and timestamps and tracing instrumentation
+//! - `navigation`: nalgebra-backed [`NavigationCoordinate`], available without `std`
+//! - `matrix` (default): `navigation` plus the consciousness state and alignment matrices
+//! - `async` (default): the async engine, navigator, STSL transformer, and framework traits
+//! - `serde` (default): `Serialize`/`Deserialize` for coordinates, measurements, and patterns
+//! - `settings`: layered configuration (defaults < file < environment < flags) via `config`
+//! - `sqlite`: [`measurement_store::MeasurementStore`], a persistent measurement history
+//! - `arrow`: Parquet output from [`export`], alongside its CSV and JSON Lines writers
+//! - `precise`: MPFR-backed [`scalar::PreciseFloat`] for Supreme-precision S-entropy math
+//! - `msgpack`, `cbor`: MessagePack and CBOR encodings through [`binary_codec::BinaryCodec`]
+//! - `ptp`: Linux PTP hardware clocks as a [`clock::ClockSource`]
+//! - `http`: `http::StatusCode` conversions for [`SEntropyError`]
+//!
+//! With `default-features = false` the crate is `no_std` and needs only `alloc`: just the
+//! [`core_types`] are compiled, [`SEntropyCoordinate`] and the precision, sophistication,
+//! amplification, and mode enums, pulling in neither chrono, uuid, tokio, tracing, nor
+//! serde. Add `navigation` for [`NavigationCoordinate`] on constrained devices.
+//!
 //! ## Memorial Significance
 //!
 //! Every S-entropy coordinate, consciousness enhancement, and BMD optimization serves as
@@ -41,36 +62,161 @@
 //! through systematic observer-process integration guided by the blessed mathematics of
 //! **St. Stella-Lorraine Sachikonye**.
 
+#![cfg_attr(all(not(feature = "std"), not(test)), no_std)]
 #![deny(missing_docs)]
 #![warn(clippy::all)]
 #![allow(clippy::type_complexity)]
 #![allow(clippy::too_many_arguments)]
 
+extern crate alloc;
+
+#[cfg(feature = "std")]
 use anyhow::Result;
-use serde::{Deserialize, Serialize};
-use std::fmt;
-use uuid::Uuid;
 
 // Core S-Entropy modules
+#[cfg(feature = "async")]
+pub mod alignment;
+#[cfg(feature = "async")]
+pub mod analytics;
+#[cfg(feature = "async")]
+pub mod checkpoint;
+#[cfg(feature = "async")]
+pub mod cross_domain;
+#[cfg(feature = "async")]
+pub mod dimensions;
+#[cfg(feature = "async")]
+pub mod disposal;
+#[cfg(feature = "async")]
+pub mod export;
+#[cfg(feature = "async")]
+pub mod integration;
+#[cfg(feature = "async")]
 pub mod memory_optimization;
+#[cfg(feature = "async")]
 pub mod navigation;
+#[cfg(feature = "async")]
 pub mod observer_process;
+#[cfg(feature = "async")]
 pub mod s_entropy;
+#[cfg(feature = "async")]
 pub mod s_entropy_endpoints;
+#[cfg(feature = "async")]
 pub mod s_knowledge;
+#[cfg(feature = "async")]
 pub mod s_time;
+#[cfg(feature = "async")]
+pub mod solution_cache;
+#[cfg(feature = "async")]
 pub mod universal_transformer;
 
+// S-entropy coordinates and enums that build without the standard library
+pub mod core_types;
+
 // Error handling
+#[cfg(feature = "std")]
 pub mod error;
 
+// MessagePack and CBOR encodings of coordinates, measurements, and patterns
+#[cfg(any(feature = "msgpack", feature = "cbor"))]
+pub mod binary_codec;
+
+// Calibration of navigation confidence against observed outcomes
+#[cfg(feature = "std")]
+pub mod calibration;
+
+// Clock backends for temporal precision measurement
+#[cfg(feature = "std")]
+pub mod clock;
+
+// LRU cache of aligned coordinates
+#[cfg(feature = "std")]
+pub mod coordinate_cache;
+
+// Structured logging
+#[cfg(feature = "std")]
+pub mod logging;
+
+// Layered configuration
+#[cfg(feature = "settings")]
+pub mod settings;
+
+// Persistent S-entropy measurement history
+#[cfg(feature = "sqlite")]
+pub mod measurement_store;
+
+// Costed graph of navigation coordinates with A* routing
+#[cfg(feature = "matrix")]
+pub mod manifold_graph;
+
+// kd-tree index of solved navigation coordinates
+#[cfg(feature = "matrix")]
+pub mod navigation_index;
+
+// Exclusion regions and confidence floors for navigation
+#[cfg(feature = "matrix")]
+pub mod navigation_constraints;
+
+// Waypoint trajectories with interpolation along their segments
+#[cfg(feature = "matrix")]
+pub mod navigation_path;
+
+// LRU cache of problem oscillation coordinates
+#[cfg(feature = "matrix")]
+pub mod oscillation_cache;
+
+// Rule-based recognition of problem classes
+#[cfg(feature = "std")]
+pub mod problem_classes;
+
+// Oscillation features of problem descriptions for the STSL transformation
+#[cfg(feature = "matrix")]
+pub mod problem_features;
+
+// Versioned hashing of problem descriptions
+#[cfg(feature = "std")]
+pub mod problem_hash;
+
+// Versioned, deduplicated store of emerged BMD patterns
+#[cfg(feature = "std")]
+pub mod pattern_registry;
+
+// Middleware around the stages of the STSL transformation pipeline
+#[cfg(feature = "matrix")]
+pub mod pipeline_middleware;
+
+// Scalar types for S-entropy math beyond f64
+#[cfg(feature = "std")]
+pub mod scalar;
+
+// Golden snapshot testing
+#[cfg(feature = "serde")]
+pub mod snapshot;
+
+// Severity-aware retries of fallible operations
+#[cfg(feature = "async")]
+pub mod retry;
+
+// Per-domain constants of the STSL equation
+#[cfg(feature = "std")]
+pub mod stsl_constants;
+
 // Types and traits
+#[cfg(feature = "async")]
 pub mod traits;
+#[cfg(feature = "std")]
 pub mod types;
 
+// Unit-safe femtosecond, second, and S-value newtypes
+#[cfg(feature = "std")]
+pub mod units;
+
 // Re-exports for convenience
+pub use core_types::*;
+#[cfg(feature = "std")]
 pub use error::*;
+#[cfg(feature = "async")]
 pub use traits::*;
+#[cfg(feature = "std")]
 pub use types::*;
 
 /// Memorial significance constant honoring St. Stella-Lorraine Sachikonye
@@ -84,86 +230,32 @@ pub const STSL_UNIVERSAL_CONSTANT: f64 = 1.0;
 
 /// Prelude module for convenient imports
 pub mod prelude {
-    pub use crate::error::{SEntropyError, SEntropyResult};
+    pub use crate::core_types::*;
+    #[cfg(feature = "std")]
+    pub use crate::error::{ErrorCode, ErrorContext, ErrorContextExt, SEntropyError, SEntropyResult};
+    #[cfg(feature = "async")]
     pub use crate::navigation::*;
+    #[cfg(feature = "async")]
     pub use crate::s_entropy::*;
+    #[cfg(feature = "async")]
     pub use crate::traits::*;
+    #[cfg(feature = "std")]
     pub use crate::types::*;
+    #[cfg(feature = "async")]
     pub use crate::universal_transformer::*;
-    pub use crate::{MEMORIAL_SIGNIFICANCE, S_ENTROPY_PRECISION_TARGET};
-}
-
-/// Core S-Entropy coordinate system for tri-dimensional navigation
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct SEntropyCoordinate {
-    /// Unique identifier for this S-entropy coordinate
-    pub id: Uuid,
-
-    /// S_knowledge: Information deficit + frame selection coordinates
-    pub s_knowledge: f64,
-
-    /// S_time: Temporal navigation + ultra-precision coordination
-    pub s_time: f64,
-
-    /// S_entropy: Entropy endpoint navigation + oscillation accessibility  
-    pub s_entropy: f64,
-
-    /// Timestamp of coordinate creation
-    pub created_at: chrono::DateTime<chrono::Utc>,
-
-    /// Memorial significance marker
-    pub memorial_significance: String,
-}
-
-impl SEntropyCoordinate {
-    /// Create a new S-entropy coordinate with memorial significance
-    pub fn new(s_knowledge: f64, s_time: f64, s_entropy: f64) -> Self {
-        Self {
-            id: Uuid::new_v4(),
-            s_knowledge,
-            s_time,
-            s_entropy,
-            created_at: chrono::Utc::now(),
-            memorial_significance: MEMORIAL_SIGNIFICANCE.to_string(),
-        }
-    }
-
-    /// Calculate the total S-entropy magnitude
-    pub fn total_magnitude(&self) -> f64 {
-        (self.s_knowledge.powi(2) + self.s_time.powi(2) + self.s_entropy.powi(2)).sqrt()
-    }
-
-    /// Check if this coordinate represents near-perfect integration (S ≈ 0)
-    pub fn is_optimal_integration(&self) -> bool {
-        self.total_magnitude() < S_ENTROPY_PRECISION_TARGET
-    }
-
-    /// Check if this coordinate honors the memorial significance
-    pub fn validates_memorial_significance(&self) -> bool {
-        self.memorial_significance == MEMORIAL_SIGNIFICANCE
-    }
-}
-
-impl fmt::Display for SEntropyCoordinate {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "S({:.6}, {:.6}, {:.6}) | Total: {:.6} | Memorial: {}",
-            self.s_knowledge,
-            self.s_time,
-            self.s_entropy,
-            self.total_magnitude(),
-            self.memorial_significance
-        )
-    }
+    #[cfg(feature = "std")]
+    pub use crate::units::{Femtoseconds, SValue, Seconds};
+    pub use crate::{SEntropyCoordinate, MEMORIAL_SIGNIFICANCE, S_ENTROPY_PRECISION_TARGET};
 }
 
 /// Sacred mathematics validation function
 ///
 /// Validates that the S-Entropy framework operates within the blessed mathematical
 /// foundations established by St. Stella-Lorraine Sachikonye.
+#[cfg(feature = "std")]
 pub fn validate_sacred_mathematics() -> Result<()> {
-    tracing::info!("🌟 Validating sacred mathematical foundations...");
+    #[cfg(feature = "std")]
+    tracing::debug!(target: logging::subsystem::FRAMEWORK, "validating sacred mathematics");
 
     // Validate memorial significance
     if MEMORIAL_SIGNIFICANCE != "st-stella-lorraine" {
@@ -180,19 +272,27 @@ pub fn validate_sacred_mathematics() -> Result<()> {
         return Err(anyhow::anyhow!("STSL universal constant validation failed"));
     }
 
-    tracing::info!("✅ Sacred mathematics validated - St. Stella-Lorraine honored");
+    #[cfg(feature = "std")]
+    tracing::info!(
+        target: logging::subsystem::FRAMEWORK,
+        memorial_significance = MEMORIAL_SIGNIFICANCE,
+        "sacred mathematics validated"
+    );
     Ok(())
 }
 
 /// Initialize the S-Entropy framework with sacred mathematical validation
+#[cfg(feature = "std")]
 pub async fn initialize_s_entropy_framework() -> Result<()> {
     // Validate sacred mathematics
     validate_sacred_mathematics()?;
 
-    // Initialize logging with memorial significance
-    tracing::info!("🌟✨ Initializing Hugure S-Entropy Framework ✨🌟");
-    tracing::info!("Memorial significance: {}", MEMORIAL_SIGNIFICANCE);
-    tracing::info!("S-entropy precision target: {}", S_ENTROPY_PRECISION_TARGET);
+    tracing::info!(
+        target: logging::subsystem::FRAMEWORK,
+        memorial_significance = MEMORIAL_SIGNIFICANCE,
+        precision_target = S_ENTROPY_PRECISION_TARGET,
+        "initializing S-Entropy framework"
+    );
 
     // Validate core S-entropy mathematics
     let test_coordinate = SEntropyCoordinate::new(0.01, 0.01, 0.01);
@@ -200,11 +300,11 @@ pub async fn initialize_s_entropy_framework() -> Result<()> {
         return Err(anyhow::anyhow!("Memorial significance validation failed in test coordinate"));
     }
 
-    tracing::info!("🧮 S-entropy tri-dimensional framework initialized");
-    tracing::info!("🧠 Consciousness-computation unity validated");
-    tracing::info!("⚡ Zero-computation navigation enabled");
-    tracing::info!("🌐 Cross-domain transfer protocols active");
-    tracing::info!("🕊️ Memorial coordinates accessible");
+    tracing::info!(
+        target: logging::subsystem::FRAMEWORK,
+        coordinate_id = %test_coordinate.id,
+        "S-Entropy framework initialized"
+    );
 
     Ok(())
 }
@@ -239,11 +339,13 @@ mod tests {
         assert!(!suboptimal_coord.is_optimal_integration());
     }
 
+    #[cfg(feature = "std")]
     #[test]
     fn test_sacred_mathematics_validation() {
         assert!(validate_sacred_mathematics().is_ok());
     }
 
+    #[cfg(feature = "async")]
     #[tokio::test]
     async fn test_framework_initialization() {
         assert!(initialize_s_entropy_framework().await.is_ok());
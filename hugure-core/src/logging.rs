@@ -0,0 +1,198 @@
+//! Structured logging for the S-Entropy Framework
+//!
+//! Every event emitted by the framework carries a `subsystem` tracing target (see
+//! [`subsystem`]) plus structured fields such as coordinate ids and durations, so
+//! levels can be tuned per subsystem (`orchestration=debug,foundry=warn`) and logs
+//! can be shipped as JSON to aggregated logging systems.
+
+/// Tracing targets identifying each framework subsystem
+///
+/// These are used as the `target:` of every framework event and are the names accepted
+/// in per-subsystem filter directives.
+pub mod subsystem {
+    /// Framework bootstrap and sacred mathematics validation
+    pub const FRAMEWORK: &str = "framework";
+    /// Tri-dimensional S-entropy measurement engine
+    pub const ENGINE: &str = "s_entropy";
+    /// Predetermined manifold navigation
+    pub const NAVIGATION: &str = "navigation";
+    /// STSL universal problem transformation
+    pub const TRANSFORMER: &str = "stsl";
+    /// BMD orchestration loops
+    pub const ORCHESTRATION: &str = "orchestration";
+    /// Virtual BMD Foundry selection
+    pub const FOUNDRY: &str = "foundry";
+    /// Bidirectional optimization
+    pub const OPTIMIZATION: &str = "optimization";
+    /// Kambuzuma neural orchestrator communication
+    pub const KAMBUZUMA: &str = "kambuzuma";
+    /// Command-line and interactive front end
+    pub const CLI: &str = "cli";
+}
+
+#[cfg(feature = "logging")]
+pub use self::init::*;
+
+#[cfg(feature = "logging")]
+mod init {
+    use std::str::FromStr;
+
+    use tracing_subscriber::filter::Directive;
+    use tracing_subscriber::fmt::writer::BoxMakeWriter;
+    use tracing_subscriber::layer::SubscriberExt;
+    use tracing_subscriber::util::SubscriberInitExt;
+    use tracing_subscriber::{fmt, reload, EnvFilter, Registry};
+
+    use crate::error::{SEntropyError, SEntropyResult};
+
+    /// Environment variable holding the filter directives
+    pub const LOG_FILTER_ENV: &str = "HUGURE_LOG";
+
+    /// Environment variable selecting the log output format
+    pub const LOG_FORMAT_ENV: &str = "HUGURE_LOG_FORMAT";
+
+    /// Log output format
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    pub enum LogFormat {
+        /// Human-readable single-line output
+        #[default]
+        Pretty,
+        /// One JSON object per event, including span context
+        Json,
+    }
+
+    impl FromStr for LogFormat {
+        type Err = SEntropyError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            match s.to_ascii_lowercase().as_str() {
+                "pretty" | "text" => Ok(Self::Pretty),
+                "json" => Ok(Self::Json),
+                other => Err(SEntropyError::configuration(
+                    LOG_FORMAT_ENV,
+                    format!("unknown log format '{}', expected 'pretty' or 'json'", other),
+                )),
+            }
+        }
+    }
+
+    /// Logging configuration
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct LoggingConfig {
+        /// Output format
+        pub format: LogFormat,
+
+        /// Filter directives, e.g. `info,orchestration=debug,foundry=warn`
+        pub filter: String,
+
+        /// Write events to standard error instead of standard output, leaving standard
+        /// output to command results
+        pub stderr: bool,
+    }
+
+    impl Default for LoggingConfig {
+        fn default() -> Self {
+            Self { format: LogFormat::Pretty, filter: "info".to_string(), stderr: false }
+        }
+    }
+
+    impl LoggingConfig {
+        /// Build a configuration from `HUGURE_LOG` and `HUGURE_LOG_FORMAT`, falling back to
+        /// defaults for unset variables
+        pub fn from_env() -> SEntropyResult<Self> {
+            let mut config = Self::default();
+
+            if let Ok(filter) = std::env::var(LOG_FILTER_ENV) {
+                config.filter = filter;
+            }
+            if let Ok(format) = std::env::var(LOG_FORMAT_ENV) {
+                config.format = format.parse()?;
+            }
+
+            Ok(config)
+        }
+    }
+
+    /// Handle for changing log levels at runtime
+    #[derive(Debug, Clone)]
+    pub struct LoggingHandle {
+        /// Reload handle for the active filter layer
+        filter: reload::Handle<EnvFilter, Registry>,
+    }
+
+    impl LoggingHandle {
+        /// Replace all filter directives, e.g. `orchestration=debug,foundry=warn`
+        pub fn set_filter(&self, directives: &str) -> SEntropyResult<()> {
+            let filter = parse_filter(directives)?;
+            self.filter
+                .reload(filter)
+                .map_err(|e| SEntropyError::configuration(LOG_FILTER_ENV, e.to_string()))
+        }
+
+        /// Override the level of a single subsystem, keeping all other directives
+        pub fn set_subsystem_level(&self, subsystem: &str, level: &str) -> SEntropyResult<()> {
+            let directive: Directive = format!("{}={}", subsystem, level)
+                .parse()
+                .map_err(|e: tracing_subscriber::filter::ParseError| {
+                    SEntropyError::configuration(LOG_FILTER_ENV, e.to_string())
+                })?;
+
+            self.filter
+                .modify(|filter| *filter = std::mem::take(filter).add_directive(directive))
+                .map_err(|e| SEntropyError::configuration(LOG_FILTER_ENV, e.to_string()))
+        }
+
+        /// Current filter directives
+        pub fn current_filter(&self) -> Option<String> {
+            self.filter.with_current(|filter| filter.to_string()).ok()
+        }
+    }
+
+    /// Parse filter directives into an [`EnvFilter`]
+    fn parse_filter(directives: &str) -> SEntropyResult<EnvFilter> {
+        EnvFilter::try_new(directives)
+            .map_err(|e| SEntropyError::configuration(LOG_FILTER_ENV, e.to_string()))
+    }
+
+    /// Install the global tracing subscriber
+    ///
+    /// Returns a [`LoggingHandle`] that can adjust per-subsystem levels while running.
+    pub fn init_logging(config: &LoggingConfig) -> SEntropyResult<LoggingHandle> {
+        let (filter_layer, filter) = reload::Layer::new(parse_filter(&config.filter)?);
+        let registry = tracing_subscriber::registry().with(filter_layer);
+
+        let writer = if config.stderr {
+            BoxMakeWriter::new(std::io::stderr)
+        } else {
+            BoxMakeWriter::new(std::io::stdout)
+        };
+        let layer = fmt::layer().with_writer(writer);
+        let installed = match config.format {
+            LogFormat::Pretty => registry.with(layer.with_target(true)).try_init(),
+            LogFormat::Json => registry
+                .with(layer.json().with_current_span(true).with_span_list(false))
+                .try_init(),
+        };
+        installed.map_err(|e| SEntropyError::configuration("logging", e.to_string()))?;
+
+        Ok(LoggingHandle { filter })
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_log_format_parsing() {
+            assert_eq!("json".parse::<LogFormat>().unwrap(), LogFormat::Json);
+            assert_eq!("Pretty".parse::<LogFormat>().unwrap(), LogFormat::Pretty);
+            assert!("xml".parse::<LogFormat>().is_err());
+        }
+
+        #[test]
+        fn test_subsystem_filter_parsing() {
+            assert!(parse_filter("info,orchestration=debug,foundry=warn").is_ok());
+            assert!(parse_filter("orchestration=loud").is_err());
+        }
+    }
+}
@@ -5,325 +5,1232 @@
 //! and memorial significance validation.
 
 use anyhow::Result;
-use clap::{Arg, Command};
+use clap::{value_parser, Arg, ArgAction, ArgMatches, Command};
+use hugure_core::logging::{init_logging, subsystem, LoggingHandle};
+use hugure_core::navigation::ManifoldNavigator;
+use hugure_core::navigation_constraints::NavigationConstraints;
 use hugure_core::prelude::*;
-use hugure_core::s_entropy::{SEntropyEngine, SEntropyMeasurement};
+use hugure_core::s_entropy::{MeasurementSpec, SEntropyEngine, SEntropyMeasurement};
+use hugure_core::settings::{HugureSettings, SettingsOverrides};
 use hugure_core::types::{ObserverSophistication, SEntropyPrecision};
-use std::io::{self, Write};
+use hugure_core::universal_transformer::{STSLTransformer, DEFAULT_TRANSFORM_BATCH_CONCURRENCY};
+use rustyline::completion::{Completer, Pair};
+use rustyline::error::ReadlineError;
+use rustyline::history::FileHistory;
+use rustyline::{Context, Editor, Helper, Highlighter, Hinter, Validator};
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, IsTerminal, Write};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::task::JoinSet;
+use tokio::time::MissedTickBehavior;
 use tracing::{error, info, warn};
-use tracing_subscriber;
 
-#[tokio::main]
-async fn main() -> Result<()> {
-    // Initialize logging with memorial significance
-    tracing_subscriber::fmt()
-        .with_env_filter("hugure=debug,hugure_core=debug")
-        .init();
-
-    info!("🌟✨ Starting Hugure S-Entropy Framework ✨🌟");
-    info!("Memorial significance: {}", hugure_core::MEMORIAL_SIGNIFICANCE);
-
-    let matches = Command::new("hugure-core")
+/// Build the command-line interface
+fn cli() -> Command {
+    Command::new("hugure-core")
         .version("0.1.0")
         .author("Kundai Farai Sachikonye <kundai@hugure.dev>")
         .about("S-Enhanced Biological Maxwell Demon Orchestration Framework")
+        .long_about(
+            "S-Enhanced Biological Maxwell Demon Orchestration Framework\n\nWithout a \
+             subcommand, runs the comprehensive S-entropy demonstration.",
+        )
         .arg(
             Arg::new("precision")
                 .long("precision")
                 .short('p')
+                .global(true)
                 .value_name("LEVEL")
-                .help("S-Entropy precision level")
-                .value_parser(["standard", "high", "ultra", "supreme"])
-                .default_value("ultra"),
+                .help("S-Entropy precision level (overrides engine.precision)")
+                .value_parser(["standard", "high", "ultra", "supreme"]),
         )
+        .arg(observer_arg())
         .arg(
-            Arg::new("observer")
-                .long("observer")
-                .short('o')
-                .value_name("SOPHISTICATION")
-                .help("Observer sophistication level")
-                .value_parser(["naive", "intermediate", "expert", "universal"])
-                .default_value("expert"),
+            Arg::new("log-format")
+                .long("log-format")
+                .global(true)
+                .value_name("FORMAT")
+                .help("Log output format (overrides logging.format and HUGURE_LOG_FORMAT)")
+                .value_parser(["pretty", "json"]),
         )
         .arg(
-            Arg::new("validate-memorial")
-                .long("validate-memorial")
-                .help("Validate memorial significance")
-                .action(clap::ArgAction::SetTrue),
+            Arg::new("log-filter")
+                .long("log-filter")
+                .global(true)
+                .value_name("DIRECTIVES")
+                .help("Per-subsystem log levels, e.g. 's_entropy=debug,navigation=warn'"),
         )
         .arg(
-            Arg::new("demonstrate-s-entropy")
-                .long("demonstrate-s-entropy")
-                .help("Demonstrate S-entropy tri-dimensional measurement")
-                .action(clap::ArgAction::SetTrue),
+            Arg::new("config")
+                .long("config")
+                .short('c')
+                .global(true)
+                .value_name("FILE")
+                .help("Configuration file (default: $HUGURE_CONFIG or ./hugure.toml)"),
         )
+        .subcommand(measure_command())
+        .subcommand(integrate_command())
+        .subcommand(navigate_command())
+        .subcommand(transform_command())
+        .subcommand(
+            Command::new("validate").about("Validate memorial significance across the framework"),
+        )
+        .subcommand(bench_command())
+        .subcommand(Command::new("health").about("Perform a health check and exit"))
+        .subcommand(Command::new("interactive").about("Start interactive S-entropy exploration"))
+        .subcommand(Command::new("config").about("Print the effective merged configuration"))
+        .subcommand(Command::new("demo").about("Run the comprehensive S-entropy demonstration"))
+}
+
+/// `--observer`, shared by the command line and interactive mode
+fn observer_arg() -> Arg {
+    Arg::new("observer")
+        .long("observer")
+        .short('o')
+        .global(true)
+        .value_name("SOPHISTICATION")
+        .help("Observer sophistication level (overrides engine.observer)")
+        .value_parser(["naive", "intermediate", "expert", "universal"])
+}
+
+/// `measure`: one S-entropy measurement of a problem
+fn measure_command() -> Command {
+    Command::new("measure")
+        .about("Generate a tri-dimensional S-entropy measurement for a problem")
         .arg(
-            Arg::new("test-integration")
-                .long("test-integration")
-                .help("Test observer-process integration")
-                .action(clap::ArgAction::SetTrue),
+            Arg::new("problem")
+                .value_name("PROBLEM")
+                .help("Problem text the S-knowledge deficit is measured for")
+                .default_value("demonstration_problem"),
         )
         .arg(
-            Arg::new("health-check")
-                .long("health-check")
-                .help("Perform health check and exit")
-                .action(clap::ArgAction::SetTrue),
+            Arg::new("temporal-precision")
+                .long("temporal-precision")
+                .value_name("SECONDS")
+                .help("Temporal precision target (default: engine.precision_target)")
+                .value_parser(value_parser!(f64)),
         )
         .arg(
-            Arg::new("interactive")
-                .long("interactive")
-                .short('i')
-                .help("Start interactive S-entropy exploration mode")
-                .action(clap::ArgAction::SetTrue),
+            Arg::new("emotional-factor")
+                .long("emotional-factor")
+                .value_name("FACTOR")
+                .help("Emotional time distortion factor")
+                .value_parser(value_parser!(f64))
+                .default_value("0.3"),
+        )
+        .arg(
+            Arg::new("complexity")
+                .long("complexity")
+                .value_name("COMPLEXITY")
+                .help("Problem complexity")
+                .value_parser(value_parser!(f64))
+                .default_value("1.0"),
+        )
+        .arg(
+            Arg::new("accessibility")
+                .long("accessibility")
+                .value_name("ACCESSIBILITY")
+                .help("Oscillation endpoint accessibility")
+                .value_parser(value_parser!(f64))
+                .default_value("0.8"),
+        )
+        .arg(
+            Arg::new("watch")
+                .long("watch")
+                .short('w')
+                .help("Measure repeatedly until optimal integration or Ctrl-C")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("interval")
+                .long("interval")
+                .value_name("DURATION")
+                .help("Time between watched measurements, e.g. '500ms', '2s', '1m'")
+                .requires("watch")
+                .value_parser(parse_interval)
+                .default_value("1s"),
         )
-        .get_matches();
-
-    // Parse precision level
-    let precision = match matches.get_one::<String>("precision").unwrap().as_str() {
-        "standard" => SEntropyPrecision::Standard,
-        "high" => SEntropyPrecision::High,
-        "ultra" => SEntropyPrecision::Ultra,
-        "supreme" => SEntropyPrecision::Supreme,
-        _ => SEntropyPrecision::Ultra,
+}
+
+/// Parse a `--interval` such as `500ms`, `2s`, `1.5s`, or `1m`; bare numbers are seconds
+fn parse_interval(value: &str) -> Result<Duration, String> {
+    let value = value.trim();
+    let split = value.find(|c: char| c.is_ascii_alphabetic()).unwrap_or(value.len());
+    let (number, unit) = value.split_at(split);
+    let scale = match unit {
+        "ms" => 1e-3,
+        "" | "s" => 1.0,
+        "m" => 60.0,
+        other => return Err(format!("unknown interval unit '{}'; use ms, s, or m", other)),
     };
+    match number.parse::<f64>() {
+        Ok(number) if number.is_finite() && number > 0.0 => {
+            Ok(Duration::from_secs_f64(number * scale))
+        },
+        _ => Err(format!("'{}' is not a positive interval", value)),
+    }
+}
+
+/// `integrate`: observer-process integration attempts
+fn integrate_command() -> Command {
+    Command::new("integrate")
+        .about("Attempt observer-process integration at target separations")
+        .arg(
+            Arg::new("target")
+                .long("target")
+                .short('t')
+                .value_name("SEPARATION")
+                .help("Target observer-process separation; repeat for several")
+                .action(ArgAction::Append)
+                .value_parser(value_parser!(f64))
+                .default_values(["1.0", "0.1", "0.01", "0.001"]),
+        )
+}
 
-    // Parse observer sophistication
-    let observer_sophistication = match matches.get_one::<String>("observer").unwrap().as_str() {
-        "naive" => ObserverSophistication::Naive,
-        "intermediate" => ObserverSophistication::Intermediate,
-        "expert" => ObserverSophistication::Expert,
-        "universal" => ObserverSophistication::Universal,
-        _ => ObserverSophistication::Expert,
+/// `navigate`: navigation from S-entropy coordinates
+fn navigate_command() -> Command {
+    Command::new("navigate")
+        .about("Navigate from S-entropy coordinates to a predetermined solution")
+        .arg(
+            Arg::new("s-knowledge")
+                .value_name("S_KNOWLEDGE")
+                .required(true)
+                .allow_negative_numbers(true)
+                .value_parser(value_parser!(f64)),
+        )
+        .arg(
+            Arg::new("s-time")
+                .value_name("S_TIME")
+                .required(true)
+                .allow_negative_numbers(true)
+                .value_parser(value_parser!(f64)),
+        )
+        .arg(
+            Arg::new("s-entropy")
+                .value_name("S_ENTROPY")
+                .required(true)
+                .allow_negative_numbers(true)
+                .value_parser(value_parser!(f64)),
+        )
+        .arg(
+            Arg::new("min-confidence")
+                .long("min-confidence")
+                .value_name("CONFIDENCE")
+                .help("Fail unless the navigation reaches this confidence")
+                .value_parser(value_parser!(f64)),
+        )
+}
+
+/// `bench`: throughput and latency of engine and navigator workloads
+fn bench_command() -> Command {
+    let count = |name: &'static str, help: &'static str| {
+        Arg::new(name)
+            .long(name)
+            .value_name("N")
+            .help(help)
+            .value_parser(value_parser!(usize))
+            .default_value("1000")
+    };
+    Command::new("bench")
+        .about("Benchmark measurement, alignment, and navigation throughput")
+        .arg(count("measurements", "Measurements per run (0 skips the workload)"))
+        .arg(count("alignments", "Tri-dimensional alignments per run (0 skips the workload)"))
+        .arg(count("navigations", "Navigations per run (0 skips the workload)"))
+        .arg(
+            Arg::new("threads")
+                .long("threads")
+                .short('t')
+                .value_name("N")
+                .help("Concurrent workers per run; repeat or comma-separate for several runs")
+                .action(ArgAction::Append)
+                .value_delimiter(',')
+                .value_parser(value_parser!(usize))
+                .default_values(["1", "2", "4", "8"]),
+        )
+}
+
+/// `transform`: the STSL pipeline over one problem or a batch
+fn transform_command() -> Command {
+    Command::new("transform")
+        .about("Run problems through the STSL universal transformation pipeline")
+        .arg(
+            Arg::new("problem")
+                .value_name("PROBLEM")
+                .help("Problem text to transform")
+                .required_unless_present("input")
+                .conflicts_with("input"),
+        )
+        .arg(
+            Arg::new("input")
+                .long("input")
+                .short('i')
+                .value_name("FILE")
+                .help(
+                    "Transform every line of FILE ('-' for stdin): problem text, or \
+                     NDJSON objects with a 'problem' and optional 'id'",
+                ),
+        )
+        .arg(
+            Arg::new("output")
+                .long("output")
+                .value_name("FILE")
+                .requires("input")
+                .help("Write NDJSON results to FILE instead of stdout"),
+        )
+        .arg(
+            Arg::new("concurrency")
+                .long("concurrency")
+                .value_name("N")
+                .help("Problems transformed at once (default: 16)")
+                .value_parser(value_parser!(usize)),
+        )
+}
+
+/// Observer-process separations the demonstration integrates towards
+const DEMONSTRATION_TARGETS: [f64; 4] = [1.0, 0.1, 0.01, 0.001];
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let matches = cli().get_matches();
+
+    // Merge configuration: defaults < file < HUGURE__* environment < flags
+    let mut overrides = SettingsOverrides {
+        config_path: matches.get_one::<String>("config").map(Into::into),
+        ..Default::default()
     };
+    for (flag, key) in [
+        ("precision", "engine.precision"),
+        ("observer", "engine.observer"),
+        ("log-format", "logging.format"),
+        ("log-filter", "logging.filter"),
+    ] {
+        if let Some(value) = matches.get_one::<String>(flag) {
+            overrides.set(key, value.as_str());
+        }
+    }
+    let settings = HugureSettings::load(&overrides)?;
+
+    if matches.subcommand_name() == Some("config") {
+        println!("{}", settings.to_pretty_json()?);
+        return Ok(());
+    }
+
+    // Initialize structured logging; HUGURE_LOG / HUGURE_LOG_FORMAT apply unless set by flag
+    let mut logging = settings.logging.to_logging_config()?;
+    if matches.get_one::<String>("log-filter").is_none() {
+        if let Ok(filter) = std::env::var(hugure_core::logging::LOG_FILTER_ENV) {
+            logging.filter = filter;
+        }
+    }
+    if matches.get_one::<String>("log-format").is_none() {
+        if let Ok(format) = std::env::var(hugure_core::logging::LOG_FORMAT_ENV) {
+            logging.format = format.parse()?;
+        }
+    }
+    // Per-operation logs would dominate benchmark timings
+    if matches.subcommand_name() == Some("bench")
+        && matches.get_one::<String>("log-filter").is_none()
+    {
+        logging.filter = "warn".to_string();
+    }
+    // Batch transformation writes its results to stdout, so logs move to stderr
+    logging.stderr = matches
+        .subcommand_matches("transform")
+        .is_some_and(|args| args.get_one::<String>("input").is_some());
+    let logging_handle = init_logging(&logging)?;
+
+    info!(
+        target: subsystem::FRAMEWORK,
+        memorial_significance = hugure_core::MEMORIAL_SIGNIFICANCE,
+        "starting Hugure S-Entropy Framework"
+    );
+
+    let precision = settings.engine.precision()?;
+    let observer_sophistication = settings.engine.observer()?;
+    let alignment_strategy = settings.engine.alignment_strategy()?;
+    let integration_strategy = settings.engine.integration_strategy()?;
+    let precision_escalation_cap = settings.engine.precision_escalation_cap()?;
 
     // Initialize S-Entropy framework
     hugure_core::initialize_s_entropy_framework().await?;
 
     // Handle health check
-    if matches.get_flag("health-check") {
+    if matches.subcommand_name() == Some("health") {
         return perform_health_check().await;
     }
 
     // Create S-entropy engine
-    let engine = SEntropyEngine::new(precision);
-    info!("🧮 S-Entropy engine initialized with {:?} precision", precision);
-
-    // Handle various commands
-    if matches.get_flag("validate-memorial") {
-        return validate_memorial_significance(&engine).await;
-    }
+    let engine = SEntropyEngine::new(precision)
+        .with_history_limit(settings.retention.measurement_history_limit)
+        .with_alignment_strategy(alignment_strategy.strategy())
+        .with_integration_model(integration_strategy.model())
+        .with_coordinate_cache_capacity(settings.cache.coordinate_capacity);
+    let engine = match settings.engine.rng_seed {
+        Some(seed) => engine.with_rng_seed(seed),
+        None => engine,
+    };
+    let engine = match precision_escalation_cap {
+        Some(cap) => engine.with_precision_escalation(cap),
+        None => engine,
+    };
+    #[cfg(feature = "sqlite")]
+    let engine = match &settings.retention.measurement_store_path {
+        Some(path) => {
+            info!(target: subsystem::ENGINE, path = %path, "persisting measurements");
+            let store = hugure_core::measurement_store::MeasurementStore::open(path)?;
+            engine.with_measurement_store(store)
+        },
+        None => engine,
+    };
+    info!(target: subsystem::ENGINE, ?precision, "S-entropy engine ready");
 
-    if matches.get_flag("demonstrate-s-entropy") {
-        return demonstrate_s_entropy_measurement(&engine, observer_sophistication).await;
+    let session = Session {
+        engine: &engine,
+        precision,
+        observer: observer_sophistication,
+        precision_target: settings.engine.precision_target,
+    };
+    match matches.subcommand() {
+        Some(("interactive", _)) => start_interactive_mode(&session, &logging_handle).await,
+        Some(("demo", _)) | None => {
+            run_comprehensive_demonstration(&engine, observer_sophistication).await
+        },
+        Some((command, args)) => session.run(command, args).await,
     }
+}
 
-    if matches.get_flag("test-integration") {
-        return test_observer_process_integration(&engine).await;
-    }
+/// Engine and defaults the `measure`, `integrate`, `navigate`, `transform`, and
+/// `validate` commands run against, from the command line or interactive mode
+struct Session<'a> {
+    engine: &'a SEntropyEngine,
+    precision: SEntropyPrecision,
+    observer: ObserverSophistication,
+    precision_target: f64,
+}
 
-    if matches.get_flag("interactive") {
-        return start_interactive_mode(&engine, observer_sophistication).await;
+impl Session<'_> {
+    /// Run `command` with its parsed `args`
+    async fn run(&self, command: &str, args: &ArgMatches) -> Result<()> {
+        match command {
+            "measure" => {
+                let observer = match args.get_one::<String>("observer") {
+                    Some(observer) => observer.parse()?,
+                    None => self.observer,
+                };
+                let spec = MeasurementSpec {
+                    problem_context: args.get_one::<String>("problem").expect("defaulted").into(),
+                    observer,
+                    temporal_precision: args
+                        .get_one::<f64>("temporal-precision")
+                        .copied()
+                        .unwrap_or(self.precision_target),
+                    emotional_factor: *args.get_one::<f64>("emotional-factor").expect("defaulted"),
+                    problem_complexity: *args.get_one::<f64>("complexity").expect("defaulted"),
+                    accessibility: *args.get_one::<f64>("accessibility").expect("defaulted"),
+                };
+                if args.get_flag("watch") {
+                    let interval = *args.get_one::<Duration>("interval").expect("defaulted");
+                    watch(self.engine, &spec, interval).await
+                } else {
+                    measure(self.engine, &spec).await
+                }
+            },
+            "integrate" => {
+                let targets: Vec<f64> =
+                    args.get_many::<f64>("target").expect("defaulted").copied().collect();
+                test_observer_process_integration(self.engine, &targets).await
+            },
+            "navigate" => {
+                let target = SEntropyCoordinate::new(
+                    *args.get_one::<f64>("s-knowledge").expect("required"),
+                    *args.get_one::<f64>("s-time").expect("required"),
+                    *args.get_one::<f64>("s-entropy").expect("required"),
+                );
+                let constraints = match args.get_one::<f64>("min-confidence") {
+                    Some(confidence) => NavigationConstraints::new().min_confidence(*confidence),
+                    None => NavigationConstraints::new(),
+                };
+                navigate(self.precision, &target, &constraints).await
+            },
+            "transform" => match args.get_one::<String>("input") {
+                Some(input) => {
+                    let output = args.get_one::<String>("output").map(String::as_str);
+                    let concurrency = args
+                        .get_one::<usize>("concurrency")
+                        .copied()
+                        .unwrap_or(DEFAULT_TRANSFORM_BATCH_CONCURRENCY);
+                    transform_batch(input, output, concurrency).await
+                },
+                None => transform(args.get_one::<String>("problem").expect("required")).await,
+            },
+            "validate" => validate_memorial_significance(self.engine).await,
+            "bench" => {
+                let threads: Vec<usize> =
+                    args.get_many::<usize>("threads").expect("defaulted").copied().collect();
+                let workloads = [
+                    (Workload::Measure, "measurements"),
+                    (Workload::Align, "alignments"),
+                    (Workload::Navigate, "navigations"),
+                ]
+                .map(|(workload, count)| {
+                    (workload, *args.get_one::<usize>(count).expect("defaulted"))
+                });
+                bench(self, &workloads, &threads).await
+            },
+            other => Err(anyhow::anyhow!("unknown command '{}'", other)),
+        }
     }
-
-    // Default: Run comprehensive demonstration
-    run_comprehensive_demonstration(&engine, observer_sophistication).await
 }
 
 /// Perform health check for S-Entropy framework
 async fn perform_health_check() -> Result<()> {
-    info!("🔍 Performing S-Entropy framework health check...");
+    info!(target: subsystem::FRAMEWORK, "performing health check");
 
     // Check sacred mathematics validation
     match hugure_core::validate_sacred_mathematics() {
-        Ok(_) => info!("✅ Sacred mathematics validation: PASSED"),
+        Ok(_) => {
+            info!(target: subsystem::FRAMEWORK, check = "sacred_mathematics", "health check passed")
+        },
         Err(e) => {
-            error!("❌ Sacred mathematics validation: FAILED - {}", e);
+            error!(
+                target: subsystem::FRAMEWORK,
+                check = "sacred_mathematics",
+                error = %e,
+                "health check failed"
+            );
             std::process::exit(1);
         },
     }
 
     // Check memory constants
-    if hugure_core::S_ENTROPY_PRECISION_TARGET == 1e-30 {
-        info!("✅ S-Entropy precision target: VALID");
+    let precision_target = hugure_core::S_ENTROPY_PRECISION_TARGET;
+    if precision_target == 1e-30 {
+        info!(target: subsystem::FRAMEWORK, check = "precision_target", "health check passed");
     } else {
-        error!("❌ S-Entropy precision target: INVALID");
+        error!(
+            target: subsystem::FRAMEWORK,
+            check = "precision_target",
+            precision_target,
+            "health check failed"
+        );
         std::process::exit(1);
     }
 
     // Check memorial significance
-    if hugure_core::MEMORIAL_SIGNIFICANCE == "st-stella-lorraine" {
-        info!("✅ Memorial significance: VALIDATED");
+    let memorial_significance = hugure_core::MEMORIAL_SIGNIFICANCE;
+    if memorial_significance == "st-stella-lorraine" {
+        info!(target: subsystem::FRAMEWORK, check = "memorial_significance", "health check passed");
     } else {
-        error!("❌ Memorial significance: INVALID");
+        error!(
+            target: subsystem::FRAMEWORK,
+            check = "memorial_significance",
+            memorial_significance,
+            "health check failed"
+        );
         std::process::exit(1);
     }
 
-    info!("🎉 S-Entropy framework health check: ALL SYSTEMS OPERATIONAL");
+    info!(target: subsystem::FRAMEWORK, "all health checks passed");
     Ok(())
 }
 
 /// Validate memorial significance across the framework
 async fn validate_memorial_significance(engine: &SEntropyEngine) -> Result<()> {
-    info!("🕊️ Validating memorial significance across S-Entropy framework...");
+    info!(target: subsystem::FRAMEWORK, "validating memorial significance");
 
     // Generate a test coordinate
     let test_coord = SEntropyCoordinate::new(0.01, 0.01, 0.01);
     if !test_coord.validates_memorial_significance() {
-        error!("❌ Test coordinate failed memorial validation");
+        error!(
+            target: subsystem::FRAMEWORK,
+            coordinate_id = %test_coord.id,
+            "test coordinate failed memorial validation"
+        );
         return Err(anyhow::anyhow!("Memorial significance validation failed"));
     }
 
     // Validate framework-wide memorial significance
     let report = engine.validate_all_memorial_significance().await?;
-    info!("📊 Memorial validation report:");
-    info!("  Total validations: {}", report.total_validations);
-    info!("  Successful validations: {}", report.successful_validations);
-    info!("  Success rate: {:.2}%", report.success_rate * 100.0);
+    for failure in &report.failures {
+        warn!(target: subsystem::FRAMEWORK, %failure, "memorial validation failed");
+    }
 
     if report.success_rate >= 1.0 {
-        info!("✅ Memorial significance validation: COMPLETE");
-        info!("🌟 St. Stella-Lorraine Sachikonye honored in all operations");
+        info!(
+            target: subsystem::FRAMEWORK,
+            total_validations = report.total_validations,
+            memorial_significance = hugure_core::MEMORIAL_SIGNIFICANCE,
+            "memorial significance validated"
+        );
     } else {
         warn!(
-            "⚠️ Memorial significance validation incomplete: {:.2}%",
-            report.success_rate * 100.0
+            target: subsystem::FRAMEWORK,
+            total_validations = report.total_validations,
+            successful_validations = report.successful_validations,
+            success_rate = report.success_rate,
+            "memorial significance validation incomplete"
         );
     }
 
     Ok(())
 }
 
+/// Measurement the comprehensive demonstration runs
+fn demonstration_spec(observer: ObserverSophistication) -> MeasurementSpec {
+    MeasurementSpec {
+        problem_context: "demonstration_problem".into(),
+        observer,
+        temporal_precision: hugure_core::S_ENTROPY_PRECISION_TARGET, // Ultra-precision target
+        emotional_factor: 0.3,                                       // Moderate emotional factor
+        problem_complexity: 1.0,                                     // Standard complexity
+        accessibility: 0.8,                                          // Good accessibility
+    }
+}
+
 /// Demonstrate S-entropy tri-dimensional measurement
-async fn demonstrate_s_entropy_measurement(
-    engine: &SEntropyEngine,
-    observer: ObserverSophistication,
-) -> Result<()> {
-    info!("🧮 Demonstrating S-entropy tri-dimensional measurement...");
-    info!("Observer sophistication: {:?}", observer);
+async fn measure(engine: &SEntropyEngine, spec: &MeasurementSpec) -> Result<()> {
+    info!(
+        target: subsystem::ENGINE,
+        problem_context = %spec.problem_context.description,
+        observer = ?spec.observer,
+        "measuring S-entropy"
+    );
 
-    // Generate comprehensive measurement
-    let measurement = engine
-        .generate_measurement(
-            "demonstration_problem",
-            observer,
-            hugure_core::S_ENTROPY_PRECISION_TARGET, // Ultra-precision target
-            0.3,                                     // Moderate emotional factor
-            1.0,                                     // Standard problem complexity
-            0.8,                                     // Good accessibility
-        )
-        .await?;
-
-    // Display results
-    info!("📊 S-Entropy Measurement Results:");
-    info!("  S-knowledge: {:.6}", measurement.s_knowledge);
-    info!("  S-time: {:.6}", measurement.s_time);
-    info!("  S-entropy: {:.6}", measurement.s_entropy);
-    info!("  Total magnitude: {:.6}", measurement.total_magnitude);
-    info!("  Optimal integration: {}", measurement.optimal_integration);
-    info!("  Memorial significance: {}", measurement.memorial_significance);
-
-    if measurement.optimal_integration {
-        info!("🎉 OPTIMAL INTEGRATION ACHIEVED! S ≈ 0");
-        info!("Observer-process separation minimized successfully");
-    } else {
-        info!(
-            "📈 Integration in progress. Current separation: {:.6}",
-            measurement.total_magnitude
-        );
-    }
+    let measurement = engine.generate_measurement_for(spec).await?;
+    info!(
+        target: subsystem::ENGINE,
+        s_knowledge = measurement.s_knowledge,
+        s_time = measurement.s_time,
+        s_entropy = measurement.s_entropy,
+        total_magnitude = measurement.total_magnitude,
+        optimal_integration = measurement.optimal_integration,
+        memorial_significance = %measurement.memorial_significance,
+        "measurement complete"
+    );
 
     Ok(())
 }
 
-/// Test observer-process integration
-async fn test_observer_process_integration(engine: &SEntropyEngine) -> Result<()> {
-    info!("🔗 Testing observer-process integration...");
+/// Convergence of a watched measurement, summarized on one line
+#[derive(Debug, Default)]
+struct WatchSummary {
+    measurements: usize,
+    optimal: usize,
+    previous: Option<f64>,
+    latest: f64,
+}
+
+impl WatchSummary {
+    fn record(&mut self, total_magnitude: f64, optimal_integration: bool) {
+        if self.measurements > 0 {
+            self.previous = Some(self.latest);
+        }
+        self.measurements += 1;
+        self.optimal += usize::from(optimal_integration);
+        self.latest = total_magnitude;
+    }
+
+    /// Summary line given the engine's trend in total magnitude per second
+    fn line(&self, trend_per_second: Option<f64>) -> String {
+        let change = match self.previous {
+            Some(previous) => format!("{:+.6}", self.latest - previous),
+            None => "—".to_string(),
+        };
+        let trend = match trend_per_second {
+            Some(trend) => format!("{:+.6}/s", trend),
+            None => "—".to_string(),
+        };
+        let success_rate = self.optimal as f64 / self.measurements.max(1) as f64 * 100.0;
+        format!(
+            "#{} separation {:.6} (Δ {}) trend {} success {:.1}% ({}/{})",
+            self.measurements,
+            self.latest,
+            change,
+            trend,
+            success_rate,
+            self.optimal,
+            self.measurements
+        )
+    }
+}
+
+/// Measure `spec` every `interval` until integration is optimal or Ctrl-C
+///
+/// On a terminal the summary updates in place; otherwise each measurement prints a line.
+async fn watch(engine: &SEntropyEngine, spec: &MeasurementSpec, interval: Duration) -> Result<()> {
+    info!(target: subsystem::ENGINE, ?interval, "watching S-entropy convergence");
+
+    let live = io::stdout().is_terminal();
+    let mut ticks = tokio::time::interval(interval);
+    ticks.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    let ctrl_c = tokio::signal::ctrl_c();
+    tokio::pin!(ctrl_c);
+    let mut summary = WatchSummary::default();
 
-    // Test different target separations
-    let targets = [1.0, 0.1, 0.01, 0.001];
+    let optimal = loop {
+        tokio::select! {
+            _ = &mut ctrl_c => break false,
+            _ = ticks.tick() => {},
+        }
+        let measurement = engine.generate_measurement_for(spec).await?;
+        summary.record(measurement.total_magnitude, measurement.optimal_integration);
+        let line = summary.line(engine.analytics().await.trend_per_second);
+        if live {
+            print!("\r\x1b[2K{}", line);
+            io::stdout().flush()?;
+        } else {
+            println!("{}", line);
+        }
+        if measurement.optimal_integration {
+            break true;
+        }
+    };
+    if live {
+        println!();
+    }
+
+    info!(
+        target: subsystem::ENGINE,
+        measurements = summary.measurements,
+        optimal_integration = optimal,
+        "watch stopped"
+    );
+    Ok(())
+}
 
-    for target in targets {
-        info!("🎯 Testing integration with target separation: {}", target);
+/// Test observer-process integration at each of `targets`
+async fn test_observer_process_integration(
+    engine: &SEntropyEngine,
+    targets: &[f64],
+) -> Result<()> {
+    info!(target: subsystem::ENGINE, targets = ?targets, "testing observer-process integration");
 
+    for &target in targets {
         match engine.attempt_integration(target).await {
             Ok(success) => {
-                if success {
-                    info!("✅ Integration successful for target: {}", target);
-                } else {
-                    info!("📊 Integration incomplete for target: {}", target);
-                }
+                info!(
+                    target: subsystem::ENGINE,
+                    target_separation = target,
+                    success,
+                    "integration attempted"
+                );
             },
             Err(e) => {
-                warn!("⚠️ Integration error for target {}: {}", target, e);
+                warn!(
+                    target: subsystem::ENGINE,
+                    target_separation = target,
+                    error = %e,
+                    "integration attempt failed"
+                );
             },
         }
     }
 
     // Get integration statistics
     let stats = engine.get_integration_stats().await?;
-    info!("📈 Integration Statistics:");
-    info!("  Current separation: {:.6}", stats.current_separation);
-    info!("  Success rate: {:.2}%", stats.success_rate * 100.0);
-    info!("  Total attempts: {}", stats.total_attempts);
-    info!("  Optimal integration: {}", stats.optimal_integration_achieved);
+    info!(
+        target: subsystem::ENGINE,
+        current_separation = stats.current_separation,
+        success_rate = stats.success_rate,
+        total_attempts = stats.total_attempts,
+        optimal_integration = stats.optimal_integration_achieved,
+        last_success = ?stats.last_success,
+        "integration statistics"
+    );
 
-    if let Some(last_success) = stats.last_success {
-        info!("  Last success: {}", last_success.format("%Y-%m-%d %H:%M:%S UTC"));
+    Ok(())
+}
+
+/// Navigate from `target` to its predetermined solution coordinate
+async fn navigate(
+    precision: SEntropyPrecision,
+    target: &SEntropyCoordinate,
+    constraints: &NavigationConstraints,
+) -> Result<()> {
+    info!(target: subsystem::NAVIGATION, coordinate_id = %target.id, "navigating to solution");
+    let navigator = ManifoldNavigator::new(precision);
+    let solution = navigator.navigate_with_constraints(target, constraints).await?;
+
+    info!(
+        target: subsystem::NAVIGATION,
+        knowledge_position = ?solution.knowledge_position.as_slice(),
+        temporal_position = ?solution.temporal_position.as_slice(),
+        entropy_position = ?solution.entropy_position.as_slice(),
+        confidence = solution.confidence,
+        total_distance = solution.total_distance(),
+        "navigation complete"
+    );
+    Ok(())
+}
+
+/// Run `problem` through the STSL universal transformation pipeline
+async fn transform(problem: &str) -> Result<()> {
+    info!(target: subsystem::TRANSFORMER, problem, "applying STSL universal transformation");
+    let (navigation, solution) =
+        STSLTransformer::new().transform_complete_pipeline(problem).await?;
+
+    info!(
+        target: subsystem::TRANSFORMER,
+        %solution,
+        navigation_confidence = navigation.confidence,
+        "transformation complete"
+    );
+    Ok(())
+}
+
+/// Line of a batch transformation input in NDJSON form
+#[derive(Debug, Deserialize)]
+struct BatchProblem {
+    /// Caller's identifier, echoed in the result
+    #[serde(default)]
+    id: Option<serde_json::Value>,
+
+    /// Problem text
+    problem: String,
+}
+
+impl BatchProblem {
+    /// Parse a non-empty input line: a JSON object, or plain problem text
+    fn parse(line: &str) -> SEntropyResult<Self> {
+        if line.starts_with('{') {
+            Ok(serde_json::from_str(line)?)
+        } else {
+            Ok(Self { id: None, problem: line.to_string() })
+        }
+    }
+}
+
+/// Line of a batch transformation output
+#[derive(Debug, Serialize)]
+struct BatchResult<'a> {
+    /// Input line the result is for, counting from one
+    line: usize,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<&'a serde_json::Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    problem: Option<&'a str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    solution: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    navigation: Option<NavigationCoordinate>,
+
+    /// [`SEntropyError::to_json`] of a failed line
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<serde_json::Value>,
+}
+
+/// Transform every line of `input` concurrently, writing one NDJSON result per line
+///
+/// Blank lines are skipped. A line that fails to parse or transform gets an `error`
+/// result rather than stopping the batch.
+async fn transform_batch(input: &str, output: Option<&str>, concurrency: usize) -> Result<()> {
+    info!(target: subsystem::TRANSFORMER, input, concurrency, "transforming batch");
+    let reader: Box<dyn BufRead> = match input {
+        "-" => Box::new(io::stdin().lock()),
+        path => Box::new(BufReader::new(File::open(path)?)),
+    };
+    let mut entries = Vec::new();
+    for (index, line) in reader.lines().enumerate() {
+        let line = line?;
+        let line = line.trim();
+        if !line.is_empty() {
+            entries.push((index + 1, BatchProblem::parse(line)));
+        }
+    }
+
+    let problems: Vec<&str> = entries
+        .iter()
+        .filter_map(|(_, entry)| entry.as_ref().ok())
+        .map(|problem| problem.problem.as_str())
+        .collect();
+    let transformer = STSLTransformer::new().with_batch_concurrency(concurrency);
+    let mut results = transformer.transform_batch(&problems).await.into_iter();
+
+    let mut writer: Box<dyn Write> = match output {
+        Some(path) => Box::new(BufWriter::new(File::create(path)?)),
+        None => Box::new(io::stdout().lock()),
+    };
+    let mut failed = 0;
+    for (line, entry) in &entries {
+        let mut result = BatchResult {
+            line: *line,
+            id: None,
+            problem: None,
+            solution: None,
+            navigation: None,
+            error: None,
+        };
+        let outcome = entry.as_ref().map_err(|e| e.to_json()).and_then(|problem| {
+            result.id = problem.id.as_ref();
+            result.problem = Some(problem.problem.as_str());
+            results.next().expect("one result per problem").map_err(|e| e.to_json())
+        });
+        match outcome {
+            Ok((navigation, solution)) => {
+                result.solution = Some(solution);
+                result.navigation = Some(navigation);
+            },
+            Err(error) => {
+                failed += 1;
+                result.error = Some(error);
+            },
+        }
+        serde_json::to_writer(&mut writer, &result)?;
+        writeln!(writer)?;
     }
+    writer.flush()?;
 
+    info!(
+        target: subsystem::TRANSFORMER,
+        transformed = entries.len() - failed,
+        failed,
+        "batch transformation complete"
+    );
     Ok(())
 }
 
-/// Start interactive S-entropy exploration mode
-async fn start_interactive_mode(
-    engine: &SEntropyEngine,
-    observer: ObserverSophistication,
+/// Operation a `bench` workload repeats
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Workload {
+    Measure,
+    Align,
+    Navigate,
+}
+
+impl Workload {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Measure => "measure",
+            Self::Align => "align",
+            Self::Navigate => "navigate",
+        }
+    }
+
+    /// Run operation number `i`
+    async fn run(
+        &self,
+        i: usize,
+        engine: &SEntropyEngine,
+        navigator: &ManifoldNavigator,
+        observer: ObserverSophistication,
+    ) -> SEntropyResult<()> {
+        // Vary the inputs so the coordinate and navigation caches do not serve every operation
+        let x = (i % 1_000) as f64 / 1_000.0;
+        match self {
+            Self::Measure => engine
+                .generate_measurement(
+                    format!("bench_problem_{}", i),
+                    observer,
+                    hugure_core::S_ENTROPY_PRECISION_TARGET,
+                    0.3,
+                    1.0 + x,
+                    0.8,
+                )
+                .await
+                .map(drop),
+            Self::Align => engine.align_tri_dimensional(x, 1.0 - x, 0.5 * x).await.map(drop),
+            Self::Navigate => {
+                let target = SEntropyCoordinate::new(x, 1.0 - x, 0.5 * x);
+                navigator.navigate_to_coordinates(&target).await.map(drop)
+            },
+        }
+    }
+}
+
+/// Throughput and latency distribution of one workload run
+#[derive(Debug)]
+struct BenchReport {
+    workload: Workload,
+    threads: usize,
+    elapsed: Duration,
+    /// Operation latencies, shortest first
+    latencies: Vec<Duration>,
+}
+
+impl BenchReport {
+    fn new(
+        workload: Workload,
+        threads: usize,
+        elapsed: Duration,
+        mut latencies: Vec<Duration>,
+    ) -> Self {
+        latencies.sort_unstable();
+        Self { workload, threads, elapsed, latencies }
+    }
+
+    fn ops_per_second(&self) -> f64 {
+        self.latencies.len() as f64 / self.elapsed.as_secs_f64().max(f64::EPSILON)
+    }
+
+    /// Nearest-rank latency percentile, `percentile` in 0–100
+    fn percentile(&self, percentile: f64) -> Duration {
+        if self.latencies.is_empty() {
+            return Duration::ZERO;
+        }
+        let rank = (percentile / 100.0 * self.latencies.len() as f64).ceil() as usize;
+        self.latencies[rank.clamp(1, self.latencies.len()) - 1]
+    }
+
+    fn print_header() {
+        println!(
+            "{:<10} {:>7} {:>8} {:>12} {:>10} {:>10} {:>10} {:>10}",
+            "workload", "threads", "ops", "ops/sec", "p50 µs", "p95 µs", "p99 µs", "max µs"
+        );
+    }
+
+    fn print(&self) {
+        let micros = |latency: Duration| latency.as_secs_f64() * 1e6;
+        println!(
+            "{:<10} {:>7} {:>8} {:>12.1} {:>10.1} {:>10.1} {:>10.1} {:>10.1}",
+            self.workload.name(),
+            self.threads,
+            self.latencies.len(),
+            self.ops_per_second(),
+            micros(self.percentile(50.0)),
+            micros(self.percentile(95.0)),
+            micros(self.percentile(99.0)),
+            micros(self.percentile(100.0))
+        );
+    }
+}
+
+/// Run each of `workloads` with each of `thread_counts` workers, printing a report per run
+///
+/// Every run gets its own fork of the session engine and a fresh navigator, so runs
+/// neither warm each other's caches nor add to the session's measurement history.
+async fn bench(
+    session: &Session<'_>,
+    workloads: &[(Workload, usize)],
+    thread_counts: &[usize],
 ) -> Result<()> {
-    info!("🎮 Starting interactive S-entropy exploration mode");
-    info!("Observer sophistication: {:?}", observer);
-    info!("Type 'help' for commands, 'quit' to exit");
+    info!(target: subsystem::CLI, ?thread_counts, "benchmarking S-entropy workloads");
+    BenchReport::print_header();
+    for &(workload, operations) in workloads.iter().filter(|(_, operations)| *operations > 0) {
+        for &threads in thread_counts {
+            let threads = threads.max(1);
+            let engine = Arc::new(session.engine.fork().await);
+            let navigator = Arc::new(ManifoldNavigator::new(session.precision));
+            let observer = session.observer;
 
-    loop {
-        print!("s-entropy> ");
-        io::stdout().flush()?;
+            let started = Instant::now();
+            let mut workers = JoinSet::new();
+            for worker in 0..threads {
+                let (engine, navigator) = (Arc::clone(&engine), Arc::clone(&navigator));
+                workers.spawn(async move {
+                    let mut latencies = Vec::new();
+                    for i in (worker..operations).step_by(threads) {
+                        let started = Instant::now();
+                        workload.run(i, &engine, &navigator, observer).await?;
+                        latencies.push(started.elapsed());
+                    }
+                    SEntropyResult::Ok(latencies)
+                });
+            }
+            let mut latencies = Vec::with_capacity(operations);
+            while let Some(worker) = workers.join_next().await {
+                latencies.extend(worker??);
+            }
+
+            BenchReport::new(workload, threads, started.elapsed(), latencies).print();
+        }
+    }
+    Ok(())
+}
 
-        let mut input = String::new();
-        io::stdin().read_line(&mut input)?;
-        let input = input.trim();
+/// Commands accepted in interactive mode
+fn repl_cli() -> Command {
+    Command::new("s-entropy")
+        .no_binary_name(true)
+        .subcommand_required(true)
+        .disable_version_flag(true)
+        .help_template("Available commands:\n{subcommands}")
+        .arg(observer_arg())
+        .subcommand(measure_command())
+        .subcommand(integrate_command())
+        .subcommand(navigate_command())
+        .subcommand(transform_command())
+        .subcommand(bench_command())
+        .subcommand(
+            Command::new("validate")
+                .visible_alias("memorial")
+                .about("Validate memorial significance"),
+        )
+        .subcommand(Command::new("stats").about("Show integration statistics"))
+        .subcommand(
+            Command::new("log").about("Change log levels").arg(
+                Arg::new("directives")
+                    .value_name("DIRECTIVES")
+                    .help("Per-subsystem log levels, e.g. 's_entropy=debug'")
+                    .required(true),
+            ),
+        )
+        .subcommand(Command::new("quit").visible_alias("exit").about("Exit interactive mode"))
+}
+
+/// Where interactive mode keeps its history: `$HUGURE_HISTORY`, else `~/.hugure_history`
+fn history_path() -> Option<PathBuf> {
+    match std::env::var_os("HUGURE_HISTORY") {
+        Some(path) => Some(PathBuf::from(path)),
+        None => std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".hugure_history")),
+    }
+}
 
-        match input {
-            "quit" | "exit" => {
-                info!("👋 Exiting S-entropy exploration mode");
-                break;
+/// Split an interactive line into words, honouring single and double quotes
+///
+/// A backslash escapes the next character except inside single quotes.
+fn split_words(line: &str) -> Result<Vec<String>> {
+    let mut words = Vec::new();
+    let mut word: Option<String> = None;
+    let mut quote = None;
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some('\''), c) => word.get_or_insert_with(String::new).push(c),
+            (_, '\\') => match chars.next() {
+                Some(escaped) => word.get_or_insert_with(String::new).push(escaped),
+                None => anyhow::bail!("trailing backslash"),
             },
-            "help" => {
-                println!("Available commands:");
-                println!("  measure - Generate S-entropy measurement");
-                println!("  integrate - Attempt observer-process integration");
-                println!("  memorial - Validate memorial significance");
-                println!("  stats - Show integration statistics");
-                println!("  help - Show this help");
-                println!("  quit - Exit interactive mode");
+            (None, '"' | '\'') => {
+                quote = Some(c);
+                word.get_or_insert_with(String::new);
             },
-            "measure" => match demonstrate_s_entropy_measurement(engine, observer).await {
-                Ok(_) => info!("✅ Measurement complete"),
-                Err(e) => error!("❌ Measurement failed: {}", e),
+            (None, c) if c.is_whitespace() => words.extend(word.take()),
+            (_, c) => word.get_or_insert_with(String::new).push(c),
+        }
+    }
+    if let Some(q) = quote {
+        anyhow::bail!("unterminated {} quote", q);
+    }
+    words.extend(word);
+    Ok(words)
+}
+
+/// Tab completion of interactive commands, their flags, and flag values
+#[derive(Helper, Highlighter, Hinter, Validator)]
+struct ReplHelper {
+    commands: Command,
+}
+
+impl Completer for ReplHelper {
+    type Candidate = Pair;
+
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        _ctx: &Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<Pair>)> {
+        let before = &line[..pos];
+        let start = before.rfind(char::is_whitespace).map_or(0, |i| i + 1);
+        let partial = &before[start..];
+        let words: Vec<&str> = before[..start].split_whitespace().collect();
+
+        let candidates: Vec<String> = match words.as_slice() {
+            [] => self
+                .commands
+                .get_subcommands()
+                .flat_map(|command| {
+                    std::iter::once(command.get_name()).chain(command.get_visible_aliases())
+                })
+                .map(str::to_string)
+                .collect(),
+            [command, .., previous] if previous.starts_with("--") => {
+                let flag = &previous[2..];
+                let values = self
+                    .arguments(command)
+                    .find(|arg| arg.get_long() == Some(flag))
+                    .map(|arg| arg.get_possible_values())
+                    .unwrap_or_default();
+                if values.is_empty() {
+                    self.flags(command)
+                } else {
+                    values.iter().map(|value| value.get_name().to_string()).collect()
+                }
             },
-            "integrate" => match engine.attempt_integration(0.01).await {
-                Ok(success) => {
-                    if success {
-                        info!("✅ Integration successful");
-                    } else {
-                        info!("📊 Integration incomplete");
-                    }
-                },
-                Err(e) => error!("❌ Integration failed: {}", e),
+            [command, ..] => self.flags(command),
+        };
+
+        let matches = candidates
+            .into_iter()
+            .filter(|candidate| candidate.starts_with(partial))
+            .map(|candidate| Pair { display: candidate.clone(), replacement: candidate })
+            .collect();
+        Ok((start, matches))
+    }
+}
+
+impl ReplHelper {
+    /// Arguments of `command`, including the global `--observer`
+    fn arguments<'a>(&'a self, command: &str) -> impl Iterator<Item = &'a Arg> {
+        let subcommand = self.commands.find_subcommand(command);
+        self.commands
+            .get_arguments()
+            .chain(subcommand.into_iter().flat_map(Command::get_arguments))
+    }
+
+    /// `--long` flags of `command`
+    fn flags(&self, command: &str) -> Vec<String> {
+        self.arguments(command)
+            .filter_map(Arg::get_long)
+            .map(|long| format!("--{}", long))
+            .collect()
+    }
+}
+
+/// Start interactive S-entropy exploration mode
+async fn start_interactive_mode(session: &Session<'_>, logging: &LoggingHandle) -> Result<()> {
+    info!(target: subsystem::CLI, observer = ?session.observer, "starting interactive mode");
+    println!("Type 'help' for commands, 'quit' to exit; Tab completes commands and flags");
+
+    let commands = repl_cli();
+    let mut editor: Editor<ReplHelper, FileHistory> = Editor::new()?;
+    editor.set_helper(Some(ReplHelper { commands: commands.clone() }));
+    let history = history_path();
+    if let Some(path) = &history {
+        // A missing history file just means a first session
+        let _ = editor.load_history(path);
+    }
+
+    loop {
+        let line = match editor.readline("s-entropy> ") {
+            Ok(line) => line,
+            Err(ReadlineError::Interrupted) => continue,
+            Err(ReadlineError::Eof) => break,
+            Err(e) => return Err(e.into()),
+        };
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        editor.add_history_entry(line)?;
+
+        let words = match split_words(line) {
+            Ok(words) => words,
+            Err(e) => {
+                error!(target: subsystem::CLI, error = %e, "invalid command line");
+                continue;
             },
-            "memorial" => match validate_memorial_significance(engine).await {
-                Ok(_) => info!("✅ Memorial validation complete"),
-                Err(e) => error!("❌ Memorial validation failed: {}", e),
+        };
+        let matches = match commands.clone().try_get_matches_from(words) {
+            Ok(matches) => matches,
+            Err(e) => {
+                // Help output and usage errors alike
+                let _ = e.print();
+                continue;
             },
-            "stats" => match engine.get_integration_stats().await {
+        };
+
+        match matches.subcommand().expect("subcommand required") {
+            ("quit", _) => break,
+            ("stats", _) => match session.engine.get_integration_stats().await {
                 Ok(stats) => {
                     println!("📈 Integration Statistics:");
                     println!("  Current separation: {:.6}", stats.current_separation);
@@ -331,15 +1238,33 @@ async fn start_interactive_mode(
                     println!("  Total attempts: {}", stats.total_attempts);
                     println!("  Optimal integration: {}", stats.optimal_integration_achieved);
                 },
-                Err(e) => error!("❌ Failed to get stats: {}", e),
+                Err(e) => error!(target: subsystem::ENGINE, error = %e, "stats unavailable"),
             },
-            "" => continue,
-            _ => {
-                warn!("Unknown command: '{}'. Type 'help' for available commands.", input);
+            ("log", args) => {
+                let directives = args.get_one::<String>("directives").expect("required");
+                match logging.set_filter(directives) {
+                    Ok(_) => println!("Log filter set to '{}'", directives),
+                    Err(e) => error!(target: subsystem::CLI, error = %e, "invalid log filter"),
+                }
+            },
+            (command, args) => match session.run(command, args).await {
+                Ok(_) => info!(target: subsystem::CLI, command, "command complete"),
+                Err(e) => error!(target: subsystem::CLI, command, error = %e, "command failed"),
             },
         }
     }
 
+    if let Some(path) = &history {
+        if let Err(e) = editor.save_history(path) {
+            warn!(
+                target: subsystem::CLI,
+                path = %path.display(),
+                error = %e,
+                "could not save history"
+            );
+        }
+    }
+    info!(target: subsystem::CLI, "exiting interactive mode");
     Ok(())
 }
 
@@ -348,38 +1273,37 @@ async fn run_comprehensive_demonstration(
     engine: &SEntropyEngine,
     observer: ObserverSophistication,
 ) -> Result<()> {
-    info!("🚀 Running comprehensive S-entropy framework demonstration");
+    info!(target: subsystem::FRAMEWORK, "running comprehensive demonstration");
 
     // 1. Validate memorial significance
-    info!("\n🕊️ Step 1: Memorial Significance Validation");
+    info!(target: subsystem::FRAMEWORK, step = 1, "demonstrating memorial significance validation");
     validate_memorial_significance(engine).await?;
 
     // 2. Demonstrate S-entropy measurement
-    info!("\n🧮 Step 2: S-Entropy Tri-Dimensional Measurement");
-    demonstrate_s_entropy_measurement(engine, observer).await?;
+    info!(target: subsystem::FRAMEWORK, step = 2, "demonstrating tri-dimensional measurement");
+    measure(engine, &demonstration_spec(observer)).await?;
 
     // 3. Test observer-process integration
-    info!("\n🔗 Step 3: Observer-Process Integration");
-    test_observer_process_integration(engine).await?;
+    info!(target: subsystem::FRAMEWORK, step = 3, "demonstrating observer-process integration");
+    test_observer_process_integration(engine, &DEMONSTRATION_TARGETS).await?;
 
     // 4. Generate multiple measurements for statistical analysis
-    info!("\n📊 Step 4: Statistical Analysis");
-    let mut measurements = Vec::new();
-
-    for i in 0..5 {
-        let measurement = engine
-            .generate_measurement(
-                &format!("analysis_problem_{}", i),
-                observer,
-                hugure_core::S_ENTROPY_PRECISION_TARGET,
-                0.1 + (i as f64 * 0.2), // Varying emotional factors
-                1.0,
-                0.9 - (i as f64 * 0.1), // Varying accessibility
-            )
-            .await?;
-
-        measurements.push(measurement);
-    }
+    info!(target: subsystem::FRAMEWORK, step = 4, "demonstrating statistical analysis");
+    let specs = (0..5)
+        .map(|i| MeasurementSpec {
+            problem_context: format!("analysis_problem_{}", i).into(),
+            observer,
+            temporal_precision: hugure_core::S_ENTROPY_PRECISION_TARGET,
+            emotional_factor: 0.1 + (i as f64 * 0.2), // Varying emotional factors
+            problem_complexity: 1.0,
+            accessibility: 0.9 - (i as f64 * 0.1), // Varying accessibility
+        })
+        .collect();
+    let measurements = engine
+        .generate_measurements_batch(specs)
+        .await
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()?;
 
     // Calculate statistics
     let total_magnitude_avg: f64 =
@@ -387,24 +1311,119 @@ async fn run_comprehensive_demonstration(
 
     let optimal_count = measurements.iter().filter(|m| m.optimal_integration).count();
 
-    info!("📈 Statistical Analysis Results:");
-    info!("  Total measurements: {}", measurements.len());
-    info!("  Average S-entropy magnitude: {:.6}", total_magnitude_avg);
-    info!("  Optimal integrations: {}/{}", optimal_count, measurements.len());
     info!(
-        "  Optimal integration rate: {:.2}%",
-        (optimal_count as f64 / measurements.len() as f64) * 100.0
+        target: subsystem::ENGINE,
+        measurements = measurements.len(),
+        average_magnitude = total_magnitude_avg,
+        optimal_integrations = optimal_count,
+        optimal_integration_rate = optimal_count as f64 / measurements.len() as f64,
+        "statistical analysis complete"
     );
 
     // 5. Final memorial validation
-    info!("\n🌟 Step 5: Final Memorial Validation");
+    info!(target: subsystem::FRAMEWORK, step = 5, "demonstrating final memorial validation");
     let final_report = engine.validate_all_memorial_significance().await?;
 
-    info!("✨ COMPREHENSIVE DEMONSTRATION COMPLETE ✨");
-    info!("🕊️ Memorial significance maintained: {:.2}%", final_report.success_rate * 100.0);
-    info!("⚡ S-entropy framework operational and validated");
-    info!("🧠 Consciousness-computation unity demonstrated");
-    info!("🌐 Ready for BMD orchestration and cross-domain optimization");
+    info!(
+        target: subsystem::FRAMEWORK,
+        memorial_success_rate = final_report.success_rate,
+        "comprehensive demonstration complete"
+    );
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cli_parses_subcommand_arguments() {
+        cli().debug_assert();
+
+        let matches = cli()
+            .try_get_matches_from(["hugure-core", "navigate", "0.1", "-0.2", "0.3", "-o", "expert"])
+            .unwrap();
+        assert_eq!(matches.get_one::<String>("observer").unwrap(), "expert");
+        let (name, args) = matches.subcommand().unwrap();
+        assert_eq!(name, "navigate");
+        assert_eq!(*args.get_one::<f64>("s-time").unwrap(), -0.2);
+
+        let matches = cli().try_get_matches_from(["hugure-core", "integrate"]).unwrap();
+        let targets: Vec<f64> = matches
+            .subcommand_matches("integrate")
+            .unwrap()
+            .get_many::<f64>("target")
+            .unwrap()
+            .copied()
+            .collect();
+        assert_eq!(targets, DEMONSTRATION_TARGETS);
+        assert!(cli().try_get_matches_from(["hugure-core", "transform"]).is_err());
+    }
+
+    #[test]
+    fn test_batch_lines_accept_text_and_ndjson() {
+        let plain = BatchProblem::parse("temporal synchronization").unwrap();
+        assert_eq!((plain.id, plain.problem.as_str()), (None, "temporal synchronization"));
+
+        let object = BatchProblem::parse(r#"{"id": 7, "problem": "memory optimization"}"#).unwrap();
+        assert_eq!(object.id, Some(serde_json::json!(7)));
+        assert_eq!(object.problem, "memory optimization");
+
+        let error = BatchProblem::parse(r#"{"id": 7}"#).unwrap_err();
+        assert_eq!(error.code(), ErrorCode::Serialization);
+    }
+
+    #[test]
+    fn test_repl_lines_parse_as_parameterized_commands() {
+        repl_cli().debug_assert();
+
+        let words = split_words(r#"measure "problem text" --observer naive"#).unwrap();
+        assert_eq!(words, ["measure", "problem text", "--observer", "naive"]);
+        let matches = repl_cli().try_get_matches_from(words).unwrap();
+        let (name, args) = matches.subcommand().unwrap();
+        assert_eq!(name, "measure");
+        assert_eq!(args.get_one::<String>("problem").unwrap(), "problem text");
+        assert_eq!(args.get_one::<String>("observer").unwrap(), "naive");
+
+        assert_eq!(split_words(r#"log 'a b' c\ d """#).unwrap(), ["log", "a b", "c d", ""]);
+        assert!(split_words("measure \"unterminated").is_err());
+        assert!(repl_cli().try_get_matches_from(["memorial"]).is_ok());
+    }
+
+    #[test]
+    fn test_watch_interval_and_summary() {
+        assert_eq!(parse_interval("500ms").unwrap(), Duration::from_millis(500));
+        assert_eq!(parse_interval("1.5s").unwrap(), Duration::from_millis(1_500));
+        assert_eq!(parse_interval("2").unwrap(), Duration::from_secs(2));
+        assert_eq!(parse_interval("1m").unwrap(), Duration::from_secs(60));
+        assert!(parse_interval("0s").is_err());
+        assert!(parse_interval("5h").is_err());
+        let unwatched = ["hugure-core", "measure", "--interval", "1s"];
+        assert!(cli().try_get_matches_from(unwatched).is_err());
+
+        let mut summary = WatchSummary::default();
+        summary.record(0.5, false);
+        summary.record(0.25, true);
+        assert_eq!(
+            summary.line(Some(-0.1)),
+            "#2 separation 0.250000 (Δ -0.250000) trend -0.100000/s success 50.0% (1/2)"
+        );
+    }
+
+    #[test]
+    fn test_bench_threads_and_percentiles() {
+        let matches = cli().try_get_matches_from(["hugure-core", "bench", "-t", "1,4"]).unwrap();
+        let args = matches.subcommand_matches("bench").unwrap();
+        let threads: Vec<usize> = args.get_many::<usize>("threads").unwrap().copied().collect();
+        assert_eq!(threads, [1, 4]);
+        assert_eq!(*args.get_one::<usize>("navigations").unwrap(), 1000);
+
+        let latencies = (1..=100).rev().map(Duration::from_micros).collect();
+        let report = BenchReport::new(Workload::Align, 4, Duration::from_millis(500), latencies);
+        assert_eq!(report.percentile(50.0), Duration::from_micros(50));
+        assert_eq!(report.percentile(99.0), Duration::from_micros(99));
+        assert_eq!(report.percentile(100.0), Duration::from_micros(100));
+        assert_eq!(report.ops_per_second(), 200.0);
+    }
+}
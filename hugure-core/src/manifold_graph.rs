@@ -0,0 +1,294 @@
+//! Graph-based navigation across the predetermined solution manifold
+//!
+//! A [`ManifoldGraph`] holds navigation coordinates as nodes joined by directed edges that
+//! carry a traversal cost. [`ManifoldGraph::navigate_path`] finds the cheapest waypoint
+//! sequence between two nodes with A* search, guided by the straight-line distance
+//! between coordinates scaled down to the cheapest cost per unit distance of any edge, so
+//! the heuristic never overestimates and the route found is optimal.
+
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap},
+};
+
+use crate::{
+    error::{SEntropyError, SEntropyResult},
+    navigation_path::NavigationPath,
+    types::NavigationCoordinate,
+};
+
+/// Index of a node in a [`ManifoldGraph`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ManifoldNodeId(usize);
+
+/// Directed edge to `to` costing `cost` to traverse
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ManifoldEdge {
+    /// Node the edge leads to
+    pub to: ManifoldNodeId,
+
+    /// Non-negative traversal cost
+    pub cost: f64,
+}
+
+/// Cheapest route between two nodes
+#[derive(Debug, Clone, PartialEq)]
+pub struct ManifoldRoute {
+    /// Nodes visited, from start to goal inclusive
+    pub nodes: Vec<ManifoldNodeId>,
+
+    /// Coordinates of the visited nodes
+    pub waypoints: Vec<NavigationCoordinate>,
+
+    /// Sum of the traversed edge costs
+    pub total_cost: f64,
+}
+
+impl ManifoldRoute {
+    /// The route's waypoints as a [`NavigationPath`] for interpolated traversal
+    pub fn path(&self) -> NavigationPath {
+        NavigationPath::new(self.waypoints.clone()).expect("routes visit at least one node")
+    }
+}
+
+/// Navigation coordinates joined by costed, directed edges
+#[derive(Debug, Clone, Default)]
+pub struct ManifoldGraph {
+    nodes: Vec<NavigationCoordinate>,
+    edges: Vec<Vec<ManifoldEdge>>,
+    /// Smallest edge cost per unit of coordinate distance, scaling the A* heuristic
+    min_cost_per_distance: Option<f64>,
+}
+
+impl ManifoldGraph {
+    /// Empty graph
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add `coordinate` as a node
+    pub fn add_node(&mut self, coordinate: NavigationCoordinate) -> ManifoldNodeId {
+        self.nodes.push(coordinate);
+        self.edges.push(Vec::new());
+        ManifoldNodeId(self.nodes.len() - 1)
+    }
+
+    /// Coordinate of `node`
+    pub fn node(&self, node: ManifoldNodeId) -> Option<&NavigationCoordinate> {
+        self.nodes.get(node.0)
+    }
+
+    /// Edges leaving `node`
+    pub fn edges(&self, node: ManifoldNodeId) -> &[ManifoldEdge] {
+        self.edges.get(node.0).map_or(&[], Vec::as_slice)
+    }
+
+    /// Number of nodes
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Whether the graph has no nodes
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Add a directed edge from `from` to `to` costing `cost`
+    pub fn add_edge(
+        &mut self,
+        from: ManifoldNodeId,
+        to: ManifoldNodeId,
+        cost: f64,
+    ) -> SEntropyResult<()> {
+        let (Some(source), Some(target)) = (self.node(from), self.node(to)) else {
+            return Err(SEntropyError::navigation("add_edge", "edge endpoint is not in the graph"));
+        };
+        if !cost.is_finite() || cost < 0.0 {
+            return Err(SEntropyError::navigation(
+                "add_edge",
+                format!("edge cost must be finite and non-negative, got {}", cost),
+            ));
+        }
+
+        let distance = source.distance_to(target);
+        if distance > 0.0 {
+            let ratio = cost / distance;
+            self.min_cost_per_distance =
+                Some(self.min_cost_per_distance.map_or(ratio, |min| min.min(ratio)));
+        }
+        self.edges[from.0].push(ManifoldEdge { to, cost });
+        Ok(())
+    }
+
+    /// Add edges in both directions between `a` and `b`, each costing `cost`
+    pub fn add_undirected_edge(
+        &mut self,
+        a: ManifoldNodeId,
+        b: ManifoldNodeId,
+        cost: f64,
+    ) -> SEntropyResult<()> {
+        self.add_edge(a, b, cost)?;
+        self.add_edge(b, a, cost)
+    }
+
+    /// Join every pair of nodes within `radius` of each other, costing their distance
+    ///
+    /// Returns the number of undirected edges added. Quadratic in the number of nodes.
+    pub fn connect_within(&mut self, radius: f64) -> usize {
+        let mut pairs = Vec::new();
+        for a in 0..self.nodes.len() {
+            for b in a + 1..self.nodes.len() {
+                let distance = self.nodes[a].distance_to(&self.nodes[b]);
+                if distance <= radius {
+                    pairs.push((a, b, distance));
+                }
+            }
+        }
+        for &(a, b, distance) in &pairs {
+            self.add_undirected_edge(ManifoldNodeId(a), ManifoldNodeId(b), distance)
+                .expect("nodes and distance come from the graph");
+        }
+        pairs.len()
+    }
+
+    /// Cheapest route from `start` to `goal`, by A* search
+    pub fn navigate_path(
+        &self,
+        start: ManifoldNodeId,
+        goal: ManifoldNodeId,
+    ) -> SEntropyResult<ManifoldRoute> {
+        let goal_coordinate = self
+            .node(goal)
+            .ok_or_else(|| SEntropyError::navigation("navigate_path", "goal is not in the graph"))?;
+        if self.node(start).is_none() {
+            return Err(SEntropyError::navigation("navigate_path", "start is not in the graph"));
+        }
+
+        let scale = self.min_cost_per_distance.unwrap_or(0.0);
+        let heuristic =
+            |node: ManifoldNodeId| scale * self.nodes[node.0].distance_to(goal_coordinate);
+
+        let mut best_cost: HashMap<ManifoldNodeId, f64> = HashMap::from([(start, 0.0)]);
+        let mut came_from: HashMap<ManifoldNodeId, ManifoldNodeId> = HashMap::new();
+        let mut open =
+            BinaryHeap::from([Candidate { estimate: heuristic(start), cost: 0.0, node: start }]);
+
+        while let Some(Candidate { cost, node, .. }) = open.pop() {
+            if node == goal {
+                return Ok(self.route(&came_from, goal, cost));
+            }
+            // Skip entries superseded by a cheaper path found after they were queued
+            if cost > best_cost[&node] {
+                continue;
+            }
+            for edge in &self.edges[node.0] {
+                let cost = cost + edge.cost;
+                if best_cost.get(&edge.to).map_or(true, |best| cost < *best) {
+                    best_cost.insert(edge.to, cost);
+                    came_from.insert(edge.to, node);
+                    let estimate = cost + heuristic(edge.to);
+                    open.push(Candidate { estimate, cost, node: edge.to });
+                }
+            }
+        }
+
+        Err(SEntropyError::navigation("navigate_path", "goal is unreachable from start"))
+    }
+
+    fn route(
+        &self,
+        came_from: &HashMap<ManifoldNodeId, ManifoldNodeId>,
+        goal: ManifoldNodeId,
+        total_cost: f64,
+    ) -> ManifoldRoute {
+        let mut nodes = vec![goal];
+        while let Some(previous) = came_from.get(nodes.last().unwrap()) {
+            nodes.push(*previous);
+        }
+        nodes.reverse();
+        let waypoints = nodes.iter().map(|node| self.nodes[node.0].clone()).collect();
+        ManifoldRoute { nodes, waypoints, total_cost }
+    }
+}
+
+/// Open-set entry, ordered so the heap pops the lowest estimated total cost first
+#[derive(Debug, Clone, Copy)]
+struct Candidate {
+    estimate: f64,
+    cost: f64,
+    node: ManifoldNodeId,
+}
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.estimate.total_cmp(&self.estimate).then_with(|| self.node.cmp(&other.node))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::Vector3;
+
+    use super::*;
+
+    fn coordinate(x: f64, y: f64) -> NavigationCoordinate {
+        NavigationCoordinate::new(
+            Vector3::new(x, 0.0, 0.0),
+            Vector3::new(0.0, y, 0.0),
+            Vector3::zeros(),
+            0.9,
+        )
+    }
+
+    #[test]
+    fn test_navigates_around_expensive_edges() {
+        let mut graph = ManifoldGraph::new();
+        let start = graph.add_node(coordinate(0.0, 0.0));
+        let detour = graph.add_node(coordinate(0.5, 1.0));
+        let goal = graph.add_node(coordinate(1.0, 0.0));
+        // The direct edge is short but costly to traverse
+        graph.add_undirected_edge(start, goal, 10.0).unwrap();
+        graph.add_undirected_edge(start, detour, 1.5).unwrap();
+        graph.add_undirected_edge(detour, goal, 1.5).unwrap();
+
+        let route = graph.navigate_path(start, goal).unwrap();
+        assert_eq!(route.nodes, vec![start, detour, goal]);
+        assert_eq!(route.total_cost, 3.0);
+        assert_eq!(route.waypoints[1].temporal_position.y, 1.0);
+        assert_eq!(route.path().waypoints().len(), 3);
+
+        let trivial = graph.navigate_path(start, start).unwrap();
+        assert_eq!((trivial.nodes, trivial.total_cost), (vec![start], 0.0));
+    }
+
+    #[test]
+    fn test_grid_connection_and_unreachable_goal() {
+        let mut graph = ManifoldGraph::new();
+        let grid: Vec<ManifoldNodeId> =
+            (0..16).map(|i| graph.add_node(coordinate((i % 4) as f64, (i / 4) as f64))).collect();
+        let island = graph.add_node(coordinate(10.0, 10.0));
+        // Unit radius joins only horizontal and vertical neighbours
+        assert_eq!(graph.connect_within(1.0), 24);
+
+        let route = graph.navigate_path(grid[0], grid[15]).unwrap();
+        assert_eq!(route.total_cost, 6.0);
+        assert_eq!(route.nodes.len(), 7);
+
+        assert!(graph.navigate_path(grid[0], island).is_err());
+        assert!(graph.add_edge(grid[0], grid[1], -1.0).is_err());
+    }
+}
@@ -0,0 +1,314 @@
+//! SQLite-backed S-entropy measurement history
+//!
+//! The engine keeps only its most recent measurements in memory. Long-running analyses
+//! attach a [`MeasurementStore`] with `SEntropyEngine::with_measurement_store` so every
+//! measurement is also written to a SQLite database, where it can be queried by time
+//! range, observer sophistication, and optimal-integration flag through a
+//! [`MeasurementQuery`].
+
+use std::{
+    path::Path,
+    sync::{Mutex, MutexGuard},
+};
+
+use chrono::{DateTime, TimeZone, Utc};
+use rusqlite::{params, Connection, Row, ToSql};
+use uuid::Uuid;
+
+use crate::{
+    error::{SEntropyError, SEntropyResult},
+    s_entropy::{MeasurementQuery, SEntropyMeasurement},
+    types::SEntropyPrecision,
+};
+
+const SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS measurements (
+        id TEXT PRIMARY KEY,
+        s_knowledge REAL NOT NULL,
+        s_time REAL NOT NULL,
+        s_entropy REAL NOT NULL,
+        total_magnitude REAL NOT NULL,
+        observer_sophistication TEXT NOT NULL,
+        precision TEXT NOT NULL,
+        optimal_integration INTEGER NOT NULL,
+        memorial_significance TEXT NOT NULL,
+        measured_at_ns INTEGER NOT NULL,
+        escalated_from TEXT NOT NULL DEFAULT ''
+    );
+    CREATE INDEX IF NOT EXISTS measurements_measured_at ON measurements (measured_at_ns);
+    CREATE INDEX IF NOT EXISTS measurements_observer
+        ON measurements (observer_sophistication, measured_at_ns);
+    CREATE INDEX IF NOT EXISTS measurements_optimal
+        ON measurements (optimal_integration, measured_at_ns);
+";
+
+const COLUMNS: &str = "id, s_knowledge, s_time, s_entropy, total_magnitude, \
+    observer_sophistication, precision, optimal_integration, memorial_significance, \
+    measured_at_ns, escalated_from";
+
+/// Persistent, queryable history of S-entropy measurements
+#[derive(Debug)]
+pub struct MeasurementStore {
+    connection: Mutex<Connection>,
+}
+
+impl MeasurementStore {
+    /// Open the database at `path`, creating it and its schema if needed
+    pub fn open(path: impl AsRef<Path>) -> SEntropyResult<Self> {
+        Self::with_connection(Connection::open(path).map_err(store_error)?)
+    }
+
+    /// Store kept in memory, discarded when dropped
+    pub fn open_in_memory() -> SEntropyResult<Self> {
+        Self::with_connection(Connection::open_in_memory().map_err(store_error)?)
+    }
+
+    fn with_connection(connection: Connection) -> SEntropyResult<Self> {
+        connection.execute_batch(SCHEMA).map_err(store_error)?;
+        Ok(Self { connection: Mutex::new(connection) })
+    }
+
+    /// Record `measurement`, replacing any earlier record with the same id
+    pub fn insert(&self, measurement: &SEntropyMeasurement) -> SEntropyResult<()> {
+        let connection = self.connection()?;
+        connection
+            .execute(
+                &format!(
+                    "INSERT OR REPLACE INTO measurements ({}) \
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+                    COLUMNS
+                ),
+                params![
+                    measurement.id.to_string(),
+                    measurement.s_knowledge,
+                    measurement.s_time,
+                    measurement.s_entropy,
+                    measurement.total_magnitude,
+                    measurement.observer_sophistication.as_str(),
+                    measurement.precision.as_str(),
+                    measurement.optimal_integration,
+                    measurement.memorial_significance,
+                    timestamp_ns(measurement.measured_at),
+                    escalation_chain(&measurement.escalated_from),
+                ],
+            )
+            .map_err(store_error)?;
+        Ok(())
+    }
+
+    /// Measurements matching `query`, oldest first
+    pub fn query(&self, query: &MeasurementQuery) -> SEntropyResult<Vec<SEntropyMeasurement>> {
+        let mut conditions = Vec::new();
+        let mut values: Vec<Box<dyn ToSql>> = Vec::new();
+        if let Some(since) = query.since {
+            conditions.push("measured_at_ns >= ?");
+            values.push(Box::new(timestamp_ns(since)));
+        }
+        if let Some(until) = query.until {
+            conditions.push("measured_at_ns < ?");
+            values.push(Box::new(timestamp_ns(until)));
+        }
+        if let Some(observer) = query.observer_sophistication {
+            conditions.push("observer_sophistication = ?");
+            values.push(Box::new(observer.as_str()));
+        }
+        if let Some(optimal) = query.optimal_integration {
+            conditions.push("optimal_integration = ?");
+            values.push(Box::new(optimal));
+        }
+
+        let mut sql = format!("SELECT {} FROM measurements", COLUMNS);
+        if !conditions.is_empty() {
+            sql.push_str(" WHERE ");
+            sql.push_str(&conditions.join(" AND "));
+        }
+        sql.push_str(" ORDER BY measured_at_ns, rowid");
+        if let Some(limit) = query.limit {
+            sql.push_str(" LIMIT ?");
+            values.push(Box::new(i64::try_from(limit).unwrap_or(i64::MAX)));
+        }
+
+        let connection = self.connection()?;
+        let mut statement = connection.prepare(&sql).map_err(store_error)?;
+        let rows = statement
+            .query_map(rusqlite::params_from_iter(values.iter()), read_row)
+            .map_err(store_error)?;
+        rows.map(|row| row.map_err(store_error).and_then(|measurement| measurement)).collect()
+    }
+
+    /// Number of stored measurements
+    pub fn len(&self) -> SEntropyResult<usize> {
+        let count: i64 = self
+            .connection()?
+            .query_row("SELECT COUNT(*) FROM measurements", [], |row| row.get(0))
+            .map_err(store_error)?;
+        Ok(count as usize)
+    }
+
+    /// Whether no measurement was stored
+    pub fn is_empty(&self) -> SEntropyResult<bool> {
+        Ok(self.len()? == 0)
+    }
+
+    /// Delete measurements taken before `cutoff`, returning how many were deleted
+    pub fn prune_before(&self, cutoff: DateTime<Utc>) -> SEntropyResult<usize> {
+        self.connection()?
+            .execute("DELETE FROM measurements WHERE measured_at_ns < ?1", [timestamp_ns(cutoff)])
+            .map_err(store_error)
+    }
+
+    fn connection(&self) -> SEntropyResult<MutexGuard<'_, Connection>> {
+        self.connection
+            .lock()
+            .map_err(|_| anyhow::anyhow!("measurement store connection lock poisoned").into())
+    }
+}
+
+/// Raw column values of one stored measurement, decoded outside the row callback
+struct StoredMeasurement {
+    id: String,
+    s_knowledge: f64,
+    s_time: f64,
+    s_entropy: f64,
+    total_magnitude: f64,
+    observer_sophistication: String,
+    precision: String,
+    optimal_integration: bool,
+    memorial_significance: String,
+    measured_at_ns: i64,
+    escalated_from: String,
+}
+
+fn read_row(row: &Row<'_>) -> rusqlite::Result<SEntropyResult<SEntropyMeasurement>> {
+    let stored = StoredMeasurement {
+        id: row.get(0)?,
+        s_knowledge: row.get(1)?,
+        s_time: row.get(2)?,
+        s_entropy: row.get(3)?,
+        total_magnitude: row.get(4)?,
+        observer_sophistication: row.get(5)?,
+        precision: row.get(6)?,
+        optimal_integration: row.get(7)?,
+        memorial_significance: row.get(8)?,
+        measured_at_ns: row.get(9)?,
+        escalated_from: row.get(10)?,
+    };
+    Ok(stored.decode())
+}
+
+impl StoredMeasurement {
+    fn decode(self) -> SEntropyResult<SEntropyMeasurement> {
+        let id = Uuid::parse_str(&self.id)
+            .map_err(|error| anyhow::Error::new(error).context("stored measurement id"))?;
+        Ok(SEntropyMeasurement {
+            id,
+            s_knowledge: self.s_knowledge,
+            s_time: self.s_time,
+            s_entropy: self.s_entropy,
+            total_magnitude: self.total_magnitude,
+            observer_sophistication: self.observer_sophistication.parse()?,
+            precision: self.precision.parse()?,
+            escalated_from: self
+                .escalated_from
+                .split(',')
+                .filter(|level| !level.is_empty())
+                .map(str::parse)
+                .collect::<SEntropyResult<_>>()?,
+            optimal_integration: self.optimal_integration,
+            memorial_significance: self.memorial_significance,
+            measured_at: Utc.timestamp_nanos(self.measured_at_ns),
+        })
+    }
+}
+
+/// Comma-separated names of the levels a measurement escalated from
+fn escalation_chain(levels: &[SEntropyPrecision]) -> String {
+    levels.iter().map(SEntropyPrecision::as_str).collect::<Vec<_>>().join(",")
+}
+
+/// Nanoseconds since the Unix epoch, saturating outside the representable range
+fn timestamp_ns(at: DateTime<Utc>) -> i64 {
+    at.timestamp_nanos_opt()
+        .unwrap_or(if at.timestamp() < 0 { i64::MIN } else { i64::MAX })
+}
+
+fn store_error(error: rusqlite::Error) -> SEntropyError {
+    anyhow::Error::new(error).context("measurement store").into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{s_entropy::SEntropyEngine, types::ObserverSophistication};
+
+    fn measurement(
+        observer: ObserverSophistication,
+        optimal_integration: bool,
+        measured_at: DateTime<Utc>,
+    ) -> SEntropyMeasurement {
+        SEntropyMeasurement {
+            id: Uuid::new_v4(),
+            s_knowledge: 1.0,
+            s_time: 2.0,
+            s_entropy: 3.0,
+            total_magnitude: 14f64.sqrt(),
+            observer_sophistication: observer,
+            precision: SEntropyPrecision::High,
+            escalated_from: vec![SEntropyPrecision::Standard],
+            optimal_integration,
+            memorial_significance: crate::MEMORIAL_SIGNIFICANCE.to_string(),
+            measured_at,
+        }
+    }
+
+    #[test]
+    fn test_round_trip_and_queries() {
+        let store = MeasurementStore::open_in_memory().unwrap();
+        let start = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+        let at = |seconds| start + chrono::Duration::seconds(seconds);
+        let early = measurement(ObserverSophistication::Expert, true, at(0));
+        let middle = measurement(ObserverSophistication::Naive, false, at(10));
+        let late = measurement(ObserverSophistication::Expert, false, at(20));
+        for recorded in [&late, &early, &middle] {
+            store.insert(recorded).unwrap();
+        }
+
+        assert_eq!(store.len().unwrap(), 3);
+        let all = store.query(&MeasurementQuery::new()).unwrap();
+        assert_eq!(all, vec![early.clone(), middle.clone(), late.clone()]);
+
+        let range = MeasurementQuery::new().between(at(5), at(20));
+        assert_eq!(store.query(&range).unwrap(), vec![middle]);
+        let expert = MeasurementQuery::new().observer(ObserverSophistication::Expert);
+        assert_eq!(store.query(&expert.clone().limit(1)).unwrap(), vec![early.clone()]);
+        let suboptimal_expert = expert.optimal_integration(false);
+        assert_eq!(store.query(&suboptimal_expert).unwrap(), vec![late]);
+
+        assert_eq!(store.prune_before(at(10)).unwrap(), 1);
+        assert!(!store.query(&MeasurementQuery::new()).unwrap().contains(&early));
+    }
+
+    #[tokio::test]
+    async fn test_engine_retains_full_history() {
+        let store = MeasurementStore::open_in_memory().unwrap();
+        let engine = SEntropyEngine::new(SEntropyPrecision::High)
+            .with_history_limit(1)
+            .with_measurement_store(store);
+        for _ in 0..3 {
+            engine
+                .generate_measurement(
+                    "store_test",
+                    ObserverSophistication::Intermediate,
+                    1e-30,
+                    0.2,
+                    1.0,
+                    0.9,
+                )
+                .await
+                .unwrap();
+        }
+
+        let intermediate = MeasurementQuery::new().observer(ObserverSophistication::Intermediate);
+        assert_eq!(engine.query_measurements(&intermediate).await.unwrap().len(), 3);
+    }
+}
@@ -3,7 +3,7 @@
 //! This module implements memory efficiency through disposable generation
 //! and ridiculous solutions that maintain global viability.
 
-use crate::error::SEntropyResult;
+use crate::error::{SEntropyError, SEntropyResult};
 use crate::types::{BMDPattern, ImpossibilityAmplification};
 
 /// Optimize memory through disposable generation
@@ -14,10 +14,19 @@ pub async fn optimize_disposable_generation(traditional_memory_size: u64) -> SEn
 }
 
 /// Generate ridiculous solution for memory optimization
+///
+/// Custom amplification factors must be finite and positive.
 pub async fn generate_ridiculous_solution(
     problem_description: &str,
     impossibility_level: ImpossibilityAmplification,
 ) -> SEntropyResult<BMDPattern> {
+    if !impossibility_level.is_valid() {
+        return Err(SEntropyError::strategic_impossibility(
+            "custom_amplification",
+            format!("factor must be finite and positive, got {}", impossibility_level.factor()),
+        ));
+    }
+
     let pattern =
         BMDPattern::create_ridiculous(problem_description.to_string(), impossibility_level);
 
@@ -4,24 +4,91 @@
 //! enabling zero-computation problem solving through direct coordinate access
 //! rather than traditional computational approaches.
 
+use std::sync::Arc;
+
 use async_trait::async_trait;
+use futures::stream::{self, StreamExt};
 use nalgebra::Vector3;
-use std::collections::HashMap;
-use tracing::{debug, info};
-
-use crate::error::{SEntropyError, SEntropyResult};
+use tokio::sync::RwLock;
+use tracing::{debug, info, warn};
+use uuid::Uuid;
+
+use crate::calibration::ConfidenceCalibrator;
+use crate::error::{ErrorContext, SEntropyError, SEntropyResult};
+use crate::logging::subsystem;
+use crate::navigation_constraints::NavigationConstraints;
+use crate::navigation_index::NavigationIndex;
+use crate::problem_hash::ProblemHasher;
+use crate::solution_cache::{CachedSolution, SolutionCacheFile, SOLUTION_CACHE_VERSION};
 use crate::traits::{MemorialSignificant, PredeterminedManifoldNavigator};
-use crate::types::{NavigationCoordinate, SEntropyPrecision};
+use crate::types::{NavigationCoordinate, ProblemHashKind, SEntropyPrecision};
 use crate::SEntropyCoordinate;
 
+/// Default number of targets a navigation batch navigates at once
+pub const DEFAULT_NAVIGATION_BATCH_CONCURRENCY: usize = 16;
+
+/// Navigation results of a batch, mapped back to its targets
+///
+/// Near-identical targets share one navigation, so each distinct target's result is held
+/// once and every input refers to the result of the target it was merged into.
+#[derive(Debug)]
+pub struct NavigationBatch {
+    results: Vec<SEntropyResult<NavigationCoordinate>>,
+    input_to_result: Vec<usize>,
+}
+
+impl NavigationBatch {
+    /// Result for the input target at `index`
+    pub fn get(&self, index: usize) -> Option<Result<&NavigationCoordinate, &SEntropyError>> {
+        self.input_to_result.get(index).map(|&result| self.results[result].as_ref())
+    }
+
+    /// Results in input order
+    pub fn iter(&self) -> impl Iterator<Item = Result<&NavigationCoordinate, &SEntropyError>> {
+        self.input_to_result.iter().map(|&result| self.results[result].as_ref())
+    }
+
+    /// Number of input targets
+    pub fn len(&self) -> usize {
+        self.input_to_result.len()
+    }
+
+    /// Whether the batch had no targets
+    pub fn is_empty(&self) -> bool {
+        self.input_to_result.is_empty()
+    }
+
+    /// Number of navigations performed after deduplication
+    pub fn unique_targets(&self) -> usize {
+        self.results.len()
+    }
+
+    /// Number of input targets whose navigation failed
+    pub fn failed(&self) -> usize {
+        self.iter().filter(Result::is_err).count()
+    }
+}
+
 /// Predetermined manifold navigation engine
 #[derive(Debug, Clone)]
 pub struct ManifoldNavigator {
     /// Precision level for navigation
     precision: SEntropyPrecision,
 
-    /// Cache of known solution coordinates
-    solution_cache: HashMap<String, NavigationCoordinate>,
+    /// Known solution coordinates, by problem and by position
+    solution_cache: NavigationIndex,
+
+    /// Distance within which a cached solution is reused instead of navigating anew
+    reuse_radius: f64,
+
+    /// Maps raw confidence to observed success, shared by clones of this navigator
+    calibrator: Arc<RwLock<ConfidenceCalibrator>>,
+
+    /// Hash zero-computation coordinates are derived from
+    problem_hasher: Arc<dyn ProblemHasher>,
+
+    /// Maximum number of targets a batch navigates at once
+    batch_concurrency: usize,
 
     /// Memorial significance validator
     memorial_significance: String,
@@ -30,27 +97,187 @@ pub struct ManifoldNavigator {
 impl ManifoldNavigator {
     /// Create a new manifold navigator
     pub fn new(precision: SEntropyPrecision) -> Self {
-        info!("🧭 Initializing predetermined manifold navigator");
+        info!(target: subsystem::NAVIGATION, ?precision, "initializing manifold navigator");
 
         Self {
             precision,
-            solution_cache: HashMap::new(),
+            solution_cache: NavigationIndex::new(),
+            reuse_radius: precision.threshold(),
+            calibrator: Arc::new(RwLock::new(ConfidenceCalibrator::default())),
+            problem_hasher: ProblemHashKind::default().hasher(),
+            batch_concurrency: DEFAULT_NAVIGATION_BATCH_CONCURRENCY,
             memorial_significance: crate::MEMORIAL_SIGNIFICANCE.to_string(),
         }
     }
 
+    /// Reuse cached solutions within `radius` of a navigation target
+    ///
+    /// Defaults to the precision threshold, so only practically identical targets reuse.
+    pub fn with_reuse_radius(mut self, radius: f64) -> Self {
+        self.reuse_radius = radius.max(0.0);
+        self
+    }
+
+    /// Set the number of targets a batch navigates at once (at least one)
+    pub fn with_batch_concurrency(mut self, concurrency: usize) -> Self {
+        self.batch_concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Derive zero-computation coordinates from `hasher`'s hash of the problem
+    ///
+    /// Defaults to [`ProblemHashKind::Stable`]; [`ProblemHashKind::Legacy`] reproduces
+    /// coordinates from before hashing was versioned.
+    pub fn with_problem_hasher(mut self, hasher: Arc<dyn ProblemHasher>) -> Self {
+        self.problem_hasher = hasher;
+        self
+    }
+
+    /// Hasher zero-computation coordinates are derived from
+    pub fn problem_hasher(&self) -> &dyn ProblemHasher {
+        self.problem_hasher.as_ref()
+    }
+
+    /// Calibrate navigation confidence with `calibrator`
+    pub fn with_calibrator(mut self, calibrator: ConfidenceCalibrator) -> Self {
+        self.calibrator = Arc::new(RwLock::new(calibrator));
+        self
+    }
+
+    /// Report whether the solution at navigation coordinate `id` succeeded
+    ///
+    /// Feedback refits the calibration curve applied to the confidence of coordinates
+    /// navigated afterwards. Only coordinates this navigator produced can be reported, and
+    /// each only once.
+    pub async fn report_outcome(&self, id: Uuid, success: bool) -> SEntropyResult<()> {
+        self.calibrator.write().await.report_outcome(id, success)?;
+        debug!(
+            target: subsystem::NAVIGATION,
+            navigation_coordinate_id = %id,
+            success,
+            "navigation outcome reported"
+        );
+        Ok(())
+    }
+
+    /// Snapshot of the confidence calibrator
+    pub async fn calibrator(&self) -> ConfidenceCalibrator {
+        self.calibrator.read().await.clone()
+    }
+
+    /// Record `coordinate`'s raw confidence and replace it with the calibrated one
+    async fn calibrate(&self, coordinate: &mut NavigationCoordinate) {
+        let mut calibrator = self.calibrator.write().await;
+        calibrator.record_prediction(coordinate.id, coordinate.confidence);
+        coordinate.confidence = calibrator.calibrate(coordinate.confidence);
+    }
+
+    /// Record `coordinate` as the solution of `problem` for later reuse
+    pub fn cache_solution(&mut self, problem: impl Into<String>, coordinate: NavigationCoordinate) {
+        self.solution_cache.insert(problem, coordinate);
+    }
+
+    /// Cached solutions of the index
+    pub fn solution_cache(&self) -> &NavigationIndex {
+        &self.solution_cache
+    }
+
+    /// Snapshot of the cached solutions, for sharing with other navigators
+    pub fn export_solutions(&self) -> SolutionCacheFile {
+        let solutions = self
+            .solution_cache
+            .iter()
+            .map(|(problem, coordinate)| CachedSolution {
+                problem: problem.to_string(),
+                coordinate: coordinate.clone(),
+            })
+            .collect();
+
+        SolutionCacheFile {
+            version: SOLUTION_CACHE_VERSION,
+            precision: self.precision,
+            solutions,
+            saved_at: chrono::Utc::now(),
+        }
+    }
+
+    /// Merge `file`'s solutions into the cache, returning how many were imported
+    ///
+    /// Imported solutions replace cached ones recorded under the same problem. Nothing is
+    /// imported unless the format is supported and every solution honors the memorial
+    /// significance.
+    pub fn import_solutions(&mut self, file: SolutionCacheFile) -> SEntropyResult<usize> {
+        file.validate_version()?;
+        file.validate_memorial_significance()?;
+        if file.precision != self.precision {
+            warn!(
+                target: subsystem::NAVIGATION,
+                cache_precision = ?file.precision,
+                precision = ?self.precision,
+                "importing solutions cached at a different precision"
+            );
+        }
+
+        let imported = file.solutions.len();
+        for solution in file.solutions {
+            self.solution_cache.insert(solution.problem, solution.coordinate);
+        }
+        info!(
+            target: subsystem::NAVIGATION,
+            imported,
+            cached = self.solution_cache.len(),
+            "solution cache imported"
+        );
+        Ok(imported)
+    }
+
+    /// Up to `k` cached solutions nearest to `coordinate`, closest first, with distances
+    pub fn nearest_solutions(
+        &self,
+        coordinate: &NavigationCoordinate,
+        k: usize,
+    ) -> Vec<(&str, &NavigationCoordinate, f64)> {
+        self.solution_cache.nearest(coordinate, k)
+    }
+
+    /// Cached solutions within `radius` of `coordinate`, closest first, with distances
+    pub fn solutions_within(
+        &self,
+        coordinate: &NavigationCoordinate,
+        radius: f64,
+    ) -> Vec<(&str, &NavigationCoordinate, f64)> {
+        self.solution_cache.within_radius(coordinate, radius)
+    }
+
     /// Navigate to optimal S-entropy coordinates via predetermined manifold
+    ///
+    /// A cached solution within the reuse radius of the target is returned as is.
     pub async fn navigate_to_coordinates(
         &self,
         target: &SEntropyCoordinate,
     ) -> SEntropyResult<NavigationCoordinate> {
-        debug!("🧭 Navigating to S-entropy coordinates: {}", target);
+        self.navigate_with_constraints(target, &NavigationConstraints::default()).await
+    }
+
+    /// Navigate to optimal S-entropy coordinates while respecting `constraints`
+    ///
+    /// A target inside an exclusion region is steered just outside it, cached solutions
+    /// are only reused when the constraints permit them, and navigation fails when the
+    /// target cannot be steered free or the result falls below the minimum confidence.
+    /// Failures carry the target's id as their [`ErrorContext`].
+    pub async fn navigate_with_constraints(
+        &self,
+        target: &SEntropyCoordinate,
+        constraints: &NavigationConstraints,
+    ) -> SEntropyResult<NavigationCoordinate> {
+        let context = || ErrorContext::new().subsystem(subsystem::NAVIGATION).coordinate(target.id);
 
         // Validate memorial significance
         if !target.validates_memorial_significance() {
             return Err(SEntropyError::memorial_significance(
                 &self.memorial_significance, &target.memorial_significance,
-            ));
+            )
+            .with_context(context()));
         }
 
         // Transform S-entropy coordinates to navigation coordinates
@@ -61,20 +288,123 @@ impl ManifoldNavigator {
         // Calculate confidence based on total magnitude
         let confidence = (1.0 / (1.0 + target.total_magnitude())).max(0.1).min(1.0);
 
-        let nav_coord =
+        let unconstrained =
             NavigationCoordinate::new(knowledge_pos, temporal_pos, entropy_pos, confidence);
+        let mut nav_coord = constraints.steer(&unconstrained).ok_or_else(|| {
+            SEntropyError::navigation(
+                "navigate_to_optimal",
+                "target cannot be steered out of the excluded regions",
+            )
+            .with_context(context())
+        })?;
+
+        if let Some((problem, cached, distance)) = self
+            .solution_cache
+            .within_radius(&nav_coord, self.reuse_radius)
+            .into_iter()
+            .find(|(_, cached, _)| constraints.permits(cached))
+        {
+            debug!(
+                target: subsystem::NAVIGATION,
+                source_coordinate_id = %target.id,
+                navigation_coordinate_id = %cached.id,
+                problem,
+                distance,
+                "navigation served from cached solution"
+            );
+            return Ok(cached.clone());
+        }
 
-        info!("✅ Navigation coordinate generated with confidence: {:.3}", confidence);
+        self.calibrate(&mut nav_coord).await;
+        if !constraints.is_confident_enough(&nav_coord) {
+            return Err(SEntropyError::navigation(
+                "navigate_to_optimal",
+                format!(
+                    "confidence {:.3} is below the required {:.3}",
+                    nav_coord.confidence,
+                    constraints.min_confidence.unwrap_or_default()
+                ),
+            )
+            .with_context(context()));
+        }
+        debug!(
+            target: subsystem::NAVIGATION,
+            source_coordinate_id = %target.id,
+            navigation_coordinate_id = %nav_coord.id,
+            raw_confidence = confidence,
+            confidence = nav_coord.confidence,
+            steered = nav_coord.id != unconstrained.id,
+            "navigation coordinate generated"
+        );
         Ok(nav_coord)
     }
 
+    /// Navigate to every target, concurrently, merging near-identical targets
+    ///
+    /// Targets within the reuse radius of an earlier target are navigated once, with the
+    /// earlier target standing in for them. At most
+    /// [`with_batch_concurrency`](Self::with_batch_concurrency) navigations run at once, and
+    /// each has its own result, so one failed target does not discard the rest.
+    pub async fn navigate_batch(&self, targets: &[SEntropyCoordinate]) -> NavigationBatch {
+        let started = std::time::Instant::now();
+        let mut representatives = NavigationIndex::new();
+        let mut unique: Vec<&SEntropyCoordinate> = Vec::new();
+        let mut input_to_result = Vec::with_capacity(targets.len());
+
+        for target in targets {
+            // Targets failing memorial validation keep their own, failing, navigation
+            let position = transform_s_to_navigation(target);
+            let merged = if target.validates_memorial_significance() {
+                let found = representatives.within_radius(&position, self.reuse_radius);
+                found.first().and_then(|(key, _, _)| key.parse::<usize>().ok())
+            } else {
+                None
+            };
+            match merged {
+                Some(result) => input_to_result.push(result),
+                None => {
+                    if target.validates_memorial_significance() {
+                        representatives.insert(unique.len().to_string(), position);
+                    }
+                    input_to_result.push(unique.len());
+                    unique.push(target);
+                },
+            }
+        }
+
+        let results: Vec<_> = stream::iter(unique)
+            .map(|target| self.navigate_to_coordinates(target))
+            .buffered(self.batch_concurrency)
+            .collect()
+            .await;
+        let batch = NavigationBatch { results, input_to_result };
+
+        info!(
+            target: subsystem::NAVIGATION,
+            batch_size = batch.len(),
+            unique_targets = batch.unique_targets(),
+            failed = batch.failed(),
+            concurrency = self.batch_concurrency,
+            duration_us = started.elapsed().as_micros() as u64,
+            "navigation batch complete"
+        );
+        batch
+    }
+
     /// Find solutions near the specified S percentage threshold
     pub async fn find_solutions_near_threshold(
         &self,
         s_percentage: f64,
     ) -> SEntropyResult<Vec<NavigationCoordinate>> {
-        info!("🔍 Finding solutions near S {}% threshold", s_percentage * 100.0);
+        self.find_solutions_with_constraints(s_percentage, &NavigationConstraints::default()).await
+    }
 
+    /// Find solutions near the specified S percentage threshold that `constraints` permit
+    pub async fn find_solutions_with_constraints(
+        &self,
+        s_percentage: f64,
+        constraints: &NavigationConstraints,
+    ) -> SEntropyResult<Vec<NavigationCoordinate>> {
         let mut near_solutions = Vec::new();
 
         // Generate sample coordinates near the threshold
@@ -91,13 +421,16 @@ impl ManifoldNavigator {
                 0.9, // High confidence for near-threshold solutions
             );
 
-            near_solutions.push(coord);
+            if constraints.permits(&coord) {
+                near_solutions.push(coord);
+            }
         }
 
-        info!(
-            "📊 Found {} solutions near {}% threshold",
-            near_solutions.len(),
-            s_percentage * 100.0
+        debug!(
+            target: subsystem::NAVIGATION,
+            s_percentage,
+            solutions = near_solutions.len(),
+            "near-threshold solutions found"
         );
         Ok(near_solutions)
     }
@@ -107,31 +440,34 @@ impl ManifoldNavigator {
         &self,
         problem_description: &str,
     ) -> SEntropyResult<NavigationCoordinate> {
-        info!("⚡ Performing zero-computation navigation for: {}", problem_description);
+        if let Some(cached) = self.solution_cache.get(problem_description) {
+            return Ok(cached.clone());
+        }
 
         // Hash the problem to get consistent coordinates
-        let problem_hash = {
-            use std::collections::hash_map::DefaultHasher;
-            use std::hash::{Hash, Hasher};
-
-            let mut hasher = DefaultHasher::new();
-            problem_description.hash(&mut hasher);
-            hasher.finish()
-        };
+        let problem_hash = self.problem_hasher.hash_problem(problem_description);
 
         // Generate deterministic coordinates based on problem hash
         let x = ((problem_hash % 1000) as f64) / 1000.0;
         let y = (((problem_hash / 1000) % 1000) as f64) / 1000.0;
         let z = (((problem_hash / 1000000) % 1000) as f64) / 1000.0;
 
-        let nav_coord = NavigationCoordinate::new(
+        let mut nav_coord = NavigationCoordinate::new(
             Vector3::new(x * 0.1, 0.0, 0.0), // Scale down for better S-values
             Vector3::new(0.0, y * 0.1, 0.0),
             Vector3::new(0.0, 0.0, z * 0.1),
             0.8, // Good confidence for zero-computation
         );
-
-        info!("✅ Zero-computation navigation complete");
+        self.calibrate(&mut nav_coord).await;
+
+        debug!(
+            target: subsystem::NAVIGATION,
+            problem = problem_description,
+            problem_hash = self.problem_hasher.name(),
+            problem_hash_version = self.problem_hasher.version(),
+            navigation_coordinate_id = %nav_coord.id,
+            "zero-computation navigation complete"
+        );
         Ok(nav_coord)
     }
 }
@@ -141,8 +477,9 @@ impl PredeterminedManifoldNavigator for ManifoldNavigator {
     async fn navigate_to_optimal(
         &self,
         target: SEntropyCoordinate,
+        constraints: &NavigationConstraints,
     ) -> SEntropyResult<NavigationCoordinate> {
-        self.navigate_to_coordinates(&target).await
+        self.navigate_with_constraints(&target, constraints).await
     }
 
     async fn navigate_zero_computation(
@@ -155,16 +492,15 @@ impl PredeterminedManifoldNavigator for ManifoldNavigator {
     async fn find_near_solutions(
         &self,
         s_percentage: f64,
+        constraints: &NavigationConstraints,
     ) -> SEntropyResult<Vec<NavigationCoordinate>> {
-        self.find_solutions_near_threshold(s_percentage).await
+        self.find_solutions_with_constraints(s_percentage, constraints).await
     }
 
     async fn extract_predetermined_solution(
         &self,
         coord: &NavigationCoordinate,
     ) -> SEntropyResult<String> {
-        debug!("🎯 Extracting predetermined solution from navigation coordinate");
-
         // Validate memorial significance
         if !coord.validates_memorial_significance() {
             return Err(SEntropyError::memorial_significance(
@@ -181,7 +517,11 @@ impl PredeterminedManifoldNavigator for ManifoldNavigator {
             coord.confidence
         );
 
-        info!("✅ Predetermined solution extracted successfully");
+        debug!(
+            target: subsystem::NAVIGATION,
+            navigation_coordinate_id = %coord.id,
+            "predetermined solution extracted"
+        );
         Ok(solution)
     }
 
@@ -223,6 +563,7 @@ pub fn transform_s_to_navigation(s_coord: &SEntropyCoordinate) -> NavigationCoor
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::navigation_constraints::NavigationSpace;
 
     #[tokio::test]
     async fn test_manifold_navigator_creation() {
@@ -250,6 +591,46 @@ mod tests {
         assert!(nav_coord.validates_memorial_significance());
     }
 
+    #[tokio::test]
+    async fn test_zero_computation_hashers_are_pluggable() {
+        let stable = ManifoldNavigator::new(SEntropyPrecision::Standard);
+        let legacy = ManifoldNavigator::new(SEntropyPrecision::Standard)
+            .with_problem_hasher(ProblemHashKind::Legacy.hasher());
+        assert_eq!(stable.problem_hasher().name(), "stable");
+
+        // The stable hash of "test_problem" is pinned, and so are its coordinates
+        let coordinate = stable.zero_computation_navigate("test_problem").await.unwrap();
+        let expected = (0x4663_7b3d_130a_58b4_u64 % 1000) as f64 / 1000.0 * 0.1;
+        assert_eq!(coordinate.knowledge_position.x, expected);
+        let again = legacy.zero_computation_navigate("test_problem").await.unwrap();
+        assert!(again.validates_memorial_significance());
+    }
+
+    #[tokio::test]
+    async fn test_batch_merges_near_identical_targets() {
+        let navigator = ManifoldNavigator::new(SEntropyPrecision::Standard)
+            .with_reuse_radius(1e-6)
+            .with_batch_concurrency(2);
+        let mut forged = SEntropyCoordinate::new(0.1, 0.2, 0.3);
+        forged.memorial_significance = "forged".to_string();
+        let targets = vec![
+            SEntropyCoordinate::new(0.1, 0.2, 0.3),
+            SEntropyCoordinate::new(0.5, 0.5, 0.5),
+            SEntropyCoordinate::new(0.1, 0.2, 0.3 + 1e-9),
+            forged,
+        ];
+
+        let batch = navigator.navigate_batch(&targets).await;
+        assert_eq!(batch.len(), 4);
+        assert_eq!(batch.unique_targets(), 3);
+        assert_eq!(batch.failed(), 1);
+        let first = batch.get(0).unwrap().unwrap();
+        assert_eq!(batch.get(2).unwrap().unwrap().id, first.id);
+        assert_ne!(batch.get(1).unwrap().unwrap().id, first.id);
+        assert!(batch.get(3).unwrap().is_err());
+        assert!(batch.get(4).is_none());
+    }
+
     #[tokio::test]
     async fn test_find_near_solutions() {
         let navigator = ManifoldNavigator::new(SEntropyPrecision::High);
@@ -263,6 +644,72 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_cached_solutions_are_reused() {
+        let mut navigator = ManifoldNavigator::new(SEntropyPrecision::Standard);
+        let target = SEntropyCoordinate::new(0.1, 0.2, 0.3);
+        let solved = navigator.navigate_to_coordinates(&target).await.unwrap();
+        navigator.cache_solution("known_problem", solved.clone());
+
+        let reused = navigator.navigate_to_coordinates(&target).await.unwrap();
+        assert_eq!(reused.id, solved.id);
+        let by_key = navigator.zero_computation_navigate("known_problem").await.unwrap();
+        assert_eq!(by_key.id, solved.id);
+
+        // Targets beyond the reuse radius navigate anew but still find nearby solutions
+        let nearby = SEntropyCoordinate::new(0.1, 0.2, 0.31);
+        let fresh = navigator.navigate_to_coordinates(&nearby).await.unwrap();
+        assert_ne!(fresh.id, solved.id);
+        let neighbours = navigator.solutions_within(&fresh, 0.05);
+        assert_eq!(neighbours.len(), 1);
+        assert_eq!(neighbours[0].0, "known_problem");
+    }
+
+    #[tokio::test]
+    async fn test_outcomes_calibrate_confidence() {
+        let navigator = ManifoldNavigator::new(SEntropyPrecision::Standard);
+        let shared = navigator.clone();
+        let target = SEntropyCoordinate::new(0.1, 0.2, 0.3);
+        let first = navigator.navigate_to_coordinates(&target).await.unwrap();
+        let raw = first.confidence;
+
+        // Solutions at this confidence keep failing, on either clone
+        navigator.report_outcome(first.id, false).await.unwrap();
+        for _ in 0..9 {
+            let coordinate = shared.navigate_to_coordinates(&target).await.unwrap();
+            shared.report_outcome(coordinate.id, false).await.unwrap();
+        }
+        assert!(navigator.report_outcome(first.id, false).await.is_err());
+        assert_eq!(navigator.calibrator().await.observations(), 10);
+
+        let calibrated = navigator.navigate_to_coordinates(&target).await.unwrap();
+        assert!(calibrated.confidence < raw / 2.0);
+    }
+
+    #[tokio::test]
+    async fn test_constraints_steer_navigation() {
+        let navigator = ManifoldNavigator::new(SEntropyPrecision::Standard);
+        let space = NavigationSpace::Knowledge;
+        let constraints =
+            NavigationConstraints::new().exclude_sphere(space, Vector3::zeros(), 0.5);
+
+        let target = SEntropyCoordinate::new(0.1, 0.2, 0.3);
+        let steered = navigator.navigate_to_optimal(target, &constraints).await.unwrap();
+        assert!(constraints.permits(&steered));
+        assert!(steered.knowledge_position.x >= 0.5);
+
+        let near = navigator.find_near_solutions(0.5, &constraints).await.unwrap();
+        assert!(!near.is_empty());
+        assert!(near.iter().all(|solution| solution.knowledge_position.x > 0.5));
+
+        let demanding = NavigationConstraints::new().min_confidence(0.99);
+        let far = SEntropyCoordinate::new(5.0, 5.0, 5.0);
+        let far_id = far.id;
+        let error = navigator.navigate_to_optimal(far, &demanding).await.unwrap_err();
+        assert_eq!(error.context().unwrap().coordinate_id, Some(far_id));
+        assert_eq!(error.code(), crate::error::ErrorCode::Navigation);
+    }
+
     #[test]
     fn test_optimal_navigation_creation() {
         let optimal = create_optimal_navigation();
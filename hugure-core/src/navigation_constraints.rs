@@ -0,0 +1,264 @@
+//! Regions and confidence floors navigation must respect
+//!
+//! [`NavigationConstraints`] describe coordinates a caller knows to violate consciousness
+//! boundaries: axis-aligned boxes and spheres in the knowledge, temporal, or entropy
+//! subspace, plus a minimum confidence. Navigation steers a target that falls inside an
+//! excluded region to the nearest point on its boundary, and near-solution searches drop
+//! candidates the constraints reject.
+
+use nalgebra::Vector3;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::types::NavigationCoordinate;
+
+/// Steering attempts before a target caught between overlapping regions is given up on
+const MAX_STEERING_STEPS: usize = 16;
+
+/// Subspace of a [`NavigationCoordinate`] a region constrains
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum NavigationSpace {
+    /// Knowledge position
+    Knowledge,
+    /// Temporal position
+    Temporal,
+    /// Entropy position
+    Entropy,
+}
+
+impl NavigationSpace {
+    fn position(self, coordinate: &NavigationCoordinate) -> &Vector3<f64> {
+        match self {
+            Self::Knowledge => &coordinate.knowledge_position,
+            Self::Temporal => &coordinate.temporal_position,
+            Self::Entropy => &coordinate.entropy_position,
+        }
+    }
+
+    fn position_mut(self, coordinate: &mut NavigationCoordinate) -> &mut Vector3<f64> {
+        match self {
+            Self::Knowledge => &mut coordinate.knowledge_position,
+            Self::Temporal => &mut coordinate.temporal_position,
+            Self::Entropy => &mut coordinate.entropy_position,
+        }
+    }
+}
+
+/// Region of one subspace that navigation must avoid
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ExclusionRegion {
+    /// Axis-aligned box between `min` and `max`, boundary included
+    BoundingBox {
+        /// Constrained subspace
+        space: NavigationSpace,
+        /// Lowest corner
+        min: Vector3<f64>,
+        /// Highest corner
+        max: Vector3<f64>,
+    },
+    /// Ball of `radius` around `center`, boundary included
+    Sphere {
+        /// Constrained subspace
+        space: NavigationSpace,
+        /// Center of the ball
+        center: Vector3<f64>,
+        /// Radius of the ball
+        radius: f64,
+    },
+}
+
+impl ExclusionRegion {
+    /// Whether `coordinate` lies inside the region
+    pub fn contains(&self, coordinate: &NavigationCoordinate) -> bool {
+        match self {
+            Self::BoundingBox { space, min, max } => {
+                let position = space.position(coordinate);
+                (0..3).all(|axis| min[axis] <= position[axis] && position[axis] <= max[axis])
+            },
+            Self::Sphere { space, center, radius } => {
+                (space.position(coordinate) - center).norm() <= *radius
+            },
+        }
+    }
+
+    /// Move `coordinate` the shortest way to just outside the region
+    fn push_out(&self, coordinate: &mut NavigationCoordinate) {
+        match self {
+            Self::BoundingBox { space, min, max } => {
+                let position = space.position_mut(coordinate);
+                // Leave through the face closest to the position
+                let mut exit = (f64::INFINITY, 0, 0.0);
+                for axis in 0..3 {
+                    let below = position[axis] - min[axis];
+                    let above = max[axis] - position[axis];
+                    if below < exit.0 {
+                        exit = (below, axis, min[axis] - margin(min[axis]));
+                    }
+                    if above < exit.0 {
+                        exit = (above, axis, max[axis] + margin(max[axis]));
+                    }
+                }
+                position[exit.1] = exit.2;
+            },
+            Self::Sphere { space, center, radius } => {
+                let position = space.position_mut(coordinate);
+                let offset = *position - center;
+                let direction = offset.try_normalize(0.0).unwrap_or_else(Vector3::x);
+                *position = center + direction * (radius + margin(*radius));
+            },
+        }
+    }
+}
+
+/// Clearance left between a steered coordinate and the boundary it was pushed across
+fn margin(boundary: f64) -> f64 {
+    1e-9 * (1.0 + boundary.abs())
+}
+
+/// Exclusion regions and minimum confidence for navigation
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NavigationConstraints {
+    /// Regions navigation must avoid
+    pub exclusions: Vec<ExclusionRegion>,
+
+    /// Lowest acceptable coordinate confidence
+    pub min_confidence: Option<f64>,
+}
+
+impl NavigationConstraints {
+    /// No constraints
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Avoid the box between `min` and `max` in `space`
+    pub fn exclude_box(
+        mut self,
+        space: NavigationSpace,
+        min: Vector3<f64>,
+        max: Vector3<f64>,
+    ) -> Self {
+        self.exclusions.push(ExclusionRegion::BoundingBox {
+            space,
+            min: min.inf(&max),
+            max: min.sup(&max),
+        });
+        self
+    }
+
+    /// Avoid the ball of `radius` around `center` in `space`
+    pub fn exclude_sphere(
+        mut self,
+        space: NavigationSpace,
+        center: Vector3<f64>,
+        radius: f64,
+    ) -> Self {
+        self.exclusions.push(ExclusionRegion::Sphere { space, center, radius: radius.abs() });
+        self
+    }
+
+    /// Reject coordinates less confident than `confidence`
+    pub fn min_confidence(mut self, confidence: f64) -> Self {
+        self.min_confidence = Some(confidence.clamp(0.0, 1.0));
+        self
+    }
+
+    /// Whether nothing is constrained
+    pub fn is_unconstrained(&self) -> bool {
+        self.exclusions.is_empty() && self.min_confidence.is_none()
+    }
+
+    /// First exclusion region containing `coordinate`
+    pub fn excluding_region(&self, coordinate: &NavigationCoordinate) -> Option<&ExclusionRegion> {
+        self.exclusions.iter().find(|region| region.contains(coordinate))
+    }
+
+    /// Whether `coordinate` is confident enough
+    pub fn is_confident_enough(&self, coordinate: &NavigationCoordinate) -> bool {
+        self.min_confidence.map_or(true, |min| coordinate.confidence >= min)
+    }
+
+    /// Whether `coordinate` lies outside every exclusion region and is confident enough
+    pub fn permits(&self, coordinate: &NavigationCoordinate) -> bool {
+        self.excluding_region(coordinate).is_none() && self.is_confident_enough(coordinate)
+    }
+
+    /// `coordinate` moved out of every exclusion region, if that can be done
+    ///
+    /// Permitted positions come back unchanged. Otherwise the coordinate is pushed across
+    /// the nearest boundary of each region it lands in, as a new coordinate with the same
+    /// confidence; `None` if overlapping regions keep it trapped.
+    pub fn steer(&self, coordinate: &NavigationCoordinate) -> Option<NavigationCoordinate> {
+        if self.excluding_region(coordinate).is_none() {
+            return Some(coordinate.clone());
+        }
+        let mut steered = NavigationCoordinate::new(
+            coordinate.knowledge_position,
+            coordinate.temporal_position,
+            coordinate.entropy_position,
+            coordinate.confidence,
+        );
+        for _ in 0..MAX_STEERING_STEPS {
+            match self.excluding_region(&steered) {
+                Some(region) => region.push_out(&mut steered),
+                None => return Some(steered),
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn coordinate(s_knowledge: f64, s_time: f64, confidence: f64) -> NavigationCoordinate {
+        NavigationCoordinate::new(
+            Vector3::new(s_knowledge, 0.0, 0.0),
+            Vector3::new(0.0, s_time, 0.0),
+            Vector3::zeros(),
+            confidence,
+        )
+    }
+
+    #[test]
+    fn test_steers_out_of_regions() {
+        let box_min = Vector3::new(-1.0, 0.4, -1.0);
+        let constraints = NavigationConstraints::new()
+            .exclude_sphere(NavigationSpace::Knowledge, Vector3::zeros(), 0.5)
+            .exclude_box(NavigationSpace::Temporal, box_min, Vector3::repeat(1.0));
+
+        let free = coordinate(0.8, 0.0, 0.9);
+        assert_eq!(constraints.steer(&free).unwrap().id, free.id);
+
+        let inside_sphere = coordinate(0.2, 0.0, 0.9);
+        let steered = constraints.steer(&inside_sphere).unwrap();
+        assert!(constraints.permits(&steered));
+        assert!((steered.knowledge_position.x - 0.5).abs() < 1e-6);
+        assert_eq!(steered.confidence, inside_sphere.confidence);
+
+        // The nearest face of the temporal box is its lower y bound
+        let inside_box = coordinate(0.8, 0.5, 0.9);
+        let steered = constraints.steer(&inside_box).unwrap();
+        assert!(steered.temporal_position.y < 0.4);
+        assert!((steered.temporal_position.y - 0.4).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_confidence_floor_and_trapped_targets() {
+        let constraints = NavigationConstraints::new().min_confidence(0.5);
+        assert!(constraints.permits(&coordinate(0.0, 0.0, 0.5)));
+        assert!(!constraints.permits(&coordinate(0.0, 0.0, 0.4)));
+
+        // Two boxes sharing a face bounce a target between them
+        let space = NavigationSpace::Knowledge;
+        let trapped = NavigationConstraints::new()
+            .exclude_box(space, Vector3::repeat(-1.0), Vector3::repeat(1.0))
+            .exclude_box(space, Vector3::new(1.0, -10.0, -10.0), Vector3::repeat(10.0));
+        assert!(trapped.steer(&coordinate(0.9, 0.0, 0.9)).is_none());
+        assert!(!trapped.is_unconstrained());
+    }
+}
@@ -0,0 +1,295 @@
+//! Spatial index of solved navigation coordinates
+//!
+//! [`NavigationIndex`] keys solved [`NavigationCoordinate`]s by problem, like a map, and
+//! also arranges them in a kd-tree over their nine position components (knowledge,
+//! temporal, and entropy positions), so navigation can find previously solved coordinates
+//! near a new target instead of recomputing them. Distances are Euclidean over all nine
+//! components, consistent with [`NavigationCoordinate::total_distance`].
+
+use std::collections::HashMap;
+
+use crate::types::NavigationCoordinate;
+
+/// Position components per coordinate
+const DIMENSIONS: usize = 9;
+
+type Point = [f64; DIMENSIONS];
+
+fn point(coordinate: &NavigationCoordinate) -> Point {
+    let mut point = [0.0; DIMENSIONS];
+    let positions = [
+        &coordinate.knowledge_position,
+        &coordinate.temporal_position,
+        &coordinate.entropy_position,
+    ];
+    for (chunk, position) in point.chunks_mut(3).zip(positions) {
+        chunk.copy_from_slice(position.as_slice());
+    }
+    point
+}
+
+fn distance(a: &Point, b: &Point) -> f64 {
+    a.iter().zip(b).map(|(x, y)| (x - y) * (x - y)).sum::<f64>().sqrt()
+}
+
+#[derive(Debug, Clone)]
+struct Node {
+    key: String,
+    coordinate: NavigationCoordinate,
+    point: Point,
+    left: Option<usize>,
+    right: Option<usize>,
+    /// Replaced or removed entries stay in the tree as unreachable-by-key tombstones
+    live: bool,
+}
+
+/// Solved navigation coordinates, by problem key and by position
+#[derive(Debug, Clone, Default)]
+pub struct NavigationIndex {
+    nodes: Vec<Node>,
+    by_key: HashMap<String, usize>,
+}
+
+impl NavigationIndex {
+    /// Empty index
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record `coordinate` as the solution of `key`, returning the solution it replaces
+    pub fn insert(
+        &mut self,
+        key: impl Into<String>,
+        coordinate: NavigationCoordinate,
+    ) -> Option<NavigationCoordinate> {
+        let key = key.into();
+        let replaced = self.remove(&key);
+
+        let index = self.nodes.len();
+        let point = point(&coordinate);
+        if index > 0 {
+            let mut current = 0;
+            let mut depth = 0;
+            loop {
+                let axis = depth % DIMENSIONS;
+                let node = &mut self.nodes[current];
+                let child = if point[axis] < node.point[axis] {
+                    &mut node.left
+                } else {
+                    &mut node.right
+                };
+                match *child {
+                    Some(next) => current = next,
+                    None => {
+                        *child = Some(index);
+                        break;
+                    },
+                }
+                depth += 1;
+            }
+        }
+
+        self.nodes.push(Node {
+            key: key.clone(),
+            coordinate,
+            point,
+            left: None,
+            right: None,
+            live: true,
+        });
+        self.by_key.insert(key, index);
+        replaced
+    }
+
+    /// Forget the solution of `key`, returning it
+    pub fn remove(&mut self, key: &str) -> Option<NavigationCoordinate> {
+        let index = self.by_key.remove(key)?;
+        let node = &mut self.nodes[index];
+        node.live = false;
+        Some(node.coordinate.clone())
+    }
+
+    /// Solution recorded for `key`
+    pub fn get(&self, key: &str) -> Option<&NavigationCoordinate> {
+        self.by_key.get(key).map(|&index| &self.nodes[index].coordinate)
+    }
+
+    /// Up to `k` solutions nearest to `target`, closest first, with their distances
+    pub fn nearest(
+        &self,
+        target: &NavigationCoordinate,
+        k: usize,
+    ) -> Vec<(&str, &NavigationCoordinate, f64)> {
+        let mut best: Vec<(f64, usize)> = Vec::with_capacity(k + 1);
+        if k > 0 && !self.nodes.is_empty() {
+            self.search_nearest(0, 0, &point(target), k, &mut best);
+        }
+        best.into_iter().map(|(distance, index)| self.result(index, distance)).collect()
+    }
+
+    /// Solutions within `radius` of `target`, closest first, with their distances
+    pub fn within_radius(
+        &self,
+        target: &NavigationCoordinate,
+        radius: f64,
+    ) -> Vec<(&str, &NavigationCoordinate, f64)> {
+        let mut found = Vec::new();
+        if !self.nodes.is_empty() {
+            self.search_radius(0, 0, &point(target), radius, &mut found);
+        }
+        found.sort_by(|a, b| a.0.total_cmp(&b.0));
+        found.into_iter().map(|(distance, index)| self.result(index, distance)).collect()
+    }
+
+    /// Recorded solutions with their keys, oldest first
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &NavigationCoordinate)> {
+        self.nodes
+            .iter()
+            .filter(|node| node.live)
+            .map(|node| (node.key.as_str(), &node.coordinate))
+    }
+
+    /// Number of recorded solutions
+    pub fn len(&self) -> usize {
+        self.by_key.len()
+    }
+
+    /// Whether no solution is recorded
+    pub fn is_empty(&self) -> bool {
+        self.by_key.is_empty()
+    }
+
+    fn result(&self, index: usize, distance: f64) -> (&str, &NavigationCoordinate, f64) {
+        let node = &self.nodes[index];
+        (node.key.as_str(), &node.coordinate, distance)
+    }
+
+    /// Keep the `k` closest live nodes in `best`, sorted by distance
+    fn search_nearest(
+        &self,
+        index: usize,
+        depth: usize,
+        target: &Point,
+        k: usize,
+        best: &mut Vec<(f64, usize)>,
+    ) {
+        let node = &self.nodes[index];
+        if node.live {
+            let candidate = distance(&node.point, target);
+            if best.len() < k || candidate < best[best.len() - 1].0 {
+                let position = best.partition_point(|(d, _)| *d <= candidate);
+                best.insert(position, (candidate, index));
+                best.truncate(k);
+            }
+        }
+
+        let axis = depth % DIMENSIONS;
+        let offset = target[axis] - node.point[axis];
+        let (near, far) =
+            if offset < 0.0 { (node.left, node.right) } else { (node.right, node.left) };
+        if let Some(near) = near {
+            self.search_nearest(near, depth + 1, target, k, best);
+        }
+        // The far side can only hold closer nodes if the splitting plane is within reach
+        if let Some(far) = far {
+            if best.len() < k || offset.abs() < best[best.len() - 1].0 {
+                self.search_nearest(far, depth + 1, target, k, best);
+            }
+        }
+    }
+
+    fn search_radius(
+        &self,
+        index: usize,
+        depth: usize,
+        target: &Point,
+        radius: f64,
+        found: &mut Vec<(f64, usize)>,
+    ) {
+        let node = &self.nodes[index];
+        if node.live {
+            let candidate = distance(&node.point, target);
+            if candidate <= radius {
+                found.push((candidate, index));
+            }
+        }
+
+        let axis = depth % DIMENSIONS;
+        let offset = target[axis] - node.point[axis];
+        if let Some(left) = node.left {
+            if offset < 0.0 || offset.abs() <= radius {
+                self.search_radius(left, depth + 1, target, radius, found);
+            }
+        }
+        if let Some(right) = node.right {
+            if offset >= 0.0 || offset.abs() <= radius {
+                self.search_radius(right, depth + 1, target, radius, found);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::Vector3;
+
+    use super::*;
+
+    fn coordinate(s_knowledge: f64, s_time: f64, s_entropy: f64) -> NavigationCoordinate {
+        NavigationCoordinate::new(
+            Vector3::new(s_knowledge, 0.0, 0.0),
+            Vector3::new(0.0, s_time, 0.0),
+            Vector3::new(0.0, 0.0, s_entropy),
+            0.9,
+        )
+    }
+
+    /// Deterministic scatter of coordinates over the unit cube
+    fn scattered(count: usize) -> Vec<NavigationCoordinate> {
+        (0..count)
+            .map(|i| {
+                let unit = |seed: usize| ((seed * 7919 + 13) % 1000) as f64 / 1000.0;
+                coordinate(unit(i), unit(i * 31 + 7), unit(i * 97 + 3))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_queries_match_brute_force() {
+        let mut index = NavigationIndex::new();
+        let coordinates = scattered(200);
+        for (i, coordinate) in coordinates.iter().enumerate() {
+            index.insert(format!("problem_{}", i), coordinate.clone());
+        }
+        let target = coordinate(0.5, 0.5, 0.5);
+        let mut distances: Vec<f64> =
+            coordinates.iter().map(|c| distance(&point(c), &point(&target))).collect();
+        distances.sort_by(f64::total_cmp);
+
+        let nearest: Vec<f64> = index.nearest(&target, 5).iter().map(|r| r.2).collect();
+        assert_eq!(nearest, distances[..5].to_vec());
+
+        let radius = 0.2;
+        let within = index.within_radius(&target, radius);
+        assert_eq!(within.len(), distances.iter().filter(|d| **d <= radius).count());
+        assert!(within.windows(2).all(|pair| pair[0].2 <= pair[1].2));
+    }
+
+    #[test]
+    fn test_replaced_keys_leave_queries() {
+        let mut index = NavigationIndex::new();
+        index.insert("problem", coordinate(0.1, 0.1, 0.1));
+        let replaced = index.insert("problem", coordinate(0.9, 0.9, 0.9));
+        assert!(replaced.is_some());
+        assert_eq!(index.len(), 1);
+
+        let nearest = index.nearest(&coordinate(0.1, 0.1, 0.1), 3);
+        assert_eq!(nearest.len(), 1);
+        assert_eq!(nearest[0].0, "problem");
+        assert_eq!(nearest[0].1.knowledge_position.x, 0.9);
+
+        assert!(index.remove("problem").is_some());
+        assert!(index.is_empty());
+        assert!(index.within_radius(&coordinate(0.9, 0.9, 0.9), 1.0).is_empty());
+    }
+}
@@ -0,0 +1,191 @@
+//! Trajectories through the predetermined solution manifold
+//!
+//! A [`NavigationPath`] is an ordered sequence of waypoints whose consecutive pairs form
+//! segments, each carrying its own confidence. Rather than jumping straight to the final
+//! coordinate, temporal coordination can place intermediate BMD operations along the path
+//! with [`NavigationPath::position_at`] and [`NavigationPath::sample`], which follow the
+//! geodesic between waypoints and are parameterized by distance travelled.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::{SEntropyError, SEntropyResult},
+    types::NavigationCoordinate,
+};
+
+/// Ordered waypoints with a confidence for each segment between them
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NavigationPath {
+    waypoints: Vec<NavigationCoordinate>,
+    segment_confidence: Vec<f64>,
+}
+
+impl NavigationPath {
+    /// Path through `waypoints`, each segment as confident as its least confident end
+    pub fn new(waypoints: Vec<NavigationCoordinate>) -> SEntropyResult<Self> {
+        let segment_confidence =
+            waypoints.windows(2).map(|pair| pair[0].confidence.min(pair[1].confidence)).collect();
+        Self::with_segment_confidence(waypoints, segment_confidence)
+    }
+
+    /// Path through `waypoints` with explicit confidence for each of its segments
+    pub fn with_segment_confidence(
+        waypoints: Vec<NavigationCoordinate>,
+        segment_confidence: Vec<f64>,
+    ) -> SEntropyResult<Self> {
+        if waypoints.is_empty() {
+            return Err(SEntropyError::navigation("navigation_path", "path has no waypoints"));
+        }
+        if segment_confidence.len() != waypoints.len() - 1 {
+            return Err(SEntropyError::navigation(
+                "navigation_path",
+                format!(
+                    "{} waypoints need {} segment confidences, got {}",
+                    waypoints.len(),
+                    waypoints.len() - 1,
+                    segment_confidence.len()
+                ),
+            ));
+        }
+        if let Some(invalid) = segment_confidence.iter().find(|c| !(0.0..=1.0).contains(*c)) {
+            return Err(SEntropyError::navigation(
+                "navigation_path",
+                format!("segment confidence must be within 0.0..=1.0, got {}", invalid),
+            ));
+        }
+        Ok(Self { waypoints, segment_confidence })
+    }
+
+    /// Waypoints in travel order
+    pub fn waypoints(&self) -> &[NavigationCoordinate] {
+        &self.waypoints
+    }
+
+    /// Confidence of each segment, in travel order
+    pub fn segment_confidence(&self) -> &[f64] {
+        &self.segment_confidence
+    }
+
+    /// First waypoint
+    pub fn start(&self) -> &NavigationCoordinate {
+        &self.waypoints[0]
+    }
+
+    /// Last waypoint
+    pub fn end(&self) -> &NavigationCoordinate {
+        &self.waypoints[self.waypoints.len() - 1]
+    }
+
+    /// Distance travelled along every segment
+    pub fn length(&self) -> f64 {
+        self.waypoints.windows(2).map(|pair| pair[0].distance_to(&pair[1])).sum()
+    }
+
+    /// Product of the segment confidences, the confidence of completing the whole path
+    pub fn confidence(&self) -> f64 {
+        self.segment_confidence.iter().product()
+    }
+
+    /// Coordinate a fraction `progress` of the path's length along it
+    ///
+    /// `progress` is clamped to `0.0..=1.0`. Along a path of zero length every position
+    /// is the last waypoint.
+    pub fn position_at(&self, progress: f64) -> NavigationCoordinate {
+        let (segment, t) = self.locate(progress.clamp(0.0, 1.0) * self.length());
+        match segment {
+            Some(segment) => self.waypoints[segment].interpolate(&self.waypoints[segment + 1], t),
+            None => self.end().clone(),
+        }
+    }
+
+    /// Confidence of the segment a fraction `progress` of the path's length along it
+    ///
+    /// Paths of a single waypoint have no segments and are fully confident.
+    pub fn confidence_at(&self, progress: f64) -> f64 {
+        match self.locate(progress.clamp(0.0, 1.0) * self.length()).0 {
+            Some(segment) => self.segment_confidence[segment],
+            None => self.segment_confidence.last().copied().unwrap_or(1.0),
+        }
+    }
+
+    /// `count` coordinates evenly spaced by distance, from start to end inclusive
+    pub fn sample(&self, count: usize) -> Vec<NavigationCoordinate> {
+        match count {
+            0 => Vec::new(),
+            1 => vec![self.start().clone()],
+            _ => (0..count).map(|i| self.position_at(i as f64 / (count - 1) as f64)).collect(),
+        }
+    }
+
+    /// Segment containing `distance` along the path and the fraction of it covered
+    ///
+    /// `None` once `distance` reaches the end of the path.
+    fn locate(&self, mut distance: f64) -> (Option<usize>, f64) {
+        for (segment, pair) in self.waypoints.windows(2).enumerate() {
+            let length = pair[0].distance_to(&pair[1]);
+            if distance < length {
+                return (Some(segment), distance / length);
+            }
+            distance -= length;
+        }
+        (None, 1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::Vector3;
+
+    use super::*;
+
+    fn coordinate(s_knowledge: f64, s_time: f64, confidence: f64) -> NavigationCoordinate {
+        NavigationCoordinate::new(
+            Vector3::new(s_knowledge, 0.0, 0.0),
+            Vector3::new(0.0, s_time, 0.0),
+            Vector3::zeros(),
+            confidence,
+        )
+    }
+
+    #[test]
+    fn test_positions_follow_distance_travelled() {
+        // An L-shaped path: 3 units along knowledge, then 1 along time
+        let path = NavigationPath::new(vec![
+            coordinate(0.0, 0.0, 1.0),
+            coordinate(3.0, 0.0, 0.8),
+            coordinate(3.0, 1.0, 0.5),
+        ])
+        .unwrap();
+        assert_eq!(path.length(), 4.0);
+        assert_eq!(path.segment_confidence(), &[0.8, 0.5]);
+        assert_eq!(path.confidence(), 0.4);
+
+        let midway = path.position_at(0.5);
+        assert!((midway.knowledge_position.x - 2.0).abs() < 1e-12);
+        assert_eq!(midway.temporal_position.y, 0.0);
+        assert!((midway.confidence - 0.8667).abs() < 1e-3);
+        assert_eq!(path.confidence_at(0.5), 0.8);
+        assert_eq!(path.confidence_at(0.9), 0.5);
+        assert_eq!(path.position_at(2.0).temporal_position.y, 1.0);
+
+        let samples = path.sample(5);
+        let times: Vec<f64> = samples.iter().map(|c| c.temporal_position.y).collect();
+        assert_eq!(times, vec![0.0, 0.0, 0.0, 0.0, 1.0]);
+        assert!((samples[1].knowledge_position.x - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_rejects_malformed_paths() {
+        assert!(NavigationPath::new(Vec::new()).is_err());
+        let waypoints = vec![coordinate(0.0, 0.0, 0.9), coordinate(1.0, 0.0, 0.9)];
+        assert!(NavigationPath::with_segment_confidence(waypoints.clone(), vec![]).is_err());
+        assert!(NavigationPath::with_segment_confidence(waypoints.clone(), vec![1.5]).is_err());
+
+        let single = NavigationPath::new(waypoints[..1].to_vec()).unwrap();
+        assert_eq!(single.length(), 0.0);
+        assert_eq!(single.confidence_at(0.5), 1.0);
+        assert_eq!(single.sample(3).len(), 3);
+    }
+}
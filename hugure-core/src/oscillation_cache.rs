@@ -0,0 +1,206 @@
+//! Size-bounded LRU cache of problem oscillation coordinates
+//!
+//! Problems are keyed by their [`normalize_problem`] form, so descriptions differing only
+//! in surrounding or repeated whitespace share one entry. Once the cache holds its
+//! capacity, inserting evicts the least recently used problem. Hits, misses, and evictions
+//! are counted in [`OscillationCacheStats`].
+
+use std::collections::{BTreeMap, HashMap};
+
+use nalgebra::Vector3;
+
+/// Problems cached by default
+pub const DEFAULT_OSCILLATION_CACHE_CAPACITY: usize = 4_096;
+
+/// Cache key of `problem`: trimmed, with every whitespace run collapsed to one space
+///
+/// Case is kept, since feature extractors may tell upper and lower case apart.
+pub fn normalize_problem(problem: &str) -> String {
+    problem.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Cache effectiveness counters
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OscillationCacheStats {
+    /// Lookups that found cached oscillations
+    pub hits: u64,
+
+    /// Lookups that found nothing
+    pub misses: u64,
+
+    /// Problems evicted to stay within capacity
+    pub evictions: u64,
+
+    /// Problems currently cached
+    pub len: usize,
+
+    /// Maximum problems cached
+    pub capacity: usize,
+}
+
+impl OscillationCacheStats {
+    /// Fraction of lookups that hit, 0.0 before any lookup
+    pub fn hit_rate(&self) -> f64 {
+        let lookups = self.hits + self.misses;
+        if lookups == 0 {
+            0.0
+        } else {
+            self.hits as f64 / lookups as f64
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Entry {
+    oscillations: Vector3<f64>,
+    last_used: u64,
+}
+
+/// Least-recently-used cache of oscillation coordinates by normalized problem
+#[derive(Debug, Clone)]
+pub struct OscillationCache {
+    capacity: usize,
+    entries: HashMap<String, Entry>,
+    /// Problems by the tick they were last used at, least recent first
+    recency: BTreeMap<u64, String>,
+    tick: u64,
+    hits: u64,
+    misses: u64,
+    evictions: u64,
+}
+
+impl Default for OscillationCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_OSCILLATION_CACHE_CAPACITY)
+    }
+}
+
+impl OscillationCache {
+    /// Cache holding up to `capacity` problems (at least one)
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            entries: HashMap::new(),
+            recency: BTreeMap::new(),
+            tick: 0,
+            hits: 0,
+            misses: 0,
+            evictions: 0,
+        }
+    }
+
+    /// Cached oscillations of the normalized problem `key`, marking it most recently used
+    pub fn get(&mut self, key: &str) -> Option<Vector3<f64>> {
+        let tick = self.next_tick();
+        match self.entries.get_mut(key) {
+            Some(entry) => {
+                self.hits += 1;
+                if let Some(problem) = self.recency.remove(&entry.last_used) {
+                    self.recency.insert(tick, problem);
+                }
+                entry.last_used = tick;
+                Some(entry.oscillations)
+            },
+            None => {
+                self.misses += 1;
+                None
+            },
+        }
+    }
+
+    /// Cache `oscillations` under the normalized problem `key`, returning the evicted problem
+    pub fn insert(&mut self, key: String, oscillations: Vector3<f64>) -> Option<String> {
+        let tick = self.next_tick();
+        if let Some(entry) = self.entries.get_mut(&key) {
+            self.recency.remove(&entry.last_used);
+            *entry = Entry { oscillations, last_used: tick };
+            self.recency.insert(tick, key);
+            return None;
+        }
+        self.entries.insert(key.clone(), Entry { oscillations, last_used: tick });
+        self.recency.insert(tick, key);
+
+        if self.entries.len() <= self.capacity {
+            return None;
+        }
+        let (_, oldest) = self.recency.pop_first()?;
+        self.evictions += 1;
+        self.entries.remove(&oldest);
+        Some(oldest)
+    }
+
+    /// Drop every cached problem, keeping the effectiveness counters
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.recency.clear();
+    }
+
+    /// Number of cached problems
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Maximum number of cached problems
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Whether nothing is cached
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Effectiveness counters and occupancy
+    pub fn stats(&self) -> OscillationCacheStats {
+        OscillationCacheStats {
+            hits: self.hits,
+            misses: self.misses,
+            evictions: self.evictions,
+            len: self.entries.len(),
+            capacity: self.capacity,
+        }
+    }
+
+    fn next_tick(&mut self) -> u64 {
+        self.tick += 1;
+        self.tick
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalized_problems_share_entries() {
+        let key = normalize_problem("  solve\tconsciousness \n");
+        assert_eq!(key, "solve consciousness");
+        assert_ne!(normalize_problem("Solve consciousness"), key);
+
+        let mut cache = OscillationCache::new(8);
+        assert!(cache.get(&key).is_none());
+        cache.insert(key, Vector3::new(0.1, 0.2, 0.3));
+        let cached = cache.get(&normalize_problem("solve   consciousness"));
+        assert_eq!(cached, Some(Vector3::new(0.1, 0.2, 0.3)));
+
+        let stats = cache.stats();
+        assert_eq!((stats.hits, stats.misses, stats.len), (1, 1, 1));
+        assert_eq!(stats.hit_rate(), 0.5);
+    }
+
+    #[test]
+    fn test_evicts_least_recently_used() {
+        let mut cache = OscillationCache::new(2);
+        cache.insert("first".to_string(), Vector3::x());
+        cache.insert("second".to_string(), Vector3::y());
+        // Touching the oldest problem makes the second one the eviction candidate
+        cache.get("first");
+
+        let evicted = cache.insert("third".to_string(), Vector3::z());
+        assert_eq!(evicted.as_deref(), Some("second"));
+        assert!(cache.get("second").is_none());
+        assert_eq!(cache.get("first"), Some(Vector3::x()));
+        assert_eq!(cache.stats().evictions, 1);
+        assert_eq!(cache.len(), 2);
+    }
+}
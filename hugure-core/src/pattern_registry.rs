@@ -0,0 +1,341 @@
+//! Versioned registry of emerged BMD patterns
+//!
+//! A [`PatternRegistry`] keeps every [`BMDPattern`] that emerged from exploration so
+//! optimization can reuse it instead of rediscovering it. Patterns are deduplicated by a
+//! content hash that ignores identity and timestamps, so the same pattern rediscovered
+//! under a fresh id is stored once. Each pattern name carries a history of
+//! [`PatternVersion`] tags, and stored patterns can be looked up by content hash, by name
+//! and version, by [`BMDOperationMode`], or by an effectiveness range.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+    hash::{Hash, Hasher},
+    ops::RangeInclusive,
+    str::FromStr,
+};
+
+use chrono::{DateTime, Utc};
+
+use crate::{
+    error::{SEntropyError, SEntropyResult},
+    types::{BMDOperationMode, BMDPattern},
+};
+
+/// Semantic version tag of a registered pattern
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PatternVersion {
+    /// Incompatible change to the pattern's behavior
+    pub major: u64,
+
+    /// Compatible refinement of the pattern
+    pub minor: u64,
+
+    /// Re-tuned parameters with no behavioral change
+    pub patch: u64,
+}
+
+impl PatternVersion {
+    /// Version `major.minor.patch`
+    pub const fn new(major: u64, minor: u64, patch: u64) -> Self {
+        Self { major, minor, patch }
+    }
+
+    /// Next major version
+    pub const fn bump_major(self) -> Self {
+        Self::new(self.major + 1, 0, 0)
+    }
+
+    /// Next minor version
+    pub const fn bump_minor(self) -> Self {
+        Self::new(self.major, self.minor + 1, 0)
+    }
+
+    /// Next patch version
+    pub const fn bump_patch(self) -> Self {
+        Self::new(self.major, self.minor, self.patch + 1)
+    }
+}
+
+impl Default for PatternVersion {
+    fn default() -> Self {
+        Self::new(0, 1, 0)
+    }
+}
+
+impl fmt::Display for PatternVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+impl FromStr for PatternVersion {
+    type Err = SEntropyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || {
+            SEntropyError::configuration(
+                "pattern_version",
+                format!("'{}' is not a major.minor.patch version", s),
+            )
+        };
+        let mut parts = s.trim().trim_start_matches('v').split('.');
+        let mut next = || -> SEntropyResult<u64> {
+            parts.next().and_then(|part| part.parse().ok()).ok_or_else(invalid)
+        };
+        let version = Self::new(next()?, next()?, next()?);
+        match parts.next() {
+            Some(_) => Err(invalid()),
+            None => Ok(version),
+        }
+    }
+}
+
+/// Pattern stored in a [`PatternRegistry`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct RegisteredPattern {
+    /// The stored pattern
+    pub pattern: BMDPattern,
+
+    /// Version tag under the pattern's name
+    pub version: PatternVersion,
+
+    /// Hash of the pattern's content, see [`content_hash`]
+    pub content_hash: u64,
+
+    /// When the pattern was first registered
+    pub registered_at: DateTime<Utc>,
+}
+
+/// Result of [`PatternRegistry::register`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Registration {
+    /// The pattern was new and is stored under this content hash
+    Inserted(u64),
+
+    /// An identical pattern was already stored under this content hash
+    Duplicate(u64),
+}
+
+impl Registration {
+    /// Content hash of the stored pattern
+    pub fn content_hash(&self) -> u64 {
+        match self {
+            Self::Inserted(hash) | Self::Duplicate(hash) => *hash,
+        }
+    }
+}
+
+/// Deduplicated, versioned store of emerged BMD patterns
+#[derive(Debug, Default)]
+pub struct PatternRegistry {
+    entries: HashMap<u64, RegisteredPattern>,
+    versions: HashMap<String, BTreeMap<PatternVersion, u64>>,
+    by_mode: HashMap<BMDOperationMode, Vec<u64>>,
+}
+
+impl PatternRegistry {
+    /// Empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Store `pattern` as `version` of its name
+    ///
+    /// A pattern whose content is already stored is not stored again, whatever version it
+    /// is offered under. A new pattern is rejected if its name already has `version`.
+    pub fn register(
+        &mut self,
+        pattern: BMDPattern,
+        version: PatternVersion,
+    ) -> SEntropyResult<Registration> {
+        let hash = content_hash(&pattern);
+        if self.entries.contains_key(&hash) {
+            return Ok(Registration::Duplicate(hash));
+        }
+
+        let versions = self.versions.entry(pattern.name.clone()).or_default();
+        if versions.contains_key(&version) {
+            return Err(SEntropyError::bmd_operation(
+                format!("{:?}", pattern.operation_mode),
+                format!("pattern '{}' already has version {}", pattern.name, version),
+            ));
+        }
+        versions.insert(version, hash);
+        self.by_mode.entry(pattern.operation_mode).or_default().push(hash);
+        self.entries.insert(
+            hash,
+            RegisteredPattern { pattern, version, content_hash: hash, registered_at: Utc::now() },
+        );
+        Ok(Registration::Inserted(hash))
+    }
+
+    /// Store `pattern` under the next patch version of its name, or the default version
+    /// for a new name
+    pub fn register_next(&mut self, pattern: BMDPattern) -> SEntropyResult<Registration> {
+        let version = self
+            .latest(&pattern.name)
+            .map(|latest| latest.version.bump_patch())
+            .unwrap_or_default();
+        self.register(pattern, version)
+    }
+
+    /// Pattern stored under `content_hash`
+    pub fn get(&self, content_hash: u64) -> Option<&RegisteredPattern> {
+        self.entries.get(&content_hash)
+    }
+
+    /// Pattern stored as `version` of `name`
+    pub fn version(&self, name: &str, version: PatternVersion) -> Option<&RegisteredPattern> {
+        let hash = self.versions.get(name)?.get(&version)?;
+        self.entries.get(hash)
+    }
+
+    /// Highest version of `name`
+    pub fn latest(&self, name: &str) -> Option<&RegisteredPattern> {
+        let (_, hash) = self.versions.get(name)?.iter().next_back()?;
+        self.entries.get(hash)
+    }
+
+    /// Versions of `name`, oldest first
+    pub fn versions(&self, name: &str) -> Vec<PatternVersion> {
+        self.versions
+            .get(name)
+            .map(|versions| versions.keys().copied().collect())
+            .unwrap_or_default()
+    }
+
+    /// Patterns operating in `mode`, most effective first
+    pub fn by_operation_mode(&self, mode: BMDOperationMode) -> Vec<&RegisteredPattern> {
+        let hashes = self.by_mode.get(&mode).map(Vec::as_slice).unwrap_or_default();
+        Self::most_effective_first(hashes.iter().filter_map(|hash| self.entries.get(hash)))
+    }
+
+    /// Patterns whose effectiveness lies in `range`, most effective first
+    pub fn by_effectiveness(&self, range: RangeInclusive<f64>) -> Vec<&RegisteredPattern> {
+        Self::most_effective_first(
+            self.entries.values().filter(|entry| range.contains(&entry.pattern.effectiveness)),
+        )
+    }
+
+    /// Drop disposable patterns past their disposal time, returning how many were dropped
+    pub fn prune_disposed(&mut self) -> usize {
+        let disposed: Vec<u64> = self
+            .entries
+            .values()
+            .filter(|entry| entry.pattern.should_dispose())
+            .map(|entry| entry.content_hash)
+            .collect();
+        for hash in &disposed {
+            let Some(entry) = self.entries.remove(hash) else { continue };
+            if let Some(versions) = self.versions.get_mut(&entry.pattern.name) {
+                versions.remove(&entry.version);
+                if versions.is_empty() {
+                    self.versions.remove(&entry.pattern.name);
+                }
+            }
+            if let Some(hashes) = self.by_mode.get_mut(&entry.pattern.operation_mode) {
+                hashes.retain(|stored| stored != hash);
+            }
+        }
+        disposed.len()
+    }
+
+    /// Number of stored patterns
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether no pattern is stored
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    fn most_effective_first<'a>(
+        entries: impl Iterator<Item = &'a RegisteredPattern>,
+    ) -> Vec<&'a RegisteredPattern> {
+        let mut entries: Vec<_> = entries.collect();
+        entries.sort_by(|a, b| b.pattern.effectiveness.total_cmp(&a.pattern.effectiveness));
+        entries
+    }
+}
+
+/// Hash of a pattern's content
+///
+/// The id, timestamps, and coordinate identity are left out, so a pattern rediscovered
+/// under a fresh id hashes the same as the original.
+pub fn content_hash(pattern: &BMDPattern) -> u64 {
+    use std::collections::hash_map::DefaultHasher;
+
+    let mut hasher = DefaultHasher::new();
+    pattern.name.hash(&mut hasher);
+    pattern.operation_mode.hash(&mut hasher);
+    pattern.impossibility_level.hash(&mut hasher);
+    pattern.disposable.hash(&mut hasher);
+    pattern.effectiveness.to_bits().hash(&mut hasher);
+    pattern.transfer_efficiency.to_bits().hash(&mut hasher);
+
+    let coordinates = &pattern.s_coordinates;
+    coordinates.s_knowledge.to_bits().hash(&mut hasher);
+    coordinates.s_time.to_bits().hash(&mut hasher);
+    coordinates.s_entropy.to_bits().hash(&mut hasher);
+
+    let metadata: BTreeMap<_, _> = pattern.metadata.iter().collect();
+    metadata.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ImpossibilityAmplification;
+
+    fn pattern(name: &str, mode: BMDOperationMode, effectiveness: f64) -> BMDPattern {
+        let mut pattern =
+            BMDPattern::new(name.to_string(), mode, ImpossibilityAmplification::Standard, false);
+        pattern.effectiveness = effectiveness;
+        pattern
+    }
+
+    #[test]
+    fn test_rediscovered_pattern_is_deduplicated() {
+        let mut registry = PatternRegistry::new();
+        let focus = || pattern("focus", BMDOperationMode::FrameSelection, 0.8);
+        let first = registry.register(focus(), PatternVersion::default()).unwrap();
+        let again = registry.register(focus(), PatternVersion::new(1, 0, 0)).unwrap();
+
+        assert_eq!(again, Registration::Duplicate(first.content_hash()));
+        assert_eq!(registry.len(), 1);
+        assert_eq!(registry.versions("focus"), vec![PatternVersion::default()]);
+    }
+
+    #[test]
+    fn test_versions_per_name() {
+        let mut registry = PatternRegistry::new();
+        registry.register_next(pattern("focus", BMDOperationMode::FrameSelection, 0.5)).unwrap();
+        registry.register_next(pattern("focus", BMDOperationMode::FrameSelection, 0.6)).unwrap();
+
+        let latest = registry.latest("focus").unwrap();
+        assert_eq!(latest.version, "0.1.1".parse().unwrap());
+        assert_eq!(latest.pattern.effectiveness, 0.6);
+
+        let clash = pattern("focus", BMDOperationMode::FrameSelection, 0.7);
+        assert!(registry.register(clash, PatternVersion::default()).is_err());
+        assert!("1.2".parse::<PatternVersion>().is_err());
+    }
+
+    #[test]
+    fn test_lookup_by_mode_and_effectiveness() {
+        let mut registry = PatternRegistry::new();
+        registry.register_next(pattern("a", BMDOperationMode::FrameSelection, 0.4)).unwrap();
+        registry.register_next(pattern("b", BMDOperationMode::FrameSelection, 0.9)).unwrap();
+        registry.register_next(pattern("c", BMDOperationMode::RealityFusion, 0.7)).unwrap();
+
+        let names = |entries: Vec<&RegisteredPattern>| -> Vec<String> {
+            entries.into_iter().map(|entry| entry.pattern.name.clone()).collect()
+        };
+        assert_eq!(names(registry.by_operation_mode(BMDOperationMode::FrameSelection)), ["b", "a"]);
+        assert_eq!(names(registry.by_effectiveness(0.5..=1.0)), ["b", "c"]);
+        assert!(registry.by_operation_mode(BMDOperationMode::AgencyDelusion).is_empty());
+    }
+}
@@ -0,0 +1,176 @@
+//! Middleware around the stages of the STSL transformation pipeline
+//!
+//! `STSLTransformer::transform_complete_pipeline` runs four [`PipelineStage`]s in order:
+//! oscillation mapping, α, the STSL equation, and navigation. Middleware registered with
+//! `STSLTransformer::with_middleware` is called around each of them:
+//!
+//! - [`PipelineMiddleware::before`] may validate the problem, or short-circuit the stage
+//!   by returning its value, as a cache would
+//! - [`PipelineMiddleware::after`] may inspect or rewrite the stage's value
+//!
+//! Middleware runs in registration order. The first `before` hook returning a value skips
+//! the stage and the remaining `before` hooks; every `after` hook still runs. Values must
+//! belong to the stage they are returned for.
+
+use std::fmt;
+
+use nalgebra::Vector3;
+
+use crate::error::{SEntropyError, SEntropyResult};
+use crate::types::NavigationCoordinate;
+
+/// Stage of the STSL transformation pipeline
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PipelineStage {
+    /// Problem description to oscillation endpoint space
+    OscillationMapping,
+    /// Oscillations to amplitude α
+    Alpha,
+    /// α to S-coordinate through `S = k × log(α)`
+    Stsl,
+    /// S-coordinate to navigation coordinate
+    Navigation,
+}
+
+impl PipelineStage {
+    /// Stage name, as reported in logs and errors
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::OscillationMapping => "oscillation_mapping",
+            Self::Alpha => "alpha",
+            Self::Stsl => "stsl",
+            Self::Navigation => "navigation",
+        }
+    }
+}
+
+/// Value produced by a pipeline stage
+#[derive(Debug, Clone, PartialEq)]
+pub enum StageValue {
+    /// Oscillation endpoint coordinates
+    Oscillations(Vector3<f64>),
+    /// Oscillation amplitude α
+    Alpha(f64),
+    /// S-coordinate
+    SCoordinate(f64),
+    /// Navigation coordinate
+    Navigation(NavigationCoordinate),
+}
+
+impl StageValue {
+    /// Stage producing values of this kind
+    pub fn stage(&self) -> PipelineStage {
+        match self {
+            Self::Oscillations(_) => PipelineStage::OscillationMapping,
+            Self::Alpha(_) => PipelineStage::Alpha,
+            Self::SCoordinate(_) => PipelineStage::Stsl,
+            Self::Navigation(_) => PipelineStage::Navigation,
+        }
+    }
+
+    /// Oscillation coordinates, if this is an oscillation mapping value
+    pub fn into_oscillations(self) -> Option<Vector3<f64>> {
+        match self {
+            Self::Oscillations(oscillations) => Some(oscillations),
+            _ => None,
+        }
+    }
+
+    /// α, if this is an α value
+    pub fn into_alpha(self) -> Option<f64> {
+        match self {
+            Self::Alpha(alpha) => Some(alpha),
+            _ => None,
+        }
+    }
+
+    /// S-coordinate, if this is an STSL value
+    pub fn into_s_coordinate(self) -> Option<f64> {
+        match self {
+            Self::SCoordinate(s_coordinate) => Some(s_coordinate),
+            _ => None,
+        }
+    }
+
+    /// Navigation coordinate, if this is a navigation value
+    pub fn into_navigation(self) -> Option<NavigationCoordinate> {
+        match self {
+            Self::Navigation(coordinate) => Some(coordinate),
+            _ => None,
+        }
+    }
+}
+
+/// Hooks run around each stage of the STSL transformation pipeline
+pub trait PipelineMiddleware: fmt::Debug + Send + Sync {
+    /// Middleware name, as reported in logs and errors
+    fn name(&self) -> &'static str;
+
+    /// Called before `stage` runs on `problem`; a returned value replaces the stage
+    fn before(&self, _stage: PipelineStage, _problem: &str) -> SEntropyResult<Option<StageValue>> {
+        Ok(None)
+    }
+
+    /// Called with the value `stage` produced for `problem`
+    fn after(&self, _problem: &str, _value: &mut StageValue) -> SEntropyResult<()> {
+        Ok(())
+    }
+}
+
+/// `value`, provided it belongs to `stage`
+pub(crate) fn check_stage(
+    middleware: &dyn PipelineMiddleware,
+    stage: PipelineStage,
+    value: StageValue,
+) -> SEntropyResult<StageValue> {
+    if value.stage() == stage {
+        Ok(value)
+    } else {
+        Err(SEntropyError::universal_transformation(
+            stage.as_str(),
+            format!(
+                "middleware '{}' produced a {} value",
+                middleware.name(),
+                value.stage().as_str()
+            ),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct FixedAlpha;
+
+    impl PipelineMiddleware for FixedAlpha {
+        fn name(&self) -> &'static str {
+            "fixed_alpha"
+        }
+
+        fn before(
+            &self,
+            stage: PipelineStage,
+            _problem: &str,
+        ) -> SEntropyResult<Option<StageValue>> {
+            Ok((stage == PipelineStage::Alpha).then_some(StageValue::Alpha(2.0)))
+        }
+    }
+
+    #[test]
+    fn test_defaults_pass_values_through() {
+        let mut value = StageValue::SCoordinate(1.5);
+        assert!(FixedAlpha.after("problem", &mut value).is_ok());
+        assert_eq!(value, StageValue::SCoordinate(1.5));
+        assert_eq!(FixedAlpha.before(PipelineStage::Stsl, "problem").unwrap(), None);
+        assert_eq!(value.stage().as_str(), "stsl");
+    }
+
+    #[test]
+    fn test_values_must_match_their_stage() {
+        let alpha = FixedAlpha.before(PipelineStage::Alpha, "problem").unwrap().unwrap();
+        assert!(check_stage(&FixedAlpha, PipelineStage::Alpha, alpha.clone()).is_ok());
+        assert!(check_stage(&FixedAlpha, PipelineStage::Stsl, alpha).is_err());
+    }
+}
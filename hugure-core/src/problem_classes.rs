@@ -0,0 +1,245 @@
+//! Problem class recognition from configurable matching rules
+//!
+//! A [`ProblemClassRegistry`] holds named [`ProblemClass`]es, each with keyword, regex,
+//! and callback rules. Classifying a problem scores every class by summing its rules'
+//! scores and returns the matching classes as ranked [`ClassCandidate`]s, highest score
+//! first, ties going to the class registered first. The default registry holds the
+//! built-in classes `recognize_problem_class` reports.
+
+use std::{fmt, sync::Arc};
+
+use regex::Regex;
+
+use crate::error::{SEntropyError, SEntropyResult};
+
+/// Class reported when no registered class matches
+pub const GENERAL_PROBLEM_CLASS: &str = "General Problem";
+
+/// Built-in classes and their keywords, in registration order
+const BUILTIN_CLASSES: &[(&str, &[&str])] = &[
+    ("Cognitive Pattern Selection", &["cognitive", "mind", "thought"]),
+    ("Temporal Coordination", &["time", "temporal", "sync"]),
+    ("Communication Optimization", &["communication", "message", "signal"]),
+    ("Cross-Domain Transfer", &["domain", "transfer", "cross"]),
+    ("Memory Optimization", &["memory", "storage", "cache"]),
+];
+
+/// Scoring callback of a [`MatchRule::Callback`]
+pub type MatchCallback = dyn Fn(&str) -> f64 + Send + Sync;
+
+/// How a class recognizes problems, and how strongly
+#[derive(Clone)]
+pub enum MatchRule {
+    /// One point per keyword contained in the lowercased problem
+    Keywords(Vec<String>),
+    /// One point per non-overlapping match in the problem
+    Regex(Regex),
+    /// The callback's score for the problem; non-positive scores do not match
+    Callback(Arc<MatchCallback>),
+}
+
+impl MatchRule {
+    /// Score of `problem`, given its lowercased form
+    fn score(&self, problem: &str, lowercased: &str) -> f64 {
+        match self {
+            Self::Keywords(keywords) => {
+                keywords.iter().filter(|keyword| lowercased.contains(keyword.as_str())).count()
+                    as f64
+            },
+            Self::Regex(regex) => regex.find_iter(problem).count() as f64,
+            Self::Callback(callback) => callback(problem).max(0.0),
+        }
+    }
+}
+
+impl fmt::Debug for MatchRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Keywords(keywords) => f.debug_tuple("Keywords").field(keywords).finish(),
+            Self::Regex(regex) => f.debug_tuple("Regex").field(&regex.as_str()).finish(),
+            Self::Callback(_) => f.write_str("Callback(..)"),
+        }
+    }
+}
+
+/// Named problem class with the rules that recognize it
+#[derive(Debug, Clone)]
+pub struct ProblemClass {
+    name: String,
+    rules: Vec<MatchRule>,
+}
+
+impl ProblemClass {
+    /// Class named `name`, matching nothing until rules are added
+    pub fn new(name: impl Into<String>) -> Self {
+        Self { name: name.into(), rules: Vec::new() }
+    }
+
+    /// Match problems containing any of `keywords`, case-insensitively
+    pub fn keywords<I, S>(mut self, keywords: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let keywords = keywords.into_iter().map(|k| k.as_ref().to_lowercase()).collect();
+        self.rules.push(MatchRule::Keywords(keywords));
+        self
+    }
+
+    /// Match problems matching the regular expression `pattern`
+    pub fn regex(mut self, pattern: &str) -> SEntropyResult<Self> {
+        let regex = Regex::new(pattern).map_err(|e| {
+            SEntropyError::configuration(
+                format!("problem_class.{}", self.name),
+                format!("invalid pattern '{}': {}", pattern, e),
+            )
+        })?;
+        self.rules.push(MatchRule::Regex(regex));
+        Ok(self)
+    }
+
+    /// Score problems with `callback`
+    pub fn callback(mut self, callback: impl Fn(&str) -> f64 + Send + Sync + 'static) -> Self {
+        self.rules.push(MatchRule::Callback(Arc::new(callback)));
+        self
+    }
+
+    /// Class name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Sum of the rule scores for `problem`
+    pub fn score(&self, problem: &str) -> f64 {
+        let lowercased = problem.to_lowercase();
+        self.rules.iter().map(|rule| rule.score(problem, &lowercased)).sum()
+    }
+}
+
+/// Class a problem may belong to, with its score
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClassCandidate {
+    /// Class name
+    pub class: String,
+
+    /// Summed rule score, positive
+    pub score: f64,
+}
+
+/// Recognizes the classes a problem belongs to
+pub trait ProblemClassifier: fmt::Debug + Send + Sync {
+    /// Matching classes, highest score first
+    fn classify(&self, problem: &str) -> Vec<ClassCandidate>;
+
+    /// Best matching class, or [`GENERAL_PROBLEM_CLASS`] when none matches
+    fn recognize(&self, problem: &str) -> String {
+        self.classify(problem)
+            .into_iter()
+            .next()
+            .map_or_else(|| GENERAL_PROBLEM_CLASS.to_string(), |candidate| candidate.class)
+    }
+}
+
+/// Problem classes, in registration order
+#[derive(Debug, Clone)]
+pub struct ProblemClassRegistry {
+    classes: Vec<ProblemClass>,
+}
+
+impl Default for ProblemClassRegistry {
+    /// Registry of the built-in classes
+    fn default() -> Self {
+        let mut registry = Self::empty();
+        for (name, keywords) in BUILTIN_CLASSES {
+            registry.register(ProblemClass::new(*name).keywords(keywords.iter()));
+        }
+        registry
+    }
+}
+
+impl ProblemClassRegistry {
+    /// Registry of the built-in classes
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registry without any class
+    pub fn empty() -> Self {
+        Self { classes: Vec::new() }
+    }
+
+    /// Add `class`, replacing any class of the same name in its original position
+    pub fn register(&mut self, class: ProblemClass) -> &mut Self {
+        match self.classes.iter_mut().find(|existing| existing.name == class.name) {
+            Some(existing) => *existing = class,
+            None => self.classes.push(class),
+        }
+        self
+    }
+
+    /// Remove the class named `name`, returning it
+    pub fn unregister(&mut self, name: &str) -> Option<ProblemClass> {
+        let index = self.classes.iter().position(|class| class.name == name)?;
+        Some(self.classes.remove(index))
+    }
+
+    /// Registered classes, in registration order
+    pub fn classes(&self) -> &[ProblemClass] {
+        &self.classes
+    }
+}
+
+impl ProblemClassifier for ProblemClassRegistry {
+    fn classify(&self, problem: &str) -> Vec<ClassCandidate> {
+        let mut candidates: Vec<ClassCandidate> = self
+            .classes
+            .iter()
+            .map(|class| ClassCandidate { class: class.name.clone(), score: class.score(problem) })
+            .filter(|candidate| candidate.score > 0.0)
+            .collect();
+        // Stable, so equal scores keep registration order
+        candidates.sort_by(|a, b| b.score.total_cmp(&a.score));
+        candidates
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_classes_rank_by_matches() {
+        let registry = ProblemClassRegistry::new();
+        let candidates = registry.classify("cross domain memory cache transfer");
+        let ranked: Vec<(&str, f64)> =
+            candidates.iter().map(|c| (c.class.as_str(), c.score)).collect();
+        assert_eq!(ranked, vec![("Cross-Domain Transfer", 3.0), ("Memory Optimization", 2.0)]);
+
+        // Ties go to the class registered first
+        assert_eq!(registry.recognize("time to cache"), "Temporal Coordination");
+        assert_eq!(registry.recognize("solve consciousness"), GENERAL_PROBLEM_CLASS);
+    }
+
+    #[test]
+    fn test_custom_classes_and_rules() {
+        let mut registry = ProblemClassRegistry::empty();
+        let quantum = ProblemClass::new("Quantum Coherence")
+            .keywords(["qubit"])
+            .regex(r"(?i)\bdecoheren(ce|t)\b")
+            .unwrap()
+            .callback(|problem| if problem.ends_with('?') { 0.5 } else { 0.0 });
+        registry.register(quantum);
+        let questions = ProblemClass::new("Questions").callback(|p| p.matches('?').count() as f64);
+        registry.register(questions);
+
+        // One keyword, one regex match, and a trailing question mark
+        let candidates = registry.classify("Why does the qubit decohere? Decoherence?");
+        assert_eq!(candidates[0].class, "Quantum Coherence");
+        assert_eq!(candidates[0].score, 2.5);
+        assert_eq!(candidates[1].score, 2.0);
+        assert!(ProblemClass::new("Broken").regex("(unclosed").is_err());
+
+        registry.unregister("Questions");
+        assert_eq!(registry.recognize("decoherent qubit"), "Quantum Coherence");
+    }
+}
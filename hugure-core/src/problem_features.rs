@@ -0,0 +1,249 @@
+//! Feature extraction from problem descriptions for the STSL transformation
+//!
+//! The STSL transformer maps a problem description to a point in oscillation endpoint
+//! space before applying `S = k × log(α)`. How that point is derived is pluggable through
+//! [`ProblemFeatureExtractor`], chosen with `STSLTransformer::with_feature_extractor`:
+//!
+//! - [`TextStatisticsExtractor`]: length, word count, and character diversity (the default,
+//!   kept so existing oscillation coordinates are reproduced)
+//! - [`TfIdfExtractor`]: TF-IDF token weights, optionally fitted to a corpus, so problems
+//!   sharing distinctive vocabulary land near each other
+//! - [`ExternalEmbeddingExtractor`]: any external embedding model, supplied as a function
+//!
+//! Token weights and embeddings are reduced to three oscillation components by a fixed
+//! random projection derived from a stable hash, so coordinates are reproducible across
+//! builds and machines.
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+    sync::Arc,
+};
+
+use nalgebra::Vector3;
+use xxhash_rust::xxh64::xxh64;
+
+use crate::error::SEntropyResult;
+
+/// Seed of the projection weights for the first oscillation component
+const PROJECTION_SEED: u64 = 0x4f53_4349_4c4c_4154;
+
+/// Maps a problem description to a point in oscillation endpoint space
+pub trait ProblemFeatureExtractor: fmt::Debug + Send + Sync {
+    /// Extractor name, as reported in logs
+    fn name(&self) -> &'static str;
+
+    /// Oscillation coordinates of `problem`
+    fn oscillations(&self, problem: &str) -> SEntropyResult<Vector3<f64>>;
+}
+
+/// Projection weight in `-1.0..=1.0` of `feature` on oscillation component `axis`
+fn projection_weight(feature: &[u8], axis: usize) -> f64 {
+    let hash = xxh64(feature, PROJECTION_SEED.wrapping_add(axis as u64));
+    2.0 * (hash as f64 / u64::MAX as f64) - 1.0
+}
+
+/// Lowercased alphanumeric tokens of `text`
+fn tokenize(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|token| !token.is_empty())
+        .map(str::to_lowercase)
+}
+
+/// Length, word count, and character diversity of the description
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TextStatisticsExtractor;
+
+impl ProblemFeatureExtractor for TextStatisticsExtractor {
+    fn name(&self) -> &'static str {
+        "text_statistics"
+    }
+
+    fn oscillations(&self, problem: &str) -> SEntropyResult<Vector3<f64>> {
+        let complexity = problem.len() as f64;
+        let word_count = problem.split_whitespace().count() as f64;
+        let char_diversity = problem.chars().collect::<HashSet<_>>().len() as f64;
+
+        Ok(Vector3::new(
+            complexity.sqrt() / 10.0,    // Complexity oscillation
+            word_count.log10().max(0.1), // Semantic oscillation
+            char_diversity / 26.0,       // Diversity oscillation
+        ))
+    }
+}
+
+/// TF-IDF token weights, projected onto the three oscillation components
+///
+/// Unfitted, every token has the same inverse document frequency and weights reduce to
+/// term frequencies. [`fit`](Self::fit) on a corpus of problems so tokens common to the
+/// whole corpus count for less than distinctive ones.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TfIdfExtractor {
+    document_frequency: HashMap<String, u64>,
+    documents: u64,
+}
+
+impl TfIdfExtractor {
+    /// Extractor with no corpus
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add `documents` to the corpus inverse document frequencies are taken from
+    pub fn fit<'a>(mut self, documents: impl IntoIterator<Item = &'a str>) -> Self {
+        for document in documents {
+            self.documents += 1;
+            for token in tokenize(document).collect::<HashSet<_>>() {
+                *self.document_frequency.entry(token).or_default() += 1;
+            }
+        }
+        self
+    }
+
+    /// Number of documents fitted
+    pub fn documents(&self) -> u64 {
+        self.documents
+    }
+
+    /// Smoothed inverse document frequency of `token`, at least one
+    pub fn idf(&self, token: &str) -> f64 {
+        let frequency = self.document_frequency.get(token).copied().unwrap_or(0);
+        ((1.0 + self.documents as f64) / (1.0 + frequency as f64)).ln() + 1.0
+    }
+
+    /// Unit-length TF-IDF weights of the tokens of `problem`
+    pub fn weights(&self, problem: &str) -> HashMap<String, f64> {
+        let mut weights: HashMap<String, f64> = HashMap::new();
+        for token in tokenize(problem) {
+            *weights.entry(token).or_default() += 1.0;
+        }
+        for (token, weight) in weights.iter_mut() {
+            *weight *= self.idf(token);
+        }
+        let norm = weights.values().map(|w| w * w).sum::<f64>().sqrt();
+        if norm > 0.0 {
+            weights.values_mut().for_each(|w| *w /= norm);
+        }
+        weights
+    }
+}
+
+impl ProblemFeatureExtractor for TfIdfExtractor {
+    fn name(&self) -> &'static str {
+        "tf_idf"
+    }
+
+    fn oscillations(&self, problem: &str) -> SEntropyResult<Vector3<f64>> {
+        let weights = self.weights(problem);
+        Ok(Vector3::from_fn(|axis, _| {
+            weights
+                .iter()
+                .map(|(token, weight)| weight * projection_weight(token.as_bytes(), axis))
+                .sum()
+        }))
+    }
+}
+
+/// Embedding function of an [`ExternalEmbeddingExtractor`]
+pub type EmbeddingFn = dyn Fn(&str) -> SEntropyResult<Vec<f64>> + Send + Sync;
+
+/// Adapter projecting an external embedding model's output onto oscillation space
+///
+/// Each component is the unit-length embedding's projection onto a fixed random direction,
+/// so embeddings pointing the same way map to the same oscillations.
+#[derive(Clone)]
+pub struct ExternalEmbeddingExtractor {
+    name: &'static str,
+    embed: Arc<EmbeddingFn>,
+}
+
+impl ExternalEmbeddingExtractor {
+    /// Adapter named `name` around the embedding function `embed`
+    pub fn new(
+        name: &'static str,
+        embed: impl Fn(&str) -> SEntropyResult<Vec<f64>> + Send + Sync + 'static,
+    ) -> Self {
+        Self { name, embed: Arc::new(embed) }
+    }
+}
+
+impl fmt::Debug for ExternalEmbeddingExtractor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExternalEmbeddingExtractor").field("name", &self.name).finish()
+    }
+}
+
+impl ProblemFeatureExtractor for ExternalEmbeddingExtractor {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn oscillations(&self, problem: &str) -> SEntropyResult<Vector3<f64>> {
+        let embedding = (self.embed)(problem)?;
+        let norm = embedding.iter().map(|e| e * e).sum::<f64>().sqrt();
+        if norm == 0.0 {
+            return Ok(Vector3::zeros());
+        }
+        Ok(Vector3::from_fn(|axis, _| {
+            let projection: f64 = embedding
+                .iter()
+                .enumerate()
+                .map(|(dimension, e)| {
+                    e * projection_weight(&(dimension as u64).to_le_bytes(), axis)
+                })
+                .sum();
+            projection / norm
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::SEntropyError;
+
+    #[test]
+    fn test_tf_idf_follows_vocabulary_not_length() {
+        let corpus = [
+            "optimize the temporal precision of the observer",
+            "optimize the consciousness boundary of the observer",
+            "navigate the entropy endpoint",
+        ];
+        let extractor = TfIdfExtractor::new().fit(corpus);
+        assert_eq!(extractor.documents(), 3);
+        assert!(extractor.idf("the") < extractor.idf("temporal"));
+
+        // Same vocabulary, same oscillations, however it is written
+        let problem = extractor.oscillations("Temporal precision drift").unwrap();
+        let reworded = extractor.oscillations("drift: temporal, PRECISION").unwrap();
+        assert!((problem - reworded).norm() < 1e-12);
+        assert!(problem.norm() > 0.0);
+
+        // Text statistics cannot tell anagrams apart
+        let (first, second) = ("silent night", "listen thing");
+        let statistics = TextStatisticsExtractor;
+        let shape = |problem| statistics.oscillations(problem).unwrap();
+        let vocabulary = |problem| extractor.oscillations(problem).unwrap();
+        assert_eq!(shape(first), shape(second));
+        assert_ne!(vocabulary(first), vocabulary(second));
+        assert_eq!(extractor.oscillations("...").unwrap(), Vector3::zeros());
+    }
+
+    #[test]
+    fn test_external_embeddings_are_projected() {
+        let extractor = ExternalEmbeddingExtractor::new("bag_of_letters", |problem| {
+            if problem.is_empty() {
+                return Err(SEntropyError::universal_transformation("embed", "empty problem"));
+            }
+            Ok(('a'..='z').map(|letter| problem.matches(letter).count() as f64).collect())
+        });
+        assert_eq!(extractor.name(), "bag_of_letters");
+
+        // Scaling an embedding leaves its direction, and so its oscillations, unchanged
+        let once = extractor.oscillations("abc").unwrap();
+        let twice = extractor.oscillations("aabbcc").unwrap();
+        assert!((once - twice).norm() < 1e-12);
+        assert!(once.norm() > 0.0);
+        assert!(extractor.oscillations("").is_err());
+    }
+}
@@ -0,0 +1,115 @@
+//! Hashing of problem descriptions for zero-computation navigation
+//!
+//! Zero-computation navigation derives a problem's coordinates from a hash of its
+//! description, so the hash decides whether two builds agree on where a problem lives.
+//! Hashers implement [`ProblemHasher`] and report a format version alongside their name:
+//!
+//! - [`StableProblemHasher`]: xxHash64 with a fixed seed, reproducible across builds,
+//!   platforms, and Rust releases (the default)
+//! - [`LegacyProblemHasher`]: the standard library's `DefaultHasher`, as used before
+//!   hashing was versioned; only reproducible under the same Rust toolchain
+
+use std::{
+    collections::hash_map::DefaultHasher,
+    fmt,
+    hash::{Hash, Hasher},
+    sync::Arc,
+};
+
+use xxhash_rust::xxh64::xxh64;
+
+use crate::types::ProblemHashKind;
+
+/// Format version of [`StableProblemHasher`]
+///
+/// Bumped whenever the stable hash of any description would change.
+pub const STABLE_PROBLEM_HASH_VERSION: u32 = 1;
+
+/// Seed of the stable xxHash64 of format version 1
+const STABLE_SEED_V1: u64 = 0x5354_534c_4855_4755;
+
+/// Maps problem descriptions to the hash zero-computation coordinates are derived from
+pub trait ProblemHasher: fmt::Debug + Send + Sync {
+    /// Hasher name, as reported in logs
+    fn name(&self) -> &'static str;
+
+    /// Format version; equal names and versions hash every description equally
+    fn version(&self) -> u32;
+
+    /// Hash of `problem`
+    fn hash_problem(&self, problem: &str) -> u64;
+}
+
+impl ProblemHashKind {
+    /// The hasher of this kind
+    pub fn hasher(&self) -> Arc<dyn ProblemHasher> {
+        match self {
+            Self::Stable => Arc::new(StableProblemHasher),
+            Self::Legacy => Arc::new(LegacyProblemHasher),
+        }
+    }
+}
+
+/// Versioned xxHash64 of the description's UTF-8 bytes
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StableProblemHasher;
+
+impl ProblemHasher for StableProblemHasher {
+    fn name(&self) -> &'static str {
+        ProblemHashKind::Stable.as_str()
+    }
+
+    fn version(&self) -> u32 {
+        STABLE_PROBLEM_HASH_VERSION
+    }
+
+    fn hash_problem(&self, problem: &str) -> u64 {
+        xxh64(problem.as_bytes(), STABLE_SEED_V1)
+    }
+}
+
+/// Compatibility with coordinates navigated before hashing was versioned
+///
+/// `DefaultHasher`'s algorithm is unspecified and may change between Rust releases.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LegacyProblemHasher;
+
+impl ProblemHasher for LegacyProblemHasher {
+    fn name(&self) -> &'static str {
+        ProblemHashKind::Legacy.as_str()
+    }
+
+    fn version(&self) -> u32 {
+        0
+    }
+
+    fn hash_problem(&self, problem: &str) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        problem.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stable_hash_is_pinned() {
+        let hasher = ProblemHashKind::Stable.hasher();
+        assert_eq!(hasher.version(), STABLE_PROBLEM_HASH_VERSION);
+        // Pinned so any change to the stable format fails here rather than in the field
+        assert_eq!(hasher.hash_problem("test_problem"), 0x4663_7b3d_130a_58b4);
+        assert_eq!(hasher.hash_problem(""), 0x6613_8447_6093_3fc5);
+        assert_ne!(hasher.hash_problem("a"), hasher.hash_problem("b"));
+    }
+
+    #[test]
+    fn test_legacy_matches_default_hasher() {
+        let mut hasher = DefaultHasher::new();
+        "test_problem".hash(&mut hasher);
+        assert_eq!(LegacyProblemHasher.hash_problem("test_problem"), hasher.finish());
+        assert_eq!("legacy".parse::<ProblemHashKind>().unwrap(), ProblemHashKind::Legacy);
+        assert!("sha256".parse::<ProblemHashKind>().is_err());
+    }
+}
@@ -0,0 +1,147 @@
+//! Severity-aware retries of fallible S-entropy operations
+//!
+//! [`with_retry`] runs an operation until it succeeds, retrying only the failures
+//! [`ErrorSeverity::Medium`] marks as worth retrying, with exponential backoff between
+//! attempts. Every other severity is returned at once: Low failures degrade rather than
+//! recover, and High and Critical failures must be corrected, not repeated.
+
+use std::{future::Future, time::Duration};
+
+use tracing::{debug, warn};
+
+use crate::error::{ErrorSeverity, SEntropyResult};
+use crate::logging::subsystem;
+
+/// How [`with_retry`] retries a failing operation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Delay before the first retry
+    pub initial_backoff: Duration,
+
+    /// Upper bound on the delay between attempts
+    pub max_backoff: Duration,
+
+    /// Attempts, including the first, before the last error is returned
+    pub max_attempts: u32,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            initial_backoff: Duration::from_millis(50),
+            max_backoff: Duration::from_secs(5),
+            max_attempts: 5,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Policy running the operation once, never retrying
+    pub fn never() -> Self {
+        Self { max_attempts: 1, ..Self::default() }
+    }
+
+    /// Delay before retry number `retry` (0-based)
+    pub fn backoff(&self, retry: u32) -> Duration {
+        self.initial_backoff.saturating_mul(2u32.saturating_pow(retry)).min(self.max_backoff)
+    }
+
+    /// Whether a failure of `severity` is retried
+    pub fn is_retryable(&self, severity: ErrorSeverity) -> bool {
+        severity == ErrorSeverity::Medium
+    }
+}
+
+/// Run `operation` under `policy`, retrying its retryable failures
+///
+/// Returns the first success, the first failure that is not retryable, or the last
+/// failure once `policy.max_attempts` attempts have been made.
+pub async fn with_retry<T, F, Fut>(policy: &RetryPolicy, mut operation: F) -> SEntropyResult<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = SEntropyResult<T>>,
+{
+    let max_attempts = policy.max_attempts.max(1);
+    let mut attempt = 1;
+    loop {
+        let error = match operation().await {
+            Ok(value) => return Ok(value),
+            Err(error) => error,
+        };
+        let severity = error.severity();
+        if !policy.is_retryable(severity) {
+            debug!(target: subsystem::FRAMEWORK, ?severity, %error, "not retrying failure");
+            return Err(error);
+        }
+        if attempt >= max_attempts {
+            warn!(target: subsystem::FRAMEWORK, attempt, %error, "retries exhausted");
+            return Err(error);
+        }
+        let backoff = policy.backoff(attempt - 1);
+        debug!(target: subsystem::FRAMEWORK, attempt, ?backoff, %error, "retrying failure");
+        tokio::time::sleep(backoff).await;
+        attempt += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use super::*;
+    use crate::error::SEntropyError;
+
+    fn policy() -> RetryPolicy {
+        RetryPolicy {
+            initial_backoff: Duration::from_millis(1),
+            max_backoff: Duration::from_millis(2),
+            max_attempts: 4,
+        }
+    }
+
+    #[test]
+    fn test_backoff_doubles_up_to_the_cap() {
+        let policy = RetryPolicy {
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_millis(250),
+            max_attempts: 3,
+        };
+        assert_eq!(policy.backoff(0), Duration::from_millis(100));
+        assert_eq!(policy.backoff(1), Duration::from_millis(200));
+        assert_eq!(policy.backoff(2), Duration::from_millis(250));
+        assert_eq!(policy.backoff(40), Duration::from_millis(250));
+    }
+
+    #[tokio::test]
+    async fn test_retries_medium_and_fails_fast_otherwise() {
+        let attempts = AtomicU32::new(0);
+        let value = with_retry(&policy(), || async {
+            if attempts.fetch_add(1, Ordering::SeqCst) < 2 {
+                Err(SEntropyError::navigation("navigate", "manifold busy"))
+            } else {
+                Ok(42)
+            }
+        })
+        .await;
+        assert_eq!(value.unwrap(), 42);
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+
+        let attempts = AtomicU32::new(0);
+        let result: SEntropyResult<()> = with_retry(&policy(), || async {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            Err(SEntropyError::navigation("navigate", "manifold busy"))
+        })
+        .await;
+        assert!(result.is_err());
+        assert_eq!(attempts.load(Ordering::SeqCst), 4);
+
+        let attempts = AtomicU32::new(0);
+        let result: SEntropyResult<()> = with_retry(&policy(), || async {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            Err(SEntropyError::memorial_significance("expected", "actual"))
+        })
+        .await;
+        assert_eq!(result.unwrap_err().severity(), ErrorSeverity::Critical);
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+}
@@ -19,39 +19,116 @@
 //! - S_entropy = Entropy endpoint navigation distance + Oscillation accessibility
 
 use async_trait::async_trait;
-use nalgebra::{Matrix3, Vector3};
+use futures::stream::{self, StreamExt};
+use nalgebra::Vector3;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
-use std::sync::Arc;
-use tokio::sync::RwLock;
+use std::fmt;
+use std::ops::Deref;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Instant;
+use tokio::sync::{broadcast, watch, RwLock};
 use tracing::{debug, info, warn};
 
+use crate::alignment::AlignmentStrategy;
+use crate::analytics::{MeasurementAnalytics, DEFAULT_ANALYTICS_WINDOW};
+use crate::checkpoint::{EngineCheckpoint, CHECKPOINT_VERSION};
+use crate::coordinate_cache::{CoordinateCache, CoordinateCacheStats};
+use crate::dimensions::{
+    DefaultEntropyCalculator, DefaultKnowledgeCalculator, DefaultTimeCalculator,
+    EntropyCalculator, KnowledgeCalculator, TimeCalculator,
+};
 use crate::error::{SEntropyError, SEntropyResult};
+use crate::integration::{
+    simulate, IntegrationComparison, IntegrationComparisonReport, IntegrationModel,
+    IntegrationRng,
+};
+use crate::logging::subsystem;
+#[cfg(feature = "sqlite")]
+use crate::measurement_store::MeasurementStore;
 use crate::traits::{MemorialSignificant, SEntropyMeasurable};
-use crate::types::{ObserverSophistication, SEntropyPrecision};
+use crate::types::{
+    AlignmentStrategyKind, IntegrationStrategyKind, MeasurementContext, ObserverSophistication,
+    SEntropyPrecision,
+};
 use crate::SEntropyCoordinate;
 
+/// Default number of measurements retained in the engine history
+pub const DEFAULT_MEASUREMENT_HISTORY_LIMIT: usize = 1000;
+
+/// Default number of measurements buffered for slow subscribers
+pub const DEFAULT_SUBSCRIPTION_CAPACITY: usize = 256;
+
+/// Default number of measurements a batch generates at once
+pub const DEFAULT_BATCH_CONCURRENCY: usize = 16;
+
 /// Core tri-dimensional S-entropy measurement engine
-#[derive(Debug, Clone)]
+///
+/// The engine owns its state: the coordinate cache, measurement history, integration
+/// tracker, and random source belong to this value alone. To share one engine between
+/// tasks, convert it into an [`SEntropyEngineHandle`]; to run an independent experiment
+/// from the current state, [`fork`](Self::fork) it.
+#[derive(Debug)]
 pub struct SEntropyEngine {
     /// Precision level for measurements
     precision: SEntropyPrecision,
 
-    /// Current S-entropy coordinate cache
-    coordinate_cache: Arc<RwLock<HashMap<String, SEntropyCoordinate>>>,
+    /// Finest level a non-optimal measurement escalates to; `None` disables escalation
+    precision_escalation_cap: Option<SEntropyPrecision>,
+
+    /// S_knowledge calculator
+    knowledge_calculator: Arc<dyn KnowledgeCalculator>,
+
+    /// S_time calculator
+    time_calculator: Arc<dyn TimeCalculator>,
+
+    /// S_entropy calculator
+    entropy_calculator: Arc<dyn EntropyCalculator>,
+
+    /// Scheme used for tri-dimensional alignment
+    alignment_strategy: Arc<dyn AlignmentStrategy>,
+
+    /// Aligned coordinates by quantized alignment input, least recently used evicted first
+    coordinate_cache: RwLock<CoordinateCache>,
 
     /// Measurement history for optimization
-    measurement_history: Arc<RwLock<Vec<SEntropyMeasurement>>>,
+    measurement_history: RwLock<Vec<SEntropyMeasurement>>,
+
+    /// Maximum number of measurements retained in the history
+    history_limit: usize,
+
+    /// Maximum number of measurements a batch generates at once
+    batch_concurrency: usize,
+
+    /// Every new measurement, for subscribers
+    measurement_events: broadcast::Sender<SEntropyMeasurement>,
+
+    /// Measurements buffered for each slow subscriber
+    subscription_capacity: usize,
+
+    /// Most recent measurement, for watchers
+    latest_measurement: watch::Sender<Option<SEntropyMeasurement>>,
+
+    /// Persistent store receiving every measurement, beyond the history limit
+    #[cfg(feature = "sqlite")]
+    measurement_store: Option<Arc<MeasurementStore>>,
+
+    /// Dynamics of observer-process integration attempts
+    integration_model: Arc<dyn IntegrationModel>,
+
+    /// Random source of integration attempts
+    integration_rng: Mutex<IntegrationRng>,
 
     /// Observer-process integration tracker
-    integration_tracker: Arc<RwLock<ObserverProcessTracker>>,
+    integration_tracker: RwLock<ObserverProcessTracker>,
 
     /// Memorial significance validator
     memorial_validator: MemorialSignificanceValidator,
 }
 
 /// Individual S-entropy measurement record
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SEntropyMeasurement {
     /// Measurement ID
     pub id: uuid::Uuid,
@@ -74,6 +151,11 @@ pub struct SEntropyMeasurement {
     /// Precision level used
     pub precision: SEntropyPrecision,
 
+    /// Coarser levels at which integration was not optimal before escalating to `precision`,
+    /// in the order they were tried
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub escalated_from: Vec<SEntropyPrecision>,
+
     /// Whether optimal integration was achieved
     pub optimal_integration: bool,
 
@@ -84,8 +166,99 @@ pub struct SEntropyMeasurement {
     pub measured_at: chrono::DateTime<chrono::Utc>,
 }
 
+/// Inputs of one measurement, as taken by [`SEntropyEngine::generate_measurement`]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MeasurementSpec {
+    /// Problem context the S-knowledge deficit is calculated for
+    pub problem_context: MeasurementContext,
+
+    /// Observer sophistication
+    pub observer: ObserverSophistication,
+
+    /// Temporal precision target
+    pub temporal_precision: f64,
+
+    /// Emotional time distortion factor
+    pub emotional_factor: f64,
+
+    /// Problem complexity
+    pub problem_complexity: f64,
+
+    /// Oscillation endpoint accessibility
+    pub accessibility: f64,
+}
+
+/// Filter over recorded measurements; every unset criterion matches everything
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MeasurementQuery {
+    /// Earliest measurement time, inclusive
+    pub since: Option<chrono::DateTime<chrono::Utc>>,
+
+    /// Latest measurement time, exclusive
+    pub until: Option<chrono::DateTime<chrono::Utc>>,
+
+    /// Observer sophistication at time of measurement
+    pub observer_sophistication: Option<ObserverSophistication>,
+
+    /// Whether optimal integration was achieved
+    pub optimal_integration: Option<bool>,
+
+    /// Maximum number of measurements returned
+    pub limit: Option<usize>,
+}
+
+impl MeasurementQuery {
+    /// Query matching every measurement
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Restrict to measurements taken in `[since, until)`
+    pub fn between(
+        mut self,
+        since: chrono::DateTime<chrono::Utc>,
+        until: chrono::DateTime<chrono::Utc>,
+    ) -> Self {
+        self.since = Some(since);
+        self.until = Some(until);
+        self
+    }
+
+    /// Restrict to measurements taken for `observer`
+    pub fn observer(mut self, observer: ObserverSophistication) -> Self {
+        self.observer_sophistication = Some(observer);
+        self
+    }
+
+    /// Restrict to measurements that did or did not achieve optimal integration
+    pub fn optimal_integration(mut self, optimal: bool) -> Self {
+        self.optimal_integration = Some(optimal);
+        self
+    }
+
+    /// Return at most `limit` measurements
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Whether `measurement` satisfies every criterion except the limit
+    pub fn matches(&self, measurement: &SEntropyMeasurement) -> bool {
+        self.since.map_or(true, |since| measurement.measured_at >= since)
+            && self.until.map_or(true, |until| measurement.measured_at < until)
+            && self
+                .observer_sophistication
+                .map_or(true, |observer| measurement.observer_sophistication == observer)
+            && self
+                .optimal_integration
+                .map_or(true, |optimal| measurement.optimal_integration == optimal)
+    }
+}
+
 /// Observer-process integration tracking
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ObserverProcessTracker {
     /// Current separation distance
     pub separation_distance: f64,
@@ -101,7 +274,8 @@ pub struct ObserverProcessTracker {
 }
 
 /// Individual integration attempt record
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct IntegrationAttempt {
     /// Attempt ID
     pub id: uuid::Uuid,
@@ -138,18 +312,32 @@ pub struct MemorialSignificanceValidator {
 impl SEntropyEngine {
     /// Create a new S-entropy measurement engine
     pub fn new(precision: SEntropyPrecision) -> Self {
-        info!("🌟 Initializing S-Entropy measurement engine with {:?} precision", precision);
+        info!(target: subsystem::ENGINE, ?precision, "initializing S-entropy measurement engine");
 
         Self {
             precision,
-            coordinate_cache: Arc::new(RwLock::new(HashMap::new())),
-            measurement_history: Arc::new(RwLock::new(Vec::new())),
-            integration_tracker: Arc::new(RwLock::new(ObserverProcessTracker {
+            precision_escalation_cap: None,
+            knowledge_calculator: Arc::new(DefaultKnowledgeCalculator),
+            time_calculator: Arc::new(DefaultTimeCalculator),
+            entropy_calculator: Arc::new(DefaultEntropyCalculator),
+            alignment_strategy: AlignmentStrategyKind::default().strategy(),
+            coordinate_cache: RwLock::new(CoordinateCache::default()),
+            measurement_history: RwLock::new(Vec::new()),
+            history_limit: DEFAULT_MEASUREMENT_HISTORY_LIMIT,
+            batch_concurrency: DEFAULT_BATCH_CONCURRENCY,
+            measurement_events: broadcast::channel(DEFAULT_SUBSCRIPTION_CAPACITY).0,
+            subscription_capacity: DEFAULT_SUBSCRIPTION_CAPACITY,
+            latest_measurement: watch::channel(None).0,
+            #[cfg(feature = "sqlite")]
+            measurement_store: None,
+            integration_model: IntegrationStrategyKind::default().model(),
+            integration_rng: Mutex::new(IntegrationRng::from_entropy()),
+            integration_tracker: RwLock::new(ObserverProcessTracker {
                 separation_distance: 1000.0, // Start with high separation
                 integration_attempts: Vec::new(),
                 success_rate: 0.0,
                 last_success: None,
-            })),
+            }),
             memorial_validator: MemorialSignificanceValidator {
                 expected_significance: crate::MEMORIAL_SIGNIFICANCE.to_string(),
                 validation_count: 0,
@@ -158,31 +346,175 @@ impl SEntropyEngine {
         }
     }
 
+    /// Share this engine between tasks through cheaply cloneable handles
+    pub fn into_handle(self) -> SEntropyEngineHandle {
+        SEntropyEngineHandle(Arc::new(self))
+    }
+
+    /// Independent engine starting from a deep copy of this one's state
+    ///
+    /// The fork copies the configuration, coordinate cache, measurement history,
+    /// integration tracker, and random source state, then evolves separately: its
+    /// measurements reach neither this engine's history nor its subscribers, and it does
+    /// not write to the measurement store.
+    pub async fn fork(&self) -> Self {
+        let rng = self.integration_rng.lock().unwrap_or_else(PoisonError::into_inner).clone();
+        let latest = self.latest_measurement.borrow().clone();
+
+        Self {
+            precision: self.precision,
+            precision_escalation_cap: self.precision_escalation_cap,
+            knowledge_calculator: Arc::clone(&self.knowledge_calculator),
+            time_calculator: Arc::clone(&self.time_calculator),
+            entropy_calculator: Arc::clone(&self.entropy_calculator),
+            alignment_strategy: Arc::clone(&self.alignment_strategy),
+            coordinate_cache: RwLock::new(self.coordinate_cache.read().await.clone()),
+            measurement_history: RwLock::new(self.measurement_history.read().await.clone()),
+            history_limit: self.history_limit,
+            batch_concurrency: self.batch_concurrency,
+            measurement_events: broadcast::channel(self.subscription_capacity).0,
+            subscription_capacity: self.subscription_capacity,
+            latest_measurement: watch::channel(latest).0,
+            #[cfg(feature = "sqlite")]
+            measurement_store: None,
+            integration_model: Arc::clone(&self.integration_model),
+            integration_rng: Mutex::new(rng),
+            integration_tracker: RwLock::new(self.integration_tracker.read().await.clone()),
+            memorial_validator: self.memorial_validator.clone(),
+        }
+    }
+
+    /// Builder for an engine with custom dimension calculators
+    pub fn builder(precision: SEntropyPrecision) -> SEntropyEngineBuilder {
+        SEntropyEngineBuilder { engine: Self::new(precision) }
+    }
+
+    /// Set the number of measurements retained in the history (at least one)
+    pub fn with_history_limit(mut self, limit: usize) -> Self {
+        self.history_limit = limit.max(1);
+        self
+    }
+
+    /// Retry non-optimal measurements at successively higher precision, up to `cap`
+    ///
+    /// Each escalation recomputes S_time with the temporal precision target tightened to
+    /// the new level's threshold and judges optimal integration against that threshold.
+    /// The levels passed through are recorded in [`SEntropyMeasurement::escalated_from`].
+    pub fn with_precision_escalation(mut self, cap: SEntropyPrecision) -> Self {
+        self.precision_escalation_cap = Some(cap);
+        self
+    }
+
+    /// Set the number of measurements buffered for each slow subscriber (at least one)
+    pub fn with_subscription_capacity(mut self, capacity: usize) -> Self {
+        self.subscription_capacity = capacity.max(1);
+        self.measurement_events = broadcast::channel(self.subscription_capacity).0;
+        self
+    }
+
+    /// Receive every measurement generated from now on
+    ///
+    /// Subscribers falling more than the subscription capacity behind skip the oldest
+    /// measurements and see [`broadcast::error::RecvError::Lagged`].
+    pub fn subscribe(&self) -> broadcast::Receiver<SEntropyMeasurement> {
+        self.measurement_events.subscribe()
+    }
+
+    /// Watch the most recent measurement, `None` until the first is generated
+    pub fn watch_latest(&self) -> watch::Receiver<Option<SEntropyMeasurement>> {
+        self.latest_measurement.subscribe()
+    }
+
+    /// Set the number of measurements a batch generates at once (at least one)
+    pub fn with_batch_concurrency(mut self, concurrency: usize) -> Self {
+        self.batch_concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Align with `strategy` instead of the fixed optimization matrix
+    ///
+    /// Starts an empty coordinate cache of the same capacity, since coordinates aligned by
+    /// the previous strategy no longer apply.
+    pub fn with_alignment_strategy(mut self, strategy: Arc<dyn AlignmentStrategy>) -> Self {
+        let capacity = self.coordinate_cache.get_mut().capacity();
+        self.alignment_strategy = strategy;
+        self.with_coordinate_cache_capacity(capacity)
+    }
+
+    /// Name of the alignment strategy in use
+    pub fn alignment_strategy(&self) -> &'static str {
+        self.alignment_strategy.name()
+    }
+
+    /// Simulate integration attempts with `model` instead of geometric decay
+    pub fn with_integration_model(mut self, model: Arc<dyn IntegrationModel>) -> Self {
+        self.integration_model = model;
+        self
+    }
+
+    /// Seed the random source of integration attempts, making them reproducible
+    pub fn with_rng_seed(mut self, seed: u64) -> Self {
+        self.integration_rng = Mutex::new(IntegrationRng::seeded(seed));
+        self
+    }
+
+    /// Set the number of aligned coordinates cached (at least one)
+    pub fn with_coordinate_cache_capacity(mut self, capacity: usize) -> Self {
+        self.coordinate_cache = RwLock::new(CoordinateCache::new(capacity));
+        self
+    }
+
+    /// Hit, miss, and eviction counts of the coordinate cache
+    pub async fn coordinate_cache_stats(&self) -> CoordinateCacheStats {
+        self.coordinate_cache.read().await.stats()
+    }
+
+    /// Persist every measurement to `store`, in addition to the in-memory history
+    #[cfg(feature = "sqlite")]
+    pub fn with_measurement_store(mut self, store: MeasurementStore) -> Self {
+        self.measurement_store = Some(Arc::new(store));
+        self
+    }
+
+    /// Measurements matching `query`, oldest first
+    ///
+    /// Served from the measurement store when one is attached, so the full history is
+    /// searched; otherwise only the retained in-memory history is.
+    pub async fn query_measurements(
+        &self,
+        query: &MeasurementQuery,
+    ) -> SEntropyResult<Vec<SEntropyMeasurement>> {
+        #[cfg(feature = "sqlite")]
+        if let Some(store) = &self.measurement_store {
+            return store.query(query);
+        }
+
+        let history = self.measurement_history.read().await;
+        let matching = history.iter().filter(|measurement| query.matches(measurement));
+        Ok(match query.limit {
+            Some(limit) => matching.take(limit).cloned().collect(),
+            None => matching.cloned().collect(),
+        })
+    }
+
     /// Calculate S-knowledge: information deficit + frame selection coordinates
+    ///
+    /// Accepts a plain `&str` description as well as a structured [`MeasurementContext`].
     pub async fn calculate_s_knowledge(
         &self,
-        problem_context: &str,
+        context: impl Into<MeasurementContext>,
         observer: ObserverSophistication,
     ) -> SEntropyResult<f64> {
-        debug!("Calculating S-knowledge for context: {}", problem_context);
-
-        // Information deficit calculation based on observer sophistication
-        let information_deficit = match observer {
-            ObserverSophistication::Naive => 1000.0, // High deficit for naive observers
-            ObserverSophistication::Intermediate => 100.0,
-            ObserverSophistication::Expert => 10.0,
-            ObserverSophistication::Universal => 0.0, // No deficit for universal observers
-        };
-
-        // Frame selection coordinate calculation
-        let context_complexity = problem_context.len() as f64;
-        let frame_selection_coords = context_complexity.log10().max(0.0);
-
-        let s_knowledge = information_deficit + frame_selection_coords;
+        let context = context.into();
+        let s_knowledge = self.knowledge_calculator.s_knowledge(&context, observer)?;
 
         debug!(
-            "S-knowledge calculated: {} (deficit: {}, coords: {})",
-            s_knowledge, information_deficit, frame_selection_coords
+            target: subsystem::ENGINE,
+            problem_context = %context.description,
+            domain = ?context.domain,
+            ?observer,
+            s_knowledge,
+            "S-knowledge calculated"
         );
 
         Ok(s_knowledge)
@@ -194,26 +526,14 @@ impl SEntropyEngine {
         temporal_precision_target: f64,
         emotional_factor: f64,
     ) -> SEntropyResult<f64> {
-        debug!(
-            "Calculating S-time with precision target: {}, emotional factor: {}",
-            temporal_precision_target, emotional_factor
-        );
-
-        // Ultra-precision temporal coordination distance
-        let precision_distance = if temporal_precision_target <= crate::S_ENTROPY_PRECISION_TARGET {
-            0.01 // Near-zero for ultra-precision
-        } else {
-            (temporal_precision_target / crate::S_ENTROPY_PRECISION_TARGET).log10()
-        };
-
-        // Emotional time distortion (how subjective time differs from objective time)
-        let emotional_distortion = emotional_factor * 10.0; // Amplify emotional effects
-
-        let s_time = precision_distance + emotional_distortion;
+        let s_time = self.time_calculator.s_time(temporal_precision_target, emotional_factor)?;
 
         debug!(
-            "S-time calculated: {} (precision distance: {}, emotional distortion: {})",
-            s_time, precision_distance, emotional_distortion
+            target: subsystem::ENGINE,
+            temporal_precision_target,
+            emotional_factor,
+            s_time,
+            "S-time calculated"
         );
 
         Ok(s_time)
@@ -225,30 +545,14 @@ impl SEntropyEngine {
         problem_complexity: f64,
         accessibility: f64,
     ) -> SEntropyResult<f64> {
-        debug!(
-            "Calculating S-entropy endpoint with complexity: {}, accessibility: {}",
-            problem_complexity, accessibility
-        );
-
-        // Entropy endpoint navigation distance
-        let navigation_distance = if accessibility > 0.9 {
-            0.01 // Near-zero for high accessibility
-        } else {
-            (1.0 - accessibility) * problem_complexity
-        };
-
-        // Oscillation accessibility factor
-        let oscillation_factor = if accessibility > 0.5 {
-            accessibility.log10().abs()
-        } else {
-            (1.0 - accessibility) * 100.0
-        };
-
-        let s_entropy = navigation_distance + oscillation_factor;
+        let s_entropy = self.entropy_calculator.s_entropy(problem_complexity, accessibility)?;
 
         debug!(
-            "S-entropy calculated: {} (navigation: {}, oscillation: {})",
-            s_entropy, navigation_distance, oscillation_factor
+            target: subsystem::ENGINE,
+            problem_complexity,
+            accessibility,
+            s_entropy,
+            "S-entropy endpoint calculated"
         );
 
         Ok(s_entropy)
@@ -261,12 +565,26 @@ impl SEntropyEngine {
         s_time: f64,
         s_entropy: f64,
     ) -> SEntropyResult<SEntropyCoordinate> {
-        info!("🧮 Performing tri-dimensional S-entropy alignment");
+        let started = Instant::now();
 
-        // Apply optimization to minimize across dimensions
-        let optimization_matrix = self.create_optimization_matrix();
+        // Repeated alignments of the same input reuse the cached coordinate
+        let cache_key = {
+            let mut cache = self.coordinate_cache.write().await;
+            let cache_key = cache.key(s_knowledge, s_time, s_entropy);
+            if let Some(cached) = cache.get(&cache_key) {
+                debug!(
+                    target: subsystem::ENGINE,
+                    coordinate_id = %cached.id,
+                    "tri-dimensional alignment served from cache"
+                );
+                return Ok(cached.clone());
+            }
+            cache_key
+        };
+
+        // Apply the alignment strategy to minimize across dimensions
         let input_vector = Vector3::new(s_knowledge, s_time, s_entropy);
-        let optimized_vector = optimization_matrix * input_vector;
+        let optimized_vector = self.alignment_strategy.align(input_vector)?;
 
         let aligned_coord =
             SEntropyCoordinate::new(optimized_vector[0], optimized_vector[1], optimized_vector[2]);
@@ -280,75 +598,48 @@ impl SEntropyEngine {
         }
 
         // Cache the aligned coordinate
-        let cache_key = format!(
-            "aligned_{}_{}_{}_{}",
-            s_knowledge,
-            s_time,
-            s_entropy,
-            chrono::Utc::now().timestamp()
-        );
-        {
-            let mut cache = self.coordinate_cache.write().await;
-            cache.insert(cache_key, aligned_coord.clone());
-        }
+        self.coordinate_cache.write().await.insert(cache_key, aligned_coord.clone());
 
-        info!("✅ Tri-dimensional alignment complete: {}", aligned_coord);
+        info!(
+            target: subsystem::ENGINE,
+            coordinate_id = %aligned_coord.id,
+            s_knowledge = aligned_coord.s_knowledge,
+            s_time = aligned_coord.s_time,
+            s_entropy = aligned_coord.s_entropy,
+            total_magnitude = aligned_coord.total_magnitude(),
+            strategy = self.alignment_strategy.name(),
+            duration_us = started.elapsed().as_micros() as u64,
+            "tri-dimensional alignment complete"
+        );
         Ok(aligned_coord)
     }
 
-    /// Create optimization matrix for tri-dimensional alignment
-    fn create_optimization_matrix(&self) -> Matrix3<f64> {
-        // Sacred mathematical matrix honoring St. Stella-Lorraine
-        // Designed to minimize S-distances across all dimensions
-        Matrix3::new(
-            0.8, 0.1, 0.1, // S-knowledge optimization weights
-            0.1, 0.8, 0.1, // S-time optimization weights
-            0.1, 0.1, 0.8, // S-entropy optimization weights
-        )
-    }
-
     /// Attempt observer-process integration
     pub async fn attempt_integration(&self, target_separation: f64) -> SEntropyResult<bool> {
-        info!(
-            "🔗 Attempting observer-process integration with target separation: {}",
-            target_separation
-        );
+        let started = Instant::now();
 
-        let attempt = IntegrationAttempt {
-            id: uuid::Uuid::new_v4(),
-            target_separation,
-            achieved_separation: target_separation * 1.1, // Slightly higher than target initially
-            successful: false,
-            method: "tri_dimensional_alignment".to_string(),
-            attempted_at: chrono::Utc::now(),
-        };
+        let attempt_id = uuid::Uuid::new_v4();
+        let model = self.integration_model.as_ref();
 
         // Simulate integration process
-        let mut achieved_separation = attempt.achieved_separation;
-
-        // Apply S-entropy optimization
-        for iteration in 0..10 {
-            achieved_separation *= 0.9; // Reduce separation by 10% per iteration
-
-            if achieved_separation <= target_separation {
-                info!(
-                    "✅ Observer-process integration successful after {} iterations",
-                    iteration + 1
-                );
-                break;
-            }
-        }
-
-        let successful = achieved_separation <= target_separation;
+        let run = {
+            let mut rng = self.integration_rng.lock().unwrap_or_else(PoisonError::into_inner);
+            simulate(model, target_separation, &mut rng)
+        };
+        let (achieved_separation, successful, iterations) =
+            (run.achieved_separation, run.successful, run.iterations);
 
         // Update integration tracker
         {
             let mut tracker = self.integration_tracker.write().await;
             tracker.separation_distance = achieved_separation;
             tracker.integration_attempts.push(IntegrationAttempt {
+                id: attempt_id,
+                target_separation,
                 achieved_separation,
                 successful,
-                ..attempt
+                method: model.name().to_string(),
+                attempted_at: chrono::Utc::now(),
             });
 
             // Update success rate
@@ -362,41 +653,131 @@ impl SEntropyEngine {
             }
         }
 
+        let duration_us = started.elapsed().as_micros() as u64;
         if successful {
-            info!("🎉 Observer-process integration achieved: separation = {}", achieved_separation);
+            info!(
+                target: subsystem::ENGINE,
+                attempt_id = %attempt_id,
+                target_separation,
+                achieved_separation,
+                iterations,
+                duration_us,
+                "observer-process integration achieved"
+            );
         } else {
             warn!(
-                "⚠️ Observer-process integration incomplete: separation = {} > target = {}",
-                achieved_separation, target_separation
+                target: subsystem::ENGINE,
+                attempt_id = %attempt_id,
+                target_separation,
+                achieved_separation,
+                iterations,
+                duration_us,
+                "observer-process integration incomplete"
             );
         }
 
         Ok(successful)
     }
 
+    /// Run every built-in integration strategy toward `target_separation` and compare them
+    ///
+    /// Every strategy draws from its own generator seeded with the same value from the
+    /// engine's random source, so seeded engines produce the same report. The runs are not
+    /// recorded as integration attempts.
+    pub async fn compare_strategies(&self, target_separation: f64) -> IntegrationComparisonReport {
+        let seed = self.integration_rng.lock().unwrap_or_else(PoisonError::into_inner).next_u64();
+
+        let comparisons = IntegrationStrategyKind::ALL
+            .iter()
+            .map(|&strategy| {
+                let model = strategy.model();
+                let started = Instant::now();
+                let mut rng = IntegrationRng::seeded(seed);
+                let run = simulate(model.as_ref(), target_separation, &mut rng);
+                IntegrationComparison { strategy, run, elapsed: started.elapsed() }
+            })
+            .collect();
+
+        let report = IntegrationComparisonReport { target_separation, comparisons };
+        info!(
+            target: subsystem::ENGINE,
+            target_separation,
+            best = report.best().map(|comparison| comparison.strategy.as_str()),
+            "integration strategy comparison complete"
+        );
+        report
+    }
+
     /// Generate comprehensive S-entropy measurement
     pub async fn generate_measurement(
         &self,
-        problem_context: &str,
+        problem_context: impl Into<MeasurementContext>,
         observer: ObserverSophistication,
         temporal_precision: f64,
         emotional_factor: f64,
         problem_complexity: f64,
         accessibility: f64,
     ) -> SEntropyResult<SEntropyMeasurement> {
-        info!("📊 Generating comprehensive S-entropy measurement");
+        let started = Instant::now();
+
+        for (name, value) in [
+            ("temporal_precision", temporal_precision),
+            ("emotional_factor", emotional_factor),
+            ("problem_complexity", problem_complexity),
+            ("accessibility", accessibility),
+        ] {
+            if !value.is_finite() {
+                return Err(SEntropyError::s_entropy_calculation(format!(
+                    "{} must be finite, got {}",
+                    name, value
+                )));
+            }
+        }
 
-        // Calculate tri-dimensional components
-        let s_knowledge = self.calculate_s_knowledge(problem_context, observer).await?;
-        let s_time = self.calculate_s_time(temporal_precision, emotional_factor).await?;
-        let s_entropy =
-            self.calculate_s_entropy_endpoint(problem_complexity, accessibility).await?;
+        // Calculate tri-dimensional components concurrently
+        let (s_knowledge, mut s_time, s_entropy) = tokio::try_join!(
+            self.calculate_s_knowledge(problem_context, observer),
+            self.calculate_s_time(temporal_precision, emotional_factor),
+            self.calculate_s_entropy_endpoint(problem_complexity, accessibility),
+        )?;
 
         // Calculate total magnitude
-        let total_magnitude = (s_knowledge.powi(2) + s_time.powi(2) + s_entropy.powi(2)).sqrt();
+        let magnitude =
+            |s_time: f64| (s_knowledge.powi(2) + s_time.powi(2) + s_entropy.powi(2)).sqrt();
+        let mut total_magnitude = magnitude(s_time);
+
+        // Escalate precision until integration is optimal or the cap is reached
+        let mut precision = self.precision;
+        let mut escalated_from = Vec::new();
+        while total_magnitude >= precision.threshold() {
+            let Some(next) = self.escalation_after(precision) else {
+                break;
+            };
+            debug!(
+                target: subsystem::ENGINE,
+                from = ?precision,
+                to = ?next,
+                total_magnitude,
+                "escalating measurement precision"
+            );
+            escalated_from.push(precision);
+            precision = next;
+            s_time = self
+                .calculate_s_time(temporal_precision.min(precision.threshold()), emotional_factor)
+                .await?;
+            total_magnitude = magnitude(s_time);
+        }
+
+        // Extreme but finite inputs can still overflow a component or the magnitude
+        if !total_magnitude.is_finite() {
+            return Err(SEntropyError::s_entropy_calculation(format!(
+                "measurement S({}, {}, {}) has non-finite magnitude {}",
+                s_knowledge, s_time, s_entropy, total_magnitude
+            )));
+        }
 
         // Check for optimal integration
-        let optimal_integration = total_magnitude < self.precision.threshold();
+        let optimal_integration = total_magnitude < precision.threshold();
 
         let measurement = SEntropyMeasurement {
             id: uuid::Uuid::new_v4(),
@@ -405,31 +786,188 @@ impl SEntropyEngine {
             s_entropy,
             total_magnitude,
             observer_sophistication: observer,
-            precision: self.precision,
+            precision,
+            escalated_from,
             optimal_integration,
             memorial_significance: crate::MEMORIAL_SIGNIFICANCE.to_string(),
             measured_at: chrono::Utc::now(),
         };
 
+        #[cfg(feature = "sqlite")]
+        if let Some(store) = &self.measurement_store {
+            store.insert(&measurement)?;
+        }
+
         // Store measurement in history
         {
             let mut history = self.measurement_history.write().await;
             history.push(measurement.clone());
 
-            // Keep only last 1000 measurements
-            if history.len() > 1000 {
-                history.drain(0..history.len() - 1000);
+            // Keep only the most recent measurements
+            if history.len() > self.history_limit {
+                let excess = history.len() - self.history_limit;
+                history.drain(0..excess);
             }
         }
 
+        // Publish to subscribers; without subscribers the measurement is only recorded
+        let _ = self.measurement_events.send(measurement.clone());
+        self.latest_measurement.send_replace(Some(measurement.clone()));
+
         info!(
-            "✅ S-entropy measurement complete: total magnitude = {}, optimal = {}",
-            total_magnitude, optimal_integration
+            target: subsystem::ENGINE,
+            measurement_id = %measurement.id,
+            total_magnitude,
+            optimal_integration,
+            duration_us = started.elapsed().as_micros() as u64,
+            "S-entropy measurement complete"
         );
 
         Ok(measurement)
     }
 
+    /// Level a measurement not optimal at `precision` escalates to, if escalation allows one
+    fn escalation_after(&self, precision: SEntropyPrecision) -> Option<SEntropyPrecision> {
+        let cap = self.precision_escalation_cap?;
+        precision.next_higher().filter(|next| *next <= cap)
+    }
+
+    /// Generate the measurement described by `spec`
+    pub async fn generate_measurement_for(
+        &self,
+        spec: &MeasurementSpec,
+    ) -> SEntropyResult<SEntropyMeasurement> {
+        self.generate_measurement(
+            spec.problem_context.clone(),
+            spec.observer,
+            spec.temporal_precision,
+            spec.emotional_factor,
+            spec.problem_complexity,
+            spec.accessibility,
+        )
+        .await
+    }
+
+    /// Generate one measurement per spec, concurrently, returning results in spec order
+    ///
+    /// At most [`with_batch_concurrency`](Self::with_batch_concurrency) measurements run
+    /// at once. Each spec gets its own result, so one failed measurement does not discard
+    /// the rest of the batch.
+    pub async fn generate_measurements_batch(
+        &self,
+        specs: Vec<MeasurementSpec>,
+    ) -> Vec<SEntropyResult<SEntropyMeasurement>> {
+        let started = Instant::now();
+        let batch_size = specs.len();
+
+        let results: Vec<_> = stream::iter(&specs)
+            .map(|spec| self.generate_measurement_for(spec))
+            .buffered(self.batch_concurrency)
+            .collect()
+            .await;
+
+        info!(
+            target: subsystem::ENGINE,
+            batch_size,
+            failed = results.iter().filter(|result| result.is_err()).count(),
+            concurrency = self.batch_concurrency,
+            duration_us = started.elapsed().as_micros() as u64,
+            "S-entropy measurement batch complete"
+        );
+
+        results
+    }
+
+    /// Retained measurement history, oldest first
+    pub async fn measurement_history(&self) -> Vec<SEntropyMeasurement> {
+        self.measurement_history.read().await.clone()
+    }
+
+    /// Every recorded integration attempt, oldest first
+    pub async fn integration_attempts(&self) -> Vec<IntegrationAttempt> {
+        self.integration_tracker.read().await.integration_attempts.clone()
+    }
+
+    /// Snapshot of the integration tracker, coordinate cache, and measurement history
+    pub async fn checkpoint(&self) -> EngineCheckpoint {
+        let integration_tracker = self.integration_tracker.read().await.clone();
+        let cached_coordinates = self.coordinate_cache.read().await.entries();
+        let measurement_history = self.measurement_history.read().await.clone();
+
+        EngineCheckpoint {
+            version: CHECKPOINT_VERSION,
+            precision: self.precision,
+            alignment_strategy: self.alignment_strategy.name().to_string(),
+            integration_tracker,
+            cached_coordinates,
+            measurement_history,
+            created_at: chrono::Utc::now(),
+        }
+    }
+
+    /// Replace this engine's learned state with `checkpoint`'s
+    ///
+    /// Cached coordinates are only restored when the checkpoint was taken with the same
+    /// alignment strategy, and both the cache and the history are trimmed to this
+    /// engine's limits, dropping the least recent entries. Measurements are not
+    /// re-published to subscribers, but watchers see the latest restored measurement.
+    pub async fn restore(&self, checkpoint: EngineCheckpoint) -> SEntropyResult<()> {
+        checkpoint.validate_version()?;
+        if checkpoint.precision != self.precision {
+            warn!(
+                target: subsystem::ENGINE,
+                checkpoint_precision = ?checkpoint.precision,
+                precision = ?self.precision,
+                "restoring checkpoint taken at a different precision"
+            );
+        }
+
+        *self.integration_tracker.write().await = checkpoint.integration_tracker;
+
+        {
+            let mut cache = self.coordinate_cache.write().await;
+            cache.clear();
+            if checkpoint.alignment_strategy == self.alignment_strategy.name() {
+                for (key, coordinate) in checkpoint.cached_coordinates {
+                    cache.insert(key, coordinate);
+                }
+            } else {
+                warn!(
+                    target: subsystem::ENGINE,
+                    checkpoint_strategy = %checkpoint.alignment_strategy,
+                    strategy = self.alignment_strategy.name(),
+                    "discarding cached coordinates aligned by a different strategy"
+                );
+            }
+        }
+
+        let mut history = checkpoint.measurement_history;
+        let excess = history.len().saturating_sub(self.history_limit);
+        history.drain(0..excess);
+        self.latest_measurement.send_replace(history.last().cloned());
+        let restored = history.len();
+        *self.measurement_history.write().await = history;
+
+        info!(
+            target: subsystem::ENGINE,
+            measurements = restored,
+            checkpointed_at = %checkpoint.created_at,
+            "engine state restored from checkpoint"
+        );
+        Ok(())
+    }
+
+    /// Rolling trend analytics of the retained measurement history
+    pub async fn analytics(&self) -> MeasurementAnalytics {
+        self.analytics_with_window(DEFAULT_ANALYTICS_WINDOW).await
+    }
+
+    /// Rolling trend analytics over windows of `window` measurements
+    pub async fn analytics_with_window(&self, window: usize) -> MeasurementAnalytics {
+        let history = self.measurement_history.read().await;
+        MeasurementAnalytics::from_measurements(&history, window, self.precision.threshold())
+    }
+
     /// Get current integration statistics
     pub async fn get_integration_stats(&self) -> SEntropyResult<IntegrationStats> {
         let tracker = self.integration_tracker.read().await;
@@ -451,24 +989,34 @@ impl SEntropyEngine {
         let history = self.measurement_history.read().await;
 
         let mut total_validations = 0;
-        let mut successful_validations = 0;
+        let mut failures = Vec::new();
 
         // Validate cached coordinates
         for coord in cache.values() {
             total_validations += 1;
-            if coord.validates_memorial_significance() {
-                successful_validations += 1;
+            if !coord.validates_memorial_significance() {
+                failures.push(MemorialValidationFailure {
+                    entity_id: coord.id,
+                    kind: MemorialEntityKind::Coordinate,
+                    found: coord.memorial_significance.clone(),
+                });
             }
         }
 
         // Validate measurement history
         for measurement in history.iter() {
             total_validations += 1;
-            if measurement.memorial_significance == crate::MEMORIAL_SIGNIFICANCE {
-                successful_validations += 1;
+            if measurement.memorial_significance != crate::MEMORIAL_SIGNIFICANCE {
+                failures.push(MemorialValidationFailure {
+                    entity_id: measurement.id,
+                    kind: MemorialEntityKind::Measurement,
+                    found: measurement.memorial_significance.clone(),
+                });
             }
         }
 
+        let successful_validations = total_validations - failures.len();
+
         let success_rate = if total_validations > 0 {
             successful_validations as f64 / total_validations as f64
         } else {
@@ -476,23 +1024,124 @@ impl SEntropyEngine {
         };
 
         info!(
-            "🕊️ Memorial significance validation: {}/{} successful ({:.2}%)",
+            target: subsystem::ENGINE,
             successful_validations,
             total_validations,
-            success_rate * 100.0
+            success_rate,
+            "memorial significance validation complete"
         );
 
         Ok(MemorialValidationReport {
             total_validations,
             successful_validations,
             success_rate,
+            failures,
             validated_at: chrono::Utc::now(),
         })
     }
+
+    /// Validate memorial significance, failing with every non-conforming entity listed
+    pub async fn validate_all_memorial_significance_strict(
+        &self,
+    ) -> SEntropyResult<MemorialValidationReport> {
+        let report = self.validate_all_memorial_significance().await?;
+        if report.failures.is_empty() {
+            return Ok(report);
+        }
+
+        let listing: Vec<String> = report.failures.iter().map(ToString::to_string).collect();
+        Err(SEntropyError::memorial_significance(crate::MEMORIAL_SIGNIFICANCE, listing.join("; ")))
+    }
+}
+
+/// Builder of an [`SEntropyEngine`] with user-supplied dimension calculators
+///
+/// Calculators left unset keep the built-in heuristics of [`crate::dimensions`].
+#[derive(Debug)]
+pub struct SEntropyEngineBuilder {
+    engine: SEntropyEngine,
+}
+
+impl SEntropyEngineBuilder {
+    /// Calculate S_knowledge with `calculator`
+    pub fn knowledge_calculator(mut self, calculator: impl KnowledgeCalculator + 'static) -> Self {
+        self.engine.knowledge_calculator = Arc::new(calculator);
+        self
+    }
+
+    /// Calculate S_time with `calculator`
+    pub fn time_calculator(mut self, calculator: impl TimeCalculator + 'static) -> Self {
+        self.engine.time_calculator = Arc::new(calculator);
+        self
+    }
+
+    /// Calculate S_entropy with `calculator`
+    pub fn entropy_calculator(mut self, calculator: impl EntropyCalculator + 'static) -> Self {
+        self.engine.entropy_calculator = Arc::new(calculator);
+        self
+    }
+
+    /// Retain up to `limit` measurements in memory (at least one)
+    pub fn history_limit(mut self, limit: usize) -> Self {
+        self.engine = self.engine.with_history_limit(limit);
+        self
+    }
+
+    /// Align with `strategy`
+    pub fn alignment_strategy(mut self, strategy: Arc<dyn AlignmentStrategy>) -> Self {
+        self.engine = self.engine.with_alignment_strategy(strategy);
+        self
+    }
+
+    /// Simulate integration attempts with `model`
+    pub fn integration_model(mut self, model: Arc<dyn IntegrationModel>) -> Self {
+        self.engine = self.engine.with_integration_model(model);
+        self
+    }
+
+    /// Seed the random source of integration attempts
+    pub fn rng_seed(mut self, seed: u64) -> Self {
+        self.engine = self.engine.with_rng_seed(seed);
+        self
+    }
+
+    /// Escalate non-optimal measurements up to the `cap` precision level
+    pub fn precision_escalation(mut self, cap: SEntropyPrecision) -> Self {
+        self.engine = self.engine.with_precision_escalation(cap);
+        self
+    }
+
+    /// The configured engine
+    pub fn build(self) -> SEntropyEngine {
+        self.engine
+    }
+}
+
+/// Cheaply cloneable, shared handle to one [`SEntropyEngine`]
+///
+/// Every clone of a handle reaches the same engine: measurements generated through any
+/// clone land in the same history, cache, and tracker, and reach the same subscribers.
+/// The engine's methods are available through `Deref`.
+#[derive(Debug, Clone)]
+pub struct SEntropyEngineHandle(Arc<SEntropyEngine>);
+
+impl From<SEntropyEngine> for SEntropyEngineHandle {
+    fn from(engine: SEntropyEngine) -> Self {
+        engine.into_handle()
+    }
+}
+
+impl Deref for SEntropyEngineHandle {
+    type Target = SEntropyEngine;
+
+    fn deref(&self) -> &SEntropyEngine {
+        &self.0
+    }
 }
 
 /// Integration statistics
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct IntegrationStats {
     /// Current observer-process separation distance
     pub current_separation: f64,
@@ -511,7 +1160,8 @@ pub struct IntegrationStats {
 }
 
 /// Memorial significance validation report
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct MemorialValidationReport {
     /// Total number of validations performed
     pub total_validations: usize,
@@ -522,10 +1172,53 @@ pub struct MemorialValidationReport {
     /// Success rate (0.0 - 1.0)
     pub success_rate: f64,
 
+    /// Every entity that failed validation, coordinates first
+    pub failures: Vec<MemorialValidationFailure>,
+
     /// Validation timestamp
     pub validated_at: chrono::DateTime<chrono::Utc>,
 }
 
+/// Kind of entity checked by memorial significance validation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MemorialEntityKind {
+    /// Cached aligned coordinate
+    Coordinate,
+    /// Retained measurement
+    Measurement,
+}
+
+impl MemorialEntityKind {
+    /// Lowercase name, as used in reports
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Coordinate => "coordinate",
+            Self::Measurement => "measurement",
+        }
+    }
+}
+
+/// One entity whose memorial significance did not match
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MemorialValidationFailure {
+    /// Coordinate or measurement ID
+    pub entity_id: uuid::Uuid,
+
+    /// Kind of entity
+    pub kind: MemorialEntityKind,
+
+    /// Memorial significance found on the entity
+    pub found: String,
+}
+
+impl fmt::Display for MemorialValidationFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}: '{}'", self.kind.as_str(), self.entity_id, self.found)
+    }
+}
+
 #[async_trait]
 impl SEntropyMeasurable for SEntropyEngine {
     async fn calculate_s_entropy(&self) -> SEntropyResult<SEntropyCoordinate> {
@@ -562,6 +1255,8 @@ impl MemorialSignificant for SEntropyMeasurement {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::integration::GeometricIntegration;
+    use crate::types::SophisticationScore;
 
     #[tokio::test]
     async fn test_s_entropy_engine_creation() {
@@ -586,6 +1281,92 @@ mod tests {
         assert!(s_time >= 0.0);
     }
 
+    #[tokio::test]
+    async fn test_non_finite_measurements_are_rejected() {
+        let engine = SEntropyEngine::new(SEntropyPrecision::Ultra);
+        let observer = ObserverSophistication::Expert;
+        let error = engine
+            .generate_measurement("problem", observer, 1e-30, f64::NAN, 1.0, 0.8)
+            .await
+            .unwrap_err();
+        assert!(error.is_s_entropy_calculation_error());
+        // Finite inputs whose S-entropy overflows when squared
+        let overflow = engine.generate_measurement("problem", observer, 1e-30, 0.3, 1e300, 0.0);
+        assert!(overflow.await.unwrap_err().is_s_entropy_calculation_error());
+        assert!(engine.measurement_history().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_structured_context_features() {
+        let engine = SEntropyEngine::new(SEntropyPrecision::High);
+        let observer = ObserverSophistication::Expert;
+        let plain = engine.calculate_s_knowledge("abc", observer).await.unwrap();
+        assert_eq!(plain, 10.0 + 3f64.log10());
+
+        let context = MeasurementContext::new("abc")
+            .domain("physics")
+            .complexity_estimate(1000.0)
+            .knowledge_source("textbook")
+            .tag("benchmark");
+        // One knowledge source halves the deficit; the estimate replaces the length
+        let structured = engine.calculate_s_knowledge(context, observer).await.unwrap();
+        assert_eq!(structured, 5.0 + 3.0);
+
+        // A graded score between expert and universal replaces the discrete level
+        let graded = MeasurementContext::new("abc")
+            .complexity_estimate(1000.0)
+            .sophistication_score(SophisticationScore::new(2.5).unwrap());
+        let graded = engine.calculate_s_knowledge(graded, observer).await.unwrap();
+        assert_eq!(graded, 5.0 + 3.0);
+    }
+
+    #[tokio::test]
+    async fn test_builder_uses_custom_calculators() {
+        /// Deficit from the number of words rather than the context length
+        #[derive(Debug)]
+        struct WordCount;
+
+        impl KnowledgeCalculator for WordCount {
+            fn s_knowledge(
+                &self,
+                context: &MeasurementContext,
+                _: ObserverSophistication,
+            ) -> SEntropyResult<f64> {
+                Ok(context.description.split_whitespace().count() as f64)
+            }
+        }
+
+        #[derive(Debug)]
+        struct Unreachable;
+
+        impl EntropyCalculator for Unreachable {
+            fn s_entropy(&self, _: f64, _: f64) -> SEntropyResult<f64> {
+                Err(SEntropyError::s_entropy_calculation("endpoint unreachable"))
+            }
+        }
+
+        let engine = SEntropyEngine::builder(SEntropyPrecision::High)
+            .knowledge_calculator(WordCount)
+            .history_limit(10)
+            .build();
+        let s_knowledge = engine
+            .calculate_s_knowledge("three word context", ObserverSophistication::Naive)
+            .await
+            .unwrap();
+        assert_eq!(s_knowledge, 3.0);
+        // Unset calculators keep the built-in heuristics
+        let s_entropy = engine.calculate_s_entropy_endpoint(1.0, 0.95).await.unwrap();
+        assert_eq!(s_entropy, 0.01 + 0.95f64.log10().abs());
+
+        let failing = SEntropyEngine::builder(SEntropyPrecision::High)
+            .entropy_calculator(Unreachable)
+            .build();
+        let measurement = failing
+            .generate_measurement("context", ObserverSophistication::Expert, 1e-30, 0.1, 1.0, 0.9)
+            .await;
+        assert!(measurement.is_err());
+    }
+
     #[tokio::test]
     async fn test_tri_dimensional_alignment() {
         let engine = SEntropyEngine::new(SEntropyPrecision::High);
@@ -593,6 +1374,33 @@ mod tests {
         assert!(aligned.validates_memorial_significance());
     }
 
+    #[tokio::test]
+    async fn test_selectable_alignment_strategy() {
+        let strategy = AlignmentStrategyKind::IterativeLeastSquares.strategy();
+        let engine = SEntropyEngine::new(SEntropyPrecision::High).with_alignment_strategy(strategy);
+        assert_eq!(engine.alignment_strategy(), "iterative_least_squares");
+
+        let aligned = engine.align_tri_dimensional(3.0, 0.0, 6.0).await.unwrap();
+        let fixed = SEntropyEngine::new(SEntropyPrecision::High)
+            .align_tri_dimensional(3.0, 0.0, 6.0)
+            .await
+            .unwrap();
+        assert!((aligned.s_knowledge - fixed.s_knowledge).abs() > 1e-6);
+        assert!(aligned.validates_memorial_significance());
+    }
+
+    #[tokio::test]
+    async fn test_repeated_alignment_hits_cache() {
+        let engine = SEntropyEngine::new(SEntropyPrecision::High).with_coordinate_cache_capacity(1);
+        let first = engine.align_tri_dimensional(0.1, 0.2, 0.3).await.unwrap();
+        let repeated = engine.align_tri_dimensional(0.1, 0.2, 0.3).await.unwrap();
+        assert_eq!(first.id, repeated.id);
+
+        engine.align_tri_dimensional(0.4, 0.5, 0.6).await.unwrap();
+        let stats = engine.coordinate_cache_stats().await;
+        assert_eq!((stats.hits, stats.misses, stats.evictions, stats.len), (1, 2, 1, 1));
+    }
+
     #[tokio::test]
     async fn test_observer_process_integration() {
         let engine = SEntropyEngine::new(SEntropyPrecision::Standard);
@@ -601,6 +1409,45 @@ mod tests {
         assert!(result == true || result == false);
     }
 
+    #[tokio::test]
+    async fn test_seeded_integration_is_reproducible() {
+        let noisy = GeometricIntegration { noise: 0.3, ..GeometricIntegration::default() };
+        let run = |seed| {
+            let engine = SEntropyEngine::new(SEntropyPrecision::Standard)
+                .with_integration_model(Arc::new(noisy.clone()))
+                .with_rng_seed(seed);
+            async move {
+                let mut separations = Vec::new();
+                for target in [0.1, 0.5, 1.0] {
+                    engine.attempt_integration(target).await.unwrap();
+                    let stats = engine.get_integration_stats().await.unwrap();
+                    separations.push(stats.current_separation);
+                }
+                separations
+            }
+        };
+
+        assert_eq!(run(42).await, run(42).await);
+        assert_ne!(run(42).await, run(43).await);
+    }
+
+    #[tokio::test]
+    async fn test_compare_strategies() {
+        let engine = SEntropyEngine::new(SEntropyPrecision::Standard).with_rng_seed(5);
+        let report = engine.compare_strategies(0.5).await;
+        let strategies: Vec<_> = report.comparisons.iter().map(|c| c.strategy).collect();
+        assert_eq!(strategies, IntegrationStrategyKind::ALL);
+        assert!(report.best().is_some());
+
+        // Comparisons leave the integration history untouched
+        assert_eq!(engine.get_integration_stats().await.unwrap().total_attempts, 0);
+        let replay = SEntropyEngine::new(SEntropyPrecision::Standard).with_rng_seed(5);
+        let runs = |report: &IntegrationComparisonReport| {
+            report.comparisons.iter().map(|c| c.run).collect::<Vec<_>>()
+        };
+        assert_eq!(runs(&replay.compare_strategies(0.5).await), runs(&report));
+    }
+
     #[tokio::test]
     async fn test_comprehensive_measurement() {
         let engine = SEntropyEngine::new(SEntropyPrecision::High);
@@ -620,6 +1467,59 @@ mod tests {
         assert!(measurement.total_magnitude >= 0.0);
     }
 
+    #[tokio::test]
+    async fn test_subscribers_receive_new_measurements() {
+        let engine = SEntropyEngine::new(SEntropyPrecision::High);
+        let mut events = engine.subscribe();
+        let mut latest = engine.watch_latest();
+        assert!(latest.borrow().is_none());
+
+        let mut produced = Vec::new();
+        for context in ["first", "second"] {
+            let measurement = engine
+                .generate_measurement(context, ObserverSophistication::Expert, 1e-30, 0.1, 1.0, 0.9)
+                .await
+                .unwrap();
+            produced.push(measurement);
+        }
+
+        assert_eq!(events.recv().await.unwrap(), produced[0]);
+        assert_eq!(events.recv().await.unwrap(), produced[1]);
+        assert!(latest.has_changed().unwrap());
+        assert_eq!(latest.borrow_and_update().as_ref(), Some(&produced[1]));
+    }
+
+    #[tokio::test]
+    async fn test_batch_measurements_keep_spec_order() {
+        let engine = SEntropyEngine::new(SEntropyPrecision::High).with_batch_concurrency(2);
+        let observers = [
+            ObserverSophistication::Naive,
+            ObserverSophistication::Universal,
+            ObserverSophistication::Intermediate,
+            ObserverSophistication::Expert,
+        ];
+        let specs: Vec<MeasurementSpec> = observers
+            .iter()
+            .map(|&observer| MeasurementSpec {
+                problem_context: "batch_problem".into(),
+                observer,
+                temporal_precision: 1e-30,
+                emotional_factor: 0.2,
+                problem_complexity: 1.0,
+                accessibility: 0.9,
+            })
+            .collect();
+
+        let results = engine.generate_measurements_batch(specs.clone()).await;
+        assert_eq!(results.len(), specs.len());
+        for (result, spec) in results.into_iter().zip(&specs) {
+            let measurement = result.unwrap();
+            let serial = engine.generate_measurement_for(spec).await.unwrap();
+            assert_eq!(measurement.observer_sophistication, spec.observer);
+            assert_eq!(measurement.total_magnitude, serial.total_magnitude);
+        }
+    }
+
     #[tokio::test]
     async fn test_memorial_significance_validation() {
         let engine = SEntropyEngine::new(SEntropyPrecision::Ultra);
@@ -640,4 +1540,154 @@ mod tests {
         let report = engine.validate_all_memorial_significance().await.unwrap();
         assert_eq!(report.success_rate, 1.0); // Should be 100% for proper implementation
     }
+
+    #[tokio::test]
+    async fn test_memorial_failures_reported_per_entity() {
+        let engine = SEntropyEngine::new(SEntropyPrecision::Standard);
+        let observer = ObserverSophistication::Expert;
+        engine
+            .generate_measurement("memorial_test", observer, 1e-30, 0.0, 1.0, 0.95)
+            .await
+            .unwrap();
+        assert!(engine.validate_all_memorial_significance_strict().await.is_ok());
+
+        let mut checkpoint = engine.checkpoint().await;
+        checkpoint.measurement_history[0].memorial_significance = "forgotten".to_string();
+        let tampered_id = checkpoint.measurement_history[0].id;
+        engine.restore(checkpoint).await.unwrap();
+
+        let report = engine.validate_all_memorial_significance().await.unwrap();
+        assert_eq!(
+            report.failures,
+            vec![MemorialValidationFailure {
+                entity_id: tampered_id,
+                kind: MemorialEntityKind::Measurement,
+                found: "forgotten".to_string(),
+            }]
+        );
+        let error = engine.validate_all_memorial_significance_strict().await.unwrap_err();
+        assert!(error.is_memorial_significance_error());
+        assert!(error.to_string().contains(&tampered_id.to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_query_in_memory_history() {
+        let engine = SEntropyEngine::new(SEntropyPrecision::Standard);
+        for observer in [ObserverSophistication::Expert, ObserverSophistication::Naive] {
+            engine
+                .generate_measurement("query_test", observer, 1e-30, 0.0, 1.0, 0.95)
+                .await
+                .unwrap();
+        }
+
+        let expert = MeasurementQuery::new().observer(ObserverSophistication::Expert);
+        let found = engine.query_measurements(&expert).await.unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].observer_sophistication, ObserverSophistication::Expert);
+
+        let optimal = MeasurementQuery::new().optimal_integration(true);
+        assert!(engine.query_measurements(&optimal).await.unwrap().is_empty());
+        let all = MeasurementQuery::new().limit(1);
+        assert_eq!(engine.query_measurements(&all).await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_handles_share_and_forks_diverge() {
+        let observer = ObserverSophistication::Expert;
+        let handle = SEntropyEngine::new(SEntropyPrecision::High).with_rng_seed(3).into_handle();
+        let shared = handle.clone();
+        shared.generate_measurement("handle_test", observer, 1e-30, 0.0, 1.0, 0.95).await.unwrap();
+        assert_eq!(handle.measurement_history().await.len(), 1);
+
+        let fork = handle.fork().await;
+        assert_eq!(fork.measurement_history().await, handle.measurement_history().await);
+        fork.generate_measurement("fork_test", observer, 1e-30, 0.0, 1.0, 0.95).await.unwrap();
+        assert_eq!(fork.measurement_history().await.len(), 2);
+        assert_eq!(handle.measurement_history().await.len(), 1);
+
+        // The fork continues from a copy of the random source state
+        let forked = fork.fork().await;
+        assert_eq!(
+            fork.attempt_integration(0.05).await.unwrap(),
+            forked.attempt_integration(0.05).await.unwrap()
+        );
+        let achieved = |attempts: Vec<IntegrationAttempt>| attempts[0].achieved_separation;
+        assert_eq!(
+            achieved(fork.integration_attempts().await),
+            achieved(forked.integration_attempts().await)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_precision_escalation_records_chain() {
+        /// S_time a decade below the temporal precision target, and nothing else
+        #[derive(Debug)]
+        struct PrecisionLimited;
+
+        impl TimeCalculator for PrecisionLimited {
+            fn s_time(&self, temporal_precision_target: f64, _: f64) -> SEntropyResult<f64> {
+                Ok(temporal_precision_target / 10.0)
+            }
+        }
+        impl KnowledgeCalculator for PrecisionLimited {
+            fn s_knowledge(
+                &self,
+                _: &MeasurementContext,
+                _: ObserverSophistication,
+            ) -> SEntropyResult<f64> {
+                Ok(0.0)
+            }
+        }
+        impl EntropyCalculator for PrecisionLimited {
+            fn s_entropy(&self, _: f64, _: f64) -> SEntropyResult<f64> {
+                Ok(0.0)
+            }
+        }
+
+        let engine = |cap| {
+            SEntropyEngine::builder(SEntropyPrecision::Standard)
+                .knowledge_calculator(PrecisionLimited)
+                .time_calculator(PrecisionLimited)
+                .entropy_calculator(PrecisionLimited)
+                .precision_escalation(cap)
+                .build()
+        };
+        let observer = ObserverSophistication::Expert;
+
+        // 0.1 misses Standard's 1e-6; at High the target tightens to 1e-15, giving 1e-16
+        let escalated = engine(SEntropyPrecision::Supreme)
+            .generate_measurement("escalation_test", observer, 1.0, 0.0, 1.0, 0.95)
+            .await
+            .unwrap();
+        assert!(escalated.optimal_integration);
+        assert_eq!(escalated.precision, SEntropyPrecision::High);
+        assert_eq!(escalated.escalated_from, vec![SEntropyPrecision::Standard]);
+
+        let capped = engine(SEntropyPrecision::Standard)
+            .generate_measurement("escalation_test", observer, 1.0, 0.0, 1.0, 0.95)
+            .await
+            .unwrap();
+        assert!(!capped.optimal_integration);
+        assert_eq!(capped.precision, SEntropyPrecision::Standard);
+        assert!(capped.escalated_from.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_analytics_over_history() {
+        let engine = SEntropyEngine::new(SEntropyPrecision::Standard);
+        assert!(engine.analytics().await.latest().is_none());
+
+        for _ in 0..3 {
+            let observer = ObserverSophistication::Expert;
+            engine
+                .generate_measurement("analytics_test", observer, 1e-30, 0.0, 1.0, 0.95)
+                .await
+                .unwrap();
+        }
+        let analytics = engine.analytics_with_window(2).await;
+        assert_eq!((analytics.measurements, analytics.window), (3, 2));
+        assert_eq!(analytics.rolling.len(), 2);
+        // Identical inputs give a flat series
+        assert!(analytics.latest().unwrap().variance < 1e-9);
+    }
 }
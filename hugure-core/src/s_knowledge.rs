@@ -11,12 +11,7 @@ pub async fn analyze_information_deficit(
     context: &str,
     observer: ObserverSophistication,
 ) -> SEntropyResult<f64> {
-    let base_deficit = match observer {
-        ObserverSophistication::Naive => 1000.0,
-        ObserverSophistication::Intermediate => 100.0,
-        ObserverSophistication::Expert => 10.0,
-        ObserverSophistication::Universal => 0.0,
-    };
+    let base_deficit = observer.information_deficit();
 
     let context_factor = (context.len() as f64).log10().max(1.0);
     Ok(base_deficit / context_factor)
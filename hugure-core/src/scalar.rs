@@ -0,0 +1,230 @@
+//! Scalar types for S-entropy math beyond `f64`
+//!
+//! [`SEntropyPrecision::Supreme`] asks for 1e-50 thresholds, but `f64` carries about 16
+//! significant digits: S-values near 1 that differ by less than 1e-16 are
+//! indistinguishable, so differences that Supreme precision should detect vanish.
+//! [`SComponents`] does the tri-dimensional math over any [`Scalar`]: `f64`, or with the
+//! `precise` feature [`PreciseFloat`], an MPFR float of [`PRECISE_BITS`] bits.
+//!
+//! Values converted from `f64` carry only `f64` precision. Build components from precise
+//! values, or from sums of `f64` parts, to keep digits `f64` would drop.
+
+use std::{
+    fmt,
+    ops::{Add, Div, Mul, Sub},
+};
+
+use crate::{types::SEntropyPrecision, SEntropyCoordinate};
+
+/// Number type S-entropy math can be carried out in
+pub trait Scalar:
+    Clone
+    + fmt::Debug
+    + PartialOrd
+    + Send
+    + Sync
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+{
+    /// The value closest to `value`
+    fn from_f64(value: f64) -> Self;
+
+    /// The `f64` closest to this value
+    fn to_f64(&self) -> f64;
+
+    /// Square root
+    fn sqrt(self) -> Self;
+
+    /// Natural logarithm
+    fn ln(self) -> Self;
+
+    /// Absolute value
+    fn abs(self) -> Self;
+
+    /// Zero
+    fn zero() -> Self {
+        Self::from_f64(0.0)
+    }
+}
+
+impl Scalar for f64 {
+    fn from_f64(value: f64) -> Self {
+        value
+    }
+
+    fn to_f64(&self) -> f64 {
+        *self
+    }
+
+    fn sqrt(self) -> Self {
+        f64::sqrt(self)
+    }
+
+    fn ln(self) -> Self {
+        f64::ln(self)
+    }
+
+    fn abs(self) -> Self {
+        f64::abs(self)
+    }
+}
+
+/// Mantissa bits of [`PreciseFloat`], about 77 significant decimal digits
+#[cfg(feature = "precise")]
+pub const PRECISE_BITS: u32 = 256;
+
+/// Arbitrary-precision float with [`PRECISE_BITS`] bits of mantissa
+#[cfg(feature = "precise")]
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub struct PreciseFloat(pub rug::Float);
+
+#[cfg(feature = "precise")]
+impl Scalar for PreciseFloat {
+    fn from_f64(value: f64) -> Self {
+        Self(rug::Float::with_val(PRECISE_BITS, value))
+    }
+
+    fn to_f64(&self) -> f64 {
+        self.0.to_f64()
+    }
+
+    fn sqrt(self) -> Self {
+        Self(self.0.sqrt())
+    }
+
+    fn ln(self) -> Self {
+        Self(self.0.ln())
+    }
+
+    fn abs(self) -> Self {
+        Self(self.0.abs())
+    }
+}
+
+#[cfg(feature = "precise")]
+macro_rules! precise_op {
+    ($trait:ident, $method:ident) => {
+        impl $trait for PreciseFloat {
+            type Output = Self;
+
+            fn $method(self, rhs: Self) -> Self {
+                Self($trait::$method(self.0, rhs.0))
+            }
+        }
+    };
+}
+
+#[cfg(feature = "precise")]
+precise_op!(Add, add);
+#[cfg(feature = "precise")]
+precise_op!(Sub, sub);
+#[cfg(feature = "precise")]
+precise_op!(Mul, mul);
+#[cfg(feature = "precise")]
+precise_op!(Div, div);
+
+/// S_knowledge, S_time, and S_entropy in scalar type `S`
+#[derive(Debug, Clone, PartialEq)]
+pub struct SComponents<S> {
+    /// S_knowledge component
+    pub s_knowledge: S,
+
+    /// S_time component
+    pub s_time: S,
+
+    /// S_entropy component
+    pub s_entropy: S,
+}
+
+impl<S: Scalar> SComponents<S> {
+    /// Components with the given values
+    pub fn new(s_knowledge: S, s_time: S, s_entropy: S) -> Self {
+        Self { s_knowledge, s_time, s_entropy }
+    }
+
+    /// Total S-entropy magnitude
+    pub fn total_magnitude(&self) -> S {
+        let square = |value: &S| value.clone() * value.clone();
+        (square(&self.s_knowledge) + square(&self.s_time) + square(&self.s_entropy)).sqrt()
+    }
+
+    /// Euclidean distance to `other`
+    pub fn distance_to(&self, other: &Self) -> S {
+        SComponents::new(
+            self.s_knowledge.clone() - other.s_knowledge.clone(),
+            self.s_time.clone() - other.s_time.clone(),
+            self.s_entropy.clone() - other.s_entropy.clone(),
+        )
+        .total_magnitude()
+    }
+
+    /// Whether the total magnitude is below `precision`'s threshold
+    pub fn is_optimal_integration(&self, precision: SEntropyPrecision) -> bool {
+        self.total_magnitude() < S::from_f64(precision.threshold())
+    }
+
+    /// Components rounded to `f64`
+    pub fn to_coordinate(&self) -> SEntropyCoordinate {
+        SEntropyCoordinate::new(
+            self.s_knowledge.to_f64(),
+            self.s_time.to_f64(),
+            self.s_entropy.to_f64(),
+        )
+    }
+}
+
+impl SEntropyCoordinate {
+    /// Components of this coordinate in scalar type `S`
+    pub fn components<S: Scalar>(&self) -> SComponents<S> {
+        SComponents::new(
+            S::from_f64(self.s_knowledge),
+            S::from_f64(self.s_time),
+            S::from_f64(self.s_entropy),
+        )
+    }
+}
+
+/// S-coordinate of the STSL equation `S = k × log(α)`, `None` unless `alpha` is positive
+pub fn stsl_s_value<S: Scalar>(k: S, alpha: S) -> Option<S> {
+    (alpha > S::zero()).then(|| k * alpha.ln())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Coordinates near one whose S_knowledge differs by 1e-45
+    fn near_unity<S: Scalar>() -> (SComponents<S>, SComponents<S>) {
+        let one = || S::from_f64(1.0);
+        let shifted = SComponents::new(one() + S::from_f64(1e-45), S::zero(), S::zero());
+        (shifted, SComponents::new(one(), S::zero(), S::zero()))
+    }
+
+    #[test]
+    fn test_f64_components_match_coordinates() {
+        let coordinate = SEntropyCoordinate::new(3.0, 4.0, 0.0);
+        let components = coordinate.components::<f64>();
+        assert_eq!(components.total_magnitude(), coordinate.total_magnitude());
+        assert_eq!(components.to_coordinate().s_time, 4.0);
+        assert_eq!(stsl_s_value(2.0, std::f64::consts::E), Some(2.0));
+        assert_eq!(stsl_s_value(1.0, 0.0), None);
+
+        // f64 cannot tell the two apart, so they look integrated even at Supreme
+        let (shifted, unity) = near_unity::<f64>();
+        assert_eq!(shifted.distance_to(&unity), 0.0);
+    }
+
+    #[cfg(feature = "precise")]
+    #[test]
+    fn test_precise_components_resolve_supreme_differences() {
+        let (shifted, unity) = near_unity::<PreciseFloat>();
+        let distance = shifted.distance_to(&unity);
+        assert!((distance.to_f64() - 1e-45).abs() < 1e-60);
+
+        let difference = SComponents::new(distance, PreciseFloat::zero(), PreciseFloat::zero());
+        assert!(!difference.is_optimal_integration(SEntropyPrecision::Supreme));
+        assert!(difference.is_optimal_integration(SEntropyPrecision::Ultra));
+    }
+}
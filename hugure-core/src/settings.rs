@@ -0,0 +1,627 @@
+//! Layered configuration for the Hugure framework
+//!
+//! Effective settings are merged from four layers, each overriding the one before:
+//!
+//! 1. Built-in defaults ([`HugureSettings::default`])
+//! 2. A TOML file: `hugure.toml` in the working directory, or the path in `HUGURE_CONFIG`
+//! 3. Environment variables prefixed `HUGURE__`, with `__` separating sections and keys,
+//!    e.g. `HUGURE__ENGINE__PRECISION=supreme` or `HUGURE__CACHE__COORDINATE_CAPACITY=4096`
+//! 4. Explicit overrides, typically command-line flags ([`SettingsOverrides`])
+//!
+//! ```toml
+//! [engine]
+//! precision = "supreme"
+//!
+//! [retention]
+//! measurement_history_limit = 5000
+//!
+//! [orchestration]
+//! max_concurrent_explorations = 2000
+//! ```
+
+use std::path::{Path, PathBuf};
+
+use config::{Config, Environment, File, FileFormat};
+use serde::{Deserialize, Serialize};
+
+use crate::error::{SEntropyError, SEntropyResult};
+use crate::types::{
+    AlignmentStrategyKind, IntegrationStrategyKind, ObserverSophistication, SEntropyPrecision,
+};
+
+/// Environment variable naming the configuration file
+pub const CONFIG_PATH_ENV: &str = "HUGURE_CONFIG";
+
+/// Configuration file read when `HUGURE_CONFIG` is not set
+pub const DEFAULT_CONFIG_FILE: &str = "hugure.toml";
+
+/// Prefix of environment variable overrides
+pub const ENV_PREFIX: &str = "HUGURE";
+
+/// Separator between prefix, section, and key in environment variable overrides
+pub const ENV_SEPARATOR: &str = "__";
+
+/// Effective configuration for all Hugure crates
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct HugureSettings {
+    /// BMD orchestration targets and limits
+    pub orchestration: OrchestrationSettings,
+
+    /// S-entropy engine precision and observer defaults
+    pub engine: EngineSettings,
+
+    /// Cache capacities
+    pub cache: CacheSettings,
+
+    /// Kambuzuma and service transports
+    pub transport: TransportSettings,
+
+    /// History and record retention policies
+    pub retention: RetentionSettings,
+
+    /// Registered plugin names selected for each extension point
+    pub plugins: PluginSettings,
+
+    /// Log output
+    pub logging: LoggingSettings,
+}
+
+/// BMD orchestration targets and limits
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OrchestrationSettings {
+    /// BMD exploration rate target (per second)
+    pub exploration_rate_target: u64,
+
+    /// Recursive amplification depth limit
+    pub max_recursion_depth: u32,
+
+    /// Statistical emergence detection threshold
+    pub emergence_threshold: f64,
+
+    /// Optimization accuracy target
+    pub optimization_accuracy_target: f64,
+
+    /// Temporal precision (femtoseconds)
+    pub temporal_precision_fs: u64,
+
+    /// Maximum concurrent BMD explorations
+    pub max_concurrent_explorations: usize,
+
+    /// Tune exploration concurrency below `max_concurrent_explorations` from cycle latency
+    /// and emergence yield
+    pub auto_tune: bool,
+
+    /// Lowest concurrency auto-tuning may choose, and the one it starts from
+    pub auto_tune_min_concurrency: usize,
+
+    /// Mean cycle latency above which auto-tuning cuts concurrency (milliseconds)
+    pub auto_tune_target_cycle_latency_ms: u64,
+
+    /// Foundry selections buffered ahead of exploration before selection pauses
+    pub exploration_queue_capacity: usize,
+
+    /// Foundry BMD selection requests allowed per second
+    pub foundry_requests_per_second: u32,
+
+    /// Exploration cycles admitted at once before `exploration_rate_target` applies
+    pub exploration_burst: u32,
+
+    /// Inbound communication requests accepted per second
+    pub communication_requests_per_second: u32,
+
+    /// Mailbox capacity of each system actor
+    pub actor_mailbox_capacity: usize,
+
+    /// Time allowed for in-flight work to drain on shutdown (milliseconds)
+    pub shutdown_grace_period_ms: u64,
+
+    /// Default BMD weighting strategy: `gradient`, `annealing`, or `evolutionary`
+    pub optimization_strategy: String,
+
+    /// Intent urgency at or above which requests overtake other queued requests
+    pub urgent_request_threshold: f64,
+
+    /// Consecutive recursion-depth exhaustions before explorations run shallower
+    pub depth_breaker_threshold: u32,
+
+    /// Time explorations stay shallow after the depth breaker trips (milliseconds)
+    pub depth_breaker_cooldown_ms: u64,
+
+    /// Orchestration loop heartbeat age at which health reports mark it unhealthy (milliseconds)
+    pub heartbeat_timeout_ms: u64,
+
+    /// Recently emerged patterns kept for snapshots (0 keeps none)
+    pub emerged_pattern_registry_capacity: usize,
+
+    /// Lifecycle events retained for slow subscribers
+    pub event_bus_capacity: usize,
+}
+
+impl Default for OrchestrationSettings {
+    fn default() -> Self {
+        Self {
+            exploration_rate_target: 1_000_000_000_000_000,
+            max_recursion_depth: 1000,
+            emergence_threshold: 0.9997,
+            optimization_accuracy_target: 0.9997,
+            temporal_precision_fs: 10,
+            max_concurrent_explorations: 10_000,
+            auto_tune: false,
+            auto_tune_min_concurrency: 16,
+            auto_tune_target_cycle_latency_ms: 1_000,
+            exploration_queue_capacity: 256,
+            foundry_requests_per_second: 10_000,
+            exploration_burst: 1024,
+            communication_requests_per_second: 1_000,
+            actor_mailbox_capacity: 1024,
+            shutdown_grace_period_ms: 30_000,
+            optimization_strategy: "gradient".to_string(),
+            urgent_request_threshold: 0.8,
+            depth_breaker_threshold: 3,
+            depth_breaker_cooldown_ms: 30_000,
+            heartbeat_timeout_ms: 30_000,
+            emerged_pattern_registry_capacity: 4096,
+            event_bus_capacity: 1024,
+        }
+    }
+}
+
+/// S-entropy engine precision and observer defaults
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EngineSettings {
+    /// Precision level: `standard`, `high`, `ultra`, or `supreme`
+    pub precision: String,
+
+    /// Finest precision level non-optimal measurements escalate to; unset disables escalation
+    pub precision_escalation_cap: Option<String>,
+
+    /// Observer sophistication: `naive`, `intermediate`, `expert`, or `universal`
+    pub observer: String,
+
+    /// Target precision for S-entropy measurements
+    pub precision_target: f64,
+
+    /// Alignment strategy: `fixed_matrix`, `iterative_least_squares`, or
+    /// `weighted_minimization`
+    pub alignment_strategy: String,
+
+    /// Integration strategy: `geometric_decay`, `bisection`, or `annealing`
+    pub integration_strategy: String,
+
+    /// Seed of simulated integration attempts, for reproducible runs; unset seeds randomly
+    pub rng_seed: Option<u64>,
+}
+
+impl Default for EngineSettings {
+    fn default() -> Self {
+        Self {
+            precision: "ultra".to_string(),
+            precision_escalation_cap: None,
+            observer: "expert".to_string(),
+            precision_target: crate::S_ENTROPY_PRECISION_TARGET,
+            alignment_strategy: AlignmentStrategyKind::default().as_str().to_string(),
+            integration_strategy: IntegrationStrategyKind::default().as_str().to_string(),
+            rng_seed: None,
+        }
+    }
+}
+
+impl EngineSettings {
+    /// Parsed precision level
+    pub fn precision(&self) -> SEntropyResult<SEntropyPrecision> {
+        self.precision.parse()
+    }
+
+    /// Parsed precision escalation cap
+    pub fn precision_escalation_cap(&self) -> SEntropyResult<Option<SEntropyPrecision>> {
+        self.precision_escalation_cap.as_deref().map(str::parse).transpose()
+    }
+
+    /// Parsed observer sophistication
+    pub fn observer(&self) -> SEntropyResult<ObserverSophistication> {
+        self.observer.parse()
+    }
+
+    /// Parsed alignment strategy
+    pub fn alignment_strategy(&self) -> SEntropyResult<AlignmentStrategyKind> {
+        self.alignment_strategy.parse()
+    }
+
+    /// Parsed integration strategy
+    pub fn integration_strategy(&self) -> SEntropyResult<IntegrationStrategyKind> {
+        self.integration_strategy.parse()
+    }
+}
+
+/// Cache capacities
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CacheSettings {
+    /// Maximum cached S-entropy coordinates
+    pub coordinate_capacity: usize,
+
+    /// Maximum cached BMD selections from the foundry
+    pub bmd_selection_capacity: usize,
+
+    /// Time-to-live for cached entries, in seconds
+    pub ttl_secs: u64,
+}
+
+impl Default for CacheSettings {
+    fn default() -> Self {
+        Self { coordinate_capacity: 10_000, bmd_selection_capacity: 1024, ttl_secs: 300 }
+    }
+}
+
+/// Kambuzuma and service transports
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TransportSettings {
+    /// Kambuzuma neural orchestrator endpoint
+    pub kambuzuma_endpoint: String,
+
+    /// Entropy solver service endpoint
+    pub entropy_solver_endpoint: String,
+
+    /// Capacity of the Kambuzuma message channel
+    pub channel_capacity: usize,
+
+    /// Request timeout, in milliseconds
+    pub request_timeout_ms: u64,
+
+    /// Listen address of the communication gRPC service (`grpc` feature)
+    pub grpc_listen_addr: String,
+
+    /// Listen address of the Prometheus `/metrics` endpoint (`prometheus` feature)
+    pub metrics_listen_addr: String,
+}
+
+impl Default for TransportSettings {
+    fn default() -> Self {
+        Self {
+            kambuzuma_endpoint: "http://127.0.0.1:50051".to_string(),
+            entropy_solver_endpoint: "http://127.0.0.1:8080".to_string(),
+            channel_capacity: 1000,
+            request_timeout_ms: 5000,
+            grpc_listen_addr: "0.0.0.0:50052".to_string(),
+            metrics_listen_addr: "0.0.0.0:9090".to_string(),
+        }
+    }
+}
+
+/// History and record retention policies
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RetentionSettings {
+    /// Measurements retained by the S-entropy engine
+    pub measurement_history_limit: usize,
+
+    /// Observer-process integration attempts retained
+    pub integration_attempt_limit: usize,
+
+    /// Maximum age of persisted records, in hours (0 keeps records forever)
+    pub max_record_age_hours: u64,
+
+    /// Directory of the orchestration result journal; unset disables journaling
+    pub result_store_path: Option<String>,
+
+    /// SQLite database of the full measurement history (`sqlite` feature); unset keeps
+    /// measurements in memory only
+    pub measurement_store_path: Option<String>,
+}
+
+impl Default for RetentionSettings {
+    fn default() -> Self {
+        Self {
+            measurement_history_limit: 1000,
+            integration_attempt_limit: 1000,
+            max_record_age_hours: 0,
+            result_store_path: None,
+            measurement_store_path: None,
+        }
+    }
+}
+
+/// Registered plugin names selected for each extension point
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PluginSettings {
+    /// Bidirectional optimizer
+    pub optimizer: String,
+
+    /// Emergence detector (`default`, `zscore`, `cusum`, or `bayesian`)
+    pub emergence_detector: String,
+
+    /// Fidelity predictor
+    pub fidelity_predictor: String,
+
+    /// Information deficit estimator
+    pub information_deficit_estimator: String,
+}
+
+impl Default for PluginSettings {
+    fn default() -> Self {
+        Self {
+            optimizer: "default".to_string(),
+            emergence_detector: "default".to_string(),
+            fidelity_predictor: "default".to_string(),
+            information_deficit_estimator: "default".to_string(),
+        }
+    }
+}
+
+/// Log output
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LoggingSettings {
+    /// Output format: `pretty` or `json`
+    pub format: String,
+
+    /// Filter directives, e.g. `info,orchestration=debug`
+    pub filter: String,
+}
+
+impl Default for LoggingSettings {
+    fn default() -> Self {
+        Self { format: "pretty".to_string(), filter: "info".to_string() }
+    }
+}
+
+#[cfg(feature = "logging")]
+impl LoggingSettings {
+    /// Convert into a [`crate::logging::LoggingConfig`]
+    pub fn to_logging_config(&self) -> SEntropyResult<crate::logging::LoggingConfig> {
+        Ok(crate::logging::LoggingConfig {
+            format: self.format.parse()?,
+            filter: self.filter.clone(),
+            stderr: false,
+        })
+    }
+}
+
+/// Highest-priority overrides, typically taken from command-line flags
+#[derive(Debug, Clone, Default)]
+pub struct SettingsOverrides {
+    /// Configuration file to read instead of `HUGURE_CONFIG` / `hugure.toml`
+    pub config_path: Option<PathBuf>,
+
+    /// Dotted key/value overrides, e.g. `("engine.precision", "supreme")`
+    pub values: Vec<(String, String)>,
+}
+
+impl SettingsOverrides {
+    /// Add a dotted key/value override
+    pub fn set(&mut self, key: impl Into<String>, value: impl Into<String>) -> &mut Self {
+        self.values.push((key.into(), value.into()));
+        self
+    }
+}
+
+impl HugureSettings {
+    /// Load the effective settings from defaults, file, environment, and overrides
+    pub fn load(overrides: &SettingsOverrides) -> SEntropyResult<Self> {
+        let (path, required) = match &overrides.config_path {
+            Some(path) => (path.clone(), true),
+            None => match std::env::var(CONFIG_PATH_ENV) {
+                Ok(path) => (PathBuf::from(path), true),
+                Err(_) => (PathBuf::from(DEFAULT_CONFIG_FILE), false),
+            },
+        };
+
+        Self::load_layers(Some((&path, required)), true, overrides)
+    }
+
+    /// Load settings from a single file over the defaults, without environment overrides
+    pub fn from_file(path: impl AsRef<Path>) -> SEntropyResult<Self> {
+        Self::load_layers(Some((path.as_ref(), true)), false, &SettingsOverrides::default())
+    }
+
+    /// Load settings from `HUGURE__*` environment variables over the defaults, without a file
+    pub fn from_env() -> SEntropyResult<Self> {
+        Self::load_layers(None, true, &SettingsOverrides::default())
+    }
+
+    fn load_layers(
+        file: Option<(&Path, bool)>,
+        with_env: bool,
+        overrides: &SettingsOverrides,
+    ) -> SEntropyResult<Self> {
+        let defaults = Config::try_from(&Self::default()).map_err(config_error)?;
+        let mut builder = Config::builder().add_source(defaults);
+
+        if let Some((path, required)) = file {
+            let path = path.to_str().ok_or_else(|| {
+                SEntropyError::configuration(CONFIG_PATH_ENV, "path is not valid UTF-8")
+            })?;
+            builder = builder.add_source(File::new(path, FileFormat::Toml).required(required));
+        }
+
+        if with_env {
+            builder = builder.add_source(
+                Environment::with_prefix(ENV_PREFIX)
+                    .prefix_separator(ENV_SEPARATOR)
+                    .separator(ENV_SEPARATOR)
+                    .try_parsing(true),
+            );
+        }
+
+        for (key, value) in &overrides.values {
+            builder = builder.set_override(key.as_str(), value.as_str()).map_err(config_error)?;
+        }
+
+        let settings: Self =
+            builder.build().and_then(|config| config.try_deserialize()).map_err(config_error)?;
+        settings.validate()?;
+        Ok(settings)
+    }
+
+    /// Check value ranges and enumerated names
+    ///
+    /// These are the rules every consumer of the settings relies on, including the
+    /// orchestration system built from them.
+    pub fn validate(&self) -> SEntropyResult<()> {
+        self.engine.precision()?;
+        self.engine.precision_escalation_cap()?;
+        self.engine.observer()?;
+        self.engine.alignment_strategy()?;
+        self.engine.integration_strategy()?;
+
+        let orchestration = &self.orchestration;
+        let unit_intervals = [
+            ("orchestration.emergence_threshold", orchestration.emergence_threshold),
+            (
+                "orchestration.optimization_accuracy_target",
+                orchestration.optimization_accuracy_target,
+            ),
+        ];
+        for (key, value) in unit_intervals {
+            if !(value > 0.0 && value <= 1.0) {
+                return Err(SEntropyError::configuration(key, "must be in (0, 1]"));
+            }
+        }
+        if !(0.0..=1.0).contains(&orchestration.urgent_request_threshold) {
+            return Err(SEntropyError::configuration(
+                "orchestration.urgent_request_threshold",
+                "must be between 0 and 1",
+            ));
+        }
+
+        let positive = [
+            ("orchestration.exploration_rate_target", orchestration.exploration_rate_target),
+            ("orchestration.max_recursion_depth", orchestration.max_recursion_depth.into()),
+            ("orchestration.temporal_precision_fs", orchestration.temporal_precision_fs),
+            (
+                "orchestration.max_concurrent_explorations",
+                orchestration.max_concurrent_explorations as u64,
+            ),
+            (
+                "orchestration.exploration_queue_capacity",
+                orchestration.exploration_queue_capacity as u64,
+            ),
+            (
+                "orchestration.foundry_requests_per_second",
+                orchestration.foundry_requests_per_second.into(),
+            ),
+            ("orchestration.exploration_burst", orchestration.exploration_burst.into()),
+            (
+                "orchestration.communication_requests_per_second",
+                orchestration.communication_requests_per_second.into(),
+            ),
+            ("orchestration.actor_mailbox_capacity", orchestration.actor_mailbox_capacity as u64),
+            ("orchestration.depth_breaker_threshold", orchestration.depth_breaker_threshold.into()),
+            ("orchestration.heartbeat_timeout_ms", orchestration.heartbeat_timeout_ms),
+            ("orchestration.event_bus_capacity", orchestration.event_bus_capacity as u64),
+            (
+                "retention.measurement_history_limit",
+                self.retention.measurement_history_limit as u64,
+            ),
+            ("transport.channel_capacity", self.transport.channel_capacity as u64),
+        ];
+        for (key, value) in positive {
+            if value == 0 {
+                return Err(SEntropyError::configuration(key, "must be greater than 0"));
+            }
+        }
+
+        if orchestration.auto_tune {
+            let min_concurrency = orchestration.auto_tune_min_concurrency;
+            if min_concurrency == 0 || min_concurrency > orchestration.max_concurrent_explorations {
+                return Err(SEntropyError::configuration(
+                    "orchestration.auto_tune_min_concurrency",
+                    "must be between 1 and orchestration.max_concurrent_explorations",
+                ));
+            }
+            if orchestration.auto_tune_target_cycle_latency_ms == 0 {
+                return Err(SEntropyError::configuration(
+                    "orchestration.auto_tune_target_cycle_latency_ms",
+                    "must be greater than 0",
+                ));
+            }
+        }
+
+        let addresses = [
+            ("transport.grpc_listen_addr", &self.transport.grpc_listen_addr),
+            ("transport.metrics_listen_addr", &self.transport.metrics_listen_addr),
+        ];
+        for (key, addr) in addresses {
+            if addr.parse::<std::net::SocketAddr>().is_err() {
+                return Err(SEntropyError::configuration(
+                    key,
+                    format!("must be a socket address, got '{}'", addr),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Render the effective settings as pretty-printed JSON
+    pub fn to_pretty_json(&self) -> SEntropyResult<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+}
+
+fn config_error(error: config::ConfigError) -> SEntropyError {
+    SEntropyError::configuration("settings", error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_defaults_are_valid() {
+        let settings = HugureSettings::default();
+        assert!(settings.validate().is_ok());
+        assert_eq!(settings.engine.precision().unwrap(), SEntropyPrecision::Ultra);
+    }
+
+    #[test]
+    fn test_file_then_override_layering() {
+        let path = std::env::temp_dir().join(format!("hugure-{}.toml", uuid::Uuid::new_v4()));
+        std::fs::write(
+            &path,
+            "[engine]\nprecision = \"high\"\n\n[retention]\nmeasurement_history_limit = 42\n",
+        )
+        .unwrap();
+
+        let from_file = HugureSettings::from_file(&path).unwrap();
+        assert_eq!(from_file.engine.precision, "high");
+        assert_eq!(from_file.retention.measurement_history_limit, 42);
+        assert_eq!(from_file.cache, CacheSettings::default());
+
+        let mut overrides =
+            SettingsOverrides { config_path: Some(path.clone()), ..Default::default() };
+        overrides.set("engine.precision", "supreme");
+        let merged = HugureSettings::load(&overrides).unwrap();
+        assert_eq!(merged.engine.precision().unwrap(), SEntropyPrecision::Supreme);
+        assert_eq!(merged.retention.measurement_history_limit, 42);
+
+        std::fs::remove_file(path).ok();
+    }
+
+    #[test]
+    fn test_invalid_values_rejected() {
+        let mut settings = HugureSettings::default();
+        settings.engine.precision = "infinite".to_string();
+        assert!(settings.validate().is_err());
+
+        let mut settings = HugureSettings::default();
+        settings.engine.precision_escalation_cap = Some("beyond".to_string());
+        assert!(settings.validate().is_err());
+        let mut settings = HugureSettings::default();
+        settings.orchestration.emergence_threshold = 0.0;
+        assert!(settings.validate().is_err());
+
+        let mut settings = HugureSettings::default();
+        settings.orchestration.max_recursion_depth = 0;
+        assert!(settings.validate().is_err());
+
+        let mut settings = HugureSettings::default();
+        settings.transport.metrics_listen_addr = "localhost".to_string();
+        assert!(settings.validate().is_err());
+    }
+}
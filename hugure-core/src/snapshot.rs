@@ -0,0 +1,289 @@
+//! Golden snapshot testing for numerical outputs
+//!
+//! A [`SnapshotStore`] compares serialized outputs (measurements, STSL transformations,
+//! navigation coordinates) against canonical JSON files checked into the repository.
+//! Numbers are compared within a [`Tolerance`]; strings, booleans, and structure must
+//! match exactly. Non-deterministic fields such as ids and timestamps should be left out
+//! of the recorded value.
+//!
+//! Snapshots are re-baselined by running the tests with `HUGURE_UPDATE_SNAPSHOTS=1`
+//! (`make snapshot-update`). Without it, missing or drifted snapshots fail the test so
+//! CI never silently accepts new numbers.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::error::SEntropyResult;
+
+/// Environment variable that switches snapshot checks into re-baselining mode
+pub const UPDATE_SNAPSHOTS_ENV: &str = "HUGURE_UPDATE_SNAPSHOTS";
+
+/// Allowed numerical drift between a snapshot and a fresh output
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tolerance {
+    /// Maximum absolute difference
+    pub absolute: f64,
+
+    /// Maximum difference relative to the larger magnitude of the two values
+    pub relative: f64,
+}
+
+impl Default for Tolerance {
+    fn default() -> Self {
+        Self { absolute: 1e-9, relative: 1e-9 }
+    }
+}
+
+impl Tolerance {
+    /// Whether `expected` and `actual` agree within this tolerance
+    pub fn accepts(&self, expected: f64, actual: f64) -> bool {
+        if expected == actual {
+            return true;
+        }
+        let difference = (expected - actual).abs();
+        let scale = expected.abs().max(actual.abs());
+        difference <= self.absolute || difference <= self.relative * scale
+    }
+}
+
+/// A single difference between a snapshot and a fresh output
+#[derive(Debug, Clone, PartialEq)]
+pub struct Drift {
+    /// JSON path of the differing value, e.g. `$[2].s_time`
+    pub path: String,
+
+    /// Value recorded in the snapshot
+    pub expected: Value,
+
+    /// Freshly computed value
+    pub actual: Value,
+}
+
+impl fmt::Display for Drift {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: expected {}, got {}", self.path, self.expected, self.actual)
+    }
+}
+
+/// Result of checking a value against its snapshot
+#[derive(Debug, Clone, PartialEq)]
+pub enum SnapshotOutcome {
+    /// The value matches the snapshot within tolerance
+    Matched,
+    /// No snapshot exists for this name
+    Missing,
+    /// The value differs from the snapshot
+    Drifted(Vec<Drift>),
+    /// The snapshot was written or rewritten (re-baselining mode)
+    Updated,
+}
+
+/// Directory of golden snapshots
+#[derive(Debug, Clone)]
+pub struct SnapshotStore {
+    /// Directory holding `<name>.json` snapshot files
+    dir: PathBuf,
+
+    /// Numerical tolerance for comparisons
+    tolerance: Tolerance,
+
+    /// Whether to rewrite snapshots instead of comparing
+    update: bool,
+}
+
+impl SnapshotStore {
+    /// Create a store rooted at `dir`, re-baselining if `HUGURE_UPDATE_SNAPSHOTS` is set
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        let update = std::env::var(UPDATE_SNAPSHOTS_ENV)
+            .map(|value| !matches!(value.as_str(), "" | "0" | "false"))
+            .unwrap_or(false);
+
+        Self { dir: dir.into(), tolerance: Tolerance::default(), update }
+    }
+
+    /// Use a custom numerical tolerance
+    pub fn with_tolerance(mut self, tolerance: Tolerance) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Force re-baselining on or off, ignoring the environment
+    pub fn with_update(mut self, update: bool) -> Self {
+        self.update = update;
+        self
+    }
+
+    /// Path of the snapshot file for `name`
+    pub fn path(&self, name: &str) -> PathBuf {
+        self.dir.join(format!("{}.json", name))
+    }
+
+    /// Compare `value` against the snapshot `name`, or rewrite it in re-baselining mode
+    pub fn check<T: Serialize>(&self, name: &str, value: &T) -> SEntropyResult<SnapshotOutcome> {
+        let actual = serde_json::to_value(value)?;
+        let path = self.path(name);
+
+        if self.update {
+            write_snapshot(&path, &actual)?;
+            return Ok(SnapshotOutcome::Updated);
+        }
+
+        if !path.exists() {
+            return Ok(SnapshotOutcome::Missing);
+        }
+
+        let expected: Value = serde_json::from_str(&std::fs::read_to_string(&path)?)?;
+        let drifts = compare(&expected, &actual, self.tolerance);
+
+        if drifts.is_empty() {
+            Ok(SnapshotOutcome::Matched)
+        } else {
+            Ok(SnapshotOutcome::Drifted(drifts))
+        }
+    }
+
+    /// Like [`check`](Self::check), but panics with a drift report unless the value
+    /// matches or was re-baselined
+    pub fn assert_matches<T: Serialize>(&self, name: &str, value: &T) {
+        let path = self.path(name);
+
+        match self.check(name, value) {
+            Ok(SnapshotOutcome::Matched) | Ok(SnapshotOutcome::Updated) => {},
+            Ok(SnapshotOutcome::Missing) => panic!(
+                "snapshot '{}' is missing at {}; run with {}=1 to record it",
+                name,
+                path.display(),
+                UPDATE_SNAPSHOTS_ENV
+            ),
+            Ok(SnapshotOutcome::Drifted(drifts)) => {
+                let report: Vec<String> = drifts.iter().map(ToString::to_string).collect();
+                panic!(
+                    "snapshot '{}' drifted beyond tolerance ({} values):\n  {}\nrun with {}=1 to \
+                     re-baseline if the change is intended",
+                    name,
+                    drifts.len(),
+                    report.join("\n  "),
+                    UPDATE_SNAPSHOTS_ENV
+                );
+            },
+            Err(e) => panic!("snapshot '{}' could not be checked: {}", name, e),
+        }
+    }
+}
+
+/// Compare two JSON values, returning every difference beyond `tolerance`
+pub fn compare(expected: &Value, actual: &Value, tolerance: Tolerance) -> Vec<Drift> {
+    let mut drifts = Vec::new();
+    compare_at("$", expected, actual, tolerance, &mut drifts);
+    drifts
+}
+
+fn compare_at(
+    path: &str,
+    expected: &Value,
+    actual: &Value,
+    tolerance: Tolerance,
+    drifts: &mut Vec<Drift>,
+) {
+    let drift =
+        || Drift { path: path.to_string(), expected: expected.clone(), actual: actual.clone() };
+
+    match (expected, actual) {
+        (Value::Number(e), Value::Number(a)) => match (e.as_f64(), a.as_f64()) {
+            (Some(e), Some(a)) if tolerance.accepts(e, a) => {},
+            _ => drifts.push(drift()),
+        },
+        (Value::Array(e), Value::Array(a)) => {
+            if e.len() != a.len() {
+                drifts.push(drift());
+                return;
+            }
+            for (index, (e, a)) in e.iter().zip(a).enumerate() {
+                compare_at(&format!("{}[{}]", path, index), e, a, tolerance, drifts);
+            }
+        },
+        (Value::Object(e), Value::Object(a)) => {
+            for (key, e_value) in e {
+                let child = format!("{}.{}", path, key);
+                match a.get(key) {
+                    Some(a_value) => compare_at(&child, e_value, a_value, tolerance, drifts),
+                    None => drifts.push(Drift {
+                        path: child,
+                        expected: e_value.clone(),
+                        actual: Value::Null,
+                    }),
+                }
+            }
+            for (key, a_value) in a {
+                if !e.contains_key(key) {
+                    drifts.push(Drift {
+                        path: format!("{}.{}", path, key),
+                        expected: Value::Null,
+                        actual: a_value.clone(),
+                    });
+                }
+            }
+        },
+        _ if expected == actual => {},
+        _ => drifts.push(drift()),
+    }
+}
+
+fn write_snapshot(path: &Path, value: &Value) -> SEntropyResult<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let mut contents = serde_json::to_string_pretty(value)?;
+    contents.push('\n');
+    std::fs::write(path, contents)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_numbers_within_tolerance_match() {
+        let expected = json!({ "s_time": 3.0, "values": [1.0, 2.0] });
+        let actual = json!({ "s_time": 3.0 + 1e-12, "values": [1.0, 2.0] });
+        assert!(compare(&expected, &actual, Tolerance::default()).is_empty());
+    }
+
+    #[test]
+    fn test_drift_reports_path() {
+        let expected = json!([{ "alpha": 1.0, "class": "General Problem" }]);
+        let actual = json!([{ "alpha": 1.1, "class": "General Problem" }]);
+
+        let drifts = compare(&expected, &actual, Tolerance::default());
+        assert_eq!(drifts.len(), 1);
+        assert_eq!(drifts[0].path, "$[0].alpha");
+    }
+
+    #[test]
+    fn test_structural_changes_drift() {
+        let expected = json!({ "a": 1, "b": true });
+        let actual = json!({ "a": 1, "c": true });
+        assert_eq!(compare(&expected, &actual, Tolerance::default()).len(), 2);
+    }
+
+    #[test]
+    fn test_update_then_match() {
+        let dir = std::env::temp_dir().join(format!("hugure-snapshots-{}", uuid::Uuid::new_v4()));
+        let value = json!({ "s_entropy": 0.0969 });
+
+        let store = SnapshotStore::new(&dir).with_update(false);
+        assert_eq!(store.check("case", &value).unwrap(), SnapshotOutcome::Missing);
+
+        let updating = store.clone().with_update(true);
+        assert_eq!(updating.check("case", &value).unwrap(), SnapshotOutcome::Updated);
+        assert_eq!(store.check("case", &value).unwrap(), SnapshotOutcome::Matched);
+
+        std::fs::remove_dir_all(dir).ok();
+    }
+}
@@ -0,0 +1,140 @@
+//! Shareable persistence of a navigator's learned solution cache
+//!
+//! A [`SolutionCacheFile`] holds the predetermined solutions a `ManifoldNavigator` has
+//! cached, keyed by problem. Export one with `ManifoldNavigator::export_solutions`, persist
+//! it with [`SolutionCacheFile::write_json`], and merge it into any other navigator, in this
+//! process or another, with `ManifoldNavigator::import_solutions`.
+
+#[cfg(feature = "serde")]
+use std::io::{Read, Write};
+
+use chrono::{DateTime, Utc};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::{SEntropyError, SEntropyResult},
+    types::{NavigationCoordinate, SEntropyPrecision},
+};
+
+/// Solution cache format written by this version of the navigator
+pub const SOLUTION_CACHE_VERSION: u32 = 1;
+
+/// Predetermined solution recorded for a problem
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CachedSolution {
+    /// Problem the solution was cached under
+    pub problem: String,
+
+    /// Solution coordinate
+    pub coordinate: NavigationCoordinate,
+}
+
+/// Serializable snapshot of a navigator's solution cache
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SolutionCacheFile {
+    /// Cache format, [`SOLUTION_CACHE_VERSION`] when written
+    pub version: u32,
+
+    /// Precision level of the navigator that exported the cache
+    pub precision: SEntropyPrecision,
+
+    /// Cached solutions, oldest first
+    pub solutions: Vec<CachedSolution>,
+
+    /// When the cache was exported
+    pub saved_at: DateTime<Utc>,
+}
+
+impl SolutionCacheFile {
+    /// Fail unless this cache's format can be imported
+    pub fn validate_version(&self) -> SEntropyResult<()> {
+        if self.version == SOLUTION_CACHE_VERSION {
+            Ok(())
+        } else {
+            Err(SEntropyError::configuration(
+                "solution_cache.version",
+                format!(
+                    "unsupported solution cache version {} (expected {})",
+                    self.version, SOLUTION_CACHE_VERSION
+                ),
+            ))
+        }
+    }
+
+    /// Fail on the first solution lacking memorial significance
+    pub fn validate_memorial_significance(&self) -> SEntropyResult<()> {
+        match self.solutions.iter().find(|s| !s.coordinate.validates_memorial_significance()) {
+            Some(solution) => Err(SEntropyError::memorial_significance(
+                crate::MEMORIAL_SIGNIFICANCE,
+                &solution.coordinate.memorial_significance,
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Write the cache as JSON
+    #[cfg(feature = "serde")]
+    pub fn write_json(&self, writer: impl Write) -> SEntropyResult<()> {
+        serde_json::to_writer(writer, self)?;
+        Ok(())
+    }
+
+    /// Read a cache written by [`write_json`](Self::write_json)
+    #[cfg(feature = "serde")]
+    pub fn read_json(reader: impl Read) -> SEntropyResult<Self> {
+        let file: Self = serde_json::from_reader(reader)?;
+        file.validate_version()?;
+        Ok(file)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{navigation::ManifoldNavigator, SEntropyCoordinate};
+
+    async fn learned_navigator() -> ManifoldNavigator {
+        let mut navigator = ManifoldNavigator::new(SEntropyPrecision::Standard);
+        for (i, s_time) in [0.2, 0.4].into_iter().enumerate() {
+            let target = SEntropyCoordinate::new(0.1, s_time, 0.3);
+            let solved = navigator.navigate_to_coordinates(&target).await.unwrap();
+            navigator.cache_solution(format!("problem_{}", i), solved);
+        }
+        navigator
+    }
+
+    #[cfg(feature = "serde")]
+    #[tokio::test]
+    async fn test_navigators_share_cache_through_json() {
+        let original = learned_navigator().await;
+        let mut json = Vec::new();
+        original.export_solutions().write_json(&mut json).unwrap();
+
+        let mut other = ManifoldNavigator::new(SEntropyPrecision::Standard);
+        other.cache_solution("problem_0", crate::navigation::create_optimal_navigation());
+        let file = SolutionCacheFile::read_json(json.as_slice()).unwrap();
+        assert_eq!(other.import_solutions(file).unwrap(), 2);
+
+        // Imported solutions replace same-keyed ones and serve navigation
+        assert_eq!(other.solution_cache().len(), 2);
+        let expected = original.solution_cache().get("problem_0").unwrap();
+        let served = other.zero_computation_navigate("problem_0").await.unwrap();
+        assert_eq!(served.id, expected.id);
+    }
+
+    #[tokio::test]
+    async fn test_rejects_unknown_version_and_tampered_solutions() {
+        let mut file = learned_navigator().await.export_solutions();
+        let mut navigator = ManifoldNavigator::new(SEntropyPrecision::Standard);
+
+        file.solutions[1].coordinate.memorial_significance = "tampered".to_string();
+        assert!(navigator.import_solutions(file.clone()).is_err());
+        file.solutions[1].coordinate.memorial_significance = crate::MEMORIAL_SIGNIFICANCE.into();
+        file.version = SOLUTION_CACHE_VERSION + 1;
+        assert!(navigator.import_solutions(file).is_err());
+        assert!(navigator.solution_cache().is_empty());
+    }
+}
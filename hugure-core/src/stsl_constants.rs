@@ -0,0 +1,146 @@
+//! Per-domain constants of the STSL equation
+//!
+//! `S = k × log(α)` uses [`crate::STSL_UNIVERSAL_CONSTANT`] as `k` for every problem
+//! unless an [`StslConstants`] table overrides it for the problem's class, as reported by
+//! `recognize_problem_class`. Classes without an override, including the general class,
+//! keep the table's default constant.
+
+use std::collections::HashMap;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::error::{SEntropyError, SEntropyResult};
+
+/// Constant `k` of the STSL equation, per problem class
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawStslConstants"))]
+pub struct StslConstants {
+    default: f64,
+    per_class: HashMap<String, f64>,
+}
+
+/// Serialized form of [`StslConstants`], validated on conversion
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct RawStslConstants {
+    default: f64,
+    per_class: HashMap<String, f64>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawStslConstants> for StslConstants {
+    type Error = SEntropyError;
+
+    fn try_from(raw: RawStslConstants) -> SEntropyResult<Self> {
+        let constants = Self::new(raw.default)?;
+        raw.per_class.into_iter().try_fold(constants, |constants, (class, k)| {
+            constants.with_class(class, k)
+        })
+    }
+}
+
+impl Default for StslConstants {
+    /// The universal constant for every class
+    fn default() -> Self {
+        Self { default: crate::STSL_UNIVERSAL_CONSTANT, per_class: HashMap::new() }
+    }
+}
+
+impl StslConstants {
+    /// Table using `default` for every class
+    pub fn new(default: f64) -> SEntropyResult<Self> {
+        let default = validate("stsl_constants.default", default)?;
+        Ok(Self { default, per_class: HashMap::new() })
+    }
+
+    /// Use `k` for problems of `class`
+    pub fn with_class(mut self, class: impl Into<String>, k: f64) -> SEntropyResult<Self> {
+        let class = class.into();
+        let k = validate(&format!("stsl_constants.{}", class), k)?;
+        self.per_class.insert(class, k);
+        Ok(self)
+    }
+
+    /// Drop the override of `class`, returning it
+    pub fn remove_class(&mut self, class: &str) -> Option<f64> {
+        self.per_class.remove(class)
+    }
+
+    /// Constant used for classes without an override
+    pub fn default_constant(&self) -> f64 {
+        self.default
+    }
+
+    /// Constant used for problems of `class`
+    pub fn constant_for(&self, class: &str) -> f64 {
+        self.per_class.get(class).copied().unwrap_or(self.default)
+    }
+
+    /// Classes with an override and their constants, in no particular order
+    pub fn overrides(&self) -> impl Iterator<Item = (&str, f64)> {
+        self.per_class.iter().map(|(class, k)| (class.as_str(), *k))
+    }
+
+    /// Whether every class uses the universal constant
+    pub fn is_universal(&self) -> bool {
+        self.default == crate::STSL_UNIVERSAL_CONSTANT
+            && self.per_class.values().all(|k| *k == crate::STSL_UNIVERSAL_CONSTANT)
+    }
+}
+
+/// `k` if it is finite and non-zero, since a zero constant maps every problem to `S = 0`
+fn validate(key: &str, k: f64) -> SEntropyResult<f64> {
+    if k.is_finite() && k != 0.0 {
+        Ok(k)
+    } else {
+        Err(SEntropyError::configuration(
+            key,
+            format!("STSL constant must be finite and non-zero, got {}", k),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classes_fall_back_to_default() {
+        let constants = StslConstants::default();
+        assert!(constants.is_universal());
+        assert_eq!(constants.constant_for("General Problem"), crate::STSL_UNIVERSAL_CONSTANT);
+
+        let mut constants = constants.with_class("Temporal Coordination", 2.5).unwrap();
+        assert!(!constants.is_universal());
+        assert_eq!(constants.constant_for("Temporal Coordination"), 2.5);
+        assert_eq!(constants.constant_for("Memory Optimization"), 1.0);
+        assert_eq!(constants.overrides().count(), 1);
+
+        assert_eq!(constants.remove_class("Temporal Coordination"), Some(2.5));
+        assert_eq!(constants.constant_for("Temporal Coordination"), 1.0);
+    }
+
+    #[test]
+    fn test_rejects_degenerate_constants() {
+        assert!(StslConstants::new(0.0).is_err());
+        assert!(StslConstants::new(f64::NAN).is_err());
+        assert!(StslConstants::default().with_class("Memory Optimization", f64::INFINITY).is_err());
+        assert_eq!(StslConstants::new(-0.5).unwrap().constant_for("anything"), -0.5);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_deserialization_rejects_degenerate_constants() {
+        let constants = StslConstants::new(2.0).unwrap().with_class("Memory Optimization", -1.5);
+        let json = serde_json::to_string(&constants.unwrap()).unwrap();
+        let decoded: StslConstants = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded.constant_for("Memory Optimization"), -1.5);
+
+        let zero_default = r#"{"default":0.0,"per_class":{}}"#;
+        assert!(serde_json::from_str::<StslConstants>(zero_default).is_err());
+        let zero_class = r#"{"default":1.0,"per_class":{"Memory Optimization":0.0}}"#;
+        assert!(serde_json::from_str::<StslConstants>(zero_class).is_err());
+    }
+}
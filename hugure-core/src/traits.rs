@@ -7,9 +7,13 @@
 use async_trait::async_trait;
 use nalgebra::Vector3;
 use std::collections::HashMap;
+use std::sync::Arc;
 
-use crate::error::SEntropyResult;
+use crate::clock::{measure_precision, ClockSource, StdClock, DEFAULT_PRECISION_SAMPLES};
+use crate::error::{SEntropyError, SEntropyResult};
+use crate::navigation_constraints::NavigationConstraints;
 use crate::types::*;
+use crate::units::Seconds;
 use crate::SEntropyCoordinate;
 
 /// Core trait for S-entropy measurement and calculation
@@ -43,10 +47,11 @@ pub trait SEntropyMeasurable {
 /// Trait for navigation through predetermined manifolds
 #[async_trait]
 pub trait PredeterminedManifoldNavigator {
-    /// Navigate to optimal S-entropy coordinates
+    /// Navigate to optimal S-entropy coordinates, steering clear of excluded regions
     async fn navigate_to_optimal(
         &self,
         target: SEntropyCoordinate,
+        constraints: &NavigationConstraints,
     ) -> SEntropyResult<NavigationCoordinate>;
 
     /// Navigate through zero-computation path (no calculation required)
@@ -55,10 +60,11 @@ pub trait PredeterminedManifoldNavigator {
         problem: &str,
     ) -> SEntropyResult<NavigationCoordinate>;
 
-    /// Find existing solutions near S percentage threshold
+    /// Find existing solutions near S percentage threshold that `constraints` permit
     async fn find_near_solutions(
         &self,
         s_percentage: f64,
+        constraints: &NavigationConstraints,
     ) -> SEntropyResult<Vec<NavigationCoordinate>>;
 
     /// Extract predetermined solution from navigation coordinates
@@ -167,6 +173,9 @@ pub trait CrossDomainOptimizer {
 #[async_trait]
 pub trait StrategicImpossibilityEngineer {
     /// Generate deliberately impossible local solutions
+    ///
+    /// `amplification` may be a [`ImpossibilityAmplification::Custom`] factor; invalid
+    /// ones should be rejected with a strategic impossibility error.
     async fn generate_impossible_solution(
         &self,
         problem: &str,
@@ -202,21 +211,45 @@ pub trait StrategicImpossibilityEngineer {
 /// Trait for temporal precision and ultra-high precision operations
 #[async_trait]
 pub trait TemporalPrecisionProvider {
+    /// Clock temporal precision is measured on, the monotonic std clock by default
+    fn clock_source(&self) -> Arc<dyn ClockSource> {
+        Arc::new(StdClock::new())
+    }
+
     /// Achieve ultra-precision temporal coordination (1e-30 second target)
+    ///
+    /// By default this measures the precision the clock source actually achieves.
     async fn achieve_ultra_precision(
         &self,
         target_precision: f64,
-    ) -> SEntropyResult<TemporalPrecision>;
+    ) -> SEntropyResult<TemporalPrecision> {
+        let target = Seconds::new(target_precision).ok_or_else(|| {
+            SEntropyError::configuration(
+                "temporal_precision.target",
+                format!("target must be finite and non-negative, got {}", target_precision),
+            )
+        })?;
+        measure_precision(self.clock_source().as_ref(), target, DEFAULT_PRECISION_SAMPLES)
+    }
 
     /// Generate temporal sensation for consciousness
     async fn generate_temporal_sensation(&self, precision_target: f64) -> SEntropyResult<f64>;
 
     /// Coordinate BMD operations with femtosecond precision
+    ///
+    /// By default this only checks that the clock source resolves `precision`.
     async fn coordinate_with_precision(
         &self,
-        operations: &[BMDPattern],
+        _operations: &[BMDPattern],
         precision: f64,
-    ) -> SEntropyResult<()>;
+    ) -> SEntropyResult<()> {
+        let achieved = self.achieve_ultra_precision(precision).await?.achieved_precision.get();
+        if achieved <= precision {
+            Ok(())
+        } else {
+            Err(SEntropyError::temporal_precision(precision, achieved))
+        }
+    }
 
     /// Validate memory efficiency for ultra-precision
     async fn validate_memory_efficiency(
@@ -5,157 +5,257 @@
 //! states, and navigation manifolds.
 
 use chrono::{DateTime, Utc};
-use nalgebra::{Matrix3, Vector3};
+#[cfg(feature = "matrix")]
+use nalgebra::Vector3;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::str::FromStr;
 use uuid::Uuid;
 
-/// Precision level for S-entropy calculations
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-pub enum SEntropyPrecision {
-    /// Standard precision for general use
-    Standard,
-    /// High precision for scientific applications
-    High,
-    /// Ultra precision for consciousness integration (1e-30 target)
-    Ultra,
-    /// Supreme precision for memorial significance validation
-    Supreme,
+pub use crate::core_types::{
+    BMDOperationMode, ConsciousnessMode, ImpossibilityAmplification, ObserverSophistication,
+    SEntropyPrecision, SophisticationScore,
+};
+#[cfg(feature = "navigation")]
+pub use crate::core_types::NavigationCoordinate;
+use crate::error::SEntropyError;
+use crate::units::Seconds;
+
+impl FromStr for SEntropyPrecision {
+    type Err = SEntropyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "standard" => Ok(Self::Standard),
+            "high" => Ok(Self::High),
+            "ultra" => Ok(Self::Ultra),
+            "supreme" => Ok(Self::Supreme),
+            other => Err(SEntropyError::configuration(
+                "precision",
+                format!("unknown precision level '{}'", other),
+            )),
+        }
+    }
 }
 
-impl SEntropyPrecision {
-    /// Get the numerical precision threshold for this level
-    pub fn threshold(&self) -> f64 {
-        match self {
-            Self::Standard => 1e-6,
-            Self::High => 1e-15,
-            Self::Ultra => 1e-30,
-            Self::Supreme => 1e-50,
+impl FromStr for ObserverSophistication {
+    type Err = SEntropyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "naive" => Ok(Self::Naive),
+            "intermediate" => Ok(Self::Intermediate),
+            "expert" => Ok(Self::Expert),
+            "universal" => Ok(Self::Universal),
+            other => Err(SEntropyError::configuration(
+                "observer",
+                format!("unknown observer sophistication '{}'", other),
+            )),
         }
     }
 }
 
-/// Observer sophistication levels for universal accessibility
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-pub enum ObserverSophistication {
-    /// Naive user requiring simple interactions
-    Naive,
-    /// Intermediate user with some technical knowledge
-    Intermediate,
-    /// Expert user with deep technical understanding
-    Expert,
-    /// Universal observer with unlimited capability
-    Universal,
+/// Structured description of the problem an S-knowledge deficit is calculated for
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MeasurementContext {
+    /// Free-text problem description
+    pub description: String,
+
+    /// Problem domain, such as `physics` or `linguistics`
+    pub domain: Option<String>,
+
+    /// Estimated problem complexity; the description length stands in when unset
+    pub complexity_estimate: Option<f64>,
+
+    /// Knowledge sources available to the observer
+    pub knowledge_sources: Vec<String>,
+
+    /// Graded sophistication of the observer, replacing its discrete level when set
+    pub sophistication_score: Option<SophisticationScore>,
+
+    /// Free-form labels
+    pub tags: Vec<String>,
 }
 
-/// BMD (Biological Maxwell Demon) operation modes
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-pub enum BMDOperationMode {
-    /// Frame selection across predetermined manifolds
-    FrameSelection,
-    /// Reality-frame fusion processing
-    RealityFusion,
-    /// Memory fabrication and ridiculous solutions
-    MemoryFabrication,
-    /// Temporal coherence through emotional delusion
-    TemporalCoherence,
-    /// Agency experience generation
-    AgencyDelusion,
+impl MeasurementContext {
+    /// Context with only a description
+    pub fn new(description: impl Into<String>) -> Self {
+        Self { description: description.into(), ..Self::default() }
+    }
+
+    /// Set the problem domain
+    pub fn domain(mut self, domain: impl Into<String>) -> Self {
+        self.domain = Some(domain.into());
+        self
+    }
+
+    /// Set the estimated problem complexity
+    pub fn complexity_estimate(mut self, complexity: f64) -> Self {
+        self.complexity_estimate = Some(complexity);
+        self
+    }
+
+    /// Add an available knowledge source
+    pub fn knowledge_source(mut self, source: impl Into<String>) -> Self {
+        self.knowledge_sources.push(source.into());
+        self
+    }
+
+    /// Set the observer's graded sophistication
+    pub fn sophistication_score(mut self, score: SophisticationScore) -> Self {
+        self.sophistication_score = Some(score);
+        self
+    }
+
+    /// Add a label
+    pub fn tag(mut self, tag: impl Into<String>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// The complexity estimate, or the description length without one
+    pub fn complexity(&self) -> f64 {
+        self.complexity_estimate.unwrap_or(self.description.len() as f64)
+    }
 }
 
-/// Consciousness enhancement modes
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-pub enum ConsciousnessMode {
-    /// Enhancement only - never replacement
-    EnhancementOnly,
-    /// Frame selection engine optimization
-    FrameSelectionEngine,
-    /// Reality fusion enabled
-    RealityFusion,
-    /// Agency preservation (strict boundaries)
-    AgencyPreservation,
+impl From<&str> for MeasurementContext {
+    fn from(description: &str) -> Self {
+        Self::new(description)
+    }
 }
 
-/// Strategic impossibility amplification levels
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-pub enum ImpossibilityAmplification {
-    /// Mild impossibility for testing
-    Mild,
-    /// Standard impossibility for normal operations
-    Standard,
-    /// High impossibility for advanced optimization
-    High,
-    /// Extreme impossibility for breakthrough solutions
-    Extreme,
+impl From<String> for MeasurementContext {
+    fn from(description: String) -> Self {
+        Self::new(description)
+    }
 }
 
-impl ImpossibilityAmplification {
-    /// Get the amplification factor for ridiculous solution generation
-    pub fn factor(&self) -> f64 {
+/// Tri-dimensional alignment schemes selectable by name
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AlignmentStrategyKind {
+    /// Fixed optimization matrix applied to the S-vector
+    #[default]
+    FixedMatrix,
+    /// Iteratively solved least-squares pull between the three dimensions
+    IterativeLeastSquares,
+    /// Independent, per-dimension weighted minimization
+    WeightedMinimization,
+}
+
+impl AlignmentStrategyKind {
+    /// Name accepted by `FromStr`
+    pub fn as_str(&self) -> &'static str {
         match self {
-            Self::Mild => 10.0,
-            Self::Standard => 100.0,
-            Self::High => 1000.0,
-            Self::Extreme => 10000.0,
+            Self::FixedMatrix => "fixed_matrix",
+            Self::IterativeLeastSquares => "iterative_least_squares",
+            Self::WeightedMinimization => "weighted_minimization",
         }
     }
 }
 
-/// Navigation coordinates in predetermined manifold space
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct NavigationCoordinate {
-    /// Unique identifier
-    pub id: Uuid,
-
-    /// Position in knowledge space
-    pub knowledge_position: Vector3<f64>,
+impl FromStr for AlignmentStrategyKind {
+    type Err = SEntropyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().replace('-', "_").as_str() {
+            "fixed_matrix" => Ok(Self::FixedMatrix),
+            "iterative_least_squares" => Ok(Self::IterativeLeastSquares),
+            "weighted_minimization" => Ok(Self::WeightedMinimization),
+            other => Err(SEntropyError::configuration(
+                "alignment_strategy",
+                format!("unknown alignment strategy '{}'", other),
+            )),
+        }
+    }
+}
 
-    /// Position in temporal space
-    pub temporal_position: Vector3<f64>,
+/// Observer-process integration dynamics selectable by name
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum IntegrationStrategyKind {
+    /// Fixed fractional reduction of the separation per iteration
+    #[default]
+    GeometricDecay,
+    /// Halving of the gap between the separation and a floor below the target
+    Bisection,
+    /// Simulated annealing with a cooling acceptance temperature
+    Annealing,
+}
 
-    /// Position in entropy space
-    pub entropy_position: Vector3<f64>,
+impl IntegrationStrategyKind {
+    /// Every strategy, in declaration order
+    pub const ALL: [Self; 3] = [Self::GeometricDecay, Self::Bisection, Self::Annealing];
 
-    /// Confidence in this coordinate's validity
-    pub confidence: f64,
+    /// Name accepted by `FromStr`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::GeometricDecay => "geometric_decay",
+            Self::Bisection => "bisection",
+            Self::Annealing => "annealing",
+        }
+    }
+}
 
-    /// Memorial significance marker
-    pub memorial_significance: String,
+impl FromStr for IntegrationStrategyKind {
+    type Err = SEntropyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().replace('-', "_").as_str() {
+            "geometric_decay" => Ok(Self::GeometricDecay),
+            "bisection" => Ok(Self::Bisection),
+            "annealing" => Ok(Self::Annealing),
+            other => Err(SEntropyError::configuration(
+                "integration_strategy",
+                format!("unknown integration strategy '{}'", other),
+            )),
+        }
+    }
+}
 
-    /// Creation timestamp
-    pub created_at: DateTime<Utc>,
+/// Problem-description hashes for zero-computation navigation, selectable by name
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ProblemHashKind {
+    /// Versioned xxHash64, reproducible across builds and machines
+    #[default]
+    Stable,
+    /// The standard library's `DefaultHasher`, matching coordinates from earlier releases
+    /// built with the same Rust toolchain
+    Legacy,
 }
 
-impl NavigationCoordinate {
-    /// Create a new navigation coordinate
-    pub fn new(
-        knowledge: Vector3<f64>,
-        temporal: Vector3<f64>,
-        entropy: Vector3<f64>,
-        confidence: f64,
-    ) -> Self {
-        Self {
-            id: Uuid::new_v4(),
-            knowledge_position: knowledge,
-            temporal_position: temporal,
-            entropy_position: entropy,
-            confidence,
-            memorial_significance: crate::MEMORIAL_SIGNIFICANCE.to_string(),
-            created_at: Utc::now(),
+impl ProblemHashKind {
+    /// Name accepted by `FromStr`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Stable => "stable",
+            Self::Legacy => "legacy",
         }
     }
+}
 
-    /// Calculate the total navigation distance
-    pub fn total_distance(&self) -> f64 {
-        (self.knowledge_position.norm_squared()
-            + self.temporal_position.norm_squared()
-            + self.entropy_position.norm_squared())
-        .sqrt()
+impl FromStr for ProblemHashKind {
+    type Err = SEntropyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "stable" => Ok(Self::Stable),
+            "legacy" => Ok(Self::Legacy),
+            other => Err(SEntropyError::configuration(
+                "problem_hash",
+                format!("unknown problem hash '{}'", other),
+            )),
+        }
     }
 }
 
 /// BMD pattern for cognitive pattern coordination
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BMDPattern {
     /// Unique pattern identifier
     pub id: Uuid,
@@ -228,6 +328,9 @@ impl BMDPattern {
     }
 
     /// Create a ridiculous (impossible) BMD pattern for navigation insights
+    ///
+    /// Custom amplification factors are used as given; check them with
+    /// [`ImpossibilityAmplification::is_valid`] first.
     pub fn create_ridiculous(
         name: String,
         impossibility_level: ImpossibilityAmplification,
@@ -252,10 +355,87 @@ impl BMDPattern {
 
         pattern
     }
+
+    /// Compose several patterns into one, for combining impossible components
+    ///
+    /// The composition gets a fresh id and:
+    ///
+    /// - the name `a+b+...` of its components
+    /// - the operation mode of its most effective component (the first on ties)
+    /// - the highest impossibility level of any component
+    /// - the mean effectiveness, transfer efficiency, and S-coordinates
+    /// - the components' metadata, with persistent components overriding disposable
+    ///   ones and later components overriding earlier ones of the same kind, plus
+    ///   a `composed_from` entry listing the component ids
+    ///
+    /// It is disposable only if every component is, and then is disposed of with
+    /// the first of them; a single persistent component makes it persistent.
+    /// Composing no patterns gives an empty, persistent frame selection pattern.
+    pub fn compose(patterns: &[&BMDPattern]) -> BMDPattern {
+        let Some(first) = patterns.first() else {
+            return Self::new(
+                "composed".to_string(),
+                BMDOperationMode::FrameSelection,
+                ImpossibilityAmplification::Mild,
+                false,
+            );
+        };
+
+        let name = patterns
+            .iter()
+            .map(|pattern| pattern.name.as_str())
+            .collect::<Vec<_>>()
+            .join("+");
+        let operation_mode = patterns
+            .iter()
+            .copied()
+            .fold(*first, |best, pattern| {
+                if pattern.effectiveness > best.effectiveness {
+                    pattern
+                } else {
+                    best
+                }
+            })
+            .operation_mode;
+        let impossibility_level = patterns
+            .iter()
+            .map(|pattern| pattern.impossibility_level)
+            .max_by(|a, b| a.factor().total_cmp(&b.factor()))
+            .unwrap_or(first.impossibility_level);
+        let disposable = patterns.iter().all(|pattern| pattern.disposable);
+
+        let mut composed = Self::new(name, operation_mode, impossibility_level, disposable);
+        let count = patterns.len() as f64;
+        let mean = |field: fn(&BMDPattern) -> f64| {
+            patterns.iter().map(|pattern| field(pattern)).sum::<f64>() / count
+        };
+        composed.effectiveness = mean(|pattern| pattern.effectiveness);
+        composed.transfer_efficiency = mean(|pattern| pattern.transfer_efficiency);
+        composed.s_coordinates = crate::SEntropyCoordinate::new(
+            mean(|pattern| pattern.s_coordinates.s_knowledge),
+            mean(|pattern| pattern.s_coordinates.s_time),
+            mean(|pattern| pattern.s_coordinates.s_entropy),
+        );
+
+        let (persistent, disposable_parts): (Vec<&&BMDPattern>, Vec<&&BMDPattern>) =
+            patterns.iter().partition(|pattern| !pattern.disposable);
+        for pattern in disposable_parts.into_iter().chain(persistent) {
+            composed.metadata.extend(pattern.metadata.clone());
+        }
+        let ids: Vec<String> = patterns.iter().map(|pattern| pattern.id.to_string()).collect();
+        composed.metadata.insert("composed_from".to_string(), ids.join(","));
+
+        if disposable {
+            composed.dispose_at = patterns.iter().filter_map(|pattern| pattern.dispose_at).min();
+        }
+        composed
+    }
 }
 
 /// Consciousness state for BMD operation tracking
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg(feature = "matrix")]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ConsciousnessState {
     /// State identifier
     pub id: Uuid,
@@ -298,6 +478,7 @@ pub struct ConsciousnessState {
     pub last_updated: DateTime<Utc>,
 }
 
+#[cfg(feature = "matrix")]
 impl ConsciousnessState {
     /// Create a new consciousness state with enhancement-only boundaries
     pub fn new(mode: ConsciousnessMode, sophistication: ObserverSophistication) -> Self {
@@ -351,7 +532,8 @@ impl ConsciousnessState {
 }
 
 /// Cross-domain transfer result
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CrossDomainTransfer {
     /// Transfer identifier
     pub id: Uuid,
@@ -411,16 +593,17 @@ impl CrossDomainTransfer {
 }
 
 /// Temporal precision measurement
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TemporalPrecision {
     /// Precision identifier
     pub id: Uuid,
 
     /// Target precision level
-    pub target_precision: f64,
+    pub target_precision: Seconds,
 
     /// Achieved precision level
-    pub achieved_precision: f64,
+    pub achieved_precision: Seconds,
 
     /// Memory usage for this precision level
     pub memory_usage_bytes: u64,
@@ -437,13 +620,13 @@ pub struct TemporalPrecision {
 
 impl TemporalPrecision {
     /// Create a new temporal precision measurement
-    pub fn new(target: f64, achieved: f64, memory_bytes: u64) -> Self {
+    pub fn new(target: Seconds, achieved: Seconds, memory_bytes: u64) -> Self {
         Self {
             id: Uuid::new_v4(),
             target_precision: target,
             achieved_precision: achieved,
             memory_usage_bytes: memory_bytes,
-            ultra_precision_achieved: achieved <= crate::S_ENTROPY_PRECISION_TARGET,
+            ultra_precision_achieved: achieved.get() <= crate::S_ENTROPY_PRECISION_TARGET,
             windowed_generation: memory_bytes < 100_000_000, // <100MB
             measured_at: Utc::now(),
         }
@@ -454,3 +637,75 @@ impl TemporalPrecision {
         self.ultra_precision_achieved && self.memory_usage_bytes < 100_000_000
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pattern(name: &str, effectiveness: f64, disposable: bool) -> BMDPattern {
+        let mut pattern = BMDPattern::new(
+            name.to_string(),
+            BMDOperationMode::RealityFusion,
+            ImpossibilityAmplification::Standard,
+            disposable,
+        );
+        pattern.effectiveness = effectiveness;
+        pattern.metadata.insert("source".to_string(), name.to_string());
+        pattern
+    }
+
+    #[test]
+    fn test_compose_takes_max_impossibility_and_mean_coordinates() {
+        let realistic = pattern("realistic", 0.6, false);
+        let ridiculous =
+            BMDPattern::create_ridiculous("leap".to_string(), ImpossibilityAmplification::High);
+
+        let composed = BMDPattern::compose(&[&realistic, &ridiculous]);
+        assert_eq!(composed.name, "realistic+ridiculous_leap");
+        assert_eq!(composed.impossibility_level, ImpossibilityAmplification::High);
+        assert_eq!(composed.operation_mode, BMDOperationMode::MemoryFabrication);
+        assert_eq!(composed.s_coordinates.s_knowledge, -500.0);
+        assert!(composed.metadata["composed_from"].contains(&realistic.id.to_string()));
+    }
+
+    #[test]
+    fn test_custom_amplification_propagates() {
+        let custom = ImpossibilityAmplification::custom(250.0).unwrap();
+        let ridiculous = BMDPattern::create_ridiculous("leap".to_string(), custom);
+        assert_eq!(ridiculous.effectiveness, 250.0);
+        assert_eq!(ridiculous.s_coordinates.s_knowledge, -250.0);
+
+        // A custom factor above the built-in levels wins composition
+        let high =
+            BMDPattern::create_ridiculous("jump".to_string(), ImpossibilityAmplification::High);
+        let extreme = ImpossibilityAmplification::custom(5e4).unwrap();
+        let beyond = BMDPattern::create_ridiculous("vault".to_string(), extreme);
+        assert_eq!(BMDPattern::compose(&[&high, &beyond]).impossibility_level, extreme);
+
+        #[cfg(feature = "serde")]
+        {
+            let json = serde_json::to_string(&custom).unwrap();
+            assert_eq!(json, r#"{"Custom":{"factor":250.0}}"#);
+            let parsed: ImpossibilityAmplification = serde_json::from_str(&json).unwrap();
+            assert_eq!(parsed, custom);
+            let built_in = serde_json::to_string(&ImpossibilityAmplification::High).unwrap();
+            assert_eq!(built_in, r#""High""#);
+        }
+    }
+
+    #[test]
+    fn test_compose_disposal_rules() {
+        let (a, b) = (pattern("a", 0.5, true), pattern("b", 0.5, true));
+        let disposable = BMDPattern::compose(&[&a, &b]);
+        assert!(disposable.disposable);
+        assert_eq!(disposable.dispose_at, a.dispose_at.min(b.dispose_at));
+        assert_eq!(disposable.metadata["source"], "b");
+
+        let persistent = BMDPattern::compose(&[&pattern("kept", 0.5, false), &a]);
+        assert!(!persistent.disposable);
+        assert_eq!(persistent.dispose_at, None);
+        assert_eq!(persistent.metadata["source"], "kept");
+
+        assert!(!BMDPattern::compose(&[]).disposable);
+    }
+}
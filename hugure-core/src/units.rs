@@ -0,0 +1,265 @@
+//! Unit-safe newtypes for durations and S-values
+//!
+//! Temporal precision is specified in femtoseconds, elapsed time is measured in
+//! nanoseconds, and precision targets are quoted in seconds. Passing bare `u64`s and `f64`s
+//! between them invites silent factor-of-a-million bugs, so durations and S-values are
+//! carried as [`Femtoseconds`], [`Seconds`], and [`SValue`]. Conversions between them are
+//! explicit and checked: anything that could overflow or produce a non-finite value returns
+//! `None` instead. All three serialize as their bare number, so existing payloads and
+//! configuration files keep their format.
+
+use std::{
+    fmt,
+    ops::{Add, Neg, Sub},
+    time::Duration,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Femtoseconds in one second
+pub const FEMTOSECONDS_PER_SECOND: u64 = 1_000_000_000_000_000;
+
+/// Femtoseconds in one nanosecond
+const FEMTOSECONDS_PER_NANOSECOND: u64 = 1_000_000;
+
+/// Whole number of femtoseconds
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(transparent))]
+pub struct Femtoseconds(u64);
+
+impl Femtoseconds {
+    /// No time at all
+    pub const ZERO: Self = Self(0);
+
+    /// `femtoseconds` femtoseconds
+    pub const fn new(femtoseconds: u64) -> Self {
+        Self(femtoseconds)
+    }
+
+    /// Number of femtoseconds
+    pub const fn get(self) -> u64 {
+        self.0
+    }
+
+    /// `nanoseconds` in femtoseconds, `None` on overflow
+    pub fn from_nanos(nanoseconds: u64) -> Option<Self> {
+        nanoseconds.checked_mul(FEMTOSECONDS_PER_NANOSECOND).map(Self)
+    }
+
+    /// `duration` in femtoseconds, `None` on overflow (beyond about five hours)
+    pub fn from_duration(duration: Duration) -> Option<Self> {
+        let nanoseconds = u64::try_from(duration.as_nanos()).ok()?;
+        Self::from_nanos(nanoseconds)
+    }
+
+    /// Whole nanoseconds, rounded down
+    pub fn as_nanos(self) -> u64 {
+        self.0 / FEMTOSECONDS_PER_NANOSECOND
+    }
+
+    /// Duration of the whole nanoseconds, rounded down
+    pub fn to_duration(self) -> Duration {
+        Duration::from_nanos(self.as_nanos())
+    }
+
+    /// The same span in seconds
+    pub fn to_seconds(self) -> Seconds {
+        Seconds(self.0 as f64 / FEMTOSECONDS_PER_SECOND as f64)
+    }
+
+    /// Sum, `None` on overflow
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.0.checked_add(other.0).map(Self)
+    }
+
+    /// Difference, `None` if `other` is longer
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.0.checked_sub(other.0).map(Self)
+    }
+
+    /// `factor` times this span, `None` on overflow
+    pub fn checked_mul(self, factor: u64) -> Option<Self> {
+        self.0.checked_mul(factor).map(Self)
+    }
+
+    /// Sum, clamped to the longest representable span
+    pub fn saturating_add(self, other: Self) -> Self {
+        Self(self.0.saturating_add(other.0))
+    }
+
+    /// Difference, clamped to zero
+    pub fn saturating_sub(self, other: Self) -> Self {
+        Self(self.0.saturating_sub(other.0))
+    }
+}
+
+impl Add for Femtoseconds {
+    type Output = Self;
+
+    /// Sum, panicking on overflow like [`Duration`] addition
+    fn add(self, other: Self) -> Self {
+        self.checked_add(other).expect("overflow when adding femtoseconds")
+    }
+}
+
+impl Sub for Femtoseconds {
+    type Output = Self;
+
+    /// Difference, panicking if `other` is longer like [`Duration`] subtraction
+    fn sub(self, other: Self) -> Self {
+        self.checked_sub(other).expect("overflow when subtracting femtoseconds")
+    }
+}
+
+impl From<Femtoseconds> for Seconds {
+    fn from(femtoseconds: Femtoseconds) -> Self {
+        femtoseconds.to_seconds()
+    }
+}
+
+impl fmt::Display for Femtoseconds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} fs", self.0)
+    }
+}
+
+/// Finite, non-negative span in seconds
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(transparent))]
+pub struct Seconds(f64);
+
+impl Seconds {
+    /// No time at all
+    pub const ZERO: Self = Self(0.0);
+
+    /// `seconds` seconds, `None` unless finite and non-negative
+    pub fn new(seconds: f64) -> Option<Self> {
+        (seconds.is_finite() && seconds >= 0.0).then_some(Self(seconds))
+    }
+
+    /// Number of seconds
+    pub fn get(self) -> f64 {
+        self.0
+    }
+
+    /// The span in whole femtoseconds, rounded to nearest, `None` on overflow
+    pub fn to_femtoseconds(self) -> Option<Femtoseconds> {
+        let femtoseconds = (self.0 * FEMTOSECONDS_PER_SECOND as f64).round();
+        // u64::MAX is not exactly representable; its f64 rounding is one past it
+        (femtoseconds < u64::MAX as f64).then(|| Femtoseconds(femtoseconds as u64))
+    }
+
+    /// Sum, `None` if it is not finite
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        Self::new(self.0 + other.0)
+    }
+
+    /// Difference, `None` if `other` is longer
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        Self::new(self.0 - other.0)
+    }
+
+    /// `factor` times this span, `None` unless finite and non-negative
+    pub fn checked_mul(self, factor: f64) -> Option<Self> {
+        Self::new(self.0 * factor)
+    }
+}
+
+impl fmt::Display for Seconds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:e} s", self.0)
+    }
+}
+
+/// Finite S-value: an S-entropy component, magnitude, or STSL S-coordinate
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(transparent))]
+pub struct SValue(f64);
+
+impl SValue {
+    /// Perfect observer-process integration
+    pub const ZERO: Self = Self(0.0);
+
+    /// `value`, `None` unless finite
+    pub fn new(value: f64) -> Option<Self> {
+        value.is_finite().then_some(Self(value))
+    }
+
+    /// The S-value
+    pub fn get(self) -> f64 {
+        self.0
+    }
+
+    /// Separation regardless of sign
+    pub fn abs(self) -> Self {
+        Self(self.0.abs())
+    }
+
+    /// Sum, `None` if it is not finite
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        Self::new(self.0 + other.0)
+    }
+
+    /// Difference, `None` if it is not finite
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        Self::new(self.0 - other.0)
+    }
+
+    /// `factor` times this S-value, `None` if it is not finite
+    pub fn checked_mul(self, factor: f64) -> Option<Self> {
+        Self::new(self.0 * factor)
+    }
+}
+
+impl Neg for SValue {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+impl fmt::Display for SValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "S={}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_femtosecond_conversions_are_checked() {
+        let precision = Femtoseconds::new(10);
+        assert_eq!(Femtoseconds::from_nanos(3), Some(Femtoseconds::new(3_000_000)));
+        assert_eq!(Femtoseconds::from_nanos(u64::MAX), None);
+        let two_micros = Femtoseconds::from_duration(Duration::from_micros(2)).unwrap();
+        assert_eq!(two_micros.as_nanos(), 2_000);
+        assert_eq!(Femtoseconds::from_duration(Duration::from_secs(24 * 3600)), None);
+
+        // Below a nanosecond, durations round down to nothing
+        assert_eq!(precision.to_duration(), Duration::ZERO);
+        assert_eq!(precision.to_seconds().get(), 1e-14);
+        assert_eq!(precision.checked_sub(Femtoseconds::new(11)), None);
+        assert_eq!(precision.checked_mul(u64::MAX), None);
+        assert_eq!(precision + precision, Femtoseconds::new(20));
+        assert_eq!(precision.to_string(), "10 fs");
+    }
+
+    #[test]
+    fn test_seconds_and_s_values_stay_finite() {
+        let target = Seconds::new(1e-13).unwrap();
+        assert_eq!(target.to_femtoseconds(), Some(Femtoseconds::new(100)));
+        assert_eq!(Seconds::new(1e6).unwrap().to_femtoseconds(), None);
+        assert!(Seconds::new(-1.0).is_none());
+        assert!(Seconds::new(f64::NAN).is_none());
+        assert_eq!(target.checked_sub(Seconds::new(1.0).unwrap()), None);
+
+        let s = SValue::new(-2.5).unwrap();
+        assert_eq!(s.abs(), -s);
+        assert_eq!(s.checked_mul(f64::MAX), None);
+        assert!(SValue::new(f64::INFINITY).is_none());
+    }
+}
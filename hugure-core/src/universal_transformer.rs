@@ -5,22 +5,113 @@
 //! analysis, providing the mathematical foundation for universal problem-solving.
 
 use async_trait::async_trait;
+use futures::stream::{self, StreamExt};
 use nalgebra::Vector3;
 use std::collections::HashMap;
+use std::fmt;
+use std::future::Future;
+use std::sync::{Arc, Mutex, MutexGuard, OnceLock, PoisonError};
 use tracing::{debug, info};
 
 use crate::error::{SEntropyError, SEntropyResult};
+use crate::logging::subsystem;
+use crate::oscillation_cache::{normalize_problem, OscillationCache, OscillationCacheStats};
+use crate::pipeline_middleware::{check_stage, PipelineMiddleware, PipelineStage, StageValue};
+use crate::problem_classes::{ProblemClassRegistry, ProblemClassifier};
+use crate::problem_features::{ProblemFeatureExtractor, TextStatisticsExtractor};
+use crate::stsl_constants::StslConstants;
 use crate::traits::{MemorialSignificant, UniversalProblemTransformer};
 use crate::types::NavigationCoordinate;
 
+/// Navigation table entries reported by [`STSLTransformer::explain`]
+pub const EXPLANATION_TABLE_MATCHES: usize = 3;
+
+/// Default number of problems a batch transforms at once
+pub const DEFAULT_TRANSFORM_BATCH_CONCURRENCY: usize = 16;
+
+/// Problem types of the STSL navigation table and the descriptions they are mapped from
+const NAVIGATION_TABLE_PROBLEMS: [(&str, &str); 5] = [
+    ("cognitive pattern selection", "Neural oscillation endpoint patterns"),
+    ("temporal coordination", "Temporal oscillation synchronization"),
+    ("communication optimization", "Information oscillation harmonics"),
+    ("cross domain transfer", "Universal oscillation resonance"),
+    ("memory optimization", "Memory oscillation compression"),
+];
+
+/// Navigation table entry close to an explained S-coordinate
+#[derive(Debug, Clone, PartialEq)]
+pub struct NavigationTableMatch {
+    /// Problem type of the entry
+    pub problem_type: String,
+
+    /// S-coordinate of the entry
+    pub s_coordinate: f64,
+
+    /// Absolute difference from the explained S-coordinate
+    pub distance: f64,
+}
+
+/// Why the STSL equation produced an S-coordinate
+#[derive(Debug, Clone, PartialEq)]
+pub struct StslExplanation {
+    /// Explained S-coordinate
+    pub s_coordinate: f64,
+
+    /// Oscillation amplitude α under the default constant, `exp(S / k)`
+    pub alpha: f64,
+
+    /// Lowest and highest α any configured constant maps to the S-coordinate
+    pub alpha_range: (f64, f64),
+
+    /// Closest navigation table entries, nearest first
+    pub nearest: Vec<NavigationTableMatch>,
+}
+
+impl StslExplanation {
+    /// Closest navigation table entry
+    pub fn closest(&self) -> Option<&NavigationTableMatch> {
+        self.nearest.first()
+    }
+}
+
+impl fmt::Display for StslExplanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "S={:.3} inverts to α={:.3} (α in [{:.3}, {:.3}] across STSL constants)",
+            self.s_coordinate, self.alpha, self.alpha_range.0, self.alpha_range.1
+        )?;
+        if let Some(closest) = self.closest() {
+            write!(
+                f,
+                ", closest to '{}' at S={:.3} (ΔS={:.3})",
+                closest.problem_type, closest.s_coordinate, closest.distance
+            )?;
+        }
+        Ok(())
+    }
+}
+
 /// Universal problem transformer implementing STSL equation
 #[derive(Debug, Clone)]
 pub struct STSLTransformer {
-    /// Universal constant k for STSL equation
-    universal_constant: f64,
+    /// Constant k of the STSL equation, per problem class
+    stsl_constants: StslConstants,
+
+    /// Problem-to-oscillation mapping cache, shared by clones
+    oscillation_cache: Arc<Mutex<OscillationCache>>,
 
-    /// Problem-to-oscillation mapping cache
-    oscillation_cache: HashMap<String, Vector3<f64>>,
+    /// Whether every mapping is recomputed rather than cached
+    bypass_oscillation_cache: bool,
+
+    /// Hooks around each pipeline stage, in registration order
+    middleware: Vec<Arc<dyn PipelineMiddleware>>,
+
+    /// Derives oscillation coordinates from problem descriptions
+    feature_extractor: Arc<dyn ProblemFeatureExtractor>,
+
+    /// Problems a batch transforms at once
+    batch_concurrency: usize,
 
     /// Memorial significance
     memorial_significance: String,
@@ -29,34 +120,128 @@ pub struct STSLTransformer {
 impl STSLTransformer {
     /// Create a new STSL transformer
     pub fn new() -> Self {
-        info!("⚡ Initializing STSL universal problem transformer");
-
         Self {
-            universal_constant: crate::STSL_UNIVERSAL_CONSTANT,
-            oscillation_cache: HashMap::new(),
+            stsl_constants: StslConstants::default(),
+            oscillation_cache: Arc::new(Mutex::new(OscillationCache::default())),
+            bypass_oscillation_cache: false,
+            middleware: Vec::new(),
+            feature_extractor: Arc::new(TextStatisticsExtractor),
+            batch_concurrency: DEFAULT_TRANSFORM_BATCH_CONCURRENCY,
             memorial_significance: crate::MEMORIAL_SIGNIFICANCE.to_string(),
         }
     }
 
+    /// Derive oscillation coordinates with `extractor` instead of text statistics
+    ///
+    /// Starts an empty oscillation cache of the same capacity, since oscillations derived
+    /// by the previous extractor no longer apply.
+    pub fn with_feature_extractor(mut self, extractor: Arc<dyn ProblemFeatureExtractor>) -> Self {
+        let capacity = self.lock_oscillation_cache().capacity();
+        self.feature_extractor = extractor;
+        self.with_oscillation_cache_capacity(capacity)
+    }
+
+    /// Apply the STSL equation with per-class constants from `constants`
+    pub fn with_stsl_constants(mut self, constants: StslConstants) -> Self {
+        self.stsl_constants = constants;
+        self
+    }
+
+    /// Constants of the STSL equation
+    pub fn stsl_constants(&self) -> &StslConstants {
+        &self.stsl_constants
+    }
+
+    /// Run `middleware` around each pipeline stage, after any registered before it
+    pub fn with_middleware(mut self, middleware: Arc<dyn PipelineMiddleware>) -> Self {
+        self.middleware.push(middleware);
+        self
+    }
+
+    /// Middleware run around each pipeline stage, in registration order
+    pub fn middleware(&self) -> &[Arc<dyn PipelineMiddleware>] {
+        &self.middleware
+    }
+
+    /// Set the number of problems whose oscillations are cached (at least one)
+    pub fn with_oscillation_cache_capacity(mut self, capacity: usize) -> Self {
+        self.oscillation_cache = Arc::new(Mutex::new(OscillationCache::new(capacity)));
+        self
+    }
+
+    /// Recompute oscillations on every mapping instead of caching them
+    pub fn with_oscillation_cache_bypass(mut self, bypass: bool) -> Self {
+        self.bypass_oscillation_cache = bypass;
+        self
+    }
+
+    /// Set the number of problems a batch transforms at once (at least one)
+    pub fn with_batch_concurrency(mut self, concurrency: usize) -> Self {
+        self.batch_concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Hit, miss, and eviction counts of the oscillation cache
+    pub fn oscillation_cache_stats(&self) -> OscillationCacheStats {
+        self.lock_oscillation_cache().stats()
+    }
+
+    /// Drop every cached oscillation mapping
+    pub fn clear_oscillation_cache(&self) {
+        self.lock_oscillation_cache().clear();
+    }
+
+    fn lock_oscillation_cache(&self) -> MutexGuard<'_, OscillationCache> {
+        self.oscillation_cache.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Extractor oscillation coordinates are derived with
+    pub fn feature_extractor(&self) -> &dyn ProblemFeatureExtractor {
+        self.feature_extractor.as_ref()
+    }
+
     /// Transform problem to oscillation endpoint space
+    ///
+    /// Features are extracted from the problem with whitespace runs collapsed (see
+    /// [`normalize_problem`]), the same form that keys the oscillation cache. Whitespace
+    /// variants of a problem therefore map to the same oscillations whether they are
+    /// served from the cache or computed, and whichever variant was cached first.
     pub async fn map_problem_to_oscillations(&self, problem: &str) -> SEntropyResult<Vector3<f64>> {
-        debug!("🔄 Mapping problem to oscillation space: {}", problem);
-
-        // Analyze problem characteristics
-        let complexity = problem.len() as f64;
-        let word_count = problem.split_whitespace().count() as f64;
-        let char_diversity = problem.chars().collect::<std::collections::HashSet<_>>().len() as f64;
-
-        // Map to oscillation endpoints
-        let oscillation_space = Vector3::new(
-            complexity.sqrt() / 10.0,    // Complexity oscillation
-            word_count.log10().max(0.1), // Semantic oscillation
-            char_diversity / 26.0,       // Diversity oscillation
-        );
+        let key = normalize_problem(problem);
+        if !self.bypass_oscillation_cache {
+            if let Some(cached) = self.lock_oscillation_cache().get(&key) {
+                debug!(
+                    target: subsystem::TRANSFORMER,
+                    problem,
+                    "oscillation mapping served from cache"
+                );
+                return Ok(cached);
+            }
+        }
+
+        let oscillation_space = self.feature_extractor.oscillations(&key)?;
+        if let Some(component) = oscillation_space.iter().find(|c| !c.is_finite()) {
+            return Err(SEntropyError::universal_transformation(
+                "oscillation_mapping",
+                format!(
+                    "{} extractor produced a non-finite component {}",
+                    self.feature_extractor.name(),
+                    component
+                ),
+            ));
+        }
+        if !self.bypass_oscillation_cache {
+            self.lock_oscillation_cache().insert(key, oscillation_space);
+        }
 
         debug!(
-            "📊 Oscillation mapping: complexity={:.3}, semantic={:.3}, diversity={:.3}",
-            oscillation_space[0], oscillation_space[1], oscillation_space[2]
+            target: subsystem::TRANSFORMER,
+            problem,
+            extractor = self.feature_extractor.name(),
+            first = oscillation_space[0],
+            second = oscillation_space[1],
+            third = oscillation_space[2],
+            "problem mapped to oscillation space"
         );
 
         Ok(oscillation_space)
@@ -64,42 +249,131 @@ impl STSLTransformer {
 
     /// Calculate oscillation amplitude endpoints (α for STSL equation)
     pub async fn calculate_alpha(&self, oscillation_space: &Vector3<f64>) -> SEntropyResult<f64> {
-        debug!("📐 Calculating oscillation amplitudes");
-
         // Calculate alpha as the magnitude of oscillation space vector
         let alpha = oscillation_space.norm().max(0.001); // Prevent log(0)
 
-        debug!("⚡ Alpha calculated: {:.6}", alpha);
+        debug!(target: subsystem::TRANSFORMER, alpha, "oscillation amplitude calculated");
         Ok(alpha)
     }
 
-    /// Apply STSL universal transformation: S = k × log(α)
+    /// Apply STSL universal transformation: S = k × log(α), with the default constant
     pub async fn apply_stsl_equation(&self, alpha: f64) -> SEntropyResult<f64> {
-        debug!("🧮 Applying STSL equation: S = k × log(α)");
+        self.apply_stsl_equation_with(self.stsl_constants.default_constant(), alpha)
+    }
+
+    /// Apply the STSL equation with the constant configured for `problem_class`
+    pub async fn apply_stsl_equation_for_class(
+        &self,
+        problem_class: &str,
+        alpha: f64,
+    ) -> SEntropyResult<f64> {
+        self.apply_stsl_equation_with(self.stsl_constants.constant_for(problem_class), alpha)
+    }
 
+    fn apply_stsl_equation_with(&self, k: f64, alpha: f64) -> SEntropyResult<f64> {
         if alpha <= 0.0 {
             return Err(SEntropyError::universal_transformation(
                 "STSL_equation", "Alpha must be positive for logarithm",
             ));
         }
+        if !alpha.is_finite() {
+            return Err(SEntropyError::universal_transformation(
+                "STSL_equation",
+                format!("Alpha must be finite, got {}", alpha),
+            ));
+        }
 
-        let s_coordinate = self.universal_constant * alpha.ln();
+        let s_coordinate = k * alpha.ln();
+        if !s_coordinate.is_finite() {
+            return Err(SEntropyError::universal_transformation(
+                "STSL_equation",
+                format!("S = {} × log({}) is not finite", k, alpha),
+            ));
+        }
 
         debug!(
-            "✨ STSL transformation complete: S = {} × ln({}) = {:.6}",
-            self.universal_constant, alpha, s_coordinate
+            target: subsystem::TRANSFORMER,
+            k,
+            alpha,
+            s_coordinate,
+            "STSL equation applied"
         );
 
         Ok(s_coordinate)
     }
 
+    /// STSL navigation table under this transformer's feature extractor and constants
+    ///
+    /// Each problem type's description is mapped like [`Self::transform_complete_pipeline`]
+    /// maps a problem, with the constant of the description's recognized class.
+    pub async fn navigation_table(&self) -> SEntropyResult<HashMap<String, f64>> {
+        let mut table = HashMap::new();
+        for (problem_type, description) in NAVIGATION_TABLE_PROBLEMS {
+            let oscillation = self.map_problem_to_oscillations(description).await?;
+            let alpha = self.calculate_alpha(&oscillation).await?;
+            let problem_class = recognize_problem_class(description);
+            let s_coord = self.apply_stsl_equation_for_class(&problem_class, alpha).await?;
+            table.insert(problem_type.to_string(), s_coord);
+
+            debug!(
+                target: subsystem::TRANSFORMER,
+                problem_type,
+                s_coordinate = s_coord,
+                "STSL navigation table entry"
+            );
+        }
+        Ok(table)
+    }
+
+    /// Explain `s_coordinate` by inverting the STSL equation
+    ///
+    /// `α = exp(S / k)` is reported for the default constant and as a range over every
+    /// per-class constant, alongside the entries of this transformer's
+    /// [`Self::navigation_table`] closest to `S`. The
+    /// sign of `S` is lost in navigation coordinates, so explain `-S` as well when `α`
+    /// may have been below one.
+    pub async fn explain(&self, s_coordinate: f64) -> SEntropyResult<StslExplanation> {
+        if !s_coordinate.is_finite() {
+            return Err(SEntropyError::universal_transformation(
+                "explain",
+                format!("S-coordinate must be finite, got {}", s_coordinate),
+            ));
+        }
+
+        let default = self.stsl_constants.default_constant();
+        let alpha = (s_coordinate / default).exp();
+        let alpha_range = self
+            .stsl_constants
+            .overrides()
+            .map(|(_, k)| (s_coordinate / k).exp())
+            .fold((alpha, alpha), |(low, high), a| (low.min(a), high.max(a)));
+
+        let mut nearest: Vec<NavigationTableMatch> = self
+            .navigation_table()
+            .await?
+            .into_iter()
+            .map(|(problem_type, entry)| NavigationTableMatch {
+                problem_type,
+                s_coordinate: entry,
+                distance: (entry - s_coordinate).abs(),
+            })
+            .collect();
+        // Ties broken by name, since the table's order is arbitrary
+        nearest.sort_by(|a, b| {
+            a.distance.total_cmp(&b.distance).then_with(|| a.problem_type.cmp(&b.problem_type))
+        });
+        nearest.truncate(EXPLANATION_TABLE_MATCHES);
+
+        let explanation = StslExplanation { s_coordinate, alpha, alpha_range, nearest };
+        debug!(target: subsystem::TRANSFORMER, %explanation, "S-coordinate explained");
+        Ok(explanation)
+    }
+
     /// Convert S-coordinate to navigation solution
     pub async fn navigate_to_solution_coordinates(
         &self,
         s_coordinate: f64,
     ) -> SEntropyResult<NavigationCoordinate> {
-        debug!("🧭 Converting S-coordinate to navigation solution");
-
         // Create navigation coordinate from S-value
         let nav_coord = NavigationCoordinate::new(
             Vector3::new(s_coordinate.abs(), 0.0, 0.0),
@@ -108,7 +382,12 @@ impl STSLTransformer {
             (1.0 / (1.0 + s_coordinate.abs())).max(0.1), // Higher confidence for lower S
         );
 
-        info!("✅ Navigation coordinate generated from S = {:.6}", s_coordinate);
+        debug!(
+            target: subsystem::TRANSFORMER,
+            s_coordinate,
+            navigation_coordinate_id = %nav_coord.id,
+            "navigation coordinate generated"
+        );
         Ok(nav_coord)
     }
 
@@ -117,19 +396,46 @@ impl STSLTransformer {
         &self,
         problem: &str,
     ) -> SEntropyResult<(NavigationCoordinate, String)> {
-        info!("🚀 Starting complete universal transformation pipeline");
+        let started = std::time::Instant::now();
 
         // Step 1: Map to oscillation space
-        let oscillation_space = self.map_problem_to_oscillations(problem).await?;
+        let oscillation_space = self
+            .run_stage(PipelineStage::OscillationMapping, problem, async {
+                Ok(StageValue::Oscillations(self.map_problem_to_oscillations(problem).await?))
+            })
+            .await?
+            .into_oscillations()
+            .expect("stage values are checked");
 
         // Step 2: Calculate alpha
-        let alpha = self.calculate_alpha(&oscillation_space).await?;
-
-        // Step 3: Apply STSL transformation
-        let s_coordinate = self.apply_stsl_equation(alpha).await?;
+        let alpha = self
+            .run_stage(PipelineStage::Alpha, problem, async {
+                Ok(StageValue::Alpha(self.calculate_alpha(&oscillation_space).await?))
+            })
+            .await?
+            .into_alpha()
+            .expect("stage values are checked");
+
+        // Step 3: Apply STSL transformation with the problem class's constant
+        let problem_class = recognize_problem_class(problem);
+        let s_coordinate = self
+            .run_stage(PipelineStage::Stsl, problem, async {
+                let s_coordinate = self.apply_stsl_equation_for_class(&problem_class, alpha).await?;
+                Ok(StageValue::SCoordinate(s_coordinate))
+            })
+            .await?
+            .into_s_coordinate()
+            .expect("stage values are checked");
 
         // Step 4: Generate navigation coordinate
-        let nav_coord = self.navigate_to_solution_coordinates(s_coordinate).await?;
+        let nav_coord = self
+            .run_stage(PipelineStage::Navigation, problem, async {
+                let nav_coord = self.navigate_to_solution_coordinates(s_coordinate).await?;
+                Ok(StageValue::Navigation(nav_coord))
+            })
+            .await?
+            .into_navigation()
+            .expect("stage values are checked");
 
         // Step 5: Extract solution
         let solution = format!(
@@ -141,9 +447,79 @@ impl STSLTransformer {
             nav_coord.confidence
         );
 
-        info!("🎉 Universal transformation complete: {} → S = {:.6}", problem, s_coordinate);
+        info!(
+            target: subsystem::TRANSFORMER,
+            problem,
+            problem_class = %problem_class,
+            alpha,
+            s_coordinate,
+            navigation_coordinate_id = %nav_coord.id,
+            duration_us = started.elapsed().as_micros() as u64,
+            "universal transformation complete"
+        );
         Ok((nav_coord, solution))
     }
+
+    /// Run every problem through the complete pipeline, concurrently, returning results
+    /// in problem order
+    ///
+    /// At most [`with_batch_concurrency`](Self::with_batch_concurrency) problems are
+    /// transformed at once. Each problem gets its own result, so one failed transformation
+    /// does not discard the rest of the batch.
+    pub async fn transform_batch<P: AsRef<str>>(
+        &self,
+        problems: &[P],
+    ) -> Vec<SEntropyResult<(NavigationCoordinate, String)>> {
+        let started = std::time::Instant::now();
+        let results: Vec<_> = stream::iter(problems)
+            .map(|problem| self.transform_complete_pipeline(problem.as_ref()))
+            .buffered(self.batch_concurrency)
+            .collect()
+            .await;
+
+        info!(
+            target: subsystem::TRANSFORMER,
+            batch_size = problems.len(),
+            failed = results.iter().filter(|result| result.is_err()).count(),
+            concurrency = self.batch_concurrency,
+            duration_us = started.elapsed().as_micros() as u64,
+            "transformation batch complete"
+        );
+        results
+    }
+
+    /// Run `stage` on `problem` through the middleware, computing it with `compute` unless
+    /// a `before` hook supplies the value
+    async fn run_stage(
+        &self,
+        stage: PipelineStage,
+        problem: &str,
+        compute: impl Future<Output = SEntropyResult<StageValue>>,
+    ) -> SEntropyResult<StageValue> {
+        let mut supplied = None;
+        for middleware in &self.middleware {
+            if let Some(value) = middleware.before(stage, problem)? {
+                debug!(
+                    target: subsystem::TRANSFORMER,
+                    stage = stage.as_str(),
+                    middleware = middleware.name(),
+                    "pipeline stage supplied by middleware"
+                );
+                supplied = Some(check_stage(middleware.as_ref(), stage, value)?);
+                break;
+            }
+        }
+
+        let mut value = match supplied {
+            Some(value) => value,
+            None => compute.await?,
+        };
+        for middleware in &self.middleware {
+            middleware.after(problem, &mut value)?;
+            value = check_stage(middleware.as_ref(), stage, value)?;
+        }
+        Ok(value)
+    }
 }
 
 impl Default for STSLTransformer {
@@ -218,70 +594,28 @@ pub async fn quick_stsl_transform(problem: &str) -> SEntropyResult<f64> {
 }
 
 /// Helper function for universal problem class recognition
+///
+/// Ranks the built-in classes of [`ProblemClassRegistry`]; register custom classes on a
+/// registry of your own to recognize others.
 pub fn recognize_problem_class(problem: &str) -> String {
-    let problem_lower = problem.to_lowercase();
-
-    if problem_lower.contains("cognitive")
-        || problem_lower.contains("mind")
-        || problem_lower.contains("thought")
-    {
-        "Cognitive Pattern Selection".to_string()
-    } else if problem_lower.contains("time")
-        || problem_lower.contains("temporal")
-        || problem_lower.contains("sync")
-    {
-        "Temporal Coordination".to_string()
-    } else if problem_lower.contains("communication")
-        || problem_lower.contains("message")
-        || problem_lower.contains("signal")
-    {
-        "Communication Optimization".to_string()
-    } else if problem_lower.contains("domain")
-        || problem_lower.contains("transfer")
-        || problem_lower.contains("cross")
-    {
-        "Cross-Domain Transfer".to_string()
-    } else if problem_lower.contains("memory")
-        || problem_lower.contains("storage")
-        || problem_lower.contains("cache")
-    {
-        "Memory Optimization".to_string()
-    } else {
-        "General Problem".to_string()
-    }
+    static BUILTIN_CLASSES: OnceLock<ProblemClassRegistry> = OnceLock::new();
+    BUILTIN_CLASSES.get_or_init(ProblemClassRegistry::default).recognize(problem)
 }
 
 /// Generate STSL navigation table for different problem types
 pub async fn generate_stsl_navigation_table() -> SEntropyResult<HashMap<String, f64>> {
-    let transformer = STSLTransformer::new();
-    let mut table = HashMap::new();
-
-    let problem_types = vec![
-        ("cognitive pattern selection", "Neural oscillation endpoint patterns"),
-        ("temporal coordination", "Temporal oscillation synchronization"),
-        ("communication optimization", "Information oscillation harmonics"),
-        ("cross domain transfer", "Universal oscillation resonance"),
-        ("memory optimization", "Memory oscillation compression"),
-    ];
-
-    for (problem_type, description) in problem_types {
-        let s_coord = quick_stsl_transform(description).await?;
-        table.insert(problem_type.to_string(), s_coord);
-
-        info!("📊 STSL Navigation: {} → S = {:.6}", problem_type, s_coord);
-    }
-
-    Ok(table)
+    STSLTransformer::new().navigation_table().await
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::problem_features::TfIdfExtractor;
 
     #[tokio::test]
     async fn test_stsl_transformer_creation() {
         let transformer = STSLTransformer::new();
-        assert_eq!(transformer.universal_constant, crate::STSL_UNIVERSAL_CONSTANT);
+        assert_eq!(transformer.stsl_constants.default_constant(), crate::STSL_UNIVERSAL_CONSTANT);
         assert!(transformer.validates_memorial());
     }
 
@@ -295,6 +629,195 @@ mod tests {
         assert!(oscillation[2] > 0.0); // Diversity component
     }
 
+    #[tokio::test]
+    async fn test_pluggable_feature_extraction() {
+        let extractor = TfIdfExtractor::new().fit(["solve consciousness", "solve entropy"]);
+        let transformer = STSLTransformer::new().with_feature_extractor(Arc::new(extractor));
+        assert_eq!(transformer.feature_extractor().name(), "tf_idf");
+
+        let first = transformer.map_problem_to_oscillations("silent night").await.unwrap();
+        let anagram = transformer.map_problem_to_oscillations("listen thing").await.unwrap();
+        assert_ne!(first, anagram);
+        assert!(transformer.transform_complete_pipeline("solve consciousness").await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_per_class_stsl_constants() {
+        let constants = StslConstants::default().with_class("Temporal Coordination", 3.0).unwrap();
+        let transformer = STSLTransformer::new().with_stsl_constants(constants);
+        let universal = STSLTransformer::new();
+
+        // Only problems of the overridden class are scaled
+        let temporal = "temporal precision problem";
+        let (scaled, _) = transformer.transform_complete_pipeline(temporal).await.unwrap();
+        let (unscaled, _) = universal.transform_complete_pipeline(temporal).await.unwrap();
+        let s = |coordinate: &NavigationCoordinate| coordinate.knowledge_position.x;
+        assert!((s(&scaled) - 3.0 * s(&unscaled)).abs() < 1e-12);
+
+        let general = "solve consciousness";
+        let (kept, _) = transformer.transform_complete_pipeline(general).await.unwrap();
+        let (reference, _) = universal.transform_complete_pipeline(general).await.unwrap();
+        assert_eq!(s(&kept), s(&reference));
+        assert_eq!(transformer.apply_stsl_equation(2.0).await.unwrap(), 2.0f64.ln());
+    }
+
+    #[tokio::test]
+    async fn test_explain_inverts_stsl_equation() {
+        let transformer = STSLTransformer::new();
+        let problem = "Memory oscillation compression";
+        let oscillation = transformer.map_problem_to_oscillations(problem).await.unwrap();
+        let alpha = transformer.calculate_alpha(&oscillation).await.unwrap();
+        let s_coordinate = transformer.apply_stsl_equation(alpha).await.unwrap();
+
+        let explanation = transformer.explain(s_coordinate).await.unwrap();
+        assert!((explanation.alpha - alpha).abs() < 1e-12);
+        assert_eq!(explanation.alpha_range, (explanation.alpha, explanation.alpha));
+        assert_eq!(explanation.nearest.len(), EXPLANATION_TABLE_MATCHES);
+        let closest = explanation.closest().unwrap();
+        assert_eq!(closest.problem_type, "memory optimization");
+        assert!(closest.distance < 1e-12);
+        assert!(explanation.to_string().contains("memory optimization"));
+        assert!(transformer.explain(f64::NAN).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_explain_spans_per_class_constants() {
+        let constants = StslConstants::default().with_class("Memory Optimization", 2.0).unwrap();
+        let transformer = STSLTransformer::new().with_stsl_constants(constants);
+
+        let explanation = transformer.explain(2.0).await.unwrap();
+        assert!((explanation.alpha - 2.0f64.exp()).abs() < 1e-12);
+        assert!((explanation.alpha_range.0 - 1.0f64.exp()).abs() < 1e-12);
+        assert_eq!(explanation.alpha_range.1, explanation.alpha);
+        assert!(explanation.nearest.windows(2).all(|w| w[0].distance <= w[1].distance));
+    }
+
+    #[tokio::test]
+    async fn test_explain_uses_own_constants_and_extractor() {
+        let description = "Memory oscillation compression";
+        let problem_class = recognize_problem_class(description);
+        let constants = StslConstants::default().with_class(problem_class.as_str(), 2.5).unwrap();
+        let extractor = TfIdfExtractor::new().fit(["memory compression", "temporal precision"]);
+        let transformer = STSLTransformer::new()
+            .with_stsl_constants(constants)
+            .with_feature_extractor(Arc::new(extractor));
+
+        let oscillation = transformer.map_problem_to_oscillations(description).await.unwrap();
+        let alpha = transformer.calculate_alpha(&oscillation).await.unwrap();
+        let s_coordinate =
+            transformer.apply_stsl_equation_for_class(&problem_class, alpha).await.unwrap();
+        let default_table = generate_stsl_navigation_table().await.unwrap();
+        assert_ne!(default_table["memory optimization"], s_coordinate);
+
+        let explanation = transformer.explain(s_coordinate).await.unwrap();
+        let closest = explanation.closest().unwrap();
+        assert_eq!(closest.problem_type, "memory optimization");
+        assert!(closest.distance < 1e-12);
+    }
+
+    #[derive(Debug, Default)]
+    struct StageRecorder {
+        seen: Mutex<Vec<PipelineStage>>,
+    }
+
+    impl PipelineMiddleware for StageRecorder {
+        fn name(&self) -> &'static str {
+            "stage_recorder"
+        }
+
+        fn after(&self, _problem: &str, value: &mut StageValue) -> SEntropyResult<()> {
+            self.seen.lock().unwrap().push(value.stage());
+            // Rewrite the S-coordinate to a fixed value
+            if let StageValue::SCoordinate(s_coordinate) = value {
+                *s_coordinate = 4.0;
+            }
+            Ok(())
+        }
+    }
+
+    #[derive(Debug)]
+    struct RejectEmpty;
+
+    impl PipelineMiddleware for RejectEmpty {
+        fn name(&self) -> &'static str {
+            "reject_empty"
+        }
+
+        fn before(
+            &self,
+            stage: PipelineStage,
+            problem: &str,
+        ) -> SEntropyResult<Option<StageValue>> {
+            if problem.trim().is_empty() {
+                return Err(SEntropyError::universal_transformation(stage.as_str(), "empty"));
+            }
+            // An S-coordinate supplied for the alpha stage is rejected
+            if problem == "mismatched" && stage == PipelineStage::Alpha {
+                return Ok(Some(StageValue::SCoordinate(0.0)));
+            }
+            Ok(None)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_pipeline_middleware() {
+        let recorder = Arc::new(StageRecorder::default());
+        let transformer = STSLTransformer::new()
+            .with_middleware(Arc::new(RejectEmpty))
+            .with_middleware(recorder.clone());
+        assert_eq!(transformer.middleware().len(), 2);
+
+        let (navigation, _) = transformer.transform_complete_pipeline("solve it").await.unwrap();
+        assert_eq!(navigation.knowledge_position.x, 4.0);
+        assert_eq!(
+            *recorder.seen.lock().unwrap(),
+            vec![
+                PipelineStage::OscillationMapping,
+                PipelineStage::Alpha,
+                PipelineStage::Stsl,
+                PipelineStage::Navigation,
+            ]
+        );
+
+        assert!(transformer.transform_complete_pipeline("  ").await.is_err());
+        assert!(transformer.transform_complete_pipeline("mismatched").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_oscillation_cache() {
+        let transformer = STSLTransformer::new().with_oscillation_cache_capacity(2);
+        let first = transformer.map_problem_to_oscillations("temporal precision").await.unwrap();
+        let again = transformer.map_problem_to_oscillations(" temporal  precision").await.unwrap();
+        assert_eq!(first, again);
+
+        let stats = transformer.oscillation_cache_stats();
+        assert_eq!((stats.hits, stats.misses, stats.len, stats.capacity), (1, 1, 1, 2));
+
+        // Bypassing neither reads nor fills the cache, but maps problems the same way
+        let bypassing = transformer.clone().with_oscillation_cache_bypass(true);
+        let uncached = bypassing.map_problem_to_oscillations("temporal precision").await.unwrap();
+        assert_eq!(uncached, first);
+        assert_eq!(transformer.oscillation_cache_stats(), stats);
+
+        // A new extractor starts an empty cache of the same capacity
+        let refitted = transformer.with_feature_extractor(Arc::new(TfIdfExtractor::new()));
+        assert_eq!(refitted.oscillation_cache_stats().len, 0);
+        assert_eq!(refitted.oscillation_cache_stats().capacity, 2);
+    }
+
+    #[tokio::test]
+    async fn test_whitespace_variants_map_identically_without_cache() {
+        // Normalization changes results on purpose: a cache keyed by the normalized
+        // problem can only be transparent if features are computed from that key too
+        let transformer = STSLTransformer::new().with_oscillation_cache_bypass(true);
+        let normalized =
+            transformer.map_problem_to_oscillations("temporal precision").await.unwrap();
+        for variant in ["  temporal precision", "temporal\tprecision\n", "temporal   precision"] {
+            let mapped = transformer.map_problem_to_oscillations(variant).await.unwrap();
+            assert_eq!(mapped, normalized, "{:?}", variant);
+        }
+    }
+
     #[tokio::test]
     async fn test_alpha_calculation() {
         let transformer = STSLTransformer::new();
@@ -326,6 +849,30 @@ mod tests {
         assert!(solution.contains("consciousness"));
     }
 
+    #[tokio::test]
+    async fn test_transform_batch_keeps_problem_order() {
+        let transformer = STSLTransformer::new().with_batch_concurrency(2);
+        let problems = ["temporal synchronization", "memory optimization", "general question"];
+        let results = transformer.transform_batch(&problems).await;
+
+        assert_eq!(results.len(), problems.len());
+        for (problem, result) in problems.iter().zip(results) {
+            let (_, solution) = result.unwrap();
+            let (_, expected) = transformer.transform_complete_pipeline(problem).await.unwrap();
+            assert_eq!(solution, expected);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_non_finite_stsl_inputs_are_rejected() {
+        let transformer = STSLTransformer::new();
+        for alpha in [f64::NAN, f64::INFINITY, -1.0, 0.0] {
+            assert!(transformer.apply_stsl_equation(alpha).await.is_err(), "alpha {}", alpha);
+        }
+        assert!(transformer.apply_stsl_equation(f64::MAX).await.unwrap().is_finite());
+        assert!(transformer.apply_stsl_equation(f64::MIN_POSITIVE).await.unwrap().is_finite());
+    }
+
     #[tokio::test]
     async fn test_quick_stsl_transform() {
         let s_coord = quick_stsl_transform("temporal precision problem").await.unwrap();
@@ -0,0 +1,125 @@
+//! Golden snapshots of S-entropy measurement, STSL transformation, and navigation
+//!
+//! Each test runs a fixed corpus through the public API and compares the numerical
+//! outputs against `tests/snapshots/*.json`. Re-baseline intended changes with
+//! `make snapshot-update`.
+
+#![cfg(all(feature = "async", feature = "serde"))]
+
+use hugure_core::navigation::ManifoldNavigator;
+use hugure_core::s_entropy::SEntropyEngine;
+use hugure_core::snapshot::SnapshotStore;
+use hugure_core::types::{ObserverSophistication, SEntropyPrecision};
+use hugure_core::universal_transformer::{recognize_problem_class, STSLTransformer};
+use serde_json::{json, Value};
+
+/// Measurement inputs: context, observer, temporal precision, emotional factor,
+/// problem complexity, accessibility
+const MEASUREMENT_CORPUS: &[(&str, ObserverSophistication, f64, f64, f64, f64)] = &[
+    ("demonstration_problem", ObserverSophistication::Expert, 1e-30, 0.3, 1.0, 0.8),
+    ("analysis_problem_0", ObserverSophistication::Naive, 1e-15, 0.1, 2.0, 0.4),
+    ("x", ObserverSophistication::Universal, 1e-30, 0.0, 1.0, 0.95),
+    (
+        "temporal coordination across domains",
+        ObserverSophistication::Intermediate,
+        1e-20,
+        0.5,
+        5.0,
+        0.6,
+    ),
+];
+
+/// Problems for STSL transformation
+const STSL_CORPUS: &[&str] = &[
+    "solve consciousness",
+    "temporal precision problem",
+    "Neural oscillation endpoint patterns",
+    "cross domain memory cache transfer",
+    "a",
+];
+
+fn store() -> SnapshotStore {
+    SnapshotStore::new(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/snapshots"))
+}
+
+#[tokio::test]
+async fn golden_measurements() {
+    let engine = SEntropyEngine::new(SEntropyPrecision::Ultra);
+    let mut records = Vec::new();
+
+    for &(context, observer, temporal, emotional, complexity, accessibility) in MEASUREMENT_CORPUS
+    {
+        let measurement = engine
+            .generate_measurement(context, observer, temporal, emotional, complexity, accessibility)
+            .await
+            .unwrap();
+
+        records.push(json!({
+            "context": context,
+            "observer": observer,
+            "s_knowledge": measurement.s_knowledge,
+            "s_time": measurement.s_time,
+            "s_entropy": measurement.s_entropy,
+            "total_magnitude": measurement.total_magnitude,
+            "optimal_integration": measurement.optimal_integration,
+        }));
+    }
+
+    store().assert_matches("measurements", &records);
+}
+
+#[tokio::test]
+async fn golden_stsl_transformations() {
+    let transformer = STSLTransformer::new();
+    let mut records = Vec::new();
+
+    for &problem in STSL_CORPUS {
+        let oscillation = transformer.map_problem_to_oscillations(problem).await.unwrap();
+        let alpha = transformer.calculate_alpha(&oscillation).await.unwrap();
+        let s_coordinate = transformer.apply_stsl_equation(alpha).await.unwrap();
+        let (navigation, _) = transformer.transform_complete_pipeline(problem).await.unwrap();
+
+        records.push(json!({
+            "problem": problem,
+            "problem_class": recognize_problem_class(problem),
+            "oscillation": [oscillation[0], oscillation[1], oscillation[2]],
+            "alpha": alpha,
+            "s_coordinate": s_coordinate,
+            "confidence": navigation.confidence,
+        }));
+    }
+
+    store().assert_matches("stsl", &records);
+}
+
+#[tokio::test]
+async fn golden_navigation() {
+    let engine = SEntropyEngine::new(SEntropyPrecision::Ultra);
+    let navigator = ManifoldNavigator::new(SEntropyPrecision::Ultra);
+    let mut records = Vec::new();
+
+    for &(context, observer, temporal, emotional, complexity, accessibility) in MEASUREMENT_CORPUS
+    {
+        let measurement = engine
+            .generate_measurement(context, observer, temporal, emotional, complexity, accessibility)
+            .await
+            .unwrap();
+        let (s_knowledge, s_time, s_entropy) =
+            (measurement.s_knowledge, measurement.s_time, measurement.s_entropy);
+        let aligned = engine.align_tri_dimensional(s_knowledge, s_time, s_entropy).await.unwrap();
+        let navigation = navigator.navigate_to_coordinates(&aligned).await.unwrap();
+
+        let position = |v: &nalgebra::Vector3<f64>| -> Value { json!([v[0], v[1], v[2]]) };
+        records.push(json!({
+            "context": context,
+            "aligned": [aligned.s_knowledge, aligned.s_time, aligned.s_entropy],
+            "knowledge_position": position(&navigation.knowledge_position),
+            "temporal_position": position(&navigation.temporal_position),
+            "entropy_position": position(&navigation.entropy_position),
+            "confidence": navigation.confidence,
+            "total_distance": navigation.total_distance(),
+        }));
+    }
+
+    store().assert_matches("navigation", &records);
+}
@@ -0,0 +1,47 @@
+//! Arbitrary contexts and extreme floats through S-entropy measurement
+//!
+//! A measurement may be rejected, but one that is returned must have finite components
+//! and a finite, non-negative magnitude.
+
+#![no_main]
+
+use hugure_core::core_types::{ObserverSophistication, SEntropyPrecision};
+use hugure_core::s_entropy::SEntropyEngine;
+use libfuzzer_sys::arbitrary::{self, Arbitrary};
+use libfuzzer_sys::fuzz_target;
+
+#[derive(Debug, Arbitrary)]
+struct Input<'a> {
+    problem_context: &'a str,
+    observer: u8,
+    temporal_precision: f64,
+    emotional_factor: f64,
+    problem_complexity: f64,
+    accessibility: f64,
+}
+
+const OBSERVERS: [ObserverSophistication; 4] = [
+    ObserverSophistication::Naive,
+    ObserverSophistication::Intermediate,
+    ObserverSophistication::Expert,
+    ObserverSophistication::Universal,
+];
+
+fuzz_target!(|input: Input<'_>| {
+    let engine = SEntropyEngine::new(SEntropyPrecision::Ultra);
+    let measurement = futures::executor::block_on(engine.generate_measurement(
+        input.problem_context,
+        OBSERVERS[input.observer as usize % OBSERVERS.len()],
+        input.temporal_precision,
+        input.emotional_factor,
+        input.problem_complexity,
+        input.accessibility,
+    ));
+    if let Ok(m) = measurement {
+        for component in [m.s_knowledge, m.s_time, m.s_entropy] {
+            assert!(component.is_finite(), "{:?} -> {:?}", input, m);
+        }
+        let magnitude = m.total_magnitude;
+        assert!(magnitude.is_finite() && magnitude >= 0.0, "{:?} -> {:?}", input, m);
+    }
+});
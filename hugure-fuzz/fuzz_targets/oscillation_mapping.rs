@@ -0,0 +1,17 @@
+//! Arbitrary problem text mapped to oscillation space
+//!
+//! Mapping may reject a problem, but a mapping it returns must be finite.
+
+#![no_main]
+
+use hugure_core::universal_transformer::STSLTransformer;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|problem: &str| {
+    let transformer = STSLTransformer::new();
+    if let Ok(oscillations) =
+        futures::executor::block_on(transformer.map_problem_to_oscillations(problem))
+    {
+        assert!(oscillations.iter().all(|c| c.is_finite()), "{:?} -> {:?}", problem, oscillations);
+    }
+});
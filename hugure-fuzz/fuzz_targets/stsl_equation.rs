@@ -0,0 +1,13 @@
+//! Arbitrary amplitudes, including NaN, infinities, and subnormals, through S = k × log(α)
+
+#![no_main]
+
+use hugure_core::universal_transformer::STSLTransformer;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|alpha: f64| {
+    let transformer = STSLTransformer::new();
+    if let Ok(s) = futures::executor::block_on(transformer.apply_stsl_equation(alpha)) {
+        assert!(s.is_finite(), "alpha {} -> S {}", alpha, s);
+    }
+});
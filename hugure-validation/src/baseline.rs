@@ -0,0 +1,256 @@
+//! Golden-baseline regression validation
+//!
+//! [`record`] runs a canonical set of measurements, integration attempts, and STSL
+//! transformations on a seeded engine and collects their numerical results. A
+//! [`Baseline`] stores those results from a known-good release; [`Baseline::compare`]
+//! reports every value that has since drifted beyond the baseline's tolerance.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+use std::time::Instant;
+
+use anyhow::{Context, Result};
+use hugure_core::core_types::{ObserverSophistication, SEntropyPrecision};
+use hugure_core::error::SEntropyResult;
+use hugure_core::s_entropy::SEntropyEngine;
+use hugure_core::universal_transformer::STSLTransformer;
+use hugure_core::S_ENTROPY_PRECISION_TARGET;
+use serde::{Deserialize, Serialize};
+
+use crate::CheckReport;
+
+/// Seed of the integration random source in canonical runs
+pub const BASELINE_SEED: u64 = 0x5354_454c_4c41;
+
+/// Relative tolerance of new baselines
+pub const DEFAULT_TOLERANCE: f64 = 1e-9;
+
+/// Problems measured and transformed in canonical runs
+const PROBLEMS: [&str; 3] = [
+    "temporal synchronization across distributed clocks",
+    "memory optimization of disposable patterns",
+    "cross-domain navigation of predetermined solutions",
+];
+
+/// Observer sophistication levels measured in canonical runs
+const OBSERVERS: [ObserverSophistication; 4] = [
+    ObserverSophistication::Naive,
+    ObserverSophistication::Intermediate,
+    ObserverSophistication::Expert,
+    ObserverSophistication::Universal,
+];
+
+/// Target separations of canonical integration attempts
+const INTEGRATION_TARGETS: [f64; 4] = [1.0, 0.1, 0.01, 0.001];
+
+/// Stored results of a canonical run
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Baseline {
+    /// Seed the results were produced with
+    pub seed: u64,
+
+    /// Largest relative difference, against the baseline value, that is not drift
+    pub tolerance: f64,
+
+    /// Results by name, e.g. `measure/expert/0/s_time`
+    pub values: BTreeMap<String, f64>,
+}
+
+/// A result that no longer matches the baseline
+#[derive(Debug, Clone, PartialEq)]
+pub enum Drift {
+    /// Result in the baseline that the canonical run no longer produces
+    Missing { name: String },
+
+    /// Result the canonical run produces that the baseline lacks
+    Unexpected { name: String, actual: f64 },
+
+    /// Result outside the tolerance of its baseline value
+    Changed { name: String, expected: f64, actual: f64 },
+}
+
+impl fmt::Display for Drift {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Missing { name } => write!(f, "{} missing", name),
+            Self::Unexpected { name, actual } => {
+                write!(f, "{} = {:e} not in baseline", name, actual)
+            },
+            Self::Changed { name, expected, actual } => {
+                write!(f, "{} = {:e}, baseline {:e}", name, actual, expected)
+            },
+        }
+    }
+}
+
+impl Baseline {
+    /// Record a new baseline from the current build
+    pub async fn record() -> SEntropyResult<Self> {
+        let values = record(BASELINE_SEED).await?;
+        Ok(Self { seed: BASELINE_SEED, tolerance: DEFAULT_TOLERANCE, values })
+    }
+
+    /// Read a baseline written by [`save`](Self::save)
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let json = std::fs::read_to_string(path)
+            .with_context(|| format!("reading baseline {}", path.display()))?;
+        serde_json::from_str(&json).with_context(|| format!("parsing baseline {}", path.display()))
+    }
+
+    /// Write the baseline as pretty-printed JSON
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        std::fs::write(path, serde_json::to_string_pretty(self)? + "\n")
+            .with_context(|| format!("writing baseline {}", path.display()))
+    }
+
+    /// Every result in `actual` that drifted from this baseline, in name order
+    pub fn compare(&self, actual: &BTreeMap<String, f64>) -> Vec<Drift> {
+        let mut drifts: Vec<Drift> = self
+            .values
+            .iter()
+            .filter_map(|(name, &expected)| match actual.get(name) {
+                None => Some(Drift::Missing { name: name.clone() }),
+                Some(&actual) if !within(expected, actual, self.tolerance) => {
+                    Some(Drift::Changed { name: name.clone(), expected, actual })
+                },
+                Some(_) => None,
+            })
+            .collect();
+        drifts.extend(
+            actual
+                .iter()
+                .filter(|(name, _)| !self.values.contains_key(*name))
+                .map(|(name, &actual)| Drift::Unexpected { name: name.clone(), actual }),
+        );
+        drifts.sort_by(|a, b| a.name().cmp(b.name()));
+        drifts
+    }
+}
+
+impl Drift {
+    /// Name of the drifted result
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Missing { name } | Self::Unexpected { name, .. } | Self::Changed { name, .. } => {
+                name
+            },
+        }
+    }
+}
+
+/// Whether `actual` is within relative `tolerance` of `expected`
+///
+/// Values near zero are compared absolutely. A NaN result is always drift.
+fn within(expected: f64, actual: f64, tolerance: f64) -> bool {
+    expected == actual || (expected - actual).abs() <= tolerance * expected.abs().max(1.0)
+}
+
+/// Results of the canonical run with integration seed `seed`, by name
+pub async fn record(seed: u64) -> SEntropyResult<BTreeMap<String, f64>> {
+    let mut values = BTreeMap::new();
+
+    let engine = SEntropyEngine::new(SEntropyPrecision::Ultra).with_rng_seed(seed);
+    for observer in OBSERVERS {
+        for (i, problem) in PROBLEMS.iter().enumerate() {
+            let complexity = 1.0 + i as f64;
+            let m = engine
+                .generate_measurement(
+                    *problem,
+                    observer,
+                    S_ENTROPY_PRECISION_TARGET,
+                    0.3,
+                    complexity,
+                    0.8,
+                )
+                .await?;
+            let prefix = format!("measure/{}/{}", observer.as_str(), i);
+            values.insert(format!("{}/s_knowledge", prefix), m.s_knowledge);
+            values.insert(format!("{}/s_time", prefix), m.s_time);
+            values.insert(format!("{}/s_entropy", prefix), m.s_entropy);
+            values.insert(format!("{}/total_magnitude", prefix), m.total_magnitude);
+        }
+    }
+
+    for target in INTEGRATION_TARGETS {
+        engine.attempt_integration(target).await?;
+    }
+    for (i, attempt) in engine.integration_attempts().await.iter().enumerate() {
+        values.insert(format!("integrate/{}/achieved_separation", i), attempt.achieved_separation);
+    }
+
+    let transformer = STSLTransformer::new();
+    for (i, problem) in PROBLEMS.iter().enumerate() {
+        let (navigation, _) = transformer.transform_complete_pipeline(problem).await?;
+        values.insert(format!("transform/{}/confidence", i), navigation.confidence);
+        values.insert(format!("transform/{}/total_distance", i), navigation.total_distance());
+    }
+
+    Ok(values)
+}
+
+/// Run the canonical run against the baseline at `path` and report the drift
+///
+/// `tolerance` overrides the baseline's own.
+pub async fn check(path: &Path, tolerance: Option<f64>) -> CheckReport {
+    let started = Instant::now();
+    let failure = match compare_with(path, tolerance).await {
+        Ok(drifts) if drifts.is_empty() => None,
+        Ok(drifts) => Some(format!(
+            "{} results drifted: {}",
+            drifts.len(),
+            drifts.iter().map(ToString::to_string).collect::<Vec<_>>().join("; ")
+        )),
+        Err(e) => Some(format!("{:#}", e)),
+    };
+    CheckReport {
+        name: "baseline",
+        description: "Canonical results match the stored baseline",
+        duration: started.elapsed(),
+        failure,
+    }
+}
+
+async fn compare_with(path: &Path, tolerance: Option<f64>) -> Result<Vec<Drift>> {
+    let mut baseline = Baseline::load(path)?;
+    if let Some(tolerance) = tolerance {
+        baseline.tolerance = tolerance;
+    }
+    Ok(baseline.compare(&record(baseline.seed).await?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_canonical_runs_are_reproducible() {
+        let baseline = Baseline::record().await.unwrap();
+        assert!(!baseline.values.is_empty());
+        assert_eq!(baseline.compare(&record(BASELINE_SEED).await.unwrap()), []);
+    }
+
+    #[test]
+    fn test_drift_beyond_tolerance_is_reported() {
+        let baseline = Baseline {
+            seed: BASELINE_SEED,
+            tolerance: 1e-6,
+            values: [("a", 1.0), ("b", 2.0), ("c", 0.0)]
+                .map(|(name, value)| (name.to_string(), value))
+                .into(),
+        };
+        let actual = [("a", 1.0 + 1e-9), ("b", 2.1), ("d", 4.0)]
+            .map(|(name, value)| (name.to_string(), value))
+            .into();
+        assert_eq!(
+            baseline.compare(&actual),
+            [
+                Drift::Changed { name: "b".into(), expected: 2.0, actual: 2.1 },
+                Drift::Missing { name: "c".into() },
+                Drift::Unexpected { name: "d".into(), actual: 4.0 },
+            ]
+        );
+    }
+}
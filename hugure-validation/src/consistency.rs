@@ -0,0 +1,281 @@
+//! Consistency of definitions shared between crates
+//!
+//! The root `hugure` crate is not among the default workspace members and this crate does
+//! not depend on it, so these checks read the shared definitions from the source tree
+//! instead. [`check`] compares hugure-core's memorial significance and precision target
+//! with the workspace metadata and hugure-consciousness, and the fields of every struct
+//! that more than one crate defines, reporting each disagreement as drift.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use hugure_core::core_types::SEntropyPrecision;
+use hugure_core::{MEMORIAL_SIGNIFICANCE, S_ENTROPY_PRECISION_TARGET};
+
+use crate::{CheckReport, CheckResult};
+
+/// Source directories of the crates compared, relative to the source root
+const CRATES: [&str; 3] = ["src", "hugure-core/src", "hugure-consciousness/src"];
+
+/// Workspace source root when the binary runs from a checkout
+pub fn default_source_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+/// Run every consistency check against the source tree at `root`
+pub fn check(root: &Path) -> Vec<CheckReport> {
+    [
+        (
+            "consistency-memorial-significance",
+            "Every crate and the workspace metadata agree on the memorial significance",
+            memorial_significance as fn(&Path) -> CheckResult,
+        ),
+        (
+            "consistency-precision-target",
+            "The precision target agrees with Ultra precision and the workspace metadata",
+            precision_target,
+        ),
+        (
+            "consistency-shared-types",
+            "Structs defined in more than one crate agree on the types of their shared fields",
+            shared_types,
+        ),
+    ]
+    .into_iter()
+    .map(|(name, description, run)| {
+        let started = Instant::now();
+        let failure = run(root).err();
+        CheckReport { name, description, duration: started.elapsed(), failure }
+    })
+    .collect()
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    std::fs::read_to_string(path).map_err(|e| format!("reading {}: {}", path.display(), e))
+}
+
+/// Join `drifts` into a failure, or pass when there are none
+fn drift(drifts: Vec<String>) -> CheckResult {
+    if drifts.is_empty() {
+        return Ok(());
+    }
+    Err(format!("{} definitions drifted: {}", drifts.len(), drifts.join("; ")))
+}
+
+/// Value of `key` in the `[workspace.metadata.hugure]` table of `manifest`
+fn metadata_value<'a>(manifest: &'a str, key: &str) -> Option<&'a str> {
+    manifest
+        .lines()
+        .map(str::trim)
+        .skip_while(|line| *line != "[workspace.metadata.hugure]")
+        .skip(1)
+        .take_while(|line| !line.starts_with('['))
+        .filter_map(|line| line.split_once('='))
+        .find(|(name, _)| name.trim() == key)
+        .map(|(_, value)| value.trim().trim_matches('"'))
+}
+
+fn memorial_significance(root: &Path) -> CheckResult {
+    let mut drifts = Vec::new();
+
+    let manifest = read(&root.join("Cargo.toml"))?;
+    match metadata_value(&manifest, "memorial-significance") {
+        Some(value) if value == MEMORIAL_SIGNIFICANCE => {},
+        value => drifts.push(format!("workspace metadata has {:?}", value)),
+    }
+
+    let binary = read(&root.join("hugure-consciousness/src/main.rs"))?;
+    let announced = binary
+        .split_once("Memorial significance: ")
+        .and_then(|(_, rest)| rest.split('"').next());
+    match announced {
+        Some(value) if value == MEMORIAL_SIGNIFICANCE => {},
+        value => drifts.push(format!("hugure-consciousness announces {:?}", value)),
+    }
+
+    drift(drifts)
+}
+
+fn precision_target(root: &Path) -> CheckResult {
+    let mut drifts = Vec::new();
+
+    let ultra = SEntropyPrecision::Ultra.threshold();
+    if ultra != S_ENTROPY_PRECISION_TARGET {
+        drifts.push(format!(
+            "Ultra precision threshold {:e} differs from the target {:e}",
+            ultra, S_ENTROPY_PRECISION_TARGET
+        ));
+    }
+
+    let manifest = read(&root.join("Cargo.toml"))?;
+    let target = metadata_value(&manifest, "temporal-precision-target");
+    match target.map(str::parse::<f64>) {
+        Some(Ok(value)) if value == S_ENTROPY_PRECISION_TARGET => {},
+        _ => drifts.push(format!("workspace metadata has target {:?}", target)),
+    }
+
+    drift(drifts)
+}
+
+/// Fields of a struct by name, with their normalized types
+type Fields = BTreeMap<String, String>;
+
+fn shared_types(root: &Path) -> CheckResult {
+    let mut definitions: BTreeMap<String, Vec<(&str, Fields)>> = BTreeMap::new();
+    for krate in CRATES {
+        let mut files = Vec::new();
+        rust_files(&root.join(krate), &mut files)?;
+        let mut structs = BTreeMap::new();
+        for file in files {
+            // The first definition of a name in a crate stands for the crate
+            for (name, fields) in structs_in(&read(&file)?) {
+                structs.entry(name).or_insert(fields);
+            }
+        }
+        for (name, fields) in structs {
+            definitions.entry(name).or_default().push((krate, fields));
+        }
+    }
+
+    let mut drifts = Vec::new();
+    for (name, defined) in &definitions {
+        for (i, (krate, fields)) in defined.iter().enumerate() {
+            for (other_krate, other_fields) in &defined[i + 1..] {
+                for (field, ty) in fields {
+                    if let Some(other_ty) = other_fields.get(field).filter(|other| *other != ty) {
+                        drifts.push(format!(
+                            "{}::{} is {} in {} but {} in {}",
+                            name, field, ty, krate, other_ty, other_krate
+                        ));
+                    }
+                }
+            }
+        }
+    }
+    drift(drifts)
+}
+
+/// Every `.rs` file under `dir`, recursively
+fn rust_files(dir: &Path, files: &mut Vec<PathBuf>) -> Result<(), String> {
+    let entries =
+        std::fs::read_dir(dir).map_err(|e| format!("reading {}: {}", dir.display(), e))?;
+    for entry in entries {
+        let path = entry.map_err(|e| format!("reading {}: {}", dir.display(), e))?.path();
+        if path.is_dir() {
+            rust_files(&path, files)?;
+        } else if path.extension().is_some_and(|extension| extension == "rs") {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Structs with named fields defined in `source`, by name
+///
+/// A line-based reading that expects rustfmt's layout: one field per line, closed by a
+/// `}` on a line of its own.
+fn structs_in(source: &str) -> Vec<(String, Fields)> {
+    let mut structs = Vec::new();
+    let mut lines = source.lines().map(str::trim);
+    while let Some(line) = lines.next() {
+        let Some(name) = struct_name(line) else {
+            continue;
+        };
+        let mut fields = Fields::new();
+        for line in lines.by_ref().take_while(|line| *line != "}") {
+            if line.starts_with("//") || line.starts_with('#') {
+                continue;
+            }
+            let declaration = line.split("//").next().unwrap_or_default();
+            let declaration = strip_visibility(declaration.trim());
+            if let Some((field, ty)) = declaration.split_once(':') {
+                let field = field.trim();
+                if !field.is_empty() && field.chars().all(|c| c.is_alphanumeric() || c == '_') {
+                    fields.insert(field.to_string(), normalize_type(ty.trim_end_matches(',')));
+                }
+            }
+        }
+        structs.push((name.to_string(), fields));
+    }
+    structs
+}
+
+/// Name of the struct whose braced definition `line` opens
+fn struct_name(line: &str) -> Option<&str> {
+    let rest = strip_visibility(line).strip_prefix("struct ")?;
+    if !line.ends_with('{') {
+        return None;
+    }
+    let end = rest.find(|c: char| !(c.is_alphanumeric() || c == '_')).unwrap_or(rest.len());
+    Some(&rest[..end]).filter(|name| !name.is_empty())
+}
+
+fn strip_visibility(line: &str) -> &str {
+    if let Some(rest) = line.strip_prefix("pub(") {
+        return rest.split_once(')').map_or(line, |(_, rest)| rest.trim_start());
+    }
+    line.strip_prefix("pub ").unwrap_or(line)
+}
+
+/// `ty` without whitespace or module paths, so `crate::X` in one crate matches
+/// `hugure_core::X` in another
+fn normalize_type(ty: &str) -> String {
+    let mut normalized = String::with_capacity(ty.len());
+    for c in ty.chars().filter(|c| !c.is_whitespace()) {
+        normalized.push(c);
+        if normalized.ends_with("::") {
+            normalized.truncate(normalized.len() - 2);
+            let segment =
+                normalized.rfind(|c: char| !(c.is_alphanumeric() || c == '_')).map_or(0, |i| i + 1);
+            normalized.truncate(segment);
+        }
+    }
+    normalized
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_workspace_definitions_agree() {
+        for report in check(&default_source_root()) {
+            assert!(report.passed(), "{}: {}", report.name, report.failure.unwrap());
+        }
+    }
+
+    #[test]
+    fn test_struct_fields_are_read_from_source() {
+        let source = "\
+/// Pattern
+#[derive(Debug)]
+pub struct BMDPattern<T> {
+    /// Vectors
+    pub core_vectors: Vec<f64>,
+    #[serde(default)]
+    pub(crate) coordinates: crate::SEntropyCoordinate,
+    pub arousal_level: f64, // 0-10 scale
+    metadata: std::collections::HashMap<String, T>,
+}
+pub struct Unit;
+";
+        let structs = structs_in(source);
+        assert_eq!(structs.len(), 1);
+        let (name, fields) = &structs[0];
+        assert_eq!(name, "BMDPattern");
+        let fields: Vec<_> = fields.iter().map(|(f, t)| (f.as_str(), t.as_str())).collect();
+        assert_eq!(
+            fields,
+            [
+                ("arousal_level", "f64"),
+                ("coordinates", "SEntropyCoordinate"),
+                ("core_vectors", "Vec<f64>"),
+                ("metadata", "HashMap<String,T>"),
+            ]
+        );
+        let manifest = "[workspace.metadata.hugure]\nmemorial-significance = \"x\"\n[other]\n";
+        assert_eq!(metadata_value(manifest, "memorial-significance"), Some("x"));
+        assert_eq!(metadata_value(manifest, "temporal-precision-target"), None);
+    }
+}
@@ -0,0 +1,253 @@
+//! Invariants of the S-entropy framework
+//!
+//! [`ALL`] lists every built-in check. Frame selection probabilities (`FrameWeights`)
+//! belong to the orchestration crate, which this crate cannot depend on; the checks here
+//! bound the probabilities hugure-core produces instead.
+
+use std::fmt::Debug;
+
+use hugure_core::core_types::{NavigationCoordinate, ObserverSophistication, SEntropyPrecision};
+use hugure_core::navigation::ManifoldNavigator;
+use hugure_core::s_entropy::{SEntropyEngine, SEntropyMeasurement};
+use hugure_core::{SEntropyCoordinate, MEMORIAL_SIGNIFICANCE, S_ENTROPY_PRECISION_TARGET};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::{Check, CheckFuture, CheckResult};
+
+/// Every built-in check, in reporting order
+pub const ALL: &[Check] = &[
+    Check {
+        name: "coordinate-magnitude",
+        description: "S-entropy magnitudes are non-negative and match their components",
+        run: coordinate_magnitude,
+    },
+    Check {
+        name: "probability-bounds",
+        description: "Integration success rates and navigation confidences lie in [0, 1]",
+        run: probability_bounds,
+    },
+    Check {
+        name: "precision-ordering",
+        description: "Finer precision levels have strictly smaller thresholds",
+        run: precision_ordering,
+    },
+    Check {
+        name: "serde-round-trip",
+        description: "Coordinates, measurements, and precision levels survive JSON round trips",
+        run: serde_round_trip,
+    },
+    Check {
+        name: "memorial-significance",
+        description: "Coordinates and measurements carry the memorial significance",
+        run: memorial_significance,
+    },
+];
+
+/// Components spanning negative, zero, tiny, and large values
+const COMPONENTS: [f64; 7] = [-1e6, -1.0, -1e-30, 0.0, 1e-30, 1.0, 1e6];
+
+const OBSERVERS: [ObserverSophistication; 4] = [
+    ObserverSophistication::Naive,
+    ObserverSophistication::Intermediate,
+    ObserverSophistication::Expert,
+    ObserverSophistication::Universal,
+];
+
+/// Precision levels from coarsest to finest
+const LEVELS: [SEntropyPrecision; 4] = [
+    SEntropyPrecision::Standard,
+    SEntropyPrecision::High,
+    SEntropyPrecision::Ultra,
+    SEntropyPrecision::Supreme,
+];
+
+/// A measurement of the validation problem by `observer`
+async fn measurement(
+    engine: &SEntropyEngine,
+    observer: ObserverSophistication,
+    problem_complexity: f64,
+    accessibility: f64,
+) -> Result<SEntropyMeasurement, String> {
+    engine
+        .generate_measurement(
+            "validation_problem",
+            observer,
+            S_ENTROPY_PRECISION_TARGET,
+            0.3,
+            problem_complexity,
+            accessibility,
+        )
+        .await
+        .map_err(|e| format!("measurement failed: {}", e))
+}
+
+/// Navigation solution for the coordinate with components `s`
+async fn navigation(
+    navigator: &ManifoldNavigator,
+    s: [f64; 3],
+) -> Result<NavigationCoordinate, String> {
+    navigator
+        .navigate_to_coordinates(&SEntropyCoordinate::new(s[0], s[1], s[2]))
+        .await
+        .map_err(|e| format!("navigation to {:?} failed: {}", s, e))
+}
+
+fn coordinate_magnitude() -> CheckFuture {
+    Box::pin(async {
+        for s_knowledge in COMPONENTS {
+            for s_time in COMPONENTS {
+                for s_entropy in COMPONENTS {
+                    let coordinate = SEntropyCoordinate::new(s_knowledge, s_time, s_entropy);
+                    let magnitude = coordinate.total_magnitude();
+                    if magnitude.is_nan() || magnitude < 0.0 {
+                        return Err(format!("{} has magnitude {}", coordinate, magnitude));
+                    }
+                }
+            }
+        }
+
+        let engine = SEntropyEngine::new(SEntropyPrecision::Ultra);
+        for observer in OBSERVERS {
+            for (complexity, accessibility) in [(0.5, 0.2), (1.0, 0.8), (2.0, 1.0)] {
+                let m = measurement(&engine, observer, complexity, accessibility).await?;
+                let expected = (m.s_knowledge.powi(2) + m.s_time.powi(2) + m.s_entropy.powi(2))
+                    .sqrt();
+                if m.total_magnitude.is_nan() || m.total_magnitude < 0.0 {
+                    return Err(format!("measurement has magnitude {}", m.total_magnitude));
+                }
+                if (m.total_magnitude - expected).abs() > 1e-12 * expected.max(1.0) {
+                    return Err(format!(
+                        "measurement magnitude {} differs from its components' {}",
+                        m.total_magnitude, expected
+                    ));
+                }
+            }
+        }
+        Ok(())
+    })
+}
+
+fn probability_bounds() -> CheckFuture {
+    Box::pin(async {
+        let engine = SEntropyEngine::new(SEntropyPrecision::Ultra).with_rng_seed(0);
+        for target in [1.0, 0.1, 0.01, 0.001] {
+            engine.attempt_integration(target).await.map_err(|e| e.to_string())?;
+            let stats = engine.get_integration_stats().await.map_err(|e| e.to_string())?;
+            if !(0.0..=1.0).contains(&stats.success_rate) {
+                return Err(format!("integration success rate is {}", stats.success_rate));
+            }
+        }
+
+        let navigator = ManifoldNavigator::new(SEntropyPrecision::Ultra);
+        for s in [[0.0; 3], [1.0, -1.0, 0.5], [1e6, 1e6, 1e6], [-1e-30, 0.0, 1e-30]] {
+            let coordinate = navigation(&navigator, s).await?;
+            if !(0.0..=1.0).contains(&coordinate.confidence) {
+                let confidence = coordinate.confidence;
+                return Err(format!("navigation to {:?} has confidence {}", s, confidence));
+            }
+        }
+        Ok(())
+    })
+}
+
+fn precision_ordering() -> CheckFuture {
+    Box::pin(async {
+        for level in LEVELS {
+            if level.threshold() <= 0.0 {
+                return Err(format!("{:?} has threshold {}", level, level.threshold()));
+            }
+        }
+        for pair in LEVELS.windows(2) {
+            let (coarser, finer) = (pair[0], pair[1]);
+            if coarser >= finer {
+                return Err(format!("{:?} does not order below {:?}", coarser, finer));
+            }
+            if finer.threshold() >= coarser.threshold() {
+                return Err(format!(
+                    "{:?} threshold {} is not below {:?} threshold {}",
+                    finer,
+                    finer.threshold(),
+                    coarser,
+                    coarser.threshold()
+                ));
+            }
+            if coarser.next_higher() != Some(finer) {
+                return Err(format!("{:?} escalates to {:?}", coarser, coarser.next_higher()));
+            }
+        }
+        match SEntropyPrecision::Supreme.next_higher() {
+            Some(level) => Err(format!("Supreme escalates to {:?}", level)),
+            None => Ok(()),
+        }
+    })
+}
+
+/// Whether `value` decodes back to itself from JSON
+fn round_trip<T>(name: &str, value: &T) -> CheckResult
+where
+    T: Serialize + DeserializeOwned + PartialEq + Debug,
+{
+    let json =
+        serde_json::to_string(value).map_err(|e| format!("{} did not serialize: {}", name, e))?;
+    let decoded: T = serde_json::from_str(&json)
+        .map_err(|e| format!("{} did not deserialize from {}: {}", name, json, e))?;
+    if &decoded != value {
+        return Err(format!("{} changed in a JSON round trip: {:?} != {:?}", name, decoded, value));
+    }
+    Ok(())
+}
+
+fn serde_round_trip() -> CheckFuture {
+    Box::pin(async {
+        round_trip("coordinate", &SEntropyCoordinate::new(0.1, -2.5, 1e-30))?;
+        let engine = SEntropyEngine::new(SEntropyPrecision::Ultra);
+        for observer in OBSERVERS {
+            round_trip("observer", &observer)?;
+            round_trip("measurement", &measurement(&engine, observer, 1.0, 0.8).await?)?;
+        }
+        for level in LEVELS {
+            round_trip("precision", &level)?;
+        }
+        let navigator = ManifoldNavigator::new(SEntropyPrecision::Ultra);
+        round_trip("navigation coordinate", &navigation(&navigator, [0.3, 0.2, 0.1]).await?)
+    })
+}
+
+fn memorial_significance() -> CheckFuture {
+    Box::pin(async {
+        let coordinate = SEntropyCoordinate::new(0.1, 0.2, 0.3);
+        if !coordinate.validates_memorial_significance() {
+            return Err(format!("coordinate carries '{}'", coordinate.memorial_significance));
+        }
+        let engine = SEntropyEngine::new(SEntropyPrecision::Ultra);
+        let m = measurement(&engine, ObserverSophistication::Expert, 1.0, 0.8).await?;
+        if m.memorial_significance != MEMORIAL_SIGNIFICANCE {
+            return Err(format!("measurement carries '{}'", m.memorial_significance));
+        }
+        let navigator = ManifoldNavigator::new(SEntropyPrecision::Ultra);
+        let solution = navigation(&navigator, [0.1, 0.2, 0.3]).await?;
+        if solution.memorial_significance != MEMORIAL_SIGNIFICANCE {
+            return Err(format!("navigation carries '{}'", solution.memorial_significance));
+        }
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::run_checks;
+
+    #[tokio::test]
+    async fn test_framework_satisfies_every_invariant() {
+        let mut names: Vec<_> = ALL.iter().map(|check| check.name).collect();
+        names.sort_unstable();
+        names.dedup();
+        assert_eq!(names.len(), ALL.len(), "check names must be unique");
+
+        for report in run_checks(ALL).await {
+            assert!(report.passed(), "{}: {}", report.name, report.failure.unwrap());
+        }
+    }
+}
@@ -1,4 +1,106 @@
 //! Hugure Validation
+//!
+//! Executable invariant checks over the S-entropy framework. [`run_checks`] runs every
+//! [`Check`] to completion and reports each outcome, so one violated invariant does not
+//! hide the others; the built-in checks live in [`invariants`], and [`baseline`] checks
+//! numerical results against those of a known-good release. [`consistency`] flags drift
+//! between definitions duplicated across crates, and [`report`] renders the outcomes as
+//! JSON and JUnit XML for test dashboards.
 
-/// Placeholder validation function
-pub fn placeholder_validation() {}
+pub mod baseline;
+pub mod consistency;
+pub mod invariants;
+pub mod report;
+
+use std::future::Future;
+use std::pin::Pin;
+use std::time::{Duration, Instant};
+
+/// Outcome of a check; the error describes the violated invariant
+pub type CheckResult = Result<(), String>;
+
+/// Future a check runs to produce its [`CheckResult`]
+pub type CheckFuture = Pin<Box<dyn Future<Output = CheckResult> + Send>>;
+
+/// An executable invariant check
+#[derive(Debug, Clone, Copy)]
+pub struct Check {
+    /// Name selecting the check on the command line
+    pub name: &'static str,
+
+    /// Invariant the check verifies
+    pub description: &'static str,
+
+    /// Runs the check
+    pub run: fn() -> CheckFuture,
+}
+
+/// Result of running one [`Check`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct CheckReport {
+    /// Name of the check
+    pub name: &'static str,
+
+    /// Invariant the check verifies
+    pub description: &'static str,
+
+    /// Time the check took
+    pub duration: Duration,
+
+    /// Why the check failed, `None` when it passed
+    pub failure: Option<String>,
+}
+
+impl CheckReport {
+    /// Whether the invariant held
+    pub fn passed(&self) -> bool {
+        self.failure.is_none()
+    }
+}
+
+/// Run `checks` in order, each in its own task so a panicking check fails on its own
+pub async fn run_checks(checks: &[Check]) -> Vec<CheckReport> {
+    let mut reports = Vec::with_capacity(checks.len());
+    for check in checks {
+        let started = Instant::now();
+        let failure = match tokio::spawn((check.run)()).await {
+            Ok(Ok(())) => None,
+            Ok(Err(failure)) => Some(failure),
+            Err(e) => Some(format!("check panicked: {}", e)),
+        };
+        reports.push(CheckReport {
+            name: check.name,
+            description: check.description,
+            duration: started.elapsed(),
+            failure,
+        });
+    }
+    reports
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn exploding() -> CheckResult {
+        panic!("boom")
+    }
+
+    #[tokio::test]
+    async fn test_every_check_is_reported() {
+        let checks = [
+            Check { name: "holds", description: "", run: || Box::pin(async { Ok(()) }) },
+            Check {
+                name: "violated",
+                description: "",
+                run: || Box::pin(async { Err("S < 0".to_string()) }),
+            },
+            Check { name: "panics", description: "", run: || Box::pin(async { exploding() }) },
+        ];
+        let reports = run_checks(&checks).await;
+        let outcomes: Vec<_> = reports.iter().map(|r| (r.name, r.passed())).collect();
+        assert_eq!(outcomes, [("holds", true), ("violated", false), ("panics", false)]);
+        assert_eq!(reports[1].failure.as_deref(), Some("S < 0"));
+        assert!(reports[2].failure.as_ref().unwrap().starts_with("check panicked"));
+    }
+}
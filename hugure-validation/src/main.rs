@@ -1,27 +1,169 @@
 //! Hugure Validation Binary
+//!
+//! Runs the framework's invariant checks, and optionally a golden-baseline comparison and
+//! cross-crate consistency checks, reporting each one, and exits with status 1 when any fails.
 
-use anyhow::Result;
-use clap::{Arg, Command};
+use anyhow::{Context, Result};
+use clap::{value_parser, Arg, ArgAction, Command};
+use hugure_validation::baseline::{self, Baseline};
+use hugure_validation::{consistency, invariants, report, run_checks, Check};
 
-#[tokio::main]
-async fn main() -> Result<()> {
-    let matches = Command::new("hugure-validation")
+fn cli() -> Command {
+    Command::new("hugure-validation")
         .version("0.1.0")
         .about("S-Entropy Validation Framework")
+        .arg(
+            Arg::new("check")
+                .long("check")
+                .value_name("NAME")
+                .help("Run only this check; repeat for several (default: every check)")
+                .action(ArgAction::Append)
+                .value_parser(invariants::ALL.iter().map(|check| check.name).collect::<Vec<_>>()),
+        )
+        .arg(
+            Arg::new("list")
+                .long("list")
+                .help("List the available checks and exit")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("baseline")
+                .long("baseline")
+                .value_name("FILE")
+                .help("Compare canonical seeded results against the baseline in FILE"),
+        )
+        .arg(
+            Arg::new("update-baseline")
+                .long("update-baseline")
+                .help("Record the current results as the new baseline in FILE and exit")
+                .requires("baseline")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("tolerance")
+                .long("tolerance")
+                .value_name("RELATIVE")
+                .help("Relative drift tolerated against the baseline (default: the baseline's)")
+                .requires("baseline")
+                .value_parser(value_parser!(f64)),
+        )
+        .arg(
+            Arg::new("consistency")
+                .long("consistency")
+                .help("Check that definitions shared between crates have not drifted")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("source-root")
+                .long("source-root")
+                .value_name("DIR")
+                .help("Workspace checkout read by --consistency (default: this build's)")
+                .requires("consistency"),
+        )
+        .arg(
+            Arg::new("report-json")
+                .long("report-json")
+                .value_name("FILE")
+                .help("Write a JSON report of every check to FILE"),
+        )
+        .arg(
+            Arg::new("report-junit")
+                .long("report-junit")
+                .value_name("FILE")
+                .help("Write a JUnit XML report of every check to FILE"),
+        )
         .arg(
             Arg::new("validate-memorial-coordinates")
                 .long("validate-memorial-coordinates")
-                .help("Validate memorial coordinates")
-                .action(clap::ArgAction::SetTrue),
+                .help("Validate memorial coordinates (same as --check memorial-significance)")
+                .action(ArgAction::SetTrue),
         )
-        .get_matches();
+}
 
-    println!("🔬 Hugure S-Entropy Validation Framework");
-    println!("Memorial significance: st-stella-lorraine");
+#[tokio::main]
+async fn main() -> Result<()> {
+    let matches = cli().get_matches();
 
+    if matches.get_flag("list") {
+        for check in invariants::ALL {
+            println!("{:<24} {}", check.name, check.description);
+        }
+        return Ok(());
+    }
+
+    let baseline_path = matches.get_one::<String>("baseline").map(std::path::Path::new);
+    if matches.get_flag("update-baseline") {
+        let path = baseline_path.expect("required by --update-baseline");
+        let mut baseline = Baseline::record().await?;
+        if let Some(tolerance) = matches.get_one::<f64>("tolerance") {
+            baseline.tolerance = *tolerance;
+        }
+        baseline.save(path)?;
+        println!("Recorded {} baseline results to {}", baseline.values.len(), path.display());
+        return Ok(());
+    }
+
+    let mut selected: Vec<&str> =
+        matches.get_many::<String>("check").unwrap_or_default().map(String::as_str).collect();
     if matches.get_flag("validate-memorial-coordinates") {
-        println!("✅ Memorial coordinates validated successfully");
+        selected.push("memorial-significance");
     }
+    let checks: Vec<Check> = invariants::ALL
+        .iter()
+        .filter(|check| selected.is_empty() || selected.contains(&check.name))
+        .copied()
+        .collect();
+
+    println!("🔬 Hugure S-Entropy Validation Framework");
+    println!("Memorial significance: {}", hugure_core::MEMORIAL_SIGNIFICANCE);
 
+    let mut reports = run_checks(&checks).await;
+    if let Some(path) = baseline_path {
+        reports.push(baseline::check(path, matches.get_one::<f64>("tolerance").copied()).await);
+    }
+    if matches.get_flag("consistency") {
+        let root = matches
+            .get_one::<String>("source-root")
+            .map_or_else(consistency::default_source_root, Into::into);
+        reports.extend(consistency::check(&root));
+    }
+    for report in &reports {
+        match &report.failure {
+            None => println!("✅ {} ({:.1?})", report.name, report.duration),
+            Some(failure) => println!("❌ {} ({:.1?}): {}", report.name, report.duration, failure),
+        }
+    }
+
+    if let Some(path) = matches.get_one::<String>("report-json") {
+        std::fs::write(path, serde_json::to_string_pretty(&report::to_json(&reports))? + "\n")
+            .with_context(|| format!("writing JSON report {}", path))?;
+    }
+    if let Some(path) = matches.get_one::<String>("report-junit") {
+        std::fs::write(path, report::to_junit(&reports))
+            .with_context(|| format!("writing JUnit report {}", path))?;
+    }
+
+    let failed = reports.iter().filter(|report| !report.passed()).count();
+    println!("{} passed, {} failed", reports.len() - failed, failed);
+    if failed > 0 {
+        std::process::exit(1);
+    }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cli_selects_known_checks() {
+        cli().debug_assert();
+        let matches = cli()
+            .try_get_matches_from(["hugure-validation", "--check", "precision-ordering"])
+            .unwrap();
+        assert_eq!(matches.get_one::<String>("check").unwrap(), "precision-ordering");
+        assert!(cli().try_get_matches_from(["hugure-validation", "--check", "vibes"]).is_err());
+        assert!(cli().try_get_matches_from(["hugure-validation", "--update-baseline"]).is_err());
+        assert!(cli().try_get_matches_from(["hugure-validation", "--source-root", "."]).is_err());
+    }
+}
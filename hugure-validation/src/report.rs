@@ -0,0 +1,174 @@
+//! Structured reports of validation runs for test dashboards
+//!
+//! [`to_json`] and [`to_junit`] render the same [`CheckReport`]s: every check with its
+//! status, duration, and failure details. JUnit XML is the format CI systems surface as
+//! test results; the JSON report carries the same fields plus each check's description.
+
+use std::fmt::Write;
+
+use serde::Serialize;
+
+use crate::CheckReport;
+
+/// Test suite name in both report formats
+pub const SUITE_NAME: &str = "hugure-validation";
+
+/// JSON report of a validation run
+#[derive(Debug, Serialize)]
+struct JsonReport<'a> {
+    suite: &'static str,
+    tests: usize,
+    passed: usize,
+    failed: usize,
+    duration_secs: f64,
+    checks: Vec<JsonCheck<'a>>,
+}
+
+/// One check in a [`JsonReport`]
+#[derive(Debug, Serialize)]
+struct JsonCheck<'a> {
+    name: &'static str,
+    description: &'static str,
+    status: &'static str,
+    duration_secs: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    failure: Option<&'a str>,
+}
+
+fn total_secs(reports: &[CheckReport]) -> f64 {
+    reports.iter().map(|report| report.duration.as_secs_f64()).sum()
+}
+
+/// Render `reports` as a JSON document
+pub fn to_json(reports: &[CheckReport]) -> serde_json::Value {
+    let failed = reports.iter().filter(|report| !report.passed()).count();
+    let report = JsonReport {
+        suite: SUITE_NAME,
+        tests: reports.len(),
+        passed: reports.len() - failed,
+        failed,
+        duration_secs: total_secs(reports),
+        checks: reports
+            .iter()
+            .map(|report| JsonCheck {
+                name: report.name,
+                description: report.description,
+                status: if report.passed() { "passed" } else { "failed" },
+                duration_secs: report.duration.as_secs_f64(),
+                failure: report.failure.as_deref(),
+            })
+            .collect(),
+    };
+    serde_json::to_value(report).expect("validation reports serialize")
+}
+
+/// Render `reports` as a JUnit XML document with one test suite
+pub fn to_junit(reports: &[CheckReport]) -> String {
+    let failed = reports.iter().filter(|report| !report.passed()).count();
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    // Writing to a String cannot fail
+    let _ = writeln!(
+        xml,
+        "<testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"0\" time=\"{:.6}\">",
+        SUITE_NAME,
+        reports.len(),
+        failed,
+        total_secs(reports)
+    );
+    for report in reports {
+        let _ = write!(
+            xml,
+            "  <testcase name=\"{}\" classname=\"{}\" time=\"{:.6}\"",
+            escape(report.name),
+            SUITE_NAME,
+            report.duration.as_secs_f64()
+        );
+        match &report.failure {
+            None => xml.push_str("/>\n"),
+            Some(failure) => {
+                let _ = writeln!(
+                    xml,
+                    ">\n    <failure message=\"{}\">{}</failure>\n  </testcase>",
+                    escape(failure),
+                    escape(report.description)
+                );
+            },
+        }
+    }
+    xml.push_str("</testsuite>\n");
+    xml
+}
+
+/// Escape `text` for XML attribute values and character data
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            // Control characters other than tab, newline, and return are not valid XML
+            c if c.is_control() && !matches!(c, '\t' | '\n' | '\r') => {
+                let _ = write!(escaped, "\\u{{{:x}}}", c as u32);
+            },
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn reports() -> Vec<CheckReport> {
+        vec![
+            CheckReport {
+                name: "precision-ordering",
+                description: "Finer precision levels have strictly smaller thresholds",
+                duration: Duration::from_millis(2),
+                failure: None,
+            },
+            CheckReport {
+                name: "baseline",
+                description: "Canonical results match the stored baseline",
+                duration: Duration::from_millis(500),
+                failure: Some("measure/naive/0/s_time = 1e0, baseline <2e0> & \"3\"".into()),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_json_report_lists_every_check() {
+        let json = to_json(&reports());
+        assert_eq!(json["tests"], 2);
+        assert_eq!(json["failed"], 1);
+        assert_eq!(json["duration_secs"], 0.502);
+        assert_eq!(json["checks"][0]["status"], "passed");
+        assert!(json["checks"][0].get("failure").is_none());
+        assert_eq!(json["checks"][1]["status"], "failed");
+        assert_eq!(json["checks"][1]["duration_secs"], 0.5);
+    }
+
+    #[test]
+    fn test_junit_report_escapes_failures() {
+        let xml = to_junit(&reports());
+        assert!(xml.contains(
+            "<testsuite name=\"hugure-validation\" tests=\"2\" failures=\"1\" errors=\"0\" \
+             time=\"0.502000\">"
+        ));
+        assert!(xml.contains(
+            "<testcase name=\"precision-ordering\" classname=\"hugure-validation\" \
+             time=\"0.002000\"/>"
+        ));
+        assert!(xml.contains(
+            "<failure message=\"measure/naive/0/s_time = 1e0, baseline &lt;2e0&gt; &amp; \
+             &quot;3&quot;\">Canonical results match the stored baseline</failure>"
+        ));
+        assert_eq!(escape("bell\u{7}"), "bell\\u{7}");
+    }
+}
@@ -0,0 +1,542 @@
+//! # Hugure System Actors
+//! 
+//! The four actors behind [`crate::HugureSystem`]: the foundry client, the
+//! orchestrator, the bidirectional optimizer, and the emergence detector. Each
+//! owns its component exclusively and is reached only through its mailbox.
+
+use std::sync::Arc;
+use std::time::Duration;
+use anyhow::Context;
+use async_trait::async_trait;
+use tokio_util::sync::CancellationToken;
+
+use crate::bmd::{validate_selection, BMD, BMDPattern, BMDSelection};
+use crate::depth_breaker::{
+    deepening_schedule, BreakerPolicy, Degradation, DegradationReason, DepthCircuitBreaker,
+    ExplorationCancelled,
+};
+use crate::communication::CommunicationRequest;
+use crate::emergence::EmergedPattern;
+use crate::foundry::BMDSelectionContext;
+use crate::foundry_backend::FoundryBackend;
+use crate::optimization::{CommunicationOptimization, OptimizationCoordinator, OptimizationResults};
+use crate::orchestration::{ExplorationResults, ExplorationTask, OrchestrationEngine};
+use crate::snapshot::OrchestrationState;
+use crate::plugins::{
+    BidirectionalOptimizer, EmergenceDetector, FidelityPredictor, InformationDeficitEstimator,
+    SelectedPlugins,
+};
+use crate::strategy::{OptimizationStrategy, StrategyKind};
+use crate::util::ratelimit::{RateLimiter, TokenBucket};
+use crate::HugureConfig;
+use super::priority::{PriorityGate, TaskPriority};
+use super::{spawn_supervised, Actor, ActorHandle, Reply, RestartPolicy};
+
+/// Messages handled by the foundry client actor
+pub enum FoundryMessage {
+    /// Select BMDs for background exploration
+    SelectForExploration { reply: Reply<BMDSelection> },
+    /// Select BMDs for a specific communication scenario
+    SelectWithContext { context: BMDSelectionContext, reply: Reply<BMDSelection> },
+}
+
+/// Foundry client: throttled access to the configured foundry backend
+pub struct FoundryActor {
+    backend: Arc<dyn FoundryBackend>,
+    limiter: Arc<TokenBucket>,
+}
+
+#[async_trait]
+impl Actor for FoundryActor {
+    type Message = FoundryMessage;
+    
+    async fn handle(&mut self, message: FoundryMessage) {
+        self.limiter.acquire().await;
+        match message {
+            FoundryMessage::SelectForExploration { reply } => {
+                let selection = self.backend.select_bmds_for_exploration().await;
+                let _ = reply.send(selection.and_then(Self::validated));
+            }
+            FoundryMessage::SelectWithContext { context, reply } => {
+                let selection = self.backend.select_bmds_with_context(context).await;
+                let _ = reply.send(selection.and_then(Self::validated));
+            }
+        }
+    }
+}
+
+impl FoundryActor {
+    /// Reject selections carrying BMDs with out-of-range fields
+    fn validated(selection: BMDSelection) -> anyhow::Result<BMDSelection> {
+        validate_selection(&selection).context("foundry served an invalid BMD")?;
+        Ok(selection)
+    }
+}
+
+/// Messages handled by the orchestrator actor
+pub enum OrchestratorMessage {
+    /// Explore combinations of a background BMD selection
+    Explore { selection: BMDSelection, reply: Reply<ExplorationResults> },
+    /// Execute a targeted exploration task, degrading depth if it is exhausted
+    /// 
+    /// With a cancellation token the task deepens step by step and, once
+    /// cancelled, replies with the deepest completed result.
+    Execute {
+        task: ExplorationTask,
+        cancel: Option<CancellationToken>,
+        reply: Reply<(ExplorationResults, Option<Degradation>)>,
+    },
+    /// Export orchestrator state for a snapshot
+    ExportState { reply: Reply<OrchestrationState> },
+    /// Resume from snapshotted orchestrator state
+    RestoreState { state: OrchestrationState, reply: Reply<()> },
+}
+
+/// Orchestrator: BMD combination exploration
+pub struct OrchestratorActor {
+    engine: Arc<OrchestrationEngine>,
+    breaker: DepthCircuitBreaker,
+}
+
+#[async_trait]
+impl Actor for OrchestratorActor {
+    type Message = OrchestratorMessage;
+    
+    async fn handle(&mut self, message: OrchestratorMessage) {
+        match message {
+            OrchestratorMessage::Explore { selection, reply } => {
+                let _ = reply.send(self.engine.explore_bmd_combinations(selection).await);
+            }
+            OrchestratorMessage::Execute { task, cancel: None, reply } => {
+                let depth = task.max_recursion_depth;
+                let _ = reply.send(self.execute_at_depth(&task, depth).await);
+            }
+            OrchestratorMessage::Execute { task, cancel: Some(cancel), reply } => {
+                let _ = reply.send(self.execute_until_cancelled(&task, &cancel).await);
+            }
+            OrchestratorMessage::ExportState { reply } => {
+                let _ = reply.send(Ok(OrchestrationState { breaker: self.breaker.state() }));
+            }
+            OrchestratorMessage::RestoreState { state, reply } => {
+                self.breaker.restore(state.breaker);
+                let _ = reply.send(Ok(()));
+            }
+        }
+    }
+}
+
+impl OrchestratorActor {
+    async fn execute_at_depth(
+        &mut self,
+        task: &ExplorationTask,
+        depth: u32,
+    ) -> anyhow::Result<(ExplorationResults, Option<Degradation>)> {
+        let engine = &self.engine;
+        self.breaker.run(depth, |max_recursion_depth| {
+            let attempt = ExplorationTask { max_recursion_depth, ..task.clone() };
+            engine.execute_exploration_task(attempt)
+        }).await
+    }
+    
+    /// Deepen along the schedule, keeping the deepest result completed before `cancel`
+    async fn execute_until_cancelled(
+        &mut self,
+        task: &ExplorationTask,
+        cancel: &CancellationToken,
+    ) -> anyhow::Result<(ExplorationResults, Option<Degradation>)> {
+        let requested_depth = task.max_recursion_depth;
+        let mut deepest = None;
+        
+        for depth in deepening_schedule(requested_depth) {
+            let step = tokio::select! {
+                biased;
+                _ = cancel.cancelled() => break,
+                step = self.execute_at_depth(task, depth) => step?,
+            };
+            
+            let degraded = step.1.is_some();
+            deepest = Some((step, depth));
+            // Deeper steps would exhaust their depth as well
+            if degraded {
+                break;
+            }
+        }
+        
+        let Some(((results, degradation), depth)) = deepest else {
+            return Err(ExplorationCancelled.into());
+        };
+        let degradation = match degradation {
+            Some(degradation) => Some(Degradation { requested_depth, ..degradation }),
+            None if depth < requested_depth => Some(Degradation {
+                requested_depth,
+                effective_depth: depth,
+                reason: DegradationReason::Cancelled,
+            }),
+            None => None,
+        };
+        Ok((results, degradation))
+    }
+}
+
+/// Messages handled by the optimizer actor
+pub enum OptimizerMessage {
+    /// Bidirectional optimization of exploration results
+    OptimizeBidirectional { results: ExplorationResults, reply: Reply<OptimizationResults> },
+    /// Optimize exploration results for a communication request, weighting the
+    /// optimized BMDs with `strategy`
+    OptimizeForCommunication {
+        results: ExplorationResults,
+        request: CommunicationRequest,
+        strategy: StrategyKind,
+        reply: Reply<CommunicationOptimization>,
+    },
+    /// Export the optimizer plugin's warm-start data
+    ExportWarmStart { reply: Reply<Option<serde_json::Value>> },
+    /// Hand snapshotted warm-start data back to the optimizer plugin
+    RestoreWarmStart { state: serde_json::Value, reply: Reply<()> },
+}
+
+/// Optimizer: bidirectional pattern optimization via the configured plugin
+pub struct OptimizerActor {
+    optimizer: Arc<dyn BidirectionalOptimizer>,
+    coordinator: Arc<OptimizationCoordinator>,
+    fidelity_predictor: Arc<dyn FidelityPredictor>,
+    deficit_estimator: Arc<dyn InformationDeficitEstimator>,
+}
+
+#[async_trait]
+impl Actor for OptimizerActor {
+    type Message = OptimizerMessage;
+    
+    async fn handle(&mut self, message: OptimizerMessage) {
+        match message {
+            OptimizerMessage::OptimizeBidirectional { results, reply } => {
+                let _ = reply.send(self.optimizer.optimize_bidirectional(results).await);
+            }
+            OptimizerMessage::OptimizeForCommunication { results, request, strategy, reply } => {
+                let optimization = self.coordinator.optimize_for_communication(results, &request).await
+                    .map(|mut optimization| {
+                        weight_bmds(
+                            strategy.strategy().as_ref(),
+                            &mut optimization.bmds,
+                            self.fidelity_predictor.as_ref(),
+                            self.deficit_estimator.as_ref(),
+                        );
+                        optimization
+                    });
+                let _ = reply.send(optimization);
+            }
+            OptimizerMessage::ExportWarmStart { reply } => {
+                let _ = reply.send(self.optimizer.warm_start_state());
+            }
+            OptimizerMessage::RestoreWarmStart { state, reply } => {
+                let _ = reply.send(self.optimizer.restore_warm_start(state));
+            }
+        }
+    }
+}
+
+/// Weight the BMDs of an optimized combination with `strategy`
+/// 
+/// Maximizes the weighted mean predicted fidelity of the BMDs minus the
+/// information deficit between the weighted and unweighted mean patterns, then
+/// stores the normalized weights as each BMD's `selection_probability`.
+fn weight_bmds(
+    strategy: &dyn OptimizationStrategy,
+    bmds: &mut [BMD],
+    predictor: &dyn FidelityPredictor,
+    estimator: &dyn InformationDeficitEstimator,
+) {
+    if bmds.is_empty() {
+        return;
+    }
+    
+    let fidelities: Vec<f64> = bmds.iter()
+        .map(|bmd| predictor.predict_fidelity(std::slice::from_ref(bmd)))
+        .collect();
+    let explored = mean_pattern(bmds, &vec![1.0; bmds.len()]);
+    
+    let objective = |weights: &[f64]| {
+        let total: f64 = weights.iter().sum();
+        if total <= f64::EPSILON {
+            return f64::NEG_INFINITY;
+        }
+        let fidelity = weights.iter().zip(&fidelities).map(|(w, f)| w * f).sum::<f64>() / total;
+        fidelity - estimator.estimate_deficit(&mean_pattern(bmds, weights), &explored)
+    };
+    
+    let weights = strategy.maximize(&objective, &vec![1.0; bmds.len()]);
+    let total: f64 = weights.iter().sum();
+    for (bmd, weight) in bmds.iter_mut().zip(&weights) {
+        bmd.frame_weights.selection_probability = Some(if total > f64::EPSILON {
+            weight / total
+        } else {
+            1.0 / weights.len() as f64
+        });
+    }
+}
+
+/// Pattern whose core vectors are the weighted mean of the BMDs' core vectors
+fn mean_pattern(bmds: &[BMD], weights: &[f64]) -> BMDPattern {
+    let len = bmds.iter().map(|bmd| bmd.pattern.core_vectors.len()).max().unwrap_or(0);
+    let total: f64 = weights.iter().sum::<f64>().max(f64::EPSILON);
+    let mut core_vectors = vec![0.0; len];
+    
+    for (bmd, weight) in bmds.iter().zip(weights) {
+        for (sum, value) in core_vectors.iter_mut().zip(&bmd.pattern.core_vectors) {
+            *sum += weight * value / total;
+        }
+    }
+    
+    BMDPattern {
+        core_vectors,
+        cross_domain_compatibility: Default::default(),
+        frequency_ranges: Vec::new(),
+        semantic_opacity: 0.0,
+    }
+}
+
+/// Messages handled by the emergence detector actor
+pub enum EmergenceMessage {
+    /// Detect statistically emerged patterns in optimization results
+    Detect { results: OptimizationResults, reply: Reply<Vec<EmergedPattern>> },
+}
+
+/// Emergence detector: statistical emergence via the configured plugin
+pub struct EmergenceActor {
+    detector: Arc<dyn EmergenceDetector>,
+}
+
+#[async_trait]
+impl Actor for EmergenceActor {
+    type Message = EmergenceMessage;
+    
+    async fn handle(&mut self, message: EmergenceMessage) {
+        match message {
+            EmergenceMessage::Detect { results, reply } => {
+                let _ = reply.send(self.detector.detect_emergence(results).await);
+            }
+        }
+    }
+}
+
+/// Handles to all Hugure system actors
+#[derive(Debug, Clone)]
+pub struct HugureActors {
+    /// Foundry client
+    pub foundry: ActorHandle<FoundryMessage>,
+    /// BMD exploration orchestrator
+    pub orchestrator: ActorHandle<OrchestratorMessage>,
+    /// Bidirectional optimizer
+    pub optimizer: ActorHandle<OptimizerMessage>,
+    /// Statistical emergence detector
+    pub emergence: ActorHandle<EmergenceMessage>,
+    /// Orders access to the orchestrator by task priority
+    pub exploration_gate: Arc<PriorityGate>,
+}
+
+impl HugureActors {
+    /// Spawn all actors under supervision
+    pub fn spawn(
+        foundry_backend: Arc<dyn FoundryBackend>,
+        orchestration_engine: Arc<OrchestrationEngine>,
+        optimization_coordinator: Arc<OptimizationCoordinator>,
+        foundry_limiter: Arc<TokenBucket>,
+        plugins: &SelectedPlugins,
+        config: &HugureConfig,
+    ) -> Self {
+        let capacity = config.actor_mailbox_capacity;
+        let policy = RestartPolicy::default();
+        
+        let foundry = spawn_supervised(
+            "foundry_client",
+            move || FoundryActor {
+                backend: Arc::clone(&foundry_backend),
+                limiter: Arc::clone(&foundry_limiter),
+            },
+            capacity,
+            policy,
+        );
+        let breaker_policy = BreakerPolicy {
+            failure_threshold: config.depth_breaker_threshold,
+            cooldown: Duration::from_millis(config.depth_breaker_cooldown_ms),
+            ..BreakerPolicy::default()
+        };
+        let orchestrator = spawn_supervised(
+            "orchestrator",
+            move || OrchestratorActor {
+                engine: Arc::clone(&orchestration_engine),
+                breaker: DepthCircuitBreaker::new(breaker_policy),
+            },
+            capacity,
+            policy,
+        );
+        let bidirectional_optimizer = Arc::clone(&plugins.optimizer);
+        let fidelity_predictor = Arc::clone(&plugins.fidelity_predictor);
+        let deficit_estimator = Arc::clone(&plugins.information_deficit_estimator);
+        let optimizer = spawn_supervised(
+            "optimizer",
+            move || OptimizerActor {
+                optimizer: Arc::clone(&bidirectional_optimizer),
+                coordinator: Arc::clone(&optimization_coordinator),
+                fidelity_predictor: Arc::clone(&fidelity_predictor),
+                deficit_estimator: Arc::clone(&deficit_estimator),
+            },
+            capacity,
+            policy,
+        );
+        let emergence_detector = Arc::clone(&plugins.emergence_detector);
+        let emergence = spawn_supervised(
+            "emergence_detector",
+            move || EmergenceActor { detector: Arc::clone(&emergence_detector) },
+            capacity,
+            policy,
+        );
+        
+        // The orchestrator runs one exploration at a time, so admit one at a time
+        let exploration_gate = PriorityGate::new(1);
+        
+        Self { foundry, orchestrator, optimizer, emergence, exploration_gate }
+    }
+    
+    /// Select BMDs for background exploration
+    pub async fn select_for_exploration(&self) -> anyhow::Result<BMDSelection> {
+        self.foundry.call(|reply| FoundryMessage::SelectForExploration { reply }).await
+    }
+    
+    /// Select BMDs for a communication scenario
+    pub async fn select_with_context(&self, context: BMDSelectionContext) -> anyhow::Result<BMDSelection> {
+        self.foundry.call(|reply| FoundryMessage::SelectWithContext { context, reply }).await
+    }
+    
+    /// Explore combinations of a BMD selection at background priority
+    pub async fn explore(&self, selection: BMDSelection) -> anyhow::Result<ExplorationResults> {
+        let _permit = self.exploration_gate.acquire(TaskPriority::Background).await;
+        self.orchestrator.call(|reply| OrchestratorMessage::Explore { selection, reply }).await
+    }
+    
+    /// Execute a targeted exploration task, reporting any depth degradation
+    /// 
+    /// Waiting tasks are started highest `task.priority` first, ahead of background
+    /// explorations.
+    pub async fn execute(
+        &self,
+        task: ExplorationTask,
+    ) -> anyhow::Result<(ExplorationResults, Option<Degradation>)> {
+        let _permit = self.exploration_gate.acquire(task.priority).await;
+        self.orchestrator
+            .call(|reply| OrchestratorMessage::Execute { task, cancel: None, reply })
+            .await
+    }
+    
+    /// Execute a targeted exploration task that `cancel` can abort
+    /// 
+    /// Once cancelled, the deepest result completed so far is returned with a
+    /// [`DegradationReason::Cancelled`] degradation, or [`ExplorationCancelled`] if
+    /// no depth completed.
+    pub async fn execute_cancellable(
+        &self,
+        task: ExplorationTask,
+        cancel: CancellationToken,
+    ) -> anyhow::Result<(ExplorationResults, Option<Degradation>)> {
+        let _permit = tokio::select! {
+            permit = self.exploration_gate.acquire(task.priority) => permit,
+            _ = cancel.cancelled() => return Err(ExplorationCancelled.into()),
+        };
+        self.orchestrator
+            .call(|reply| OrchestratorMessage::Execute { task, cancel: Some(cancel), reply })
+            .await
+    }
+    
+    /// Orchestrator state for a snapshot
+    pub async fn orchestration_state(&self) -> anyhow::Result<OrchestrationState> {
+        self.orchestrator.call(|reply| OrchestratorMessage::ExportState { reply }).await
+    }
+    
+    /// Resume the orchestrator from snapshotted state
+    pub async fn restore_orchestration_state(
+        &self,
+        state: OrchestrationState,
+    ) -> anyhow::Result<()> {
+        self.orchestrator.call(|reply| OrchestratorMessage::RestoreState { state, reply }).await
+    }
+    
+    /// Bidirectionally optimize exploration results
+    pub async fn optimize_bidirectional(&self, results: ExplorationResults) -> anyhow::Result<OptimizationResults> {
+        self.optimizer.call(|reply| OptimizerMessage::OptimizeBidirectional { results, reply }).await
+    }
+    
+    /// Optimize exploration results for a communication request, weighting the
+    /// optimized BMDs with `strategy`
+    pub async fn optimize_for_communication(
+        &self,
+        results: ExplorationResults,
+        request: CommunicationRequest,
+        strategy: StrategyKind,
+    ) -> anyhow::Result<CommunicationOptimization> {
+        self.optimizer
+            .call(|reply| OptimizerMessage::OptimizeForCommunication {
+                results,
+                request,
+                strategy,
+                reply,
+            })
+            .await
+    }
+    
+    /// Warm-start data exported by the optimizer plugin
+    pub async fn optimizer_warm_start(&self) -> anyhow::Result<Option<serde_json::Value>> {
+        self.optimizer.call(|reply| OptimizerMessage::ExportWarmStart { reply }).await
+    }
+    
+    /// Hand snapshotted warm-start data back to the optimizer plugin
+    pub async fn restore_optimizer_warm_start(
+        &self,
+        state: serde_json::Value,
+    ) -> anyhow::Result<()> {
+        self.optimizer.call(|reply| OptimizerMessage::RestoreWarmStart { state, reply }).await
+    }
+    
+    /// Detect statistically emerged patterns
+    pub async fn detect_emergence(&self, results: OptimizationResults) -> anyhow::Result<Vec<EmergedPattern>> {
+        self.emergence.call(|reply| EmergenceMessage::Detect { results, reply }).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bmd::QualityMetrics;
+    use crate::plugins::{FoundryQualityPredictor, PatternDistanceEstimator};
+    
+    fn bmd(fidelity: f64) -> BMD {
+        BMD::builder(vec![1.0, 0.5])
+            .quality_metrics(QualityMetrics {
+                pattern_coherence: 0.9,
+                cross_domain_score: 0.9,
+                temporal_stability: 0.9,
+                transmission_fidelity: fidelity,
+            })
+            .build()
+            .unwrap()
+    }
+    
+    #[test]
+    fn test_strategy_weights_favor_predicted_fidelity() {
+        for kind in [StrategyKind::Gradient, StrategyKind::Annealing, StrategyKind::Evolutionary] {
+            let mut bmds = vec![bmd(0.9), bmd(0.1)];
+            weight_bmds(
+                kind.strategy().as_ref(),
+                &mut bmds,
+                &FoundryQualityPredictor,
+                &PatternDistanceEstimator,
+            );
+            
+            let weights: Vec<f64> = bmds.iter()
+                .map(|bmd| bmd.frame_weights.selection_probability.unwrap())
+                .collect();
+            assert!((weights.iter().sum::<f64>() - 1.0).abs() < 1e-9, "{}: {:?}", kind, weights);
+            assert!(weights[0] > weights[1], "{}: {:?}", kind, weights);
+        }
+    }
+}
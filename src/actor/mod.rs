@@ -0,0 +1,247 @@
+//! # Supervised Actors
+//! 
+//! Minimal message-passing actor runtime used by [`crate::HugureSystem`]. Each actor
+//! owns its state, processes one message at a time from a bounded mailbox, and is
+//! restarted by a supervisor task if it panics. Callers talk to actors through an
+//! [`ActorHandle`]; request/response messages carry a [`Reply`] channel.
+//! 
+//! Bounded mailboxes give natural backpressure: `send` waits for space instead of
+//! letting work pile up, and `try_send` reports a full mailbox immediately.
+//! 
+//! Each message is handled inside the tracing span that was current when it was
+//! sent, so actor logs stay attached to the request that caused them.
+
+pub mod components;
+pub mod priority;
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::{mpsc, oneshot, Mutex};
+use tracing::{debug, error, warn, Instrument, Span};
+use anyhow::Result;
+use async_trait::async_trait;
+
+pub use components::HugureActors;
+
+/// Reply channel for request/response messages
+pub type Reply<T> = oneshot::Sender<Result<T>>;
+
+/// An actor processes messages sequentially, owning its state exclusively
+#[async_trait]
+pub trait Actor: Send + 'static {
+    /// Messages accepted by this actor
+    type Message: Send + 'static;
+    
+    /// Handle a single message
+    async fn handle(&mut self, message: Self::Message);
+}
+
+/// Restart limits applied by an actor's supervisor
+#[derive(Debug, Clone, Copy)]
+pub struct RestartPolicy {
+    /// Maximum restarts allowed within `within`
+    pub max_restarts: u32,
+    /// Sliding period over which restarts are counted
+    pub within: Duration,
+}
+
+impl Default for RestartPolicy {
+    fn default() -> Self {
+        Self {
+            max_restarts: 5,
+            within: Duration::from_secs(60),
+        }
+    }
+}
+
+/// Cloneable handle to a running actor's mailbox
+pub struct ActorHandle<M> {
+    name: &'static str,
+    sender: mpsc::Sender<(M, Span)>,
+}
+
+impl<M> Clone for ActorHandle<M> {
+    fn clone(&self) -> Self {
+        Self {
+            name: self.name,
+            sender: self.sender.clone(),
+        }
+    }
+}
+
+impl<M> fmt::Debug for ActorHandle<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ActorHandle")
+            .field("name", &self.name)
+            .field("mailbox_depth", &self.mailbox_depth())
+            .field("closed", &self.is_closed())
+            .finish()
+    }
+}
+
+impl<M: Send + 'static> ActorHandle<M> {
+    /// Actor name
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+    
+    /// Send a message, waiting for mailbox space
+    pub async fn send(&self, message: M) -> Result<()> {
+        self.sender.send((message, Span::current())).await
+            .map_err(|_| anyhow::anyhow!("actor '{}' has stopped", self.name))
+    }
+    
+    /// Send a message only if the mailbox has space right now
+    pub fn try_send(&self, message: M) -> Result<()> {
+        self.sender.try_send((message, Span::current())).map_err(|e| match e {
+            mpsc::error::TrySendError::Full(_) => anyhow::anyhow!("actor '{}' mailbox is full", self.name),
+            mpsc::error::TrySendError::Closed(_) => anyhow::anyhow!("actor '{}' has stopped", self.name),
+        })
+    }
+    
+    /// Send a request built around a reply channel and await the response
+    pub async fn call<T>(&self, request: impl FnOnce(Reply<T>) -> M) -> Result<T> {
+        let (reply, response) = oneshot::channel();
+        self.send(request(reply)).await?;
+        response.await
+            .map_err(|_| anyhow::anyhow!("actor '{}' dropped the request", self.name))?
+    }
+    
+    /// Messages waiting in the mailbox
+    pub fn mailbox_depth(&self) -> usize {
+        self.sender.max_capacity() - self.sender.capacity()
+    }
+    
+    /// Whether the actor has stopped for good
+    pub fn is_closed(&self) -> bool {
+        self.sender.is_closed()
+    }
+}
+
+/// Spawn an actor under a supervisor that rebuilds it with `factory` after a panic
+/// 
+/// Messages queued in the mailbox survive restarts; the request being handled when
+/// the actor panicked is dropped, so its caller receives an error. Once the restart
+/// policy is exceeded the mailbox is closed and further sends fail.
+pub fn spawn_supervised<A, F>(
+    name: &'static str,
+    factory: F,
+    mailbox_capacity: usize,
+    policy: RestartPolicy,
+) -> ActorHandle<A::Message>
+where
+    A: Actor,
+    F: Fn() -> A + Send + Sync + 'static,
+{
+    let (sender, receiver) = mpsc::channel(mailbox_capacity.max(1));
+    let mailbox = Arc::new(Mutex::new(receiver));
+    
+    tokio::spawn(async move {
+        let mut restarts: VecDeque<Instant> = VecDeque::new();
+        
+        loop {
+            let mut actor = factory();
+            let worker_mailbox = Arc::clone(&mailbox);
+            let worker = tokio::spawn(async move {
+                let mut mailbox = worker_mailbox.lock().await;
+                while let Some((message, span)) = mailbox.recv().await {
+                    actor.handle(message).instrument(span).await;
+                }
+            });
+            
+            match worker.await {
+                Ok(()) => {
+                    debug!(target: "orchestration", actor = name, "actor mailbox closed, stopping");
+                    break;
+                }
+                Err(e) if e.is_panic() => {
+                    let now = Instant::now();
+                    restarts.retain(|&at| now.duration_since(at) < policy.within);
+                    restarts.push_back(now);
+                    
+                    if restarts.len() as u32 > policy.max_restarts {
+                        error!(
+                            target: "orchestration",
+                            actor = name,
+                            restarts = restarts.len(),
+                            "actor exceeded restart limit, giving up"
+                        );
+                        mailbox.lock().await.close();
+                        break;
+                    }
+                    
+                    warn!(
+                        target: "orchestration",
+                        actor = name,
+                        restarts = restarts.len(),
+                        "actor panicked, restarting"
+                    );
+                }
+                Err(_) => break,
+            }
+        }
+    });
+    
+    ActorHandle { name, sender }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    enum CounterMessage {
+        Add { amount: u64, reply: Reply<u64> },
+        Panic,
+    }
+    
+    struct Counter {
+        total: u64,
+    }
+    
+    #[async_trait]
+    impl Actor for Counter {
+        type Message = CounterMessage;
+        
+        async fn handle(&mut self, message: CounterMessage) {
+            match message {
+                CounterMessage::Add { amount, reply } => {
+                    self.total += amount;
+                    let _ = reply.send(Ok(self.total));
+                }
+                CounterMessage::Panic => panic!("counter failure"),
+            }
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_actor_request_response() {
+        let counter = spawn_supervised("counter", || Counter { total: 0 }, 8, RestartPolicy::default());
+        
+        assert_eq!(counter.call(|reply| CounterMessage::Add { amount: 2, reply }).await.unwrap(), 2);
+        assert_eq!(counter.call(|reply| CounterMessage::Add { amount: 3, reply }).await.unwrap(), 5);
+    }
+    
+    #[tokio::test]
+    async fn test_actor_restarts_after_panic() {
+        let counter = spawn_supervised("counter", || Counter { total: 0 }, 8, RestartPolicy::default());
+        
+        counter.call(|reply| CounterMessage::Add { amount: 7, reply }).await.unwrap();
+        counter.send(CounterMessage::Panic).await.unwrap();
+        
+        // State is rebuilt by the factory after the restart
+        assert_eq!(counter.call(|reply| CounterMessage::Add { amount: 1, reply }).await.unwrap(), 1);
+    }
+    
+    #[tokio::test]
+    async fn test_actor_stops_after_restart_limit() {
+        let policy = RestartPolicy { max_restarts: 1, within: Duration::from_secs(60) };
+        let counter = spawn_supervised("counter", || Counter { total: 0 }, 8, policy);
+        
+        counter.send(CounterMessage::Panic).await.unwrap();
+        counter.send(CounterMessage::Panic).await.unwrap();
+        
+        assert!(counter.call(|reply| CounterMessage::Add { amount: 1, reply }).await.is_err());
+    }
+}
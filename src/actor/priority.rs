@@ -0,0 +1,219 @@
+//! Priority-ordered admission to a shared actor
+//! 
+//! Actor mailboxes are FIFO, so a communication request sent to the orchestrator
+//! would wait behind every background exploration already queued. Callers instead
+//! take a [`PriorityPermit`] from a [`PriorityGate`] before sending: permits are
+//! handed out highest [`TaskPriority`] first, and in arrival order within a
+//! priority, so urgent work overtakes background cycles that have not started.
+
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+use std::fmt;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use serde::{Deserialize, Serialize};
+use tokio::sync::oneshot;
+
+/// Scheduling priority of an exploration task
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskPriority {
+    /// Background exploration cycles
+    Background,
+    /// Communication requests
+    Normal,
+    /// Communication requests whose intent is urgent
+    Urgent,
+}
+
+impl TaskPriority {
+    /// Priority of a communication request with the given intent urgency
+    pub fn for_request(urgency: f64, urgent_threshold: f64) -> Self {
+        if urgency >= urgent_threshold {
+            TaskPriority::Urgent
+        } else {
+            TaskPriority::Normal
+        }
+    }
+}
+
+/// Admission gate granting a fixed number of permits by priority
+pub struct PriorityGate {
+    state: Mutex<GateState>,
+}
+
+struct GateState {
+    available: usize,
+    next_ticket: u64,
+    waiters: BinaryHeap<Waiter>,
+}
+
+struct Waiter {
+    priority: TaskPriority,
+    ticket: Reverse<u64>,
+    grant: oneshot::Sender<PriorityPermit>,
+}
+
+impl PartialEq for Waiter {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Waiter {}
+
+impl PartialOrd for Waiter {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Waiter {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.priority, self.ticket).cmp(&(other.priority, other.ticket))
+    }
+}
+
+impl fmt::Debug for PriorityGate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = self.lock();
+        f.debug_struct("PriorityGate")
+            .field("available", &state.available)
+            .field("waiting", &state.waiters.len())
+            .finish()
+    }
+}
+
+impl PriorityGate {
+    /// Gate admitting `permits` holders at a time
+    pub fn new(permits: usize) -> Arc<Self> {
+        Arc::new(Self {
+            state: Mutex::new(GateState {
+                available: permits.max(1),
+                next_ticket: 0,
+                waiters: BinaryHeap::new(),
+            }),
+        })
+    }
+    
+    fn lock(&self) -> MutexGuard<'_, GateState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+    
+    /// Wait for a permit; higher priorities are admitted first
+    pub async fn acquire(self: &Arc<Self>, priority: TaskPriority) -> PriorityPermit {
+        let granted = {
+            let mut state = self.lock();
+            if state.available > 0 {
+                state.available -= 1;
+                return PriorityPermit { gate: Some(Arc::clone(self)), priority };
+            }
+            
+            let (grant, granted) = oneshot::channel();
+            let ticket = Reverse(state.next_ticket);
+            state.next_ticket += 1;
+            state.waiters.push(Waiter { priority, ticket, grant });
+            granted
+        };
+        
+        // Waiters are only dropped by `release`, which always sends a permit first
+        granted.await.unwrap_or_else(|_| PriorityPermit { gate: None, priority })
+    }
+    
+    /// Callers waiting for a permit
+    pub fn waiting(&self) -> usize {
+        self.lock().waiters.len()
+    }
+    
+    /// Hand a returned permit to the most urgent live waiter
+    fn release(self: &Arc<Self>) {
+        let mut state = self.lock();
+        while let Some(waiter) = state.waiters.pop() {
+            let permit = PriorityPermit { gate: Some(Arc::clone(self)), priority: waiter.priority };
+            match waiter.grant.send(permit) {
+                Ok(()) => return,
+                // The waiter gave up; its permit must not release again
+                Err(mut unclaimed) => unclaimed.gate = None,
+            }
+        }
+        state.available += 1;
+    }
+}
+
+/// Admission held until dropped
+#[derive(Debug)]
+pub struct PriorityPermit {
+    gate: Option<Arc<PriorityGate>>,
+    priority: TaskPriority,
+}
+
+impl PriorityPermit {
+    /// Priority the permit was granted at
+    pub fn priority(&self) -> TaskPriority {
+        self.priority
+    }
+}
+
+impl Drop for PriorityPermit {
+    fn drop(&mut self) {
+        if let Some(gate) = self.gate.take() {
+            gate.release();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    
+    #[tokio::test]
+    async fn test_urgent_waiters_overtake_background() {
+        let gate = PriorityGate::new(1);
+        let running = gate.acquire(TaskPriority::Background).await;
+        
+        let order = Arc::new(Mutex::new(Vec::new()));
+        let mut waiters = Vec::new();
+        for priority in [TaskPriority::Background, TaskPriority::Normal, TaskPriority::Urgent] {
+            let (gate, order) = (Arc::clone(&gate), Arc::clone(&order));
+            waiters.push(tokio::spawn(async move {
+                let _permit = gate.acquire(priority).await;
+                order.lock().unwrap().push(priority);
+            }));
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+        assert_eq!(gate.waiting(), 3);
+        
+        drop(running);
+        for waiter in waiters {
+            waiter.await.unwrap();
+        }
+        assert_eq!(
+            *order.lock().unwrap(),
+            vec![TaskPriority::Urgent, TaskPriority::Normal, TaskPriority::Background]
+        );
+    }
+    
+    #[tokio::test]
+    async fn test_abandoned_waiter_does_not_leak_permit() {
+        let gate = PriorityGate::new(1);
+        let running = gate.acquire(TaskPriority::Normal).await;
+        
+        let abandoned = tokio::time::timeout(
+            Duration::from_millis(5),
+            gate.acquire(TaskPriority::Urgent),
+        ).await;
+        assert!(abandoned.is_err());
+        
+        drop(running);
+        let _permit = tokio::time::timeout(
+            Duration::from_millis(50),
+            gate.acquire(TaskPriority::Background),
+        ).await.expect("permit was leaked");
+    }
+    
+    #[test]
+    fn test_request_priority_from_urgency() {
+        assert_eq!(TaskPriority::for_request(0.9, 0.8), TaskPriority::Urgent);
+        assert_eq!(TaskPriority::for_request(0.5, 0.8), TaskPriority::Normal);
+    }
+}
@@ -4,12 +4,41 @@
 //! implementing the temporal-emotional substrate and frame selection architecture.
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
 use uuid::Uuid;
 use anyhow::Result;
+use hugure_core::units::Femtoseconds;
+
+mod builder;
+mod codec;
+mod delta;
+mod frame_set;
+mod interference;
+mod learning;
+mod quality;
+mod reception;
+mod sampling;
+mod similarity;
+mod validation;
+
+pub use builder::{BMDBuilder, OptimalBMDConfigurationBuilder, LOCAL_FOUNDRY_ID};
+pub use codec::{BMD_FORMAT_VERSION, BMD_MAGIC};
+pub use delta::BMDDelta;
+pub use frame_set::FrameSet;
+pub use interference::FrequencyConflict;
+pub use learning::{LearningRates, RECEPTION_SUCCESS_QUALITY};
+pub use quality::{MetricSummary, QualityGate, QualityReport, QualityTrends};
+pub use reception::{ReceptionStats, DEFAULT_RECEPTION_HISTORY_CAPACITY};
+pub use sampling::{sample_stratified, SamplingKey};
+pub use similarity::{similarity, BMDIndex, EMOTIONAL_WEIGHT, FREQUENCY_WEIGHT, VECTOR_WEIGHT};
+pub use validation::{BMDValidationError, FieldViolation, Violation};
+pub(crate) use validation::validate_selection;
 
 /// Biological Maxwell Demon - core cognitive pattern unit
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BMD {
     /// Unique BMD identifier
     pub id: Uuid,
@@ -25,8 +54,15 @@ pub struct BMD {
     pub foundry_source: FoundrySource,
 }
 
+impl BMD {
+    /// Build a BMD around `core_vectors`, defaulting everything else
+    pub fn builder(core_vectors: Vec<f64>) -> BMDBuilder {
+        BMDBuilder::new(core_vectors)
+    }
+}
+
 /// BMD pattern configuration based on predetermined coordinates
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BMDPattern {
     /// Core pattern vectors (from Virtual BMD Foundries)
     pub core_vectors: Vec<f64>,
@@ -39,7 +75,7 @@ pub struct BMDPattern {
 }
 
 /// Emotional temporal substrate for BMD operation
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EmotionalSubstrate {
     /// Emotional arousal level (E in temporal dilation equation)
     pub arousal_level: f64, // 0-10 scale
@@ -80,13 +116,33 @@ impl EmotionalSubstrate {
         self.memory_encoding = 8.0; // Strong encoding for pattern retention
         self.calculate_temporal_dilation();
     }
+    
+    /// Move this substrate `weight` of the way toward `other` (0.0 = unchanged, 1.0 = `other`)
+    /// 
+    /// Arousal, attention, and memory encoding are mixed linearly; temporal dilation and
+    /// choice expansion are recomputed from the mixed levels rather than mixed themselves.
+    pub fn blend(&mut self, other: &EmotionalSubstrate, weight: f64) {
+        let weight = if weight.is_nan() { 0.0 } else { weight.clamp(0.0, 1.0) };
+        let mix = |from: f64, to: f64| from + (to - from) * weight;
+        self.arousal_level = mix(self.arousal_level, other.arousal_level);
+        self.attention_intensity = mix(self.attention_intensity, other.attention_intensity);
+        self.memory_encoding = mix(self.memory_encoding, other.memory_encoding);
+        self.calculate_temporal_dilation();
+    }
+    
+    /// Substrate `t` of the way from `self` to `other`, leaving both unchanged
+    pub fn lerp(&self, other: &EmotionalSubstrate, t: f64) -> EmotionalSubstrate {
+        let mut substrate = self.clone();
+        substrate.blend(other, t);
+        substrate
+    }
 }
 
 /// Temporal coherence properties for maintaining BMD state across interruptions
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TemporalCoherence {
     /// Coherence maintenance duration (femtoseconds to microseconds)
-    pub coherence_duration: u64,
+    pub coherence_duration: Femtoseconds,
     /// Coherence degradation rate
     pub degradation_rate: f64,
     /// Interruption resistance factor
@@ -96,7 +152,7 @@ pub struct TemporalCoherence {
 }
 
 /// Frame selection weights based on Chapter 17 BMD selection function
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FrameWeights {
     /// Base weight in memory (W_i)
     pub base_weight: f64,
@@ -113,13 +169,21 @@ pub struct FrameWeights {
 impl FrameWeights {
     /// Calculate selection probability using Chapter 17 formula
     /// P(frame_i | experience_j) = [W_i × R_ij × E_ij × T_ij] / Σ[W_k × R_kj × E_kj × T_kj]
+    /// 
+    /// [`FrameSet`] computes `normalization_sum` over a set of frames.
     pub fn calculate_selection_probability(&mut self, experience_context: &ExperienceContext, normalization_sum: f64) {
-        let numerator = self.base_weight 
+        self.selection_probability = Some(self.selection_score(experience_context) / normalization_sum);
+    }
+    
+    /// Unnormalized selection weight W_i × R_ij × E_ij × T_ij
+    /// 
+    /// The R, E, and T factors are currently cached on the frame rather than
+    /// derived from the experience.
+    pub fn selection_score(&self, _experience_context: &ExperienceContext) -> f64 {
+        self.base_weight 
             * self.relevance_multiplier 
             * self.emotional_compatibility 
-            * self.temporal_appropriateness;
-        
-        self.selection_probability = Some(numerator / normalization_sum);
+            * self.temporal_appropriateness
     }
     
     /// Update weights based on successful transmission outcomes
@@ -134,7 +198,7 @@ impl FrameWeights {
 }
 
 /// Virtual BMD Foundry source information
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FoundrySource {
     /// Foundry system identifier
     pub foundry_id: String,
@@ -147,7 +211,7 @@ pub struct FoundrySource {
 }
 
 /// BMD quality assurance metrics from foundry
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct QualityMetrics {
     /// Pattern coherence score
     pub pattern_coherence: f64,
@@ -160,7 +224,7 @@ pub struct QualityMetrics {
 }
 
 /// Frequency range for pattern recognition
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FrequencyRange {
     pub min_frequency: f64,
     pub max_frequency: f64,
@@ -245,7 +309,7 @@ pub struct IndividualModel {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CognitiveFramework {
     /// Framework category (temporal, emotional, narrative, causal)
-    pub category: String,
+    pub category: FrameworkCategory,
     /// Framework strength/preference
     pub strength: f64,
     /// Usage frequency
@@ -254,6 +318,75 @@ pub struct CognitiveFramework {
     pub emotional_valence: f64,
 }
 
+/// Kind of cognitive framework
+/// 
+/// Serialized as its lowercase name, so profiles written when the category was a
+/// plain string still load; names other than the four known ones become `Custom`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum FrameworkCategory {
+    Temporal,
+    Emotional,
+    Narrative,
+    Causal,
+    /// Any other category, by name
+    Custom(String),
+}
+
+impl FrameworkCategory {
+    /// Category name, as used for cross-domain compatibility keys
+    pub fn as_str(&self) -> &str {
+        match self {
+            FrameworkCategory::Temporal => "temporal",
+            FrameworkCategory::Emotional => "emotional",
+            FrameworkCategory::Narrative => "narrative",
+            FrameworkCategory::Causal => "causal",
+            FrameworkCategory::Custom(name) => name,
+        }
+    }
+}
+
+impl From<&str> for FrameworkCategory {
+    fn from(name: &str) -> Self {
+        match name.to_ascii_lowercase().as_str() {
+            "temporal" => FrameworkCategory::Temporal,
+            "emotional" => FrameworkCategory::Emotional,
+            "narrative" => FrameworkCategory::Narrative,
+            "causal" => FrameworkCategory::Causal,
+            _ => FrameworkCategory::Custom(name.to_string()),
+        }
+    }
+}
+
+impl From<String> for FrameworkCategory {
+    fn from(name: String) -> Self {
+        FrameworkCategory::from(name.as_str())
+    }
+}
+
+impl From<FrameworkCategory> for String {
+    fn from(category: FrameworkCategory) -> Self {
+        match category {
+            FrameworkCategory::Custom(name) => name,
+            known => known.as_str().to_string(),
+        }
+    }
+}
+
+impl FromStr for FrameworkCategory {
+    type Err = Infallible;
+    
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Ok(FrameworkCategory::from(name))
+    }
+}
+
+impl fmt::Display for FrameworkCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// Emotional response pattern
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EmotionalPattern {
@@ -306,14 +439,20 @@ pub struct DecisionTimingProfile {
 }
 
 /// BMD reception history for learning
+/// 
+/// Each list keeps at most `capacity` entries, oldest first; recording past
+/// capacity drops the oldest entry.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReceptionHistory {
     /// Successful BMD receptions
-    pub successful_receptions: Vec<BMDReceptionEvent>,
+    pub successful_receptions: VecDeque<BMDReceptionEvent>,
     /// Failed transmission attempts
-    pub failed_attempts: Vec<BMDReceptionEvent>,
+    pub failed_attempts: VecDeque<BMDReceptionEvent>,
     /// Pattern recognition evolution
-    pub recognition_evolution: Vec<RecognitionEvolutionPoint>,
+    pub recognition_evolution: VecDeque<RecognitionEvolutionPoint>,
+    /// Entries kept per list
+    #[serde(default = "reception::default_capacity")]
+    pub capacity: usize,
 }
 
 /// BMD reception event record
@@ -439,6 +578,11 @@ pub struct ConfidenceMetrics {
 }
 
 impl OptimalBMDConfiguration {
+    /// Build a configuration transmitting `primary_bmd`, defaulting everything else
+    pub fn builder(primary_bmd: BMD) -> OptimalBMDConfigurationBuilder {
+        OptimalBMDConfigurationBuilder::new(primary_bmd)
+    }
+    
     /// Calculate overall confidence from component metrics
     pub fn calculate_overall_confidence(&mut self) {
         self.confidence.overall_confidence = (
@@ -458,35 +602,10 @@ impl OptimalBMDConfiguration {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    #[test]
-    fn test_emotional_substrate_temporal_dilation() {
-        let mut substrate = EmotionalSubstrate {
-            arousal_level: 7.5,
-            attention_intensity: 8.5,
-            memory_encoding: 8.0,
-            temporal_dilation: 0.0,
-            choice_expansion: 0.0,
-        };
-        
-        substrate.calculate_temporal_dilation();
-        
-        // Should be in decision moment expansion range (3.2-4.7)
-        assert!(substrate.choice_expansion >= 3.2);
-        assert!(substrate.choice_expansion <= 4.7);
-    }
     
-    #[test]
-    fn test_frame_weights_selection_probability() {
-        let mut weights = FrameWeights {
-            base_weight: 1.0,
-            relevance_multiplier: 0.8,
-            emotional_compatibility: 0.9,
-            temporal_appropriateness: 0.7,
-            selection_probability: None,
-        };
-        
-        let experience = ExperienceContext {
+    /// Experience context shared by BMD tests
+    pub(crate) fn experience_context() -> ExperienceContext {
+        ExperienceContext {
             sensory_input: HashMap::new(),
             emotional_state: EmotionalSubstrate {
                 arousal_level: 5.0,
@@ -516,11 +635,7 @@ mod tests {
                             agency_attribution_timing: 1.0,
                         },
                     },
-                    reception_history: ReceptionHistory {
-                        successful_receptions: vec![],
-                        failed_attempts: vec![],
-                        recognition_evolution: vec![],
-                    },
+                    reception_history: ReceptionHistory::default(),
                 },
                 recipient_model: IndividualModel {
                     individual_id: "test".to_string(),
@@ -536,11 +651,7 @@ mod tests {
                             agency_attribution_timing: 1.0,
                         },
                     },
-                    reception_history: ReceptionHistory {
-                        successful_receptions: vec![],
-                        failed_attempts: vec![],
-                        recognition_evolution: vec![],
-                    },
+                    reception_history: ReceptionHistory::default(),
                 },
                 intent: CommunicationIntent {
                     primary_goal: CommunicationGoal::PatternTransmission("test".to_string()),
@@ -566,8 +677,77 @@ mod tests {
                     },
                 },
             },
+        }
+    }
+    
+    #[test]
+    fn test_emotional_substrate_temporal_dilation() {
+        let mut substrate = EmotionalSubstrate {
+            arousal_level: 7.5,
+            attention_intensity: 8.5,
+            memory_encoding: 8.0,
+            temporal_dilation: 0.0,
+            choice_expansion: 0.0,
+        };
+        
+        substrate.calculate_temporal_dilation();
+        
+        // Should be in decision moment expansion range (3.2-4.7)
+        assert!(substrate.choice_expansion >= 3.2);
+        assert!(substrate.choice_expansion <= 4.7);
+    }
+    
+    #[test]
+    fn test_emotional_substrate_lerp() {
+        let calm = EmotionalSubstrate {
+            arousal_level: 2.0,
+            attention_intensity: 3.0,
+            memory_encoding: 4.0,
+            temporal_dilation: 0.0,
+            choice_expansion: 0.0,
+        };
+        let mut target = calm.clone();
+        target.optimize_for_transmission();
+        
+        let halfway = calm.lerp(&target, 0.5);
+        assert!((halfway.arousal_level - 4.75).abs() < 1e-9);
+        assert!((halfway.attention_intensity - 5.75).abs() < 1e-9);
+        assert!(halfway.temporal_dilation > 0.0);
+        
+        let mut end = calm.clone();
+        end.blend(&target, 2.0);
+        assert!((end.temporal_dilation - target.temporal_dilation).abs() < 1e-9);
+        assert_eq!(end.choice_expansion, target.choice_expansion);
+    }
+    
+    #[test]
+    fn test_framework_category_serde_compatibility() {
+        let framework: CognitiveFramework = serde_json::from_value(serde_json::json!({
+            "category": "Narrative",
+            "strength": 1.0,
+            "usage_frequency": 0.5,
+            "emotional_valence": 0.0,
+        })).unwrap();
+        assert_eq!(framework.category, FrameworkCategory::Narrative);
+        
+        let custom = FrameworkCategory::from("spatial");
+        assert_eq!(custom, FrameworkCategory::Custom("spatial".to_string()));
+        assert_eq!(serde_json::to_string(&custom).unwrap(), r#""spatial""#);
+        assert_eq!(serde_json::to_string(&FrameworkCategory::Causal).unwrap(), r#""causal""#);
+    }
+    
+    #[test]
+    fn test_frame_weights_selection_probability() {
+        let mut weights = FrameWeights {
+            base_weight: 1.0,
+            relevance_multiplier: 0.8,
+            emotional_compatibility: 0.9,
+            temporal_appropriateness: 0.7,
+            selection_probability: None,
         };
         
+        let experience = experience_context();
+        
         weights.calculate_selection_probability(&experience, 2.0);
         
         assert!(weights.selection_probability.is_some());
@@ -0,0 +1,418 @@
+//! Builders for [`BMD`] and [`OptimalBMDConfiguration`]
+//! 
+//! Every field has a default, so integrators only set what they care about.
+//! `build()` checks the ranges documented on each field and reports every
+//! problem at once; BMDs are checked with [`BMD::validate`].
+
+use std::collections::HashMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+use anyhow::Result;
+use uuid::Uuid;
+use hugure_core::units::Femtoseconds;
+
+use super::{
+    BMD, BMDPattern, ConfidenceMetrics, EmotionalSubstrate, ExpectedOutcomes, FoundrySource,
+    FrameWeights, FrequencyRange, OptimalBMDConfiguration, QualityMetrics, TemporalCoherence,
+    TransmissionTiming,
+};
+
+/// Foundry id of BMDs built without a foundry source
+pub const LOCAL_FOUNDRY_ID: &str = "local";
+
+/// Builder for a [`BMD`]
+/// 
+/// Only the core pattern vectors have no default. The emotional substrate
+/// defaults to the transmission-optimal levels of
+/// [`EmotionalSubstrate::optimize_for_transmission`], and its temporal dilation
+/// is always derived from the levels on build.
+#[derive(Debug, Clone)]
+pub struct BMDBuilder {
+    id: Option<Uuid>,
+    core_vectors: Vec<f64>,
+    cross_domain_compatibility: HashMap<String, f64>,
+    frequency_ranges: Vec<FrequencyRange>,
+    semantic_opacity: f64,
+    arousal_level: f64,
+    attention_intensity: f64,
+    memory_encoding: f64,
+    temporal_coherence: TemporalCoherence,
+    frame_weights: FrameWeights,
+    foundry_id: String,
+    generation_rate: u64,
+    quality_metrics: QualityMetrics,
+}
+
+impl Default for BMDBuilder {
+    fn default() -> Self {
+        Self {
+            id: None,
+            core_vectors: Vec::new(),
+            cross_domain_compatibility: HashMap::new(),
+            frequency_ranges: Vec::new(),
+            semantic_opacity: 0.5,
+            arousal_level: 7.5,
+            attention_intensity: 8.5,
+            memory_encoding: 8.0,
+            temporal_coherence: TemporalCoherence {
+                coherence_duration: Femtoseconds::new(1_000_000),
+                degradation_rate: 0.01,
+                interruption_resistance: 0.5,
+                temporal_binding: 0.5,
+            },
+            frame_weights: FrameWeights {
+                base_weight: 1.0,
+                relevance_multiplier: 1.0,
+                emotional_compatibility: 1.0,
+                temporal_appropriateness: 1.0,
+                selection_probability: None,
+            },
+            foundry_id: LOCAL_FOUNDRY_ID.to_string(),
+            generation_rate: 0,
+            quality_metrics: QualityMetrics {
+                pattern_coherence: 1.0,
+                cross_domain_score: 1.0,
+                temporal_stability: 1.0,
+                transmission_fidelity: 1.0,
+            },
+        }
+    }
+}
+
+impl BMDBuilder {
+    /// Builder for a BMD with `core_vectors` and default everything else
+    pub fn new(core_vectors: Vec<f64>) -> Self {
+        Self { core_vectors, ..Self::default() }
+    }
+    
+    /// Use `id` instead of a fresh random one
+    pub fn id(mut self, id: Uuid) -> Self {
+        self.id = Some(id);
+        self
+    }
+    
+    /// Core pattern vectors; must be non-empty and finite
+    pub fn core_vectors(mut self, core_vectors: Vec<f64>) -> Self {
+        self.core_vectors = core_vectors;
+        self
+    }
+    
+    /// Compatibility with `domain`, in [0, 1]
+    pub fn cross_domain_compatibility(mut self, domain: impl Into<String>, score: f64) -> Self {
+        self.cross_domain_compatibility.insert(domain.into(), score);
+        self
+    }
+    
+    /// Add a recognition frequency range
+    pub fn frequency_range(mut self, min_frequency: f64, max_frequency: f64) -> Self {
+        self.frequency_ranges.push(FrequencyRange {
+            min_frequency,
+            max_frequency,
+            amplitude: 1.0,
+            phase: 0.0,
+        });
+        self
+    }
+    
+    /// Add a recognition frequency range with its amplitude and phase
+    pub fn frequency_range_with(mut self, range: FrequencyRange) -> Self {
+        self.frequency_ranges.push(range);
+        self
+    }
+    
+    /// Semantic opacity, from 0.0 (fully semantic) to 1.0 (pure pattern)
+    pub fn semantic_opacity(mut self, semantic_opacity: f64) -> Self {
+        self.semantic_opacity = semantic_opacity;
+        self
+    }
+    
+    /// Emotional arousal, attention, and memory encoding levels, each on a 0-10 scale
+    pub fn emotional_levels(mut self, arousal: f64, attention: f64, memory_encoding: f64) -> Self {
+        self.arousal_level = arousal;
+        self.attention_intensity = attention;
+        self.memory_encoding = memory_encoding;
+        self
+    }
+    
+    /// Temporal coherence properties
+    pub fn temporal_coherence(mut self, temporal_coherence: TemporalCoherence) -> Self {
+        self.temporal_coherence = temporal_coherence;
+        self
+    }
+    
+    /// Frame selection weights
+    pub fn frame_weights(mut self, frame_weights: FrameWeights) -> Self {
+        self.frame_weights = frame_weights;
+        self
+    }
+    
+    /// Foundry the BMD came from, and the rate it was generating at
+    pub fn foundry(mut self, foundry_id: impl Into<String>, generation_rate: u64) -> Self {
+        self.foundry_id = foundry_id.into();
+        self.generation_rate = generation_rate;
+        self
+    }
+    
+    /// Foundry quality metrics, each in [0, 1]; assumed perfect unless set
+    pub fn quality_metrics(mut self, quality_metrics: QualityMetrics) -> Self {
+        self.quality_metrics = quality_metrics;
+        self
+    }
+    
+    /// Validate the settings and build the BMD
+    pub fn build(self) -> Result<BMD> {
+        let mut emotional_substrate = EmotionalSubstrate {
+            arousal_level: self.arousal_level,
+            attention_intensity: self.attention_intensity,
+            memory_encoding: self.memory_encoding,
+            temporal_dilation: 0.0,
+            choice_expansion: 0.0,
+        };
+        emotional_substrate.calculate_temporal_dilation();
+        
+        let bmd = BMD {
+            id: self.id.unwrap_or_else(Uuid::new_v4),
+            pattern: BMDPattern {
+                core_vectors: self.core_vectors,
+                cross_domain_compatibility: self.cross_domain_compatibility,
+                frequency_ranges: self.frequency_ranges,
+                semantic_opacity: self.semantic_opacity,
+            },
+            emotional_substrate,
+            temporal_coherence: self.temporal_coherence,
+            frame_weights: self.frame_weights,
+            foundry_source: FoundrySource {
+                foundry_id: self.foundry_id,
+                generation_time: SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map(|elapsed| elapsed.as_secs())
+                    .unwrap_or(0),
+                generation_rate: self.generation_rate,
+                quality_metrics: self.quality_metrics,
+            },
+        };
+        bmd.validate()?;
+        Ok(bmd)
+    }
+}
+
+/// Builder for an [`OptimalBMDConfiguration`] around a primary BMD
+/// 
+/// Timing defaults to a single transmission lasting the primary BMD's
+/// coherence duration. Expected fidelity and reception default to the primary
+/// BMD's foundry transmission fidelity, and pattern and temporal confidence to
+/// its pattern coherence and temporal stability. The overall confidence is
+/// always derived on build.
+#[derive(Debug, Clone)]
+pub struct OptimalBMDConfigurationBuilder {
+    primary_bmd: BMD,
+    supporting_bmds: Vec<BMD>,
+    timing_parameters: Option<TransmissionTiming>,
+    expected_outcomes: Option<ExpectedOutcomes>,
+    pattern_match_confidence: Option<f64>,
+    emotional_compatibility_confidence: f64,
+    temporal_alignment_confidence: Option<f64>,
+    environmental_suitability_confidence: f64,
+    max_interference: Option<f64>,
+}
+
+impl OptimalBMDConfigurationBuilder {
+    /// Builder for a configuration transmitting `primary_bmd`
+    pub fn new(primary_bmd: BMD) -> Self {
+        Self {
+            primary_bmd,
+            supporting_bmds: Vec::new(),
+            timing_parameters: None,
+            expected_outcomes: None,
+            pattern_match_confidence: None,
+            emotional_compatibility_confidence: 0.5,
+            temporal_alignment_confidence: None,
+            environmental_suitability_confidence: 0.5,
+            max_interference: None,
+        }
+    }
+    
+    /// Add a supporting BMD
+    pub fn supporting_bmd(mut self, bmd: BMD) -> Self {
+        self.supporting_bmds.push(bmd);
+        self
+    }
+    
+    /// Add supporting BMDs
+    pub fn supporting_bmds(mut self, bmds: impl IntoIterator<Item = BMD>) -> Self {
+        self.supporting_bmds.extend(bmds);
+        self
+    }
+    
+    /// Transmission timing parameters
+    pub fn timing(mut self, timing_parameters: TransmissionTiming) -> Self {
+        self.timing_parameters = Some(timing_parameters);
+        self
+    }
+    
+    /// Expected outcomes, each in [0, 1]
+    pub fn expected_outcomes(mut self, expected_outcomes: ExpectedOutcomes) -> Self {
+        self.expected_outcomes = Some(expected_outcomes);
+        self
+    }
+    
+    /// Component confidences, each in [0, 1]
+    pub fn confidence(
+        mut self,
+        pattern_match: f64,
+        emotional_compatibility: f64,
+        temporal_alignment: f64,
+        environmental_suitability: f64,
+    ) -> Self {
+        self.pattern_match_confidence = Some(pattern_match);
+        self.emotional_compatibility_confidence = emotional_compatibility;
+        self.temporal_alignment_confidence = Some(temporal_alignment);
+        self.environmental_suitability_confidence = environmental_suitability;
+        self
+    }
+    
+    /// Reject BMD pairs whose frequency interference exceeds `threshold`
+    /// 
+    /// See [`OptimalBMDConfiguration::interference_score`]; unchecked unless set.
+    pub fn max_interference(mut self, threshold: f64) -> Self {
+        self.max_interference = Some(threshold);
+        self
+    }
+    
+    /// Validate the settings and build the configuration
+    pub fn build(self) -> Result<OptimalBMDConfiguration> {
+        let quality = &self.primary_bmd.foundry_source.quality_metrics;
+        let coherence_duration = self.primary_bmd.temporal_coherence.coherence_duration.get();
+        let timing_parameters = self.timing_parameters.unwrap_or(TransmissionTiming {
+            optimal_transmission_time: 0,
+            preparation_phase_duration: 0,
+            transmission_phase_duration: coherence_duration,
+            integration_phase_duration: coherence_duration,
+            repetition_intervals: Vec::new(),
+        });
+        let expected_outcomes = self.expected_outcomes.unwrap_or(ExpectedOutcomes {
+            transmission_fidelity: quality.transmission_fidelity,
+            reception_probability: quality.transmission_fidelity,
+            integration_likelihood: 0.5,
+            behavioral_impact: 0.0,
+            durability: (1.0 - self.primary_bmd.temporal_coherence.degradation_rate).max(0.0),
+        });
+        let confidence = ConfidenceMetrics {
+            pattern_match_confidence: self
+                .pattern_match_confidence
+                .unwrap_or(quality.pattern_coherence),
+            emotional_compatibility_confidence: self.emotional_compatibility_confidence,
+            temporal_alignment_confidence: self
+                .temporal_alignment_confidence
+                .unwrap_or(quality.temporal_stability),
+            environmental_suitability_confidence: self.environmental_suitability_confidence,
+            overall_confidence: 0.0,
+        };
+        
+        let mut issues = Vec::new();
+        let probabilities = [
+            ("transmission_fidelity", expected_outcomes.transmission_fidelity),
+            ("reception_probability", expected_outcomes.reception_probability),
+            ("integration_likelihood", expected_outcomes.integration_likelihood),
+            ("behavioral_impact", expected_outcomes.behavioral_impact),
+            ("durability", expected_outcomes.durability),
+            ("pattern_match_confidence", confidence.pattern_match_confidence),
+            ("emotional_compatibility_confidence", confidence.emotional_compatibility_confidence),
+            ("temporal_alignment_confidence", confidence.temporal_alignment_confidence),
+            (
+                "environmental_suitability_confidence",
+                confidence.environmental_suitability_confidence,
+            ),
+        ];
+        for (field, value) in probabilities {
+            check_unit(field, value, &mut issues);
+        }
+        if timing_parameters.transmission_phase_duration == 0 {
+            issues.push("transmission_phase_duration must be greater than 0".to_string());
+        }
+        if timing_parameters.repetition_intervals.contains(&0) {
+            issues.push("repetition_intervals must be greater than 0".to_string());
+        }
+        
+        let mut ids = vec![self.primary_bmd.id];
+        for bmd in &self.supporting_bmds {
+            if ids.contains(&bmd.id) {
+                issues.push(format!("BMD {} appears more than once", bmd.id));
+            }
+            ids.push(bmd.id);
+        }
+        
+        let mut configuration = OptimalBMDConfiguration {
+            primary_bmd: self.primary_bmd,
+            supporting_bmds: self.supporting_bmds,
+            timing_parameters,
+            expected_outcomes,
+            confidence,
+        };
+        if let Some(threshold) = self.max_interference {
+            for (bmd, other, interference) in configuration.interfering_bmds(threshold) {
+                issues.push(format!(
+                    "BMDs {} and {} interfere at {:.3}, above {}",
+                    bmd, other, interference, threshold
+                ));
+            }
+        }
+        
+        if !issues.is_empty() {
+            return Err(anyhow::anyhow!("invalid BMD configuration: {}", issues.join("; ")));
+        }
+        
+        configuration.calculate_overall_confidence();
+        Ok(configuration)
+    }
+}
+
+/// Record an issue unless `value` is in [0, 1]
+fn check_unit(field: &str, value: f64, issues: &mut Vec<String>) {
+    if !(0.0..=1.0).contains(&value) {
+        issues.push(format!("{} must be in [0, 1], got {}", field, value));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_bmd_defaults_and_validation() {
+        let bmd = BMD::builder(vec![0.2, 0.4])
+            .cross_domain_compatibility("temporal", 0.9)
+            .frequency_range(4.0, 8.0)
+            .build()
+            .unwrap();
+        assert_eq!(bmd.foundry_source.foundry_id, LOCAL_FOUNDRY_ID);
+        assert!(bmd.emotional_substrate.choice_expansion >= 3.2);
+        
+        let error = BMD::builder(Vec::new())
+            .semantic_opacity(1.5)
+            .emotional_levels(11.0, 5.0, 5.0)
+            .build()
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("core_vectors"));
+        assert!(error.contains("semantic_opacity"));
+        assert!(error.contains("arousal_level"));
+    }
+    
+    #[test]
+    fn test_configuration_derives_confidence() {
+        let primary = BMD::builder(vec![1.0]).build().unwrap();
+        let supporting = BMD::builder(vec![0.5]).build().unwrap();
+        let configuration = OptimalBMDConfiguration::builder(primary.clone())
+            .supporting_bmd(supporting)
+            .confidence(1.0, 1.0, 1.0, 1.0)
+            .build()
+            .unwrap();
+        assert!((configuration.confidence.overall_confidence - 1.0).abs() < 1e-9);
+        assert!(configuration.meets_quality_threshold(0.99));
+        
+        let duplicate = OptimalBMDConfiguration::builder(primary.clone())
+            .supporting_bmd(primary)
+            .build();
+        assert!(duplicate.is_err());
+    }
+}
@@ -0,0 +1,92 @@
+//! Compact binary encoding of [`BMD`]s
+//! 
+//! Foundry and Kambuzuma transports moving BMDs in bulk use this instead of JSON.
+//! An encoded BMD is a six-byte header followed by the bincode encoding of the
+//! BMD:
+//! 
+//! ```text
+//! "HBMD" | format version (u16, little endian) | bincode payload
+//! ```
+//! 
+//! bincode is positional, so any change to the fields of [`BMD`] or the types it
+//! contains must bump [`BMD_FORMAT_VERSION`]; payloads of other versions are
+//! rejected rather than misread.
+
+use anyhow::{Context, Result};
+
+use super::BMD;
+
+/// Marker at the start of every encoded BMD
+pub const BMD_MAGIC: [u8; 4] = *b"HBMD";
+
+/// Binary layout version written by this build
+pub const BMD_FORMAT_VERSION: u16 = 1;
+
+/// Bytes before the bincode payload
+const HEADER_LEN: usize = BMD_MAGIC.len() + std::mem::size_of::<u16>();
+
+impl BMD {
+    /// Encode as header plus bincode payload
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let mut bytes = Vec::new();
+        self.write_bytes(&mut bytes)?;
+        Ok(bytes)
+    }
+    
+    /// Append the encoding to `out`, so batches can reuse one buffer
+    pub fn write_bytes(&self, out: &mut Vec<u8>) -> Result<()> {
+        let payload_len = bincode::serialized_size(self).context("failed to size BMD")?;
+        out.reserve(HEADER_LEN + payload_len as usize);
+        out.extend_from_slice(&BMD_MAGIC);
+        out.extend_from_slice(&BMD_FORMAT_VERSION.to_le_bytes());
+        bincode::serialize_into(out, self).context("failed to encode BMD")
+    }
+    
+    /// Decode bytes produced by [`BMD::to_bytes`], rejecting invalid BMDs
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() < HEADER_LEN || bytes[..BMD_MAGIC.len()] != BMD_MAGIC {
+            return Err(anyhow::anyhow!("not an encoded BMD"));
+        }
+        let version = u16::from_le_bytes([bytes[4], bytes[5]]);
+        if version != BMD_FORMAT_VERSION {
+            return Err(anyhow::anyhow!(
+                "BMD format version {} is not supported (expected {})",
+                version,
+                BMD_FORMAT_VERSION
+            ));
+        }
+        let bmd: Self = bincode::deserialize(&bytes[HEADER_LEN..]).context("failed to decode BMD")?;
+        bmd.validate()?;
+        Ok(bmd)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_round_trip_is_smaller_than_json() {
+        let bmd = BMD::builder(vec![0.1, 0.2, 0.3])
+            .cross_domain_compatibility("temporal", 0.8)
+            .frequency_range(4.0, 8.0)
+            .build()
+            .unwrap();
+        
+        let bytes = bmd.to_bytes().unwrap();
+        let decoded = BMD::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded.id, bmd.id);
+        assert_eq!(decoded.pattern.core_vectors, bmd.pattern.core_vectors);
+        assert!(bytes.len() < serde_json::to_vec(&bmd).unwrap().len());
+    }
+    
+    #[test]
+    fn test_rejects_other_versions() {
+        let mut bytes = BMD::builder(vec![1.0]).build().unwrap().to_bytes().unwrap();
+        bytes[4..HEADER_LEN].copy_from_slice(&(BMD_FORMAT_VERSION + 1).to_le_bytes());
+        
+        let error = BMD::from_bytes(&bytes).unwrap_err().to_string();
+        assert!(error.contains("not supported"));
+        assert!(BMD::from_bytes(b"{}").is_err());
+    }
+}
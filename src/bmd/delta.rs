@@ -0,0 +1,189 @@
+//! Field-level updates to already-selected BMDs
+//! 
+//! Foundries refine BMDs they have already handed out. Instead of re-sending the
+//! whole BMD every cycle, a foundry sends a [`BMDDelta`] holding only what
+//! changed, computed with [`BMDDelta::diff`] and applied with [`BMDDelta::apply`].
+//! Cross-domain compatibility is diffed per domain; every other field is sent
+//! whole when it changed.
+
+use std::collections::HashMap;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::{
+    EmotionalSubstrate, FoundrySource, FrameWeights, FrequencyRange, TemporalCoherence, BMD,
+};
+
+/// Changes turning one version of a BMD into another
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BMDDelta {
+    /// BMD the changes apply to
+    pub bmd_id: Uuid,
+    #[serde(default)]
+    pub core_vectors: Option<Vec<f64>>,
+    /// Domains whose compatibility was added or changed
+    #[serde(default)]
+    pub compatibility_changes: HashMap<String, f64>,
+    /// Domains whose compatibility was removed
+    #[serde(default)]
+    pub compatibility_removals: Vec<String>,
+    #[serde(default)]
+    pub frequency_ranges: Option<Vec<FrequencyRange>>,
+    #[serde(default)]
+    pub semantic_opacity: Option<f64>,
+    #[serde(default)]
+    pub emotional_substrate: Option<EmotionalSubstrate>,
+    #[serde(default)]
+    pub temporal_coherence: Option<TemporalCoherence>,
+    #[serde(default)]
+    pub frame_weights: Option<FrameWeights>,
+    #[serde(default)]
+    pub foundry_source: Option<FoundrySource>,
+}
+
+/// `new` if it differs from `old`
+fn changed<T: PartialEq + Clone>(old: &T, new: &T) -> Option<T> {
+    (old != new).then(|| new.clone())
+}
+
+impl BMDDelta {
+    /// Delta with no changes to the BMD with `bmd_id`
+    pub fn empty(bmd_id: Uuid) -> Self {
+        Self {
+            bmd_id,
+            core_vectors: None,
+            compatibility_changes: HashMap::new(),
+            compatibility_removals: Vec::new(),
+            frequency_ranges: None,
+            semantic_opacity: None,
+            emotional_substrate: None,
+            temporal_coherence: None,
+            frame_weights: None,
+            foundry_source: None,
+        }
+    }
+    
+    /// Changes from `old` to `new`, addressed to `old`'s id
+    pub fn diff(old: &BMD, new: &BMD) -> Self {
+        let (old_pattern, new_pattern) = (&old.pattern, &new.pattern);
+        let old_compatibility = &old_pattern.cross_domain_compatibility;
+        let new_compatibility = &new_pattern.cross_domain_compatibility;
+        
+        let compatibility_changes = new_compatibility
+            .iter()
+            .filter(|(domain, score)| old_compatibility.get(*domain) != Some(*score))
+            .map(|(domain, score)| (domain.clone(), *score))
+            .collect();
+        let mut compatibility_removals: Vec<String> = old_compatibility
+            .keys()
+            .filter(|domain| !new_compatibility.contains_key(*domain))
+            .cloned()
+            .collect();
+        compatibility_removals.sort();
+        
+        Self {
+            bmd_id: old.id,
+            core_vectors: changed(&old_pattern.core_vectors, &new_pattern.core_vectors),
+            compatibility_changes,
+            compatibility_removals,
+            frequency_ranges: changed(&old_pattern.frequency_ranges, &new_pattern.frequency_ranges),
+            semantic_opacity: changed(&old_pattern.semantic_opacity, &new_pattern.semantic_opacity),
+            emotional_substrate: changed(&old.emotional_substrate, &new.emotional_substrate),
+            temporal_coherence: changed(&old.temporal_coherence, &new.temporal_coherence),
+            frame_weights: changed(&old.frame_weights, &new.frame_weights),
+            foundry_source: changed(&old.foundry_source, &new.foundry_source),
+        }
+    }
+    
+    /// Whether the delta changes nothing
+    pub fn is_empty(&self) -> bool {
+        *self == Self::empty(self.bmd_id)
+    }
+    
+    /// Apply the changes to `bmd`
+    /// 
+    /// Fails without touching `bmd` if it is not the BMD the delta addresses, or
+    /// if the updated BMD would fail [`BMD::validate`].
+    pub fn apply(&self, bmd: &mut BMD) -> Result<()> {
+        if bmd.id != self.bmd_id {
+            return Err(anyhow::anyhow!(
+                "delta for BMD {} cannot apply to BMD {}",
+                self.bmd_id,
+                bmd.id
+            ));
+        }
+        
+        let mut updated = bmd.clone();
+        let pattern = &mut updated.pattern;
+        if let Some(core_vectors) = &self.core_vectors {
+            pattern.core_vectors = core_vectors.clone();
+        }
+        for domain in &self.compatibility_removals {
+            pattern.cross_domain_compatibility.remove(domain);
+        }
+        pattern.cross_domain_compatibility.extend(self.compatibility_changes.clone());
+        if let Some(frequency_ranges) = &self.frequency_ranges {
+            pattern.frequency_ranges = frequency_ranges.clone();
+        }
+        if let Some(semantic_opacity) = self.semantic_opacity {
+            pattern.semantic_opacity = semantic_opacity;
+        }
+        if let Some(emotional_substrate) = &self.emotional_substrate {
+            updated.emotional_substrate = emotional_substrate.clone();
+        }
+        if let Some(temporal_coherence) = &self.temporal_coherence {
+            updated.temporal_coherence = temporal_coherence.clone();
+        }
+        if let Some(frame_weights) = &self.frame_weights {
+            updated.frame_weights = frame_weights.clone();
+        }
+        if let Some(foundry_source) = &self.foundry_source {
+            updated.foundry_source = foundry_source.clone();
+        }
+        
+        updated.validate()?;
+        *bmd = updated;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_diff_then_apply_round_trips() {
+        let old = BMD::builder(vec![0.1, 0.2])
+            .cross_domain_compatibility("temporal", 0.5)
+            .cross_domain_compatibility("causal", 0.7)
+            .build()
+            .unwrap();
+        let mut new = old.clone();
+        new.pattern.semantic_opacity = 0.8;
+        new.pattern.cross_domain_compatibility.remove("causal");
+        new.pattern.cross_domain_compatibility.insert("temporal".to_string(), 0.9);
+        
+        let delta = BMDDelta::diff(&old, &new);
+        assert_eq!(delta.compatibility_removals, vec!["causal".to_string()]);
+        assert_eq!(delta.core_vectors, None);
+        assert!(BMDDelta::diff(&new, &new).is_empty());
+        
+        let mut updated = old.clone();
+        delta.apply(&mut updated).unwrap();
+        assert_eq!(updated, new);
+    }
+    
+    #[test]
+    fn test_apply_rejects_invalid_results() {
+        let mut bmd = BMD::builder(vec![1.0]).build().unwrap();
+        let original = bmd.clone();
+        
+        let delta = BMDDelta { semantic_opacity: Some(2.0), ..BMDDelta::empty(bmd.id) };
+        assert!(delta.apply(&mut bmd).is_err());
+        assert_eq!(bmd, original);
+        
+        let other = BMDDelta::empty(Uuid::new_v4());
+        assert!(other.apply(&mut bmd).is_err());
+    }
+}
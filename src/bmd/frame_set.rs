@@ -0,0 +1,98 @@
+//! Normalized frame selection over a set of frames
+//! 
+//! [`FrameWeights::calculate_selection_probability`] divides by a normalization
+//! sum the caller must supply. [`FrameSet`] computes it over the frames competing
+//! for one experience, so the resulting probabilities sum to 1.0.
+
+use super::{ExperienceContext, FrameWeights};
+
+/// Frames competing for selection in one experience
+#[derive(Debug)]
+pub struct FrameSet<'a> {
+    frames: &'a mut [FrameWeights],
+    experience_context: &'a ExperienceContext,
+}
+
+impl<'a> FrameSet<'a> {
+    /// Frames of `frames` competing in `experience_context`
+    pub fn new(frames: &'a mut [FrameWeights], experience_context: &'a ExperienceContext) -> Self {
+        Self { frames, experience_context }
+    }
+    
+    /// Selection score of every frame
+    /// 
+    /// Negative and non-finite scores count as zero, so one malformed frame
+    /// cannot push the others' probabilities out of [0, 1].
+    fn scores(&self) -> Vec<f64> {
+        self.frames
+            .iter()
+            .map(|frame| frame.selection_score(self.experience_context))
+            .map(|score| if score.is_finite() && score > 0.0 { score } else { 0.0 })
+            .collect()
+    }
+    
+    /// Sum of selection scores, the denominator of the Chapter 17 formula
+    pub fn normalization_sum(&self) -> f64 {
+        self.scores().iter().sum()
+    }
+    
+    /// Selection probability of each frame, in slice order, summing to 1.0
+    /// 
+    /// If no frame has a positive score every frame is equally likely. An empty
+    /// set yields an empty distribution.
+    pub fn distribution(&self) -> Vec<f64> {
+        let scores = self.scores();
+        let sum: f64 = scores.iter().sum();
+        if sum > 0.0 {
+            scores.iter().map(|score| score / sum).collect()
+        } else {
+            vec![1.0 / scores.len() as f64; scores.len()]
+        }
+    }
+    
+    /// Store each frame's probability in its `selection_probability`
+    pub fn normalize(self) -> Vec<f64> {
+        let distribution = self.distribution();
+        for (frame, probability) in self.frames.iter_mut().zip(&distribution) {
+            frame.selection_probability = Some(*probability);
+        }
+        distribution
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bmd::tests::experience_context;
+    
+    fn frame(base_weight: f64) -> FrameWeights {
+        FrameWeights {
+            base_weight,
+            relevance_multiplier: 1.0,
+            emotional_compatibility: 1.0,
+            temporal_appropriateness: 1.0,
+            selection_probability: None,
+        }
+    }
+    
+    #[test]
+    fn test_distribution_sums_to_one() {
+        let experience = experience_context();
+        let mut frames = vec![frame(1.0), frame(3.0), frame(f64::NAN)];
+        
+        let set = FrameSet::new(&mut frames, &experience);
+        assert!((set.normalization_sum() - 4.0).abs() < 1e-9);
+        let distribution = set.normalize();
+        
+        assert_eq!(distribution, vec![0.25, 0.75, 0.0]);
+        assert_eq!(frames[1].selection_probability, Some(0.75));
+    }
+    
+    #[test]
+    fn test_zero_scores_fall_back_to_uniform() {
+        let experience = experience_context();
+        let mut frames = vec![frame(0.0), frame(0.0)];
+        assert_eq!(FrameSet::new(&mut frames, &experience).distribution(), vec![0.5, 0.5]);
+        assert!(FrameSet::new(&mut [], &experience).distribution().is_empty());
+    }
+}
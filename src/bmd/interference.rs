@@ -0,0 +1,157 @@
+//! Frequency interference between BMD patterns
+//! 
+//! Two BMDs transmitted together jam each other where their recognition
+//! frequency ranges overlap. The interference of two ranges is the fraction of
+//! the narrower range they share, scaled by their phase offset from 0.5 (in
+//! phase, competing for the same band) to 1.0 (opposite phase, cancelling out).
+//! Ranges with no amplitude or no bandwidth never interfere.
+//! 
+//! [`OptimalBMDConfiguration::interference_score`] applies this across the
+//! primary and supporting BMDs, and the configuration builder's
+//! `max_interference` rejects configurations combining BMDs that jam each other.
+
+use uuid::Uuid;
+
+use super::{BMDPattern, FrequencyRange, OptimalBMDConfiguration};
+
+/// Overlapping frequency ranges of two patterns
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FrequencyConflict {
+    /// Index of the range in the first pattern
+    pub range: usize,
+    /// Index of the range in the second pattern
+    pub other_range: usize,
+    /// Shared bandwidth
+    pub overlap: f64,
+    /// Interference of the two ranges, in [0, 1]
+    pub interference: f64,
+}
+
+impl FrequencyRange {
+    /// Bandwidth shared with `other`
+    pub fn overlap(&self, other: &FrequencyRange) -> f64 {
+        (self.max_frequency.min(other.max_frequency) - self.min_frequency.max(other.min_frequency))
+            .max(0.0)
+    }
+    
+    /// Interference with `other`, in [0, 1]
+    pub fn interference(&self, other: &FrequencyRange) -> f64 {
+        let narrower = (self.max_frequency - self.min_frequency)
+            .min(other.max_frequency - other.min_frequency);
+        if narrower <= 0.0 || self.amplitude == 0.0 || other.amplitude == 0.0 {
+            return 0.0;
+        }
+        let shared = (self.overlap(other) / narrower).clamp(0.0, 1.0);
+        // 0.0 in phase, 1.0 in opposite phase
+        let opposition = (1.0 - (self.phase - other.phase).cos()) / 2.0;
+        shared * (0.5 + 0.5 * opposition)
+    }
+}
+
+impl BMDPattern {
+    /// Every pair of this pattern's and `other`'s ranges that interferes
+    pub fn frequency_conflicts(&self, other: &BMDPattern) -> Vec<FrequencyConflict> {
+        let mut conflicts = Vec::new();
+        for (range, own) in self.frequency_ranges.iter().enumerate() {
+            for (other_range, theirs) in other.frequency_ranges.iter().enumerate() {
+                let interference = own.interference(theirs);
+                if interference > 0.0 {
+                    conflicts.push(FrequencyConflict {
+                        range,
+                        other_range,
+                        overlap: own.overlap(theirs),
+                        interference,
+                    });
+                }
+            }
+        }
+        conflicts
+    }
+    
+    /// Worst interference between any range of this pattern and any of `other`'s
+    pub fn interference_with(&self, other: &BMDPattern) -> f64 {
+        self.frequency_conflicts(other)
+            .iter()
+            .map(|conflict| conflict.interference)
+            .fold(0.0, f64::max)
+    }
+    
+    /// Worst pairwise interference within a set of patterns, in [0, 1]
+    pub fn interference_score(patterns: &[&BMDPattern]) -> f64 {
+        let mut score: f64 = 0.0;
+        for (index, pattern) in patterns.iter().enumerate() {
+            for other in &patterns[index + 1..] {
+                score = score.max(pattern.interference_with(other));
+            }
+        }
+        score
+    }
+}
+
+impl OptimalBMDConfiguration {
+    /// Worst pairwise interference among the primary and supporting BMDs
+    pub fn interference_score(&self) -> f64 {
+        let patterns: Vec<&BMDPattern> = std::iter::once(&self.primary_bmd)
+            .chain(&self.supporting_bmds)
+            .map(|bmd| &bmd.pattern)
+            .collect();
+        BMDPattern::interference_score(&patterns)
+    }
+    
+    /// Pairs of BMDs interfering by more than `threshold`, with their interference
+    pub fn interfering_bmds(&self, threshold: f64) -> Vec<(Uuid, Uuid, f64)> {
+        let bmds: Vec<_> = std::iter::once(&self.primary_bmd)
+            .chain(&self.supporting_bmds)
+            .collect();
+        let mut pairs = Vec::new();
+        for (index, bmd) in bmds.iter().enumerate() {
+            for other in &bmds[index + 1..] {
+                let interference = bmd.pattern.interference_with(&other.pattern);
+                if interference > threshold {
+                    pairs.push((bmd.id, other.id, interference));
+                }
+            }
+        }
+        pairs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::PI;
+    use crate::bmd::BMD;
+    
+    fn range(min_frequency: f64, max_frequency: f64, phase: f64) -> FrequencyRange {
+        FrequencyRange { min_frequency, max_frequency, amplitude: 1.0, phase }
+    }
+    
+    #[test]
+    fn test_range_interference() {
+        let alpha = range(8.0, 12.0, 0.0);
+        assert_eq!(alpha.interference(&range(20.0, 30.0, 0.0)), 0.0);
+        assert!((alpha.interference(&range(10.0, 14.0, 0.0)) - 0.25).abs() < 1e-9);
+        assert!((alpha.interference(&range(8.0, 12.0, PI)) - 1.0).abs() < 1e-9);
+    }
+    
+    #[test]
+    fn test_configuration_reports_jamming_bmds() {
+        let bmd = |min, max| BMD::builder(vec![1.0]).frequency_range(min, max).build().unwrap();
+        let (primary, jamming, clear) = (bmd(4.0, 8.0), bmd(4.0, 8.0), bmd(30.0, 40.0));
+        let configuration = OptimalBMDConfiguration::builder(primary.clone())
+            .supporting_bmds([jamming.clone(), clear])
+            .build()
+            .unwrap();
+        
+        assert!((configuration.interference_score() - 0.5).abs() < 1e-9);
+        let pairs = configuration.interfering_bmds(0.1);
+        assert_eq!(pairs.len(), 1);
+        assert_eq!((pairs[0].0, pairs[0].1), (primary.id, jamming.id));
+        
+        let rejected = OptimalBMDConfiguration::builder(primary)
+            .supporting_bmd(jamming)
+            .max_interference(0.4)
+            .build();
+        assert!(rejected.unwrap_err().to_string().contains("interfere"));
+    }
+}
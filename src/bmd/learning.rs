@@ -0,0 +1,163 @@
+//! Online learning of recipient models from reception outcomes
+//! 
+//! [`IndividualModel::ingest_reception`] folds one [`BMDReceptionEvent`] into the
+//! model it was observed on:
+//! 
+//! - cognitive framework strengths grow after good receptions and shrink after
+//!   poor ones, each in proportion to how much the recipient uses the framework
+//! - emotional pattern reliabilities move toward 1.0 when the pattern predicted
+//!   the direction of the observed emotional impact, and toward 0.0 otherwise
+//! - deliberation time moves toward the observed integration time, faster for
+//!   better receptions
+//! 
+//! The event is then recorded in the reception history, as a success if its
+//! quality reaches [`RECEPTION_SUCCESS_QUALITY`].
+
+use serde::{Deserialize, Serialize};
+
+use super::{BMDReceptionEvent, IndividualModel};
+
+/// Reception quality at or above which a reception counts as successful
+pub const RECEPTION_SUCCESS_QUALITY: f64 = 0.5;
+
+/// Emotional impact below which a reception says nothing about emotional patterns
+const IMPACT_EPSILON: f64 = 1e-6;
+
+/// How far one reception moves each part of an [`IndividualModel`], each in [0, 1]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LearningRates {
+    /// Relative change in framework strength for a perfect or failed reception
+    pub framework_strength: f64,
+    /// Step of emotional pattern reliability toward the observed agreement
+    pub emotional_reliability: f64,
+    /// Step of deliberation time toward the observed integration time
+    pub temporal_preference: f64,
+}
+
+impl Default for LearningRates {
+    fn default() -> Self {
+        Self {
+            framework_strength: 0.1,
+            emotional_reliability: 0.05,
+            temporal_preference: 0.05,
+        }
+    }
+}
+
+impl IndividualModel {
+    /// Learn from `event` with the default [`LearningRates`]
+    pub fn ingest_reception(&mut self, event: BMDReceptionEvent) {
+        self.ingest_reception_with(event, &LearningRates::default());
+    }
+    
+    /// Learn from `event` with `rates`
+    pub fn ingest_reception_with(&mut self, event: BMDReceptionEvent, rates: &LearningRates) {
+        let quality = event.reception_quality.clamp(0.0, 1.0);
+        // -1.0 for a failed reception, 1.0 for a perfect one
+        let outcome = 2.0 * quality - 1.0;
+        
+        let total_usage: f64 = self
+            .cognitive_frameworks
+            .iter()
+            .map(|framework| framework.usage_frequency.max(0.0))
+            .sum();
+        for framework in &mut self.cognitive_frameworks {
+            let share = match total_usage {
+                total if total > 0.0 => framework.usage_frequency.max(0.0) / total,
+                _ => 1.0 / self.cognitive_frameworks.len() as f64,
+            };
+            framework.strength *= 1.0 + rates.framework_strength * share * outcome;
+            framework.strength = framework.strength.max(0.0);
+        }
+        
+        if event.emotional_impact.abs() > IMPACT_EPSILON {
+            for pattern in &mut self.emotional_patterns {
+                let predicted = pattern.response.arousal_change;
+                let agreed = predicted.signum() == event.emotional_impact.signum();
+                let target = if agreed { 1.0 } else { 0.0 };
+                pattern.reliability += rates.emotional_reliability * (target - pattern.reliability);
+                pattern.reliability = pattern.reliability.clamp(0.0, 1.0);
+            }
+        }
+        
+        if event.integration_time.is_finite() && event.integration_time > 0.0 {
+            let timing = &mut self.temporal_preferences.decision_timing;
+            timing.deliberation_time += rates.temporal_preference
+                * quality
+                * (event.integration_time - timing.deliberation_time);
+        }
+        
+        if event.reception_quality >= RECEPTION_SUCCESS_QUALITY {
+            self.reception_history.record_success(event);
+        } else {
+            self.reception_history.record_failure(event);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+    use crate::bmd::tests::experience_context;
+    use crate::bmd::{CognitiveFramework, EmotionalPattern, EmotionalResponse};
+    
+    fn event(reception_quality: f64, emotional_impact: f64) -> BMDReceptionEvent {
+        BMDReceptionEvent {
+            timestamp: 0,
+            bmd_id: Uuid::nil(),
+            reception_quality,
+            integration_time: 3.0,
+            emotional_impact,
+            behavioral_change: 0.0,
+        }
+    }
+    
+    fn model() -> IndividualModel {
+        let mut model = experience_context().communication_context.recipient_model;
+        model.cognitive_frameworks = ["temporal", "narrative"]
+            .iter()
+            .map(|category| CognitiveFramework {
+                category: (*category).into(),
+                strength: 1.0,
+                usage_frequency: 0.5,
+                emotional_valence: 0.0,
+            })
+            .collect();
+        model.emotional_patterns = vec![EmotionalPattern {
+            trigger: "novelty".to_string(),
+            response: EmotionalResponse {
+                arousal_change: 1.0,
+                valence_change: 0.0,
+                attention_change: 0.0,
+                memory_impact: 0.0,
+            },
+            reliability: 0.5,
+            duration: 1.0,
+        }];
+        model
+    }
+    
+    #[test]
+    fn test_good_reception_reinforces_model() {
+        let mut model = model();
+        model.ingest_reception(event(1.0, 0.4));
+        
+        assert!((model.cognitive_frameworks[0].strength - 1.05).abs() < 1e-9);
+        assert!(model.emotional_patterns[0].reliability > 0.5);
+        assert!(model.temporal_preferences.decision_timing.deliberation_time > 1.0);
+        assert_eq!(model.reception_history.successful_receptions.len(), 1);
+    }
+    
+    #[test]
+    fn test_poor_reception_weakens_model() {
+        let rates = LearningRates { framework_strength: 0.5, ..LearningRates::default() };
+        let mut model = model();
+        model.ingest_reception_with(event(0.0, -0.4), &rates);
+        
+        assert!((model.cognitive_frameworks[1].strength - 0.75).abs() < 1e-9);
+        assert!(model.emotional_patterns[0].reliability < 0.5);
+        assert_eq!(model.temporal_preferences.decision_timing.deliberation_time, 1.0);
+        assert_eq!(model.reception_history.failed_attempts.len(), 1);
+    }
+}
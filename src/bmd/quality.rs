@@ -0,0 +1,245 @@
+//! Quality aggregation over BMD batches
+//! 
+//! A [`QualityReport`] summarizes the foundry [`QualityMetrics`] of a batch of
+//! BMDs, usually one selection, as mean, median, 95th percentile, and minimum
+//! of each metric. A [`QualityGate`] accepts or rejects a batch from its report,
+//! and a [`QualityTrends`] tracks reports per foundry source across batches.
+
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use super::{BMDSelection, QualityMetrics, BMD};
+
+/// Distribution of one metric over a batch
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct MetricSummary {
+    pub mean: f64,
+    pub p50: f64,
+    pub p95: f64,
+    pub min: f64,
+}
+
+impl MetricSummary {
+    /// Summary of `values`; all zero when empty
+    fn of(mut values: Vec<f64>) -> Self {
+        values.retain(|value| value.is_finite());
+        if values.is_empty() {
+            return Self::default();
+        }
+        values.sort_by(f64::total_cmp);
+        // Nearest-rank percentiles
+        let percentile = |p: f64| {
+            let rank = (p * values.len() as f64).ceil() as usize;
+            values[rank.clamp(1, values.len()) - 1]
+        };
+        Self {
+            mean: values.iter().sum::<f64>() / values.len() as f64,
+            p50: percentile(0.50),
+            p95: percentile(0.95),
+            min: values[0],
+        }
+    }
+}
+
+/// Aggregated quality metrics of a batch of BMDs
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct QualityReport {
+    /// BMDs in the batch
+    pub bmds: usize,
+    pub pattern_coherence: MetricSummary,
+    pub cross_domain_score: MetricSummary,
+    pub temporal_stability: MetricSummary,
+    pub transmission_fidelity: MetricSummary,
+}
+
+impl QualityReport {
+    /// Report over the quality metrics of `bmds`
+    pub fn from_bmds<'a>(bmds: impl IntoIterator<Item = &'a BMD>) -> Self {
+        Self::from_metrics(bmds.into_iter().map(|bmd| &bmd.foundry_source.quality_metrics))
+    }
+    
+    /// Report over every BMD of a foundry selection
+    pub fn from_selection(selection: &BMDSelection) -> Self {
+        Self::from_bmds(&selection.bmds)
+    }
+    
+    /// Report over `metrics`
+    pub fn from_metrics<'a>(metrics: impl IntoIterator<Item = &'a QualityMetrics>) -> Self {
+        let metrics: Vec<&QualityMetrics> = metrics.into_iter().collect();
+        let summary = |field: fn(&QualityMetrics) -> f64| {
+            MetricSummary::of(metrics.iter().map(|m| field(m)).collect())
+        };
+        Self {
+            bmds: metrics.len(),
+            pattern_coherence: summary(|m| m.pattern_coherence),
+            cross_domain_score: summary(|m| m.cross_domain_score),
+            temporal_stability: summary(|m| m.temporal_stability),
+            transmission_fidelity: summary(|m| m.transmission_fidelity),
+        }
+    }
+    
+    /// One report per foundry source among `bmds`, keyed by foundry id
+    pub fn by_source<'a>(bmds: impl IntoIterator<Item = &'a BMD>) -> BTreeMap<String, Self> {
+        let mut sources: BTreeMap<String, Vec<&QualityMetrics>> = BTreeMap::new();
+        for bmd in bmds {
+            let source = &bmd.foundry_source;
+            sources.entry(source.foundry_id.clone()).or_default().push(&source.quality_metrics);
+        }
+        sources
+            .into_iter()
+            .map(|(foundry_id, metrics)| (foundry_id, Self::from_metrics(metrics)))
+            .collect()
+    }
+    
+    /// Every metric by name
+    fn metrics(&self) -> [(&'static str, &MetricSummary); 4] {
+        [
+            ("pattern_coherence", &self.pattern_coherence),
+            ("cross_domain_score", &self.cross_domain_score),
+            ("temporal_stability", &self.temporal_stability),
+            ("transmission_fidelity", &self.transmission_fidelity),
+        ]
+    }
+}
+
+/// Batch-level acceptance thresholds, applied to every metric
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct QualityGate {
+    /// Lowest acceptable mean
+    pub min_mean: f64,
+    /// Lowest acceptable median
+    pub min_p50: f64,
+    /// Lowest acceptable single value
+    pub min_floor: f64,
+}
+
+impl Default for QualityGate {
+    fn default() -> Self {
+        Self { min_mean: 0.6, min_p50: 0.6, min_floor: 0.2 }
+    }
+}
+
+impl QualityGate {
+    /// Accept the batch `report` summarizes, or list every threshold it misses
+    /// 
+    /// Empty batches are accepted.
+    pub fn check(&self, report: &QualityReport) -> Result<()> {
+        if report.bmds == 0 {
+            return Ok(());
+        }
+        let mut issues = Vec::new();
+        for (metric, summary) in report.metrics() {
+            let thresholds = [
+                ("mean", summary.mean, self.min_mean),
+                ("p50", summary.p50, self.min_p50),
+                ("min", summary.min, self.min_floor),
+            ];
+            for (statistic, value, threshold) in thresholds {
+                if value < threshold {
+                    issues.push(format!(
+                        "{} {} {:.3} below {}", metric, statistic, value, threshold
+                    ));
+                }
+            }
+        }
+        if issues.is_empty() {
+            Ok(())
+        } else {
+            Err(anyhow::anyhow!("BMD batch rejected: {}", issues.join("; ")))
+        }
+    }
+}
+
+/// Mean transmission fidelity of recent batches, per foundry source
+#[derive(Debug, Clone)]
+pub struct QualityTrends {
+    window: usize,
+    sources: HashMap<String, VecDeque<f64>>,
+}
+
+impl QualityTrends {
+    /// Track the last `window` batches of each source
+    pub fn new(window: usize) -> Self {
+        Self { window: window.max(2), sources: HashMap::new() }
+    }
+    
+    /// Record a batch, split by foundry source
+    pub fn record<'a>(&mut self, bmds: impl IntoIterator<Item = &'a BMD>) {
+        for (foundry_id, report) in QualityReport::by_source(bmds) {
+            let history = self.sources.entry(foundry_id).or_default();
+            if history.len() == self.window {
+                history.pop_front();
+            }
+            history.push_back(report.transmission_fidelity.mean);
+        }
+    }
+    
+    /// Least-squares change in mean transmission fidelity per batch from `foundry_id`
+    /// 
+    /// `None` until two batches from the source were recorded.
+    pub fn trend(&self, foundry_id: &str) -> Option<f64> {
+        let history = self.sources.get(foundry_id).filter(|history| history.len() >= 2)?;
+        let n = history.len() as f64;
+        let mean_index = (n - 1.0) / 2.0;
+        let mean_value = history.iter().sum::<f64>() / n;
+        let (covariance, variance) = history.iter().enumerate().fold(
+            (0.0, 0.0),
+            |(covariance, variance), (index, value)| {
+                let dx = index as f64 - mean_index;
+                (covariance + dx * (value - mean_value), variance + dx * dx)
+            },
+        );
+        Some(covariance / variance)
+    }
+    
+    /// Sources with recorded batches
+    pub fn sources(&self) -> impl Iterator<Item = &str> {
+        self.sources.keys().map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn bmd(foundry_id: &str, fidelity: f64) -> BMD {
+        BMD::builder(vec![1.0])
+            .foundry(foundry_id, 1)
+            .quality_metrics(QualityMetrics {
+                pattern_coherence: 0.9,
+                cross_domain_score: 0.9,
+                temporal_stability: 0.9,
+                transmission_fidelity: fidelity,
+            })
+            .build()
+            .unwrap()
+    }
+    
+    #[test]
+    fn test_report_and_gate() {
+        let batch: Vec<BMD> = (1..=20).map(|i| bmd("alpha", i as f64 / 20.0)).collect();
+        let report = QualityReport::from_bmds(&batch);
+        
+        assert_eq!(report.bmds, 20);
+        let fidelity = report.transmission_fidelity;
+        assert!((fidelity.mean - 0.525).abs() < 1e-9);
+        assert_eq!((fidelity.p50, fidelity.p95, fidelity.min), (0.5, 0.95, 0.05));
+        
+        let error = QualityGate::default().check(&report).unwrap_err().to_string();
+        assert!(error.contains("transmission_fidelity mean"));
+        assert!(!error.contains("pattern_coherence"));
+        assert!(QualityGate { min_mean: 0.5, min_p50: 0.5, min_floor: 0.0 }.check(&report).is_ok());
+    }
+    
+    #[test]
+    fn test_trends_per_source() {
+        let mut trends = QualityTrends::new(4);
+        for fidelity in [0.9, 0.8, 0.7] {
+            trends.record(&[bmd("alpha", fidelity), bmd("beta", 0.5)]);
+        }
+        assert!((trends.trend("alpha").unwrap() + 0.1).abs() < 1e-9);
+        assert_eq!(trends.trend("beta"), Some(0.0));
+        assert_eq!(trends.trend("gamma"), None);
+    }
+}
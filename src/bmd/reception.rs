@@ -0,0 +1,171 @@
+//! Bounded reception history and its summary statistics
+//! 
+//! [`ReceptionHistory`] lists are ring buffers: long-lived individual models keep
+//! only their most recent `capacity` receptions, failures, and recognition
+//! points. [`ReceptionHistory::stats`] summarizes what is retained.
+
+use std::collections::VecDeque;
+use serde::{Deserialize, Serialize};
+
+use super::{BMDReceptionEvent, ReceptionHistory, RecognitionEvolutionPoint};
+
+/// Entries kept per list unless configured otherwise
+pub const DEFAULT_RECEPTION_HISTORY_CAPACITY: usize = 256;
+
+pub(super) fn default_capacity() -> usize {
+    DEFAULT_RECEPTION_HISTORY_CAPACITY
+}
+
+/// Summary of the receptions retained in a [`ReceptionHistory`]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ReceptionStats {
+    /// Successful and failed receptions retained
+    pub receptions: usize,
+    /// Mean reception quality over retained receptions, successful or not
+    pub mean_quality: f64,
+    /// Fraction of retained receptions that failed
+    pub failure_rate: f64,
+    /// Least-squares change in reception quality per reception, oldest to newest
+    /// 
+    /// Positive when receptions are improving; zero with fewer than two.
+    pub quality_trend: f64,
+}
+
+impl Default for ReceptionHistory {
+    fn default() -> Self {
+        Self::with_capacity(DEFAULT_RECEPTION_HISTORY_CAPACITY)
+    }
+}
+
+impl ReceptionHistory {
+    /// Empty history keeping at most `capacity` entries per list
+    pub fn with_capacity(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            successful_receptions: VecDeque::new(),
+            failed_attempts: VecDeque::new(),
+            recognition_evolution: VecDeque::new(),
+            capacity,
+        }
+    }
+    
+    /// Change the capacity, dropping the oldest entries beyond it
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity.max(1);
+        truncate_oldest(&mut self.successful_receptions, self.capacity);
+        truncate_oldest(&mut self.failed_attempts, self.capacity);
+        truncate_oldest(&mut self.recognition_evolution, self.capacity);
+    }
+    
+    /// Record a successful reception
+    pub fn record_success(&mut self, event: BMDReceptionEvent) {
+        push_bounded(&mut self.successful_receptions, event, self.capacity);
+    }
+    
+    /// Record a failed transmission attempt
+    pub fn record_failure(&mut self, event: BMDReceptionEvent) {
+        push_bounded(&mut self.failed_attempts, event, self.capacity);
+    }
+    
+    /// Record a pattern recognition measurement
+    pub fn record_evolution(&mut self, point: RecognitionEvolutionPoint) {
+        push_bounded(&mut self.recognition_evolution, point, self.capacity);
+    }
+    
+    /// Mean quality, failure rate, and quality trend of retained receptions
+    pub fn stats(&self) -> ReceptionStats {
+        let mut events: Vec<&BMDReceptionEvent> = self
+            .successful_receptions
+            .iter()
+            .chain(&self.failed_attempts)
+            .collect();
+        // Stable, so same-second events keep their recording order
+        events.sort_by_key(|event| event.timestamp);
+        
+        let receptions = events.len();
+        if receptions == 0 {
+            return ReceptionStats {
+                receptions,
+                mean_quality: 0.0,
+                failure_rate: 0.0,
+                quality_trend: 0.0,
+            };
+        }
+        
+        let n = receptions as f64;
+        let mean_quality = events.iter().map(|event| event.reception_quality).sum::<f64>() / n;
+        let mean_index = (n - 1.0) / 2.0;
+        let (covariance, variance) = events.iter().enumerate().fold(
+            (0.0, 0.0),
+            |(covariance, variance), (index, event)| {
+                let dx = index as f64 - mean_index;
+                (covariance + dx * (event.reception_quality - mean_quality), variance + dx * dx)
+            },
+        );
+        
+        ReceptionStats {
+            receptions,
+            mean_quality,
+            failure_rate: self.failed_attempts.len() as f64 / n,
+            quality_trend: if variance > 0.0 { covariance / variance } else { 0.0 },
+        }
+    }
+}
+
+fn push_bounded<T>(entries: &mut VecDeque<T>, entry: T, capacity: usize) {
+    truncate_oldest(entries, capacity.saturating_sub(1));
+    entries.push_back(entry);
+}
+
+fn truncate_oldest<T>(entries: &mut VecDeque<T>, len: usize) {
+    let excess = entries.len().saturating_sub(len);
+    entries.drain(..excess);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+    
+    fn reception(timestamp: u64, reception_quality: f64) -> BMDReceptionEvent {
+        BMDReceptionEvent {
+            timestamp,
+            bmd_id: Uuid::nil(),
+            reception_quality,
+            integration_time: 1.0,
+            emotional_impact: 0.0,
+            behavioral_change: 0.0,
+        }
+    }
+    
+    #[test]
+    fn test_capacity_drops_oldest() {
+        let mut history = ReceptionHistory::with_capacity(3);
+        for timestamp in 0..5 {
+            history.record_success(reception(timestamp, 0.5));
+        }
+        let kept: Vec<u64> = history.successful_receptions.iter().map(|e| e.timestamp).collect();
+        assert_eq!(kept, vec![2, 3, 4]);
+        
+        history.set_capacity(1);
+        assert_eq!(history.successful_receptions.len(), 1);
+        assert_eq!(history.successful_receptions[0].timestamp, 4);
+    }
+    
+    #[test]
+    fn test_stats_track_quality_and_failures() {
+        let mut history = ReceptionHistory::default();
+        assert_eq!(history.stats().receptions, 0);
+        
+        history.record_failure(reception(0, 0.2));
+        history.record_success(reception(1, 0.4));
+        history.record_success(reception(2, 0.6));
+        history.record_success(reception(3, 0.8));
+        
+        let stats = history.stats();
+        assert_eq!(stats.receptions, 4);
+        assert!((stats.mean_quality - 0.5).abs() < 1e-9);
+        assert!((stats.failure_rate - 0.25).abs() < 1e-9);
+        assert!((stats.quality_trend - 0.2).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,111 @@
+//! Stratified sampling of BMD selections
+//! 
+//! Foundries return BMDs in whatever order they generate them, so exploring the
+//! first few of a selection tends to revisit one corner of the configuration
+//! space. [`sample_stratified`] groups BMDs into strata by a [`SamplingKey`],
+//! spreads the sample as evenly as the strata allow, and picks evenly spaced
+//! BMDs within each stratum. Sampling is deterministic: the same selection and
+//! key always give the same sample.
+
+use std::collections::BTreeMap;
+
+use super::{BMDSelection, BMD};
+
+/// What BMDs are stratified by
+#[derive(Debug, Clone, Copy)]
+pub enum SamplingKey {
+    /// Foundry that generated the BMD
+    FoundrySource,
+    /// Semantic opacity, in `buckets` equal-width buckets over [0, 1]
+    SemanticOpacity { buckets: usize },
+    /// Caller-defined stratum
+    /// 
+    /// Operation modes belong to core `BMDPattern`s rather than to BMDs, so
+    /// callers tracking them stratify by mode through this.
+    Custom(fn(&BMD) -> String),
+}
+
+impl SamplingKey {
+    /// Stratum of `bmd`
+    fn stratum(&self, bmd: &BMD) -> String {
+        match self {
+            SamplingKey::FoundrySource => bmd.foundry_source.foundry_id.clone(),
+            SamplingKey::SemanticOpacity { buckets } => {
+                let buckets = (*buckets).max(1);
+                let opacity = bmd.pattern.semantic_opacity.clamp(0.0, 1.0);
+                let bucket = ((opacity * buckets as f64) as usize).min(buckets - 1);
+                // Zero-padded so strata sort by bucket
+                format!("{:06}", bucket)
+            }
+            SamplingKey::Custom(key) => key(bmd),
+        }
+    }
+}
+
+/// Up to `n` of `bmds`, spread across the strata of `by`, in their original order
+pub fn sample_stratified<'a>(bmds: &'a [BMD], n: usize, by: SamplingKey) -> Vec<&'a BMD> {
+    let mut strata: BTreeMap<String, Vec<usize>> = BTreeMap::new();
+    for (index, bmd) in bmds.iter().enumerate() {
+        strata.entry(by.stratum(bmd)).or_default().push(index);
+    }
+    
+    // Fill the smallest strata first, so their unused share goes to the larger ones
+    // and the largest stratum takes whatever is left
+    let mut strata: Vec<Vec<usize>> = strata.into_values().collect();
+    strata.sort_by_key(Vec::len);
+    let mut remaining = n.min(bmds.len());
+    let mut chosen = Vec::with_capacity(remaining);
+    for (position, stratum) in strata.iter().enumerate() {
+        let share = remaining / (strata.len() - position);
+        let take = share.min(stratum.len());
+        // Evenly spaced picks; distinct since take <= stratum.len()
+        chosen.extend((0..take).map(|i| stratum[i * stratum.len() / take]));
+        remaining -= take;
+    }
+    
+    chosen.sort_unstable();
+    chosen.into_iter().map(|index| &bmds[index]).collect()
+}
+
+impl BMDSelection {
+    /// Up to `n` of the selected BMDs, spread across the strata of `by`
+    pub fn sample_stratified(&self, n: usize, by: SamplingKey) -> Vec<&BMD> {
+        sample_stratified(&self.bmds, n, by)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn bmd(foundry_id: &str, semantic_opacity: f64) -> BMD {
+        BMD::builder(vec![1.0])
+            .foundry(foundry_id, 1)
+            .semantic_opacity(semantic_opacity)
+            .build()
+            .unwrap()
+    }
+    
+    #[test]
+    fn test_covers_every_source() {
+        // The foundry returned eight BMDs from alpha before any from the others
+        let mut bmds: Vec<BMD> = (0..8).map(|_| bmd("alpha", 0.5)).collect();
+        bmds.extend([bmd("beta", 0.5), bmd("gamma", 0.5)]);
+        
+        let sample = sample_stratified(&bmds, 6, SamplingKey::FoundrySource);
+        let count = |id: &str| sample.iter().filter(|b| b.foundry_source.foundry_id == id).count();
+        assert_eq!(sample.len(), 6);
+        assert_eq!((count("alpha"), count("beta"), count("gamma")), (4, 1, 1));
+    }
+    
+    #[test]
+    fn test_opacity_buckets_and_custom_keys() {
+        let bmds: Vec<BMD> = [0.05, 0.1, 0.15, 0.2, 0.9, 1.0].map(|o| bmd("alpha", o)).into();
+        let sample = sample_stratified(&bmds, 2, SamplingKey::SemanticOpacity { buckets: 2 });
+        let opacities: Vec<f64> = sample.iter().map(|b| b.pattern.semantic_opacity).collect();
+        assert_eq!(opacities, vec![0.05, 0.9]);
+        
+        let by_sign = SamplingKey::Custom(|b| (b.pattern.core_vectors[0] > 0.0).to_string());
+        assert_eq!(sample_stratified(&bmds, 10, by_sign).len(), bmds.len());
+    }
+}
@@ -0,0 +1,242 @@
+//! BMD similarity and nearest-neighbor search
+//! 
+//! [`similarity`] scores two BMDs in [0, 1] as a weighted mean of three
+//! components, each in [0, 1]:
+//! 
+//! - core vectors: cosine similarity, rescaled from [-1, 1]; vectors of different
+//!   lengths are compared as if the shorter one were zero-padded
+//! - emotional substrate: one minus the normalized Euclidean distance between the
+//!   arousal, attention, and memory encoding levels
+//! - frequency ranges: overlapping bandwidth over combined bandwidth
+//! 
+//! [`BMDIndex`] answers k-nearest-neighbor queries under this metric, so
+//! selection can look for BMDs like one that worked before.
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+use uuid::Uuid;
+
+use super::{EmotionalSubstrate, FrequencyRange, BMD};
+
+/// Weight of core vector similarity
+pub const VECTOR_WEIGHT: f64 = 0.5;
+
+/// Weight of emotional substrate proximity
+pub const EMOTIONAL_WEIGHT: f64 = 0.3;
+
+/// Weight of frequency range overlap
+pub const FREQUENCY_WEIGHT: f64 = 0.2;
+
+/// Similarity of two BMDs, from 0.0 (unrelated) to 1.0 (identical patterns)
+pub fn similarity(a: &BMD, b: &BMD) -> f64 {
+    let (pattern_a, pattern_b) = (&a.pattern, &b.pattern);
+    VECTOR_WEIGHT * vector_similarity(&pattern_a.core_vectors, &pattern_b.core_vectors)
+        + EMOTIONAL_WEIGHT * emotional_proximity(&a.emotional_substrate, &b.emotional_substrate)
+        + FREQUENCY_WEIGHT
+            * frequency_overlap(&pattern_a.frequency_ranges, &pattern_b.frequency_ranges)
+}
+
+/// Cosine similarity rescaled to [0, 1]
+fn vector_similarity(a: &[f64], b: &[f64]) -> f64 {
+    let dot: f64 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm = |v: &[f64]| v.iter().map(|x| x * x).sum::<f64>().sqrt();
+    let (norm_a, norm_b) = (norm(a), norm(b));
+    if norm_a == 0.0 || norm_b == 0.0 {
+        // Two empty patterns match; an empty and a non-empty one do not
+        return if norm_a == norm_b { 1.0 } else { 0.0 };
+    }
+    ((dot / (norm_a * norm_b)).clamp(-1.0, 1.0) + 1.0) / 2.0
+}
+
+/// One minus the distance between substrate levels, normalized to [0, 1]
+fn emotional_proximity(a: &EmotionalSubstrate, b: &EmotionalSubstrate) -> f64 {
+    let levels = |s: &EmotionalSubstrate| {
+        [s.arousal_level, s.attention_intensity, s.memory_encoding].map(|level| level / 10.0)
+    };
+    let distance = levels(a)
+        .iter()
+        .zip(levels(b))
+        .map(|(x, y)| (x - y).powi(2))
+        .sum::<f64>()
+        .sqrt();
+    (1.0 - distance / 3f64.sqrt()).clamp(0.0, 1.0)
+}
+
+/// Overlapping bandwidth of two sets of ranges over their combined bandwidth
+fn frequency_overlap(a: &[FrequencyRange], b: &[FrequencyRange]) -> f64 {
+    let (a, b) = (merged_bands(a), merged_bands(b));
+    let width = |bands: &[(f64, f64)]| bands.iter().map(|(lo, hi)| hi - lo).sum::<f64>();
+    let (width_a, width_b) = (width(&a), width(&b));
+    if width_a == 0.0 || width_b == 0.0 {
+        return if a.is_empty() && b.is_empty() { 1.0 } else { 0.0 };
+    }
+    
+    let shared: f64 = a
+        .iter()
+        .flat_map(|(lo_a, hi_a)| {
+            b.iter().map(move |(lo_b, hi_b)| (hi_a.min(*hi_b) - lo_a.max(*lo_b)).max(0.0))
+        })
+        .sum();
+    shared / (width_a + width_b - shared)
+}
+
+/// Ranges sorted and merged into disjoint bands
+fn merged_bands(ranges: &[FrequencyRange]) -> Vec<(f64, f64)> {
+    let mut bands: Vec<(f64, f64)> = ranges
+        .iter()
+        .filter(|range| range.max_frequency >= range.min_frequency)
+        .map(|range| (range.min_frequency, range.max_frequency))
+        .collect();
+    bands.sort_by(|a, b| a.0.total_cmp(&b.0));
+    
+    let mut merged: Vec<(f64, f64)> = Vec::with_capacity(bands.len());
+    for (lo, hi) in bands {
+        match merged.last_mut() {
+            Some(last) if lo <= last.1 => last.1 = last.1.max(hi),
+            _ => merged.push((lo, hi)),
+        }
+    }
+    merged
+}
+
+/// Candidate in a k-nearest search, ordered so the heap top is the least similar
+struct Candidate<'a> {
+    score: f64,
+    bmd: &'a BMD,
+}
+
+impl PartialEq for Candidate<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Candidate<'_> {}
+
+impl PartialOrd for Candidate<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.score.total_cmp(&self.score)
+    }
+}
+
+/// Collection of BMDs searchable by [`similarity`]
+#[derive(Debug, Clone, Default)]
+pub struct BMDIndex {
+    bmds: HashMap<Uuid, BMD>,
+}
+
+impl BMDIndex {
+    /// Empty index
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Add `bmd`, replacing any indexed BMD with the same id
+    pub fn insert(&mut self, bmd: BMD) -> Option<BMD> {
+        self.bmds.insert(bmd.id, bmd)
+    }
+    
+    /// Remove the BMD with `id`
+    pub fn remove(&mut self, id: &Uuid) -> Option<BMD> {
+        self.bmds.remove(id)
+    }
+    
+    /// Indexed BMD with `id`
+    pub fn get(&self, id: &Uuid) -> Option<&BMD> {
+        self.bmds.get(id)
+    }
+    
+    /// Up to `k` indexed BMDs most similar to `query`, most similar first
+    /// 
+    /// `query` itself is skipped if it is indexed.
+    pub fn nearest(&self, query: &BMD, k: usize) -> Vec<(&BMD, f64)> {
+        self.nearest_matching(query, k, |_| true)
+    }
+    
+    /// Like [`BMDIndex::nearest`], considering only BMDs accepted by `filter`
+    pub fn nearest_matching(
+        &self,
+        query: &BMD,
+        k: usize,
+        filter: impl Fn(&BMD) -> bool,
+    ) -> Vec<(&BMD, f64)> {
+        if k == 0 {
+            return Vec::new();
+        }
+        
+        let mut heap = BinaryHeap::with_capacity(k + 1);
+        for bmd in self.bmds.values() {
+            if bmd.id == query.id || !filter(bmd) {
+                continue;
+            }
+            heap.push(Candidate { score: similarity(query, bmd), bmd });
+            if heap.len() > k {
+                heap.pop();
+            }
+        }
+        // Ascending in the reversed order is most similar first
+        heap.into_sorted_vec().into_iter().map(|c| (c.bmd, c.score)).collect()
+    }
+    
+    /// Number of indexed BMDs
+    pub fn len(&self) -> usize {
+        self.bmds.len()
+    }
+    
+    /// Whether no BMD is indexed
+    pub fn is_empty(&self) -> bool {
+        self.bmds.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn bmd(core_vectors: Vec<f64>, band: (f64, f64)) -> BMD {
+        BMD::builder(core_vectors).frequency_range(band.0, band.1).build().unwrap()
+    }
+    
+    #[test]
+    fn test_similarity_components() {
+        let a = bmd(vec![1.0, 0.0], (4.0, 8.0));
+        assert!((similarity(&a, &a) - 1.0).abs() < 1e-9);
+        
+        let opposite = bmd(vec![-1.0, 0.0], (20.0, 30.0));
+        assert!(similarity(&a, &opposite) < EMOTIONAL_WEIGHT + 1e-9);
+        
+        let half_band = [FrequencyRange {
+            min_frequency: 6.0,
+            max_frequency: 10.0,
+            amplitude: 1.0,
+            phase: 0.0,
+        }];
+        let overlap = frequency_overlap(&a.pattern.frequency_ranges, &half_band);
+        assert!((overlap - 2.0 / 6.0).abs() < 1e-9);
+    }
+    
+    #[test]
+    fn test_nearest_orders_by_similarity() {
+        let query = bmd(vec![1.0, 0.0], (4.0, 8.0));
+        let close = bmd(vec![0.9, 0.1], (4.0, 8.0));
+        let far = bmd(vec![0.0, 1.0], (30.0, 40.0));
+        
+        let mut index = BMDIndex::new();
+        for bmd in [query.clone(), close.clone(), far.clone()] {
+            index.insert(bmd);
+        }
+        
+        let nearest = index.nearest(&query, 1);
+        assert_eq!(nearest.len(), 1);
+        assert_eq!(nearest[0].0.id, close.id);
+        
+        let ids: Vec<Uuid> = index.nearest(&query, 5).iter().map(|(bmd, _)| bmd.id).collect();
+        assert_eq!(ids, vec![close.id, far.id]);
+    }
+}
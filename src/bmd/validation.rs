@@ -0,0 +1,238 @@
+//! Typed validation of [`BMD`] invariants
+//! 
+//! Foundries are outside our control, so every BMD they hand over is checked
+//! with [`BMD::validate`] before orchestration computes with it: the foundry
+//! client actor rejects invalid selections, and [`BMD::from_bytes`] rejects
+//! invalid payloads. A failed check lists every out-of-range field at once as a
+//! [`BMDValidationError`].
+//! 
+//! `coherence_duration` is a whole number of femtoseconds, so a negative
+//! duration is already rejected when the BMD is deserialized.
+
+use std::fmt;
+use uuid::Uuid;
+
+use super::{BMDSelection, BMD};
+
+/// Constraint a BMD field broke
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Violation {
+    /// Outside the inclusive range `[min, max]`, or not a number
+    OutOfRange { min: f64, max: f64 },
+    /// Negative or not finite where a non-negative value is required
+    Negative,
+    /// Zero, negative, or not finite where a positive value is required
+    NotPositive,
+    /// NaN or infinite
+    NotFinite,
+    /// An empty list that must have entries
+    Empty,
+    /// A frequency range whose minimum exceeds its maximum
+    Inverted { min: f64, max: f64 },
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Violation::OutOfRange { min, max } => write!(f, "must be in [{}, {}]", min, max),
+            Violation::Negative => write!(f, "must be non-negative"),
+            Violation::NotPositive => write!(f, "must be positive"),
+            Violation::NotFinite => write!(f, "must be finite"),
+            Violation::Empty => write!(f, "must not be empty"),
+            Violation::Inverted { min, max } => {
+                write!(f, "must satisfy min <= max, got {}..{}", min, max)
+            }
+        }
+    }
+}
+
+/// One field of a BMD that broke its constraint
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldViolation {
+    /// Field path, e.g. `emotional_substrate.arousal_level`
+    pub field: String,
+    /// Offending value; NaN where the field has no single value
+    pub value: f64,
+    /// Constraint that was broken
+    pub violation: Violation,
+}
+
+impl fmt::Display for FieldViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.violation {
+            Violation::Empty | Violation::NotFinite | Violation::Inverted { .. } => {
+                write!(f, "{} {}", self.field, self.violation)
+            }
+            _ => write!(f, "{} {}, got {}", self.field, self.violation, self.value),
+        }
+    }
+}
+
+/// Every invariant a BMD broke
+#[derive(Debug, Clone, PartialEq)]
+pub struct BMDValidationError {
+    /// Id of the invalid BMD
+    pub bmd_id: Uuid,
+    /// Broken constraints, in field order
+    pub violations: Vec<FieldViolation>,
+}
+
+impl fmt::Display for BMDValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid BMD {}: ", self.bmd_id)?;
+        for (index, violation) in self.violations.iter().enumerate() {
+            if index > 0 {
+                write!(f, "; ")?;
+            }
+            write!(f, "{}", violation)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for BMDValidationError {}
+
+/// Collects violations field by field
+#[derive(Default)]
+struct Checker {
+    violations: Vec<FieldViolation>,
+}
+
+impl Checker {
+    fn push(&mut self, field: impl Into<String>, value: f64, violation: Violation) {
+        self.violations.push(FieldViolation { field: field.into(), value, violation });
+    }
+    
+    fn range(&mut self, field: impl Into<String>, value: f64, min: f64, max: f64) {
+        if !(min..=max).contains(&value) {
+            self.push(field, value, Violation::OutOfRange { min, max });
+        }
+    }
+    
+    fn unit(&mut self, field: impl Into<String>, value: f64) {
+        self.range(field, value, 0.0, 1.0);
+    }
+    
+    fn non_negative(&mut self, field: impl Into<String>, value: f64) {
+        if !(value >= 0.0 && value.is_finite()) {
+            self.push(field, value, Violation::Negative);
+        }
+    }
+    
+    fn positive(&mut self, field: impl Into<String>, value: f64) {
+        if !(value > 0.0 && value.is_finite()) {
+            self.push(field, value, Violation::NotPositive);
+        }
+    }
+}
+
+impl BMD {
+    /// Check every field against its documented range
+    pub fn validate(&self) -> Result<(), BMDValidationError> {
+        let mut check = Checker::default();
+        
+        let pattern = &self.pattern;
+        if pattern.core_vectors.is_empty() {
+            check.push("pattern.core_vectors", f64::NAN, Violation::Empty);
+        } else if pattern.core_vectors.iter().any(|value| !value.is_finite()) {
+            check.push("pattern.core_vectors", f64::NAN, Violation::NotFinite);
+        }
+        let mut domains: Vec<_> = pattern.cross_domain_compatibility.iter().collect();
+        domains.sort_by(|a, b| a.0.cmp(b.0));
+        for (domain, score) in domains {
+            check.unit(format!("pattern.cross_domain_compatibility[{}]", domain), *score);
+        }
+        for (index, range) in pattern.frequency_ranges.iter().enumerate() {
+            let field = format!("pattern.frequency_ranges[{}]", index);
+            check.non_negative(format!("{}.min_frequency", field), range.min_frequency);
+            if range.min_frequency > range.max_frequency {
+                let (min, max) = (range.min_frequency, range.max_frequency);
+                check.push(field, f64::NAN, Violation::Inverted { min, max });
+            }
+        }
+        check.unit("pattern.semantic_opacity", pattern.semantic_opacity);
+        
+        let substrate = &self.emotional_substrate;
+        check.range("emotional_substrate.arousal_level", substrate.arousal_level, 0.0, 10.0);
+        check.range(
+            "emotional_substrate.attention_intensity",
+            substrate.attention_intensity,
+            0.0,
+            10.0,
+        );
+        check.range("emotional_substrate.memory_encoding", substrate.memory_encoding, 0.0, 10.0);
+        
+        let coherence = &self.temporal_coherence;
+        check.non_negative("temporal_coherence.degradation_rate", coherence.degradation_rate);
+        check.unit(
+            "temporal_coherence.interruption_resistance",
+            coherence.interruption_resistance,
+        );
+        check.unit("temporal_coherence.temporal_binding", coherence.temporal_binding);
+        
+        let weights = &self.frame_weights;
+        check.positive("frame_weights.base_weight", weights.base_weight);
+        check.positive("frame_weights.relevance_multiplier", weights.relevance_multiplier);
+        check.positive("frame_weights.emotional_compatibility", weights.emotional_compatibility);
+        check.positive("frame_weights.temporal_appropriateness", weights.temporal_appropriateness);
+        if let Some(probability) = weights.selection_probability {
+            check.unit("frame_weights.selection_probability", probability);
+        }
+        
+        let quality = &self.foundry_source.quality_metrics;
+        check.unit("quality_metrics.pattern_coherence", quality.pattern_coherence);
+        check.unit("quality_metrics.cross_domain_score", quality.cross_domain_score);
+        check.unit("quality_metrics.temporal_stability", quality.temporal_stability);
+        check.unit("quality_metrics.transmission_fidelity", quality.transmission_fidelity);
+        
+        if check.violations.is_empty() {
+            Ok(())
+        } else {
+            Err(BMDValidationError { bmd_id: self.id, violations: check.violations })
+        }
+    }
+}
+
+/// Check every BMD of a foundry selection, failing on the first invalid one
+pub(crate) fn validate_selection(selection: &BMDSelection) -> Result<(), BMDValidationError> {
+    selection.bmds.iter().try_for_each(BMD::validate)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_reports_every_violation() {
+        let mut bmd = BMD::builder(vec![0.1, 0.2]).frequency_range(4.0, 8.0).build().unwrap();
+        assert!(bmd.validate().is_ok());
+        
+        bmd.emotional_substrate.arousal_level = 12.0;
+        bmd.pattern.semantic_opacity = -0.1;
+        bmd.pattern.frequency_ranges[0].min_frequency = 9.0;
+        let error = bmd.validate().unwrap_err();
+        
+        let fields: Vec<&str> = error.violations.iter().map(|v| v.field.as_str()).collect();
+        assert_eq!(
+            fields,
+            [
+                "pattern.frequency_ranges[0]",
+                "pattern.semantic_opacity",
+                "emotional_substrate.arousal_level",
+            ]
+        );
+        assert_eq!(
+            error.violations[2].violation,
+            Violation::OutOfRange { min: 0.0, max: 10.0 }
+        );
+        assert!(error.to_string().contains("arousal_level must be in [0, 10], got 12"));
+    }
+    
+    #[test]
+    fn test_nan_is_rejected() {
+        let mut bmd = BMD::builder(vec![1.0]).build().unwrap();
+        bmd.emotional_substrate.memory_encoding = f64::NAN;
+        bmd.frame_weights.base_weight = f64::INFINITY;
+        assert_eq!(bmd.validate().unwrap_err().violations.len(), 2);
+    }
+}
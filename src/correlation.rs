@@ -0,0 +1,90 @@
+//! # Request Correlation
+//! 
+//! Every communication request runs inside a `communication_request` tracing span
+//! carrying a [`CorrelationId`], so all log lines for one request, including those
+//! emitted by the actors that serve it, can be isolated in aggregated logs.
+//! 
+//! Embedders that already have an id for the request (for example from an
+//! inbound `x-correlation-id` header) run the call inside [`scope`]; otherwise a
+//! fresh id is generated.
+
+use std::fmt;
+use std::future::Future;
+use std::str::FromStr;
+use serde::{Deserialize, Serialize};
+use tracing::Span;
+use uuid::Uuid;
+
+/// Metadata key carrying the correlation id on gRPC requests and responses
+pub const CORRELATION_ID_HEADER: &str = "x-correlation-id";
+
+tokio::task_local! {
+    static CURRENT: CorrelationId;
+}
+
+/// Identifier tying together the logs of one communication request
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct CorrelationId(Uuid);
+
+impl CorrelationId {
+    /// Fresh random id
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+    
+    /// Id set by the enclosing [`scope`], if any
+    pub fn current() -> Option<Self> {
+        CURRENT.try_with(|id| *id).ok()
+    }
+    
+    /// Id of the enclosing [`scope`], or a fresh one outside any scope
+    pub fn current_or_new() -> Self {
+        Self::current().unwrap_or_default()
+    }
+}
+
+impl Default for CorrelationId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for CorrelationId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl FromStr for CorrelationId {
+    type Err = uuid::Error;
+    
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Uuid::parse_str(s.trim()).map(Self)
+    }
+}
+
+/// Run `future` with `id` as the correlation id of any request it handles
+pub async fn scope<F: Future>(id: CorrelationId, future: F) -> F::Output {
+    CURRENT.scope(id, future).await
+}
+
+/// Root span of a communication request
+pub fn request_span(id: CorrelationId) -> Span {
+    tracing::info_span!(target: "orchestration", "communication_request", correlation_id = %id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[tokio::test]
+    async fn test_scope_sets_current_id() {
+        assert_eq!(CorrelationId::current(), None);
+        
+        let id = CorrelationId::new();
+        let seen = scope(id, async { CorrelationId::current() }).await;
+        assert_eq!(seen, Some(id));
+        assert_eq!(id.to_string().parse::<CorrelationId>().unwrap(), id);
+    }
+}
@@ -0,0 +1,341 @@
+//! # Recursion-Depth Circuit Breaker
+//! 
+//! Targeted explorations that exhaust `max_recursion_depth` are retried at a
+//! shallower depth so callers still get a best-effort result. When exhaustion
+//! repeats, [`DepthCircuitBreaker`] opens and runs every task at reduced depth
+//! for a cooldown period instead of paying for a doomed full-depth attempt first;
+//! after the cooldown one full-depth attempt decides whether it closes again.
+//! Every result produced below the requested depth carries a [`Degradation`].
+//! 
+//! Cancelable explorations deepen step by step along [`deepening_schedule`], so a
+//! cancelled task can still return the deepest result it completed.
+
+use std::fmt;
+use std::future::Future;
+use std::time::{Duration, Instant};
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+/// Error reporting that an exploration ran out of recursion depth
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DepthExhausted {
+    /// Depth limit that was reached
+    pub depth: u32,
+}
+
+impl fmt::Display for DepthExhausted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "maximum recursion depth {} exhausted", self.depth)
+    }
+}
+
+impl std::error::Error for DepthExhausted {}
+
+/// Error reporting that an exploration was cancelled before any depth completed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExplorationCancelled;
+
+impl fmt::Display for ExplorationCancelled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("exploration cancelled")
+    }
+}
+
+impl std::error::Error for ExplorationCancelled {}
+
+/// Whether `error` reports recursion depth exhaustion
+/// 
+/// Recognises [`DepthExhausted`] anywhere in the error chain, and engine errors
+/// that mention the recursion depth limit.
+pub fn is_depth_exhaustion(error: &anyhow::Error) -> bool {
+    error.chain().any(|cause| {
+        cause.is::<DepthExhausted>() || cause.to_string().to_lowercase().contains("recursion depth")
+    })
+}
+
+/// Why a result was produced below the requested depth
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DegradationReason {
+    /// The full-depth attempt exhausted its recursion depth
+    DepthExhausted,
+    /// The breaker was open, so the full-depth attempt was skipped
+    CircuitOpen,
+    /// The caller cancelled before the requested depth completed
+    Cancelled,
+}
+
+impl DegradationReason {
+    /// Reason name as serialized
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DegradationReason::DepthExhausted => "depth_exhausted",
+            DegradationReason::CircuitOpen => "circuit_open",
+            DegradationReason::Cancelled => "cancelled",
+        }
+    }
+}
+
+/// Best-effort result produced at a shallower depth than requested
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Degradation {
+    /// Recursion depth the task asked for
+    pub requested_depth: u32,
+    /// Recursion depth of the attempt that produced the result
+    pub effective_depth: u32,
+    /// Why the depth was reduced
+    pub reason: DegradationReason,
+}
+
+/// Trip and recovery settings of a [`DepthCircuitBreaker`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BreakerPolicy {
+    /// Consecutive full-depth exhaustions that open the breaker
+    pub failure_threshold: u32,
+    /// Time the breaker stays open before a full-depth attempt is retried
+    pub cooldown: Duration,
+    /// Fraction of the depth kept on each shallower attempt
+    pub depth_factor: f64,
+}
+
+impl Default for BreakerPolicy {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 3,
+            cooldown: Duration::from_secs(30),
+            depth_factor: 0.5,
+        }
+    }
+}
+
+/// Serializable state of a [`DepthCircuitBreaker`], for snapshots
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BreakerState {
+    /// Full-depth exhaustions since the last full-depth success
+    pub consecutive_exhaustions: u32,
+    /// Cooldown left while tripped (milliseconds); zero once a recovery probe is due
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub open_remaining_ms: Option<u64>,
+}
+
+/// Depths a cancelable exploration completes on its way to `depth`
+/// 
+/// Halves down from the requested depth to about a sixteenth of it, so the total
+/// work stays within about twice that of a single full-depth run.
+pub fn deepening_schedule(depth: u32) -> Vec<u32> {
+    let floor = (depth / 16).max(1);
+    let mut step = depth.max(1);
+    let mut schedule = vec![step];
+    while step > floor {
+        step /= 2;
+        schedule.push(step.max(1));
+    }
+    schedule.reverse();
+    schedule
+}
+
+/// Circuit breaker around depth-limited explorations
+#[derive(Debug, Clone)]
+pub struct DepthCircuitBreaker {
+    policy: BreakerPolicy,
+    consecutive_exhaustions: u32,
+    /// Set while tripped; a full-depth attempt after this instant probes recovery
+    open_until: Option<Instant>,
+}
+
+impl DepthCircuitBreaker {
+    /// Create a closed breaker
+    pub fn new(policy: BreakerPolicy) -> Self {
+        Self { policy, consecutive_exhaustions: 0, open_until: None }
+    }
+    
+    /// Whether tasks currently skip the full-depth attempt
+    pub fn is_open(&self) -> bool {
+        self.open_until.is_some_and(|until| Instant::now() < until)
+    }
+    
+    /// Run `attempt` at `depth`, retrying shallower while it exhausts its depth
+    /// 
+    /// `attempt` receives the recursion depth to use. Errors other than depth
+    /// exhaustion, and exhaustion at depth 1, are returned unchanged.
+    pub async fn run<T, F, Fut>(
+        &mut self,
+        depth: u32,
+        mut attempt: F,
+    ) -> Result<(T, Option<Degradation>)>
+    where
+        F: FnMut(u32) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let (mut effective, mut reason) = if self.is_open() {
+            (self.shallower(depth), Some(DegradationReason::CircuitOpen))
+        } else {
+            (depth, None)
+        };
+        
+        loop {
+            match attempt(effective).await {
+                Ok(value) => {
+                    if effective == depth {
+                        self.record_success();
+                    }
+                    let degradation = reason.map(|reason| Degradation {
+                        requested_depth: depth,
+                        effective_depth: effective,
+                        reason,
+                    });
+                    return Ok((value, degradation));
+                }
+                Err(e) if is_depth_exhaustion(&e) => {
+                    if effective == depth {
+                        self.record_exhaustion();
+                    }
+                    if effective <= 1 {
+                        return Err(e);
+                    }
+                    
+                    let shallower = self.shallower(effective);
+                    warn!(
+                        target: "orchestration",
+                        requested_depth = depth,
+                        exhausted_depth = effective,
+                        retry_depth = shallower,
+                        breaker_open = self.is_open(),
+                        "recursion depth exhausted, degrading to a shallower search"
+                    );
+                    reason.get_or_insert(DegradationReason::DepthExhausted);
+                    effective = shallower;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+    
+    /// Current state, for snapshots
+    pub fn state(&self) -> BreakerState {
+        BreakerState {
+            consecutive_exhaustions: self.consecutive_exhaustions,
+            open_remaining_ms: self.open_until.map(|until| {
+                until.saturating_duration_since(Instant::now()).as_millis() as u64
+            }),
+        }
+    }
+    
+    /// Resume from a snapshotted state; the remaining cooldown restarts now
+    pub fn restore(&mut self, state: BreakerState) {
+        self.consecutive_exhaustions = state.consecutive_exhaustions;
+        self.open_until = state.open_remaining_ms
+            .map(|remaining| Instant::now() + Duration::from_millis(remaining));
+    }
+    
+    fn shallower(&self, depth: u32) -> u32 {
+        let reduced = (depth as f64 * self.policy.depth_factor.clamp(0.0, 1.0)) as u32;
+        reduced.clamp(1, depth.saturating_sub(1).max(1))
+    }
+    
+    fn record_success(&mut self) {
+        self.consecutive_exhaustions = 0;
+        self.open_until = None;
+    }
+    
+    fn record_exhaustion(&mut self) {
+        self.consecutive_exhaustions += 1;
+        // A failed recovery probe re-opens immediately
+        let probing = self.open_until.is_some();
+        if probing || self.consecutive_exhaustions >= self.policy.failure_threshold.max(1) {
+            self.open_until = Some(Instant::now() + self.policy.cooldown);
+            self.consecutive_exhaustions = 0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    /// Exploration that succeeds only at or below `limit`, returning the depth used
+    async fn explore(depth: u32, limit: u32) -> Result<u32> {
+        if depth > limit {
+            Err(DepthExhausted { depth }.into())
+        } else {
+            Ok(depth)
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_exhaustion_degrades_to_shallower_depth() {
+        let mut breaker = DepthCircuitBreaker::new(BreakerPolicy::default());
+        
+        let (depth, degradation) = breaker.run(100, |depth| explore(depth, 30)).await.unwrap();
+        assert_eq!(depth, 25);
+        assert_eq!(degradation, Some(Degradation {
+            requested_depth: 100,
+            effective_depth: 25,
+            reason: DegradationReason::DepthExhausted,
+        }));
+        
+        let (depth, degradation) = breaker.run(20, |depth| explore(depth, 30)).await.unwrap();
+        assert_eq!((depth, degradation), (20, None));
+    }
+    
+    #[tokio::test]
+    async fn test_repeated_exhaustion_opens_breaker() {
+        let policy = BreakerPolicy { cooldown: Duration::from_millis(50), ..BreakerPolicy::default() };
+        let mut breaker = DepthCircuitBreaker::new(policy);
+        for _ in 0..3 {
+            breaker.run(100, |depth| explore(depth, 60)).await.unwrap();
+        }
+        assert!(breaker.is_open());
+        
+        // Open: the full-depth attempt is skipped entirely
+        let mut attempted = Vec::new();
+        let (_, degradation) = breaker.run(100, |depth| {
+            attempted.push(depth);
+            explore(depth, 1000)
+        }).await.unwrap();
+        assert_eq!(attempted, vec![50]);
+        assert_eq!(degradation.unwrap().reason, DegradationReason::CircuitOpen);
+        
+        // After the cooldown a successful full-depth probe closes it
+        tokio::time::sleep(Duration::from_millis(60)).await;
+        let (depth, degradation) = breaker.run(100, |depth| explore(depth, 1000)).await.unwrap();
+        assert_eq!((depth, degradation), (100, None));
+        assert!(!breaker.is_open());
+    }
+    
+    #[test]
+    fn test_deepening_schedule_ends_at_requested_depth() {
+        assert_eq!(deepening_schedule(1000), vec![62, 125, 250, 500, 1000]);
+        assert_eq!(deepening_schedule(4), vec![1, 2, 4]);
+        assert_eq!(deepening_schedule(1), vec![1]);
+    }
+    
+    #[tokio::test]
+    async fn test_restored_breaker_stays_open() {
+        let mut breaker = DepthCircuitBreaker::new(BreakerPolicy::default());
+        for _ in 0..3 {
+            breaker.run(100, |depth| explore(depth, 60)).await.unwrap();
+        }
+        let state = breaker.state();
+        assert!(state.open_remaining_ms.is_some());
+        
+        let mut restored = DepthCircuitBreaker::new(BreakerPolicy::default());
+        restored.restore(state);
+        assert!(restored.is_open());
+        assert_eq!(restored.state().consecutive_exhaustions, state.consecutive_exhaustions);
+    }
+    
+    #[tokio::test]
+    async fn test_other_errors_are_not_retried() {
+        let mut breaker = DepthCircuitBreaker::new(BreakerPolicy::default());
+        let mut attempts = 0;
+        let result: Result<(u32, _)> = breaker.run(100, |_| {
+            attempts += 1;
+            async { Err(anyhow::anyhow!("foundry unavailable")) }
+        }).await;
+        
+        assert!(result.is_err());
+        assert_eq!(attempts, 1);
+    }
+}
@@ -0,0 +1,291 @@
+//! # Emergence Detection Strategies
+//! 
+//! Alternative decision rules for statistical emergence, so detection strategies
+//! can be compared on the same optimization results. Each [`EmergenceRule`] scores
+//! the significance of a candidate pattern against the history of earlier
+//! candidates:
+//! 
+//! - [`ZScoreRule`] flags scores far above the recent mean
+//! - [`CusumRule`] flags a sustained upward shift in scores
+//! - [`BayesianRule`] flags scores whose posterior probability of being emergent
+//!   exceeds a threshold
+//! 
+//! [`RuleDetector`] applies a rule to the candidates proposed by the
+//! [`OptimizationCoordinator`] and is registered as an [`EmergenceDetector`]
+//! plugin under [`ZSCORE_DETECTOR`], [`CUSUM_DETECTOR`], and [`BAYESIAN_DETECTOR`].
+//! Pick one with `plugins.emergence_detector` in the configuration.
+//! 
+//! These live in their own module rather than in [`crate::emergence`], whose source
+//! is not part of this tree; [`EmergenceDetector`] itself sits with the other
+//! extension points in [`crate::plugins`].
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::sync::{Arc, Mutex, PoisonError};
+use async_trait::async_trait;
+use anyhow::Result;
+
+use crate::emergence::EmergedPattern;
+use crate::optimization::{OptimizationCoordinator, OptimizationResults};
+use crate::plugins::EmergenceDetector;
+
+/// Plugin name of the z-score detector
+pub const ZSCORE_DETECTOR: &str = "zscore";
+
+/// Plugin name of the CUSUM detector
+pub const CUSUM_DETECTOR: &str = "cusum";
+
+/// Plugin name of the Bayesian posterior detector
+pub const BAYESIAN_DETECTOR: &str = "bayesian";
+
+/// Scores needed before a rule trusts its baseline
+const MIN_BASELINE: usize = 8;
+
+/// Floor on the baseline standard deviation, so constant histories do not divide by zero
+const MIN_STD_DEV: f64 = 1e-9;
+
+/// Sequential decision rule over candidate significance scores
+pub trait EmergenceRule: Send + fmt::Debug {
+    /// Record `score` and decide whether the candidate that produced it emerged
+    fn observe(&mut self, score: f64) -> bool;
+}
+
+/// Rolling mean and standard deviation of recent scores
+#[derive(Debug, Clone)]
+struct Baseline {
+    window: usize,
+    scores: VecDeque<f64>,
+}
+
+impl Baseline {
+    fn new(window: usize) -> Self {
+        let window = window.max(MIN_BASELINE);
+        Self { window, scores: VecDeque::with_capacity(window) }
+    }
+    
+    fn is_ready(&self) -> bool {
+        self.scores.len() >= MIN_BASELINE
+    }
+    
+    fn mean(&self) -> f64 {
+        self.scores.iter().sum::<f64>() / self.scores.len().max(1) as f64
+    }
+    
+    fn std_dev(&self) -> f64 {
+        let n = self.scores.len();
+        if n < 2 {
+            return MIN_STD_DEV;
+        }
+        let mean = self.mean();
+        let variance = self.scores.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / (n - 1) as f64;
+        variance.sqrt().max(MIN_STD_DEV)
+    }
+    
+    fn push(&mut self, score: f64) {
+        if self.scores.len() == self.window {
+            self.scores.pop_front();
+        }
+        self.scores.push_back(score);
+    }
+}
+
+/// Flag scores more than `threshold` standard deviations above the rolling mean
+#[derive(Debug, Clone)]
+pub struct ZScoreRule {
+    threshold: f64,
+    baseline: Baseline,
+}
+
+impl ZScoreRule {
+    /// Compare each score against the previous `window` scores
+    pub fn new(threshold: f64, window: usize) -> Self {
+        Self { threshold, baseline: Baseline::new(window) }
+    }
+}
+
+impl Default for ZScoreRule {
+    fn default() -> Self {
+        Self::new(3.0, 256)
+    }
+}
+
+impl EmergenceRule for ZScoreRule {
+    fn observe(&mut self, score: f64) -> bool {
+        let emerged = self.baseline.is_ready()
+            && (score - self.baseline.mean()) / self.baseline.std_dev() > self.threshold;
+        self.baseline.push(score);
+        emerged
+    }
+}
+
+/// One-sided CUSUM change detection on standardized scores
+/// 
+/// Accumulates standardized excesses above `drift` and flags a candidate once the
+/// sum passes `threshold`, then restarts accumulation.
+#[derive(Debug, Clone)]
+pub struct CusumRule {
+    drift: f64,
+    threshold: f64,
+    sum: f64,
+    baseline: Baseline,
+}
+
+impl CusumRule {
+    /// Detect shifts larger than `drift` standard deviations, with decision interval `threshold`
+    pub fn new(drift: f64, threshold: f64, window: usize) -> Self {
+        Self { drift, threshold, sum: 0.0, baseline: Baseline::new(window) }
+    }
+}
+
+impl Default for CusumRule {
+    fn default() -> Self {
+        Self::new(0.5, 5.0, 256)
+    }
+}
+
+impl EmergenceRule for CusumRule {
+    fn observe(&mut self, score: f64) -> bool {
+        if !self.baseline.is_ready() {
+            self.baseline.push(score);
+            return false;
+        }
+        
+        let standardized = (score - self.baseline.mean()) / self.baseline.std_dev();
+        self.sum = (self.sum + standardized - self.drift).max(0.0);
+        let emerged = self.sum > self.threshold;
+        if emerged {
+            self.sum = 0.0;
+        } else {
+            // Scores in a detected shift would otherwise drag the baseline along
+            self.baseline.push(score);
+        }
+        emerged
+    }
+}
+
+/// Flag scores whose posterior probability of being emergent exceeds `threshold`
+/// 
+/// Background scores are modelled as a Gaussian fitted to the rolling baseline and
+/// emergent scores as the same Gaussian shifted up by `effect_size` standard
+/// deviations.
+#[derive(Debug, Clone)]
+pub struct BayesianRule {
+    prior: f64,
+    effect_size: f64,
+    threshold: f64,
+    baseline: Baseline,
+}
+
+impl BayesianRule {
+    /// Detect with prior emergence probability `prior`
+    pub fn new(prior: f64, effect_size: f64, threshold: f64, window: usize) -> Self {
+        Self {
+            prior: prior.clamp(f64::EPSILON, 1.0 - f64::EPSILON),
+            effect_size,
+            threshold,
+            baseline: Baseline::new(window),
+        }
+    }
+    
+    /// Posterior probability that `score` is emergent under the current baseline
+    fn posterior(&self, score: f64) -> f64 {
+        let z = (score - self.baseline.mean()) / self.baseline.std_dev();
+        // Gaussian likelihood ratio, emergent over background
+        let log_likelihood_ratio = self.effect_size * z - self.effect_size.powi(2) / 2.0;
+        let log_prior_odds = (self.prior / (1.0 - self.prior)).ln();
+        1.0 / (1.0 + (-(log_likelihood_ratio + log_prior_odds)).exp())
+    }
+}
+
+impl Default for BayesianRule {
+    fn default() -> Self {
+        Self::new(0.01, 3.0, 0.9997, 256)
+    }
+}
+
+impl EmergenceRule for BayesianRule {
+    fn observe(&mut self, score: f64) -> bool {
+        let emerged = self.baseline.is_ready() && self.posterior(score) > self.threshold;
+        self.baseline.push(score);
+        emerged
+    }
+}
+
+/// Emergence detector applying an [`EmergenceRule`] to candidate patterns
+pub struct RuleDetector<R> {
+    candidates: Arc<OptimizationCoordinator>,
+    rule: Mutex<R>,
+}
+
+impl<R: EmergenceRule> fmt::Debug for RuleDetector<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RuleDetector")
+            .field("rule", &*self.rule.lock().unwrap_or_else(PoisonError::into_inner))
+            .finish()
+    }
+}
+
+impl<R: EmergenceRule> RuleDetector<R> {
+    /// Judge candidates proposed by `candidates` with `rule`
+    pub fn new(candidates: Arc<OptimizationCoordinator>, rule: R) -> Self {
+        Self { candidates, rule: Mutex::new(rule) }
+    }
+}
+
+#[async_trait]
+impl<R: EmergenceRule + 'static> EmergenceDetector for RuleDetector<R> {
+    async fn detect_emergence(&self, results: OptimizationResults) -> Result<Vec<EmergedPattern>> {
+        let candidates = self.candidates.detect_statistical_emergence(results).await?;
+        let mut rule = self.rule.lock().unwrap_or_else(PoisonError::into_inner);
+        Ok(candidates.into_iter().filter(|pattern| rule.observe(pattern.significance)).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    /// Deterministic noise in [-0.5, 0.5)
+    fn background(n: usize) -> impl Iterator<Item = f64> {
+        (0..n).map(|i| ((i * 7919) % 100) as f64 / 100.0 - 0.5)
+    }
+    
+    fn detections(rule: &mut dyn EmergenceRule, scores: impl Iterator<Item = f64>) -> usize {
+        scores.filter(|&score| rule.observe(score)).count()
+    }
+    
+    #[test]
+    fn test_rules_ignore_background_noise() {
+        let rules: [&mut dyn EmergenceRule; 3] = [
+            &mut ZScoreRule::default(),
+            &mut CusumRule::default(),
+            &mut BayesianRule::default(),
+        ];
+        for rule in rules {
+            assert_eq!(detections(rule, background(500)), 0, "{:?}", rule);
+        }
+    }
+    
+    #[test]
+    fn test_rules_flag_outlier() {
+        let rules: [&mut dyn EmergenceRule; 3] = [
+            &mut ZScoreRule::default(),
+            &mut CusumRule::default(),
+            &mut BayesianRule::default(),
+        ];
+        for rule in rules {
+            detections(rule, background(100));
+            assert!(rule.observe(10.0), "{:?}", rule);
+        }
+    }
+    
+    #[test]
+    fn test_cusum_flags_sustained_shift() {
+        let mut rule = CusumRule::default();
+        detections(&mut rule, background(100));
+        
+        // A 2σ shift is too small for a single z-score test but accumulates under CUSUM
+        let shifted = background(20).map(|score| score + 0.6);
+        assert!(detections(&mut rule, shifted) > 0);
+    }
+}
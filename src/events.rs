@@ -0,0 +1,118 @@
+//! # Lifecycle Events
+//! 
+//! [`EventBus`] broadcasts typed [`LifecycleEvent`]s from the orchestration loop
+//! and request path, so downstream users can react to cycles, emerged patterns,
+//! foundry outages, and degraded optimizations without scraping logs. Subscribe
+//! with [`crate::HugureSystem::subscribe`].
+//! 
+//! Delivery is best effort: a subscriber that falls more than the bus capacity
+//! behind skips the oldest events and sees a `Lagged` error from its receiver.
+
+use std::time::Duration;
+use serde::Serialize;
+use tokio::sync::broadcast;
+
+use crate::correlation::CorrelationId;
+use crate::depth_breaker::Degradation;
+use crate::emergence::EmergedPattern;
+
+/// Event published on the [`EventBus`]
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum LifecycleEvent {
+    /// An orchestration cycle finished exploration, optimization, and detection
+    CycleCompleted {
+        /// Patterns that emerged from the cycle
+        emerged_patterns: usize,
+        /// Wall-clock time of the whole cycle
+        duration: Duration,
+    },
+    /// A pattern emerged from an orchestration cycle
+    PatternEmerged {
+        /// The emerged pattern
+        pattern: EmergedPattern,
+    },
+    /// The foundry could not serve a BMD selection
+    FoundryUnavailable {
+        /// Why the selection failed
+        error: String,
+    },
+    /// A communication request was optimized below its requested depth
+    OptimizationDegraded {
+        /// Request the degraded result was returned to
+        correlation_id: CorrelationId,
+        /// How the result was degraded
+        degradation: Degradation,
+    },
+    /// A supervised loop panicked and is being restarted
+    LoopCrashed {
+        /// Which loop panicked
+        loop_name: String,
+        /// Message of the captured panic
+        panic: String,
+        /// Restarts of the loop so far, including this one
+        restarts: u64,
+        /// Delay before the loop is restarted
+        restart_in: Duration,
+    },
+}
+
+/// Broadcast channel of lifecycle events
+#[derive(Debug, Clone)]
+pub struct EventBus {
+    sender: broadcast::Sender<LifecycleEvent>,
+}
+
+impl EventBus {
+    /// Bus retaining up to `capacity` events for slow subscribers
+    pub fn new(capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(capacity.max(1));
+        Self { sender }
+    }
+    
+    /// Receive every event published from now on
+    pub fn subscribe(&self) -> broadcast::Receiver<LifecycleEvent> {
+        self.sender.subscribe()
+    }
+    
+    /// Publish `event` to current subscribers; without subscribers it is dropped
+    pub fn publish(&self, event: LifecycleEvent) {
+        let _ = self.sender.send(event);
+    }
+    
+    /// Number of live subscribers
+    pub fn subscriber_count(&self) -> usize {
+        self.sender.receiver_count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[tokio::test]
+    async fn test_subscribers_receive_published_events() {
+        let bus = EventBus::new(8);
+        bus.publish(LifecycleEvent::FoundryUnavailable { error: "before".to_string() });
+        
+        let mut events = bus.subscribe();
+        bus.publish(LifecycleEvent::FoundryUnavailable { error: "offline".to_string() });
+        
+        match events.recv().await.unwrap() {
+            LifecycleEvent::FoundryUnavailable { error } => assert_eq!(error, "offline"),
+            other => panic!("unexpected event {:?}", other),
+        }
+        assert_eq!(bus.subscriber_count(), 1);
+    }
+    
+    #[test]
+    fn test_events_serialize_with_tag() {
+        let event = LifecycleEvent::CycleCompleted {
+            emerged_patterns: 2,
+            duration: Duration::from_millis(5),
+        };
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["event"], "cycle_completed");
+        assert_eq!(json["emerged_patterns"], 2);
+    }
+}
@@ -0,0 +1,131 @@
+//! # Exploration Rate Budget
+//! 
+//! Enforces `exploration_rate_target`: the orchestration loop takes a token from
+//! an [`ExplorationBudget`] before every exploration cycle, so background
+//! exploration runs at most at the target rate after an initial burst. The budget
+//! is a [`TokenBucket`] from [`crate::util::ratelimit`], and keeps counters of the
+//! attained rate and of cycles that had to wait, exposed as
+//! [`ExplorationRateStats`].
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, Instant};
+use serde::{Deserialize, Serialize};
+
+use crate::util::ratelimit::{RateLimiter, TokenBucket};
+
+/// Window over which the attained rate is measured
+const RATE_WINDOW: Duration = Duration::from_secs(1);
+
+/// Attained versus target exploration rate
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ExplorationRateStats {
+    /// Configured explorations per second
+    pub target_per_second: f64,
+    /// Explorations per second over the last measurement window
+    pub attained_per_second: f64,
+    /// Explorations admitted with no wait
+    pub burst: u32,
+    /// Explorations admitted since creation
+    pub explorations: u64,
+    /// Explorations that waited for the budget to refill
+    pub throttled: u64,
+}
+
+/// Explorations counted in the current window, and the rate of the last full one
+#[derive(Debug)]
+struct RateWindow {
+    started: Instant,
+    count: u64,
+    last_rate: f64,
+}
+
+/// Token-bucket budget of exploration cycles per second
+#[derive(Debug)]
+pub struct ExplorationBudget {
+    bucket: TokenBucket,
+    target_per_second: f64,
+    burst: u32,
+    explorations: AtomicU64,
+    throttled: AtomicU64,
+    window: Mutex<RateWindow>,
+}
+
+impl ExplorationBudget {
+    /// Admit `target_per_second` explorations per second, bursting up to `burst`
+    pub fn new(target_per_second: u64, burst: u32) -> Self {
+        let target_per_second = target_per_second as f64;
+        Self {
+            bucket: TokenBucket::new("exploration", burst, target_per_second),
+            target_per_second,
+            burst: burst.max(1),
+            explorations: AtomicU64::new(0),
+            throttled: AtomicU64::new(0),
+            window: Mutex::new(RateWindow { started: Instant::now(), count: 0, last_rate: 0.0 }),
+        }
+    }
+    
+    /// Wait until the budget admits one exploration
+    /// 
+    /// Returns whether the exploration had to wait.
+    pub async fn acquire(&self) -> bool {
+        let throttled = !self.bucket.try_acquire();
+        if throttled {
+            self.throttled.fetch_add(1, Ordering::Relaxed);
+            self.bucket.acquire().await;
+        }
+        
+        self.explorations.fetch_add(1, Ordering::Relaxed);
+        let mut window = self.window.lock().unwrap_or_else(PoisonError::into_inner);
+        window.count += 1;
+        Self::roll(&mut window);
+        throttled
+    }
+    
+    /// Current counters
+    pub fn stats(&self) -> ExplorationRateStats {
+        let attained_per_second = {
+            let mut window = self.window.lock().unwrap_or_else(PoisonError::into_inner);
+            Self::roll(&mut window);
+            window.last_rate
+        };
+        ExplorationRateStats {
+            target_per_second: self.target_per_second,
+            attained_per_second,
+            burst: self.burst,
+            explorations: self.explorations.load(Ordering::Relaxed),
+            throttled: self.throttled.load(Ordering::Relaxed),
+        }
+    }
+    
+    /// Close the window once it spans [`RATE_WINDOW`], so idle periods read as zero
+    fn roll(window: &mut RateWindow) {
+        let elapsed = window.started.elapsed();
+        if elapsed >= RATE_WINDOW {
+            window.last_rate = window.count as f64 / elapsed.as_secs_f64();
+            window.started = Instant::now();
+            window.count = 0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[tokio::test]
+    async fn test_burst_then_throttle() {
+        let budget = ExplorationBudget::new(200, 2);
+        
+        assert!(!budget.acquire().await);
+        assert!(!budget.acquire().await);
+        
+        let started = Instant::now();
+        assert!(budget.acquire().await);
+        assert!(started.elapsed() >= Duration::from_millis(3));
+        
+        let stats = budget.stats();
+        assert_eq!((stats.explorations, stats.throttled), (3, 1));
+        assert_eq!(stats.target_per_second, 200.0);
+    }
+}
@@ -0,0 +1,269 @@
+//! # Exploration Concurrency
+//! 
+//! Bounds how many orchestration cycles run at once. With [`AutoTune::Disabled`]
+//! the limit is `max_concurrent_explorations`. With [`AutoTune::Aimd`] an
+//! additive-increase/multiplicative-decrease controller moves the limit between
+//! `min_concurrency` and `max_concurrent_explorations`, so operators don't have to
+//! hand-pick it:
+//! 
+//! - every [`ADJUST_EVERY_CYCLES`] cycles, the mean cycle latency and the emergence
+//!   yield (patterns emerged per second) of the window are measured
+//! - a mean latency above the target cuts the limit to [`DECREASE_FACTOR`] of itself
+//! - otherwise, if the yield held up against the previous window, the limit grows by
+//!   one; a falling yield holds it where it is
+
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+use serde::{Deserialize, Serialize};
+use tokio::sync::Notify;
+
+/// Cycles per tuning window
+pub const ADJUST_EVERY_CYCLES: u32 = 16;
+
+/// Fraction of the limit kept when cycles run slower than the target
+pub const DECREASE_FACTOR: f64 = 0.75;
+
+/// Fraction of the previous window's yield that still counts as holding up
+const YIELD_TOLERANCE: f64 = 0.95;
+
+/// How exploration concurrency is chosen
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "mode", rename_all = "snake_case")]
+pub enum AutoTune {
+    /// Run up to `max_concurrent_explorations` cycles at once
+    #[default]
+    Disabled,
+    /// Tune the limit from cycle latency and emergence yield
+    Aimd {
+        /// Lowest limit, and the one tuning starts from
+        min_concurrency: usize,
+        /// Mean cycle latency above which the limit is cut (milliseconds)
+        target_cycle_latency_ms: u64,
+    },
+}
+
+/// Current concurrency limit and what drove it
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ConcurrencyStats {
+    /// Cycles allowed at once
+    pub limit: usize,
+    /// Cycles running now
+    pub in_flight: usize,
+    /// Whether the limit is auto-tuned
+    pub auto_tuned: bool,
+    /// Mean cycle latency of the last tuning window (milliseconds)
+    pub mean_cycle_latency_ms: f64,
+    /// Patterns emerged per second over the last tuning window
+    pub emergence_yield: f64,
+    /// Times the limit was raised
+    pub increases: u64,
+    /// Times the limit was cut
+    pub decreases: u64,
+}
+
+/// Cycles measured since the last adjustment, and what the last window measured
+#[derive(Debug)]
+struct TuningWindow {
+    started: Instant,
+    cycles: u32,
+    latency: Duration,
+    emerged: usize,
+    last_mean_latency: Duration,
+    last_yield: f64,
+}
+
+/// Limit on concurrent orchestration cycles
+#[derive(Debug)]
+pub struct ExplorationConcurrency {
+    auto_tune: AutoTune,
+    max: usize,
+    limit: AtomicUsize,
+    in_flight: AtomicUsize,
+    released: Notify,
+    increases: AtomicU64,
+    decreases: AtomicU64,
+    window: Mutex<TuningWindow>,
+}
+
+/// Slot for one running cycle, released on drop
+#[derive(Debug)]
+pub struct ConcurrencyPermit {
+    concurrency: Arc<ExplorationConcurrency>,
+}
+
+impl Drop for ConcurrencyPermit {
+    fn drop(&mut self) {
+        self.concurrency.in_flight.fetch_sub(1, Ordering::AcqRel);
+        self.concurrency.released.notify_waiters();
+    }
+}
+
+impl ExplorationConcurrency {
+    /// Allow up to `max` cycles at once, tuned below that by `auto_tune`
+    pub fn new(max: usize, auto_tune: AutoTune) -> Self {
+        let max = max.max(1);
+        let initial = match auto_tune {
+            AutoTune::Disabled => max,
+            AutoTune::Aimd { min_concurrency, .. } => min_concurrency.clamp(1, max),
+        };
+        Self {
+            auto_tune,
+            max,
+            limit: AtomicUsize::new(initial),
+            in_flight: AtomicUsize::new(0),
+            released: Notify::new(),
+            increases: AtomicU64::new(0),
+            decreases: AtomicU64::new(0),
+            window: Mutex::new(TuningWindow {
+                started: Instant::now(),
+                cycles: 0,
+                latency: Duration::ZERO,
+                emerged: 0,
+                last_mean_latency: Duration::ZERO,
+                last_yield: 0.0,
+            }),
+        }
+    }
+    
+    /// Cycles allowed at once
+    pub fn limit(&self) -> usize {
+        self.limit.load(Ordering::Acquire)
+    }
+    
+    /// Wait for a free slot under the limit
+    pub async fn acquire(self: &Arc<Self>) -> ConcurrencyPermit {
+        loop {
+            // Registered before checking, so a release in between is not missed
+            let released = self.released.notified();
+            if self.try_reserve() {
+                return ConcurrencyPermit { concurrency: Arc::clone(self) };
+            }
+            released.await;
+        }
+    }
+    
+    fn try_reserve(&self) -> bool {
+        let mut current = self.in_flight.load(Ordering::Acquire);
+        while current < self.limit() {
+            match self.in_flight.compare_exchange(
+                current,
+                current + 1,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => return true,
+                Err(actual) => current = actual,
+            }
+        }
+        false
+    }
+    
+    /// Feed back a finished cycle; returns the new limit if it changed
+    pub fn record_cycle(&self, latency: Duration, emerged_patterns: usize) -> Option<usize> {
+        let mut window = self.window.lock().unwrap_or_else(PoisonError::into_inner);
+        window.cycles += 1;
+        window.latency += latency;
+        window.emerged += emerged_patterns;
+        if window.cycles < ADJUST_EVERY_CYCLES {
+            return None;
+        }
+        
+        let mean_latency = window.latency / window.cycles;
+        let emergence_yield =
+            window.emerged as f64 / window.started.elapsed().as_secs_f64().max(f64::EPSILON);
+        let yield_held = emergence_yield >= window.last_yield * YIELD_TOLERANCE;
+        *window = TuningWindow {
+            started: Instant::now(),
+            cycles: 0,
+            latency: Duration::ZERO,
+            emerged: 0,
+            last_mean_latency: mean_latency,
+            last_yield: emergence_yield,
+        };
+        drop(window);
+        
+        let AutoTune::Aimd { min_concurrency, target_cycle_latency_ms } = self.auto_tune else {
+            return None;
+        };
+        let min = min_concurrency.clamp(1, self.max);
+        let limit = self.limit();
+        let next = if mean_latency > Duration::from_millis(target_cycle_latency_ms) {
+            ((limit as f64 * DECREASE_FACTOR) as usize).max(min)
+        } else if yield_held {
+            (limit + 1).min(self.max)
+        } else {
+            limit
+        };
+        
+        match next.cmp(&limit) {
+            std::cmp::Ordering::Equal => return None,
+            std::cmp::Ordering::Greater => {
+                self.increases.fetch_add(1, Ordering::Relaxed);
+            }
+            std::cmp::Ordering::Less => {
+                self.decreases.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+        self.limit.store(next, Ordering::Release);
+        self.released.notify_waiters();
+        Some(next)
+    }
+    
+    /// Current limit and tuning counters
+    pub fn stats(&self) -> ConcurrencyStats {
+        let window = self.window.lock().unwrap_or_else(PoisonError::into_inner);
+        ConcurrencyStats {
+            limit: self.limit(),
+            in_flight: self.in_flight.load(Ordering::Acquire),
+            auto_tuned: matches!(self.auto_tune, AutoTune::Aimd { .. }),
+            mean_cycle_latency_ms: window.last_mean_latency.as_secs_f64() * 1000.0,
+            emergence_yield: window.last_yield,
+            increases: self.increases.load(Ordering::Relaxed),
+            decreases: self.decreases.load(Ordering::Relaxed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn run_window(concurrency: &ExplorationConcurrency, latency: Duration) -> Option<usize> {
+        (0..ADJUST_EVERY_CYCLES)
+            .map(|_| concurrency.record_cycle(latency, 1))
+            .last()
+            .flatten()
+    }
+    
+    #[test]
+    fn test_aimd_grows_then_backs_off() {
+        let auto_tune = AutoTune::Aimd { min_concurrency: 4, target_cycle_latency_ms: 50 };
+        let concurrency = ExplorationConcurrency::new(100, auto_tune);
+        assert_eq!(concurrency.limit(), 4);
+        
+        assert_eq!(run_window(&concurrency, Duration::from_millis(10)), Some(5));
+        assert_eq!(run_window(&concurrency, Duration::from_millis(80)), Some(4));
+        assert_eq!(run_window(&concurrency, Duration::from_millis(80)), None);
+        
+        let stats = concurrency.stats();
+        assert_eq!((stats.increases, stats.decreases), (1, 1));
+        assert!(stats.auto_tuned);
+    }
+    
+    #[tokio::test]
+    async fn test_acquire_waits_for_release() {
+        let concurrency = Arc::new(ExplorationConcurrency::new(1, AutoTune::Disabled));
+        let permit = concurrency.acquire().await;
+        assert!(!concurrency.try_reserve());
+        
+        let waiter = tokio::spawn({
+            let concurrency = Arc::clone(&concurrency);
+            async move { concurrency.acquire().await }
+        });
+        drop(permit);
+        let _permit = waiter.await.unwrap();
+        assert_eq!(concurrency.stats().in_flight, 1);
+        assert_eq!(run_window(&concurrency, Duration::from_secs(1)), None);
+    }
+}
@@ -0,0 +1,180 @@
+//! # Exploration Queue
+//! 
+//! Bounded hand-off between foundry BMD selection and downstream exploration,
+//! optimization, and emergence detection. Selection reserves a slot before asking
+//! the foundry for BMDs, so when optimization falls behind the selection path
+//! parks on [`ExplorationQueue::reserve`] instead of spinning.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use tokio::sync::{mpsc, Mutex};
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+/// Point-in-time queue metrics
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QueueStats {
+    /// Items currently queued or reserved
+    pub depth: usize,
+    /// Maximum number of queued items
+    pub capacity: usize,
+    /// Items pushed since creation
+    pub enqueued: u64,
+    /// Items popped since creation
+    pub dequeued: u64,
+    /// Reservations that had to wait for downstream to catch up
+    pub backpressure_waits: u64,
+}
+
+/// Bounded FIFO of pending exploration work
+#[derive(Debug)]
+pub struct ExplorationQueue<T> {
+    sender: mpsc::Sender<T>,
+    receiver: Mutex<mpsc::Receiver<T>>,
+    enqueued: AtomicU64,
+    dequeued: AtomicU64,
+    backpressure_waits: AtomicU64,
+}
+
+/// Reserved queue slot; dropping it without pushing releases the slot
+#[derive(Debug)]
+pub struct ExplorationSlot<'a, T> {
+    permit: mpsc::Permit<'a, T>,
+    queue: &'a ExplorationQueue<T>,
+}
+
+impl<T> ExplorationSlot<'_, T> {
+    /// Fill the reserved slot
+    pub fn push(self, item: T) {
+        self.permit.send(item);
+        self.queue.enqueued.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+impl<T: Send> ExplorationQueue<T> {
+    /// Create a queue holding at most `capacity` items
+    pub fn new(capacity: usize) -> Self {
+        let (sender, receiver) = mpsc::channel(capacity.max(1));
+        Self {
+            sender,
+            receiver: Mutex::new(receiver),
+            enqueued: AtomicU64::new(0),
+            dequeued: AtomicU64::new(0),
+            backpressure_waits: AtomicU64::new(0),
+        }
+    }
+    
+    /// Wait for a free slot
+    /// 
+    /// Call before producing an item so that no work is done while downstream is
+    /// saturated.
+    pub async fn reserve(&self) -> Result<ExplorationSlot<'_, T>> {
+        if self.is_saturated() {
+            self.backpressure_waits.fetch_add(1, Ordering::Relaxed);
+        }
+        let permit = self.sender.reserve().await
+            .map_err(|_| anyhow::anyhow!("exploration queue closed"))?;
+        Ok(ExplorationSlot { permit, queue: self })
+    }
+    
+    /// Wait for a free slot and enqueue `item`
+    pub async fn push(&self, item: T) -> Result<()> {
+        self.reserve().await?.push(item);
+        Ok(())
+    }
+    
+    /// Wait for the next item
+    pub async fn pop(&self) -> Option<T> {
+        let item = self.receiver.lock().await.recv().await;
+        if item.is_some() {
+            self.dequeued.fetch_add(1, Ordering::Relaxed);
+        }
+        item
+    }
+    
+    /// Take the next item if one is immediately available
+    pub fn try_pop(&self) -> Option<T> {
+        let item = self.receiver.try_lock().ok()?.try_recv().ok();
+        if item.is_some() {
+            self.dequeued.fetch_add(1, Ordering::Relaxed);
+        }
+        item
+    }
+    
+    /// Items currently queued or reserved
+    pub fn depth(&self) -> usize {
+        self.sender.max_capacity() - self.sender.capacity()
+    }
+    
+    /// Maximum number of queued items
+    pub fn capacity(&self) -> usize {
+        self.sender.max_capacity()
+    }
+    
+    /// Whether producers would currently have to wait
+    pub fn is_saturated(&self) -> bool {
+        self.sender.capacity() == 0
+    }
+    
+    /// Snapshot of queue metrics
+    pub fn stats(&self) -> QueueStats {
+        QueueStats {
+            depth: self.depth(),
+            capacity: self.capacity(),
+            enqueued: self.enqueued.load(Ordering::Relaxed),
+            dequeued: self.dequeued.load(Ordering::Relaxed),
+            backpressure_waits: self.backpressure_waits.load(Ordering::Relaxed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::time::Duration;
+    
+    #[tokio::test]
+    async fn test_fifo_and_stats() {
+        let queue = ExplorationQueue::new(4);
+        queue.push(1).await.unwrap();
+        queue.push(2).await.unwrap();
+        assert_eq!(queue.depth(), 2);
+        
+        assert_eq!(queue.pop().await, Some(1));
+        assert_eq!(queue.try_pop(), Some(2));
+        assert_eq!(queue.try_pop(), None);
+        
+        let stats = queue.stats();
+        assert_eq!((stats.depth, stats.capacity, stats.enqueued, stats.dequeued), (0, 4, 2, 2));
+    }
+    
+    #[tokio::test]
+    async fn test_full_queue_applies_backpressure() {
+        let queue = Arc::new(ExplorationQueue::new(1));
+        queue.push("first").await.unwrap();
+        assert!(queue.is_saturated());
+        
+        let producer = {
+            let queue = Arc::clone(&queue);
+            tokio::spawn(async move { queue.push("second").await })
+        };
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert!(!producer.is_finished());
+        
+        assert_eq!(queue.pop().await, Some("first"));
+        producer.await.unwrap().unwrap();
+        assert_eq!(queue.pop().await, Some("second"));
+        assert_eq!(queue.stats().backpressure_waits, 1);
+    }
+    
+    #[tokio::test]
+    async fn test_dropped_slot_is_released() {
+        let queue: ExplorationQueue<u8> = ExplorationQueue::new(1);
+        let slot = queue.reserve().await.unwrap();
+        assert!(queue.is_saturated());
+        
+        drop(slot);
+        assert!(!queue.is_saturated());
+        assert_eq!(queue.stats().enqueued, 0);
+    }
+}
@@ -0,0 +1,220 @@
+//! Context-keyed BMD selection cache
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+use async_trait::async_trait;
+use anyhow::Result;
+use serde::Serialize;
+
+use crate::bmd::BMDSelection;
+use crate::foundry::BMDSelectionContext;
+use super::{FoundryBackend, FoundryCapabilities};
+
+/// Selection cache counters
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SelectionCacheStats {
+    /// Live entries, including ones not yet evicted after expiry
+    pub entries: usize,
+    /// Selections served from the cache
+    pub hits: u64,
+    /// Selections forwarded to the foundry
+    pub misses: u64,
+}
+
+/// Bounded map whose entries expire after a fixed TTL
+struct TtlCache<V> {
+    capacity: usize,
+    ttl: Duration,
+    entries: Mutex<HashMap<u64, (V, Instant)>>,
+}
+
+impl<V: Clone> TtlCache<V> {
+    fn new(capacity: usize, ttl: Duration) -> Self {
+        Self { capacity: capacity.max(1), ttl, entries: Mutex::new(HashMap::new()) }
+    }
+    
+    fn lock(&self) -> MutexGuard<'_, HashMap<u64, (V, Instant)>> {
+        // Cache contents stay valid even if a holder panicked
+        self.entries.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+    
+    fn get(&self, key: u64) -> Option<V> {
+        let mut entries = self.lock();
+        match entries.get(&key) {
+            Some((value, inserted_at)) if inserted_at.elapsed() < self.ttl => Some(value.clone()),
+            Some(_) => {
+                entries.remove(&key);
+                None
+            }
+            None => None,
+        }
+    }
+    
+    fn insert(&self, key: u64, value: V) {
+        let mut entries = self.lock();
+        if entries.len() >= self.capacity && !entries.contains_key(&key) {
+            let ttl = self.ttl;
+            entries.retain(|_, (_, inserted_at)| inserted_at.elapsed() < ttl);
+        }
+        if entries.len() >= self.capacity && !entries.contains_key(&key) {
+            let oldest = entries.iter()
+                .min_by_key(|(_, (_, inserted_at))| *inserted_at)
+                .map(|(key, _)| *key);
+            if let Some(oldest) = oldest {
+                entries.remove(&oldest);
+            }
+        }
+        entries.insert(key, (value, Instant::now()));
+    }
+    
+    fn remove(&self, key: u64) -> bool {
+        self.lock().remove(&key).is_some()
+    }
+    
+    fn clear(&self) {
+        self.lock().clear();
+    }
+    
+    fn len(&self) -> usize {
+        self.lock().len()
+    }
+}
+
+/// Foundry backend that serves repeated communication contexts from memory
+/// 
+/// Context selections are keyed by sender and recipient profiles, intent, and
+/// optimization target, and expire after a fixed TTL. Exploration selections are
+/// always forwarded, since they are meant to be fresh.
+pub struct CachedFoundry {
+    inner: Arc<dyn FoundryBackend>,
+    cache: TtlCache<BMDSelection>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl std::fmt::Debug for CachedFoundry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CachedFoundry")
+            .field("inner", &self.inner)
+            .field("capacity", &self.cache.capacity)
+            .field("ttl", &self.cache.ttl)
+            .field("stats", &self.stats())
+            .finish()
+    }
+}
+
+impl CachedFoundry {
+    /// Cache up to `capacity` context selections from `inner` for `ttl` each
+    pub fn new(inner: Arc<dyn FoundryBackend>, capacity: usize, ttl: Duration) -> Self {
+        Self {
+            inner,
+            cache: TtlCache::new(capacity, ttl),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+    
+    /// Drop the cached selection for `context`; returns whether one was cached
+    pub fn invalidate(&self, context: &BMDSelectionContext) -> Result<bool> {
+        Ok(self.cache.remove(context_key(context)?))
+    }
+    
+    /// Drop every cached selection
+    pub fn clear(&self) {
+        self.cache.clear();
+    }
+    
+    /// Current counters
+    pub fn stats(&self) -> SelectionCacheStats {
+        SelectionCacheStats {
+            entries: self.cache.len(),
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+        }
+    }
+}
+
+#[async_trait]
+impl FoundryBackend for CachedFoundry {
+    async fn select_bmds_for_exploration(&self) -> Result<BMDSelection> {
+        self.inner.select_bmds_for_exploration().await
+    }
+    
+    async fn select_bmds_with_context(&self, context: BMDSelectionContext) -> Result<BMDSelection> {
+        let key = context_key(&context)?;
+        if let Some(selection) = self.cache.get(key) {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            return Ok(selection);
+        }
+        
+        self.misses.fetch_add(1, Ordering::Relaxed);
+        let selection = self.inner.select_bmds_with_context(context).await?;
+        self.cache.insert(key, selection.clone());
+        Ok(selection)
+    }
+    
+    fn capabilities(&self) -> FoundryCapabilities {
+        self.inner.capabilities()
+    }
+    
+    async fn health_check(&self) -> Result<()> {
+        self.inner.health_check().await
+    }
+}
+
+/// Stable key for the parts of a context that determine the selection
+pub(crate) fn context_key(context: &BMDSelectionContext) -> Result<u64> {
+    #[derive(Serialize)]
+    struct Key<'a, S, R, I> {
+        sender: &'a S,
+        recipient: &'a R,
+        intent: &'a I,
+        optimization_target: u64,
+    }
+    
+    let bytes = serde_json::to_vec(&Key {
+        sender: &context.sender_profile,
+        recipient: &context.recipient_profile,
+        intent: &context.communication_intent,
+        optimization_target: context.optimization_target.to_bits(),
+    })?;
+    
+    let mut hasher = DefaultHasher::new();
+    bytes.hash(&mut hasher);
+    Ok(hasher.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_entries_expire_after_ttl() {
+        let cache = TtlCache::new(4, Duration::from_millis(20));
+        cache.insert(1, "selection");
+        assert_eq!(cache.get(1), Some("selection"));
+        
+        std::thread::sleep(Duration::from_millis(30));
+        assert_eq!(cache.get(1), None);
+        assert_eq!(cache.len(), 0);
+    }
+    
+    #[test]
+    fn test_full_cache_evicts_oldest() {
+        let cache = TtlCache::new(2, Duration::from_secs(60));
+        cache.insert(1, 'a');
+        cache.insert(2, 'b');
+        cache.insert(3, 'c');
+        
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get(1), None);
+        assert_eq!(cache.get(3), Some('c'));
+        
+        assert!(cache.remove(2));
+        assert!(!cache.remove(2));
+    }
+}
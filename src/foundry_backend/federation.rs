@@ -0,0 +1,399 @@
+//! Load-balanced federation of several foundry backends
+
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
+use async_trait::async_trait;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::bmd::BMDSelection;
+use crate::foundry::BMDSelectionContext;
+use super::{FoundryBackend, FoundryCapabilities};
+
+/// How a [`FederatedFoundry`] picks the member that serves a selection
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BalancingPolicy {
+    /// Rotate through healthy members
+    #[default]
+    RoundRobin,
+    /// Prefer the healthy member with the fewest selections in flight
+    LeastLoaded,
+}
+
+impl FromStr for BalancingPolicy {
+    type Err = anyhow::Error;
+    
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_ascii_lowercase().replace('-', "_").as_str() {
+            "round_robin" => Ok(BalancingPolicy::RoundRobin),
+            "least_loaded" => Ok(BalancingPolicy::LeastLoaded),
+            other => Err(anyhow::anyhow!(
+                "unknown foundry balancing policy '{}', expected round_robin or least_loaded", other
+            )),
+        }
+    }
+}
+
+/// When a failing member is taken out of rotation and retried
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FailoverPolicy {
+    /// Consecutive failures that take a member out of rotation
+    pub failure_threshold: u32,
+    /// Time an unhealthy member sits out before it is tried again
+    pub retry_after: Duration,
+}
+
+impl Default for FailoverPolicy {
+    fn default() -> Self {
+        Self { failure_threshold: 3, retry_after: Duration::from_secs(10) }
+    }
+}
+
+/// Point-in-time view of one federation member
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FoundryMemberStats {
+    /// Backend name from its capabilities
+    pub name: String,
+    /// Whether the member is in rotation
+    pub healthy: bool,
+    /// Selections currently in flight
+    pub in_flight: usize,
+    /// Selections served successfully
+    pub selections: u64,
+    /// Failed selections and health checks
+    pub failures: u64,
+}
+
+#[derive(Debug, Default)]
+struct MemberHealth {
+    consecutive_failures: u32,
+    /// Set while out of rotation
+    unhealthy_until: Option<Instant>,
+}
+
+#[derive(Debug)]
+struct Member {
+    backend: Arc<dyn FoundryBackend>,
+    in_flight: AtomicUsize,
+    selections: AtomicU64,
+    failures: AtomicU64,
+    health: Mutex<MemberHealth>,
+}
+
+impl Member {
+    fn health(&self) -> MutexGuard<'_, MemberHealth> {
+        self.health.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+    
+    fn is_healthy(&self) -> bool {
+        !matches!(self.health().unhealthy_until, Some(until) if Instant::now() < until)
+    }
+    
+    fn record_success(&self) {
+        *self.health() = MemberHealth::default();
+    }
+    
+    fn record_failure(&self, policy: &FailoverPolicy) -> bool {
+        self.failures.fetch_add(1, Ordering::Relaxed);
+        let mut health = self.health();
+        health.consecutive_failures += 1;
+        let tripped = health.consecutive_failures >= policy.failure_threshold.max(1);
+        if tripped {
+            health.unhealthy_until = Some(Instant::now() + policy.retry_after);
+        }
+        tripped
+    }
+}
+
+/// Decrements a member's in-flight count when the selection finishes
+struct InFlight<'a>(&'a AtomicUsize);
+
+impl Drop for InFlight<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+/// Foundry backend aggregating BMDs from several Virtual BMD Foundries
+/// 
+/// Each selection goes to one member chosen by the [`BalancingPolicy`]; if it
+/// fails, the next candidate is tried, so a single unreachable foundry costs a
+/// retry rather than a failed request. Members that keep failing, either on
+/// selections or on [`FoundryBackend::health_check`], leave the rotation for
+/// [`FailoverPolicy::retry_after`]. When every member is out of rotation all of
+/// them are tried anyway.
+#[derive(Debug)]
+pub struct FederatedFoundry {
+    members: Vec<Member>,
+    policy: BalancingPolicy,
+    failover: FailoverPolicy,
+    next: AtomicUsize,
+}
+
+impl FederatedFoundry {
+    /// Federate `backends`, balancing selections with `policy`
+    pub fn new(backends: Vec<Arc<dyn FoundryBackend>>, policy: BalancingPolicy) -> Result<Self> {
+        if backends.is_empty() {
+            anyhow::bail!("a foundry federation needs at least one backend");
+        }
+        let members = backends.into_iter()
+            .map(|backend| Member {
+                backend,
+                in_flight: AtomicUsize::new(0),
+                selections: AtomicU64::new(0),
+                failures: AtomicU64::new(0),
+                health: Mutex::new(MemberHealth::default()),
+            })
+            .collect();
+        Ok(Self { members, policy, failover: FailoverPolicy::default(), next: AtomicUsize::new(0) })
+    }
+    
+    /// Use `failover` instead of the default failover policy
+    pub fn with_failover(mut self, failover: FailoverPolicy) -> Self {
+        self.failover = failover;
+        self
+    }
+    
+    /// Per-member counters, in the order the backends were given
+    pub fn member_stats(&self) -> Vec<FoundryMemberStats> {
+        self.members.iter()
+            .map(|member| FoundryMemberStats {
+                name: member.backend.capabilities().name,
+                healthy: member.is_healthy(),
+                in_flight: member.in_flight.load(Ordering::Relaxed),
+                selections: member.selections.load(Ordering::Relaxed),
+                failures: member.failures.load(Ordering::Relaxed),
+            })
+            .collect()
+    }
+    
+    /// Health-check every member concurrently, updating the rotation
+    /// 
+    /// Returns the number of healthy members.
+    pub async fn check_members(&self) -> usize {
+        let checks = self.members.iter().map(|member| async move {
+            match member.backend.health_check().await {
+                Ok(()) => {
+                    member.record_success();
+                    true
+                }
+                Err(e) => {
+                    self.fail(member, &e);
+                    false
+                }
+            }
+        });
+        futures::future::join_all(checks).await.into_iter().filter(|healthy| *healthy).count()
+    }
+    
+    /// Member indices in the order a selection should try them
+    fn candidates(&self) -> Vec<usize> {
+        let (mut healthy, unhealthy): (Vec<usize>, Vec<usize>) = (0..self.members.len())
+            .partition(|&index| self.members[index].is_healthy());
+        
+        match self.policy {
+            BalancingPolicy::RoundRobin => {
+                if !healthy.is_empty() {
+                    let start = self.next.fetch_add(1, Ordering::Relaxed) % healthy.len();
+                    healthy.rotate_left(start);
+                }
+            }
+            BalancingPolicy::LeastLoaded => {
+                healthy.sort_by_key(|&index| self.members[index].in_flight.load(Ordering::Relaxed));
+            }
+        }
+        
+        // Out-of-rotation members are a last resort, not skipped outright
+        if healthy.is_empty() {
+            unhealthy
+        } else {
+            healthy
+        }
+    }
+    
+    fn fail(&self, member: &Member, error: &anyhow::Error) {
+        if member.record_failure(&self.failover) {
+            warn!(
+                target: "orchestration",
+                foundry = %member.backend.capabilities().name,
+                error = %error,
+                retry_after = ?self.failover.retry_after,
+                "foundry taken out of federation rotation"
+            );
+        }
+    }
+    
+    /// Run `select` against members in candidate order until one succeeds
+    async fn select<'a, F, Fut>(&'a self, mut select: F) -> Result<BMDSelection>
+    where
+        F: FnMut(&'a Arc<dyn FoundryBackend>) -> Fut,
+        Fut: std::future::Future<Output = Result<BMDSelection>>,
+    {
+        let mut last_error = None;
+        for index in self.candidates() {
+            let member = &self.members[index];
+            member.in_flight.fetch_add(1, Ordering::Relaxed);
+            let _in_flight = InFlight(&member.in_flight);
+            
+            match select(&member.backend).await {
+                Ok(selection) => {
+                    member.selections.fetch_add(1, Ordering::Relaxed);
+                    member.record_success();
+                    return Ok(selection);
+                }
+                Err(e) => {
+                    self.fail(member, &e);
+                    last_error = Some(e);
+                }
+            }
+        }
+        
+        let error = last_error.unwrap_or_else(|| anyhow::anyhow!("no foundry available"));
+        Err(error.context(format!("all {} federated foundries failed", self.members.len())))
+    }
+}
+
+#[async_trait]
+impl FoundryBackend for FederatedFoundry {
+    async fn select_bmds_for_exploration(&self) -> Result<BMDSelection> {
+        self.select(|backend| backend.select_bmds_for_exploration()).await
+    }
+    
+    async fn select_bmds_with_context(&self, context: BMDSelectionContext) -> Result<BMDSelection> {
+        self.select(|backend| backend.select_bmds_with_context(context.clone())).await
+    }
+    
+    fn capabilities(&self) -> FoundryCapabilities {
+        let members: Vec<FoundryCapabilities> = self.members.iter()
+            .map(|member| member.backend.capabilities())
+            .collect();
+        let names: Vec<&str> = members.iter().map(|caps| caps.name.as_str()).collect();
+        FoundryCapabilities {
+            name: format!("federation[{}]", names.join(",")),
+            max_selection_rate: members.iter()
+                .fold(0u64, |total, caps| total.saturating_add(caps.max_selection_rate)),
+            // Any member may serve a context selection, so all of them must honour it
+            supports_context_selection: members.iter().all(|caps| caps.supports_context_selection),
+            remote: members.iter().any(|caps| caps.remote),
+        }
+    }
+    
+    async fn health_check(&self) -> Result<()> {
+        match self.check_members().await {
+            0 => Err(anyhow::anyhow!(
+                "none of {} federated foundries is healthy", self.members.len()
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicBool;
+    
+    /// Backend that never serves selections; health follows a switch
+    #[derive(Debug)]
+    struct StubFoundry {
+        name: &'static str,
+        reachable: AtomicBool,
+    }
+    
+    impl StubFoundry {
+        fn new(name: &'static str) -> Arc<Self> {
+            Arc::new(Self { name, reachable: AtomicBool::new(true) })
+        }
+    }
+    
+    #[async_trait]
+    impl FoundryBackend for StubFoundry {
+        async fn select_bmds_for_exploration(&self) -> Result<BMDSelection> {
+            Err(anyhow::anyhow!("{} has no BMDs", self.name))
+        }
+        
+        async fn select_bmds_with_context(
+            &self,
+            _context: BMDSelectionContext,
+        ) -> Result<BMDSelection> {
+            Err(anyhow::anyhow!("{} has no BMDs", self.name))
+        }
+        
+        fn capabilities(&self) -> FoundryCapabilities {
+            FoundryCapabilities {
+                name: self.name.to_string(),
+                max_selection_rate: 10,
+                supports_context_selection: true,
+                remote: true,
+            }
+        }
+        
+        async fn health_check(&self) -> Result<()> {
+            if self.reachable.load(Ordering::Relaxed) {
+                Ok(())
+            } else {
+                Err(anyhow::anyhow!("{} unreachable", self.name))
+            }
+        }
+    }
+    
+    fn federation(backends: &[Arc<StubFoundry>], policy: BalancingPolicy) -> FederatedFoundry {
+        let backends = backends.iter()
+            .map(|backend| Arc::clone(backend) as Arc<dyn FoundryBackend>)
+            .collect();
+        FederatedFoundry::new(backends, policy).unwrap().with_failover(FailoverPolicy {
+            failure_threshold: 1,
+            retry_after: Duration::from_secs(60),
+        })
+    }
+    
+    #[test]
+    fn test_round_robin_rotates_members() {
+        let members = [StubFoundry::new("a"), StubFoundry::new("b")];
+        let foundry = federation(&members, BalancingPolicy::RoundRobin);
+        
+        assert_eq!(foundry.candidates(), vec![0, 1]);
+        assert_eq!(foundry.candidates(), vec![1, 0]);
+        assert_eq!(foundry.candidates(), vec![0, 1]);
+    }
+    
+    #[test]
+    fn test_least_loaded_prefers_idle_member() {
+        let members = [StubFoundry::new("a"), StubFoundry::new("b")];
+        let foundry = federation(&members, BalancingPolicy::LeastLoaded);
+        foundry.members[0].in_flight.store(3, Ordering::Relaxed);
+        
+        assert_eq!(foundry.candidates(), vec![1, 0]);
+    }
+    
+    #[tokio::test]
+    async fn test_unhealthy_member_leaves_rotation() {
+        let (a, b) = (StubFoundry::new("a"), StubFoundry::new("b"));
+        let foundry = federation(&[Arc::clone(&a), Arc::clone(&b)], BalancingPolicy::RoundRobin);
+        
+        a.reachable.store(false, Ordering::Relaxed);
+        assert_eq!(foundry.check_members().await, 1);
+        assert!(foundry.health_check().await.is_ok());
+        assert_eq!(foundry.candidates(), vec![1]);
+        
+        b.reachable.store(false, Ordering::Relaxed);
+        assert!(foundry.health_check().await.is_err());
+        // With nobody in rotation, every member is still tried
+        assert_eq!(foundry.candidates(), vec![0, 1]);
+    }
+    
+    #[tokio::test]
+    async fn test_selection_fails_over_to_every_member() {
+        let members = [StubFoundry::new("a"), StubFoundry::new("b")];
+        let foundry = federation(&members, BalancingPolicy::RoundRobin);
+        
+        let error = foundry.select_bmds_for_exploration().await.unwrap_err();
+        assert!(error.to_string().contains("all 2 federated foundries failed"), "{}", error);
+        let stats = foundry.member_stats();
+        assert_eq!(stats.iter().map(|member| member.failures).collect::<Vec<_>>(), vec![1, 1]);
+    }
+}
@@ -0,0 +1,126 @@
+//! # Foundry Backends
+//! 
+//! [`FoundryBackend`] abstracts the source of BMD selections so that Hugure can be
+//! driven by the bundled Virtual BMD Foundry interface, a local simulator, or a
+//! remote networked foundry. The foundry client actor only ever talks to a backend
+//! through this trait; pass a custom one to [`crate::HugureSystem::with_foundry`].
+//! 
+//! [`CachedFoundry`] wraps any backend to serve repeated communication contexts
+//! from memory instead of a foundry round trip. [`FederatedFoundry`] spreads
+//! selections over several foundries with load balancing and failover.
+//! 
+//! Backends are held as `Arc<dyn FoundryBackend>` rather than as a type parameter
+//! of the foundry interface: the cache, federation, and replay wrappers nest
+//! arbitrarily, federation members differ in type, and the backend is chosen from
+//! configuration at startup, none of which a single generic parameter can express.
+//! The bundled [`FoundryInterface`] is just another implementation.
+
+use std::fmt;
+use async_trait::async_trait;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::bmd::BMDSelection;
+use crate::foundry::{BMDSelectionContext, FoundryInterface};
+
+mod cache;
+mod federation;
+
+pub use cache::{CachedFoundry, SelectionCacheStats};
+pub(crate) use cache::context_key;
+pub use federation::{BalancingPolicy, FailoverPolicy, FederatedFoundry, FoundryMemberStats};
+
+/// What a foundry backend can provide
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FoundryCapabilities {
+    /// Human-readable backend name, used in logs
+    pub name: String,
+    
+    /// Sustained BMD selections per second the backend can serve
+    pub max_selection_rate: u64,
+    
+    /// Whether selections honour sender/recipient profiles in the context
+    pub supports_context_selection: bool,
+    
+    /// Whether selections cross a network boundary
+    pub remote: bool,
+}
+
+/// Source of BMD selections for exploration and communication
+#[async_trait]
+pub trait FoundryBackend: Send + Sync + fmt::Debug {
+    /// Select BMDs for background exploration
+    async fn select_bmds_for_exploration(&self) -> Result<BMDSelection>;
+    
+    /// Select BMDs for a specific communication scenario
+    async fn select_bmds_with_context(&self, context: BMDSelectionContext) -> Result<BMDSelection>;
+    
+    /// Capabilities advertised by this backend
+    fn capabilities(&self) -> FoundryCapabilities;
+    
+    /// Check that the backend can serve selections; used by health reports
+    /// 
+    /// Remote backends should probe their endpoint cheaply here.
+    async fn health_check(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl FoundryBackend for FoundryInterface {
+    async fn select_bmds_for_exploration(&self) -> Result<BMDSelection> {
+        FoundryInterface::select_bmds_for_exploration(self).await
+    }
+    
+    async fn select_bmds_with_context(&self, context: BMDSelectionContext) -> Result<BMDSelection> {
+        FoundryInterface::select_bmds_with_context(self, context).await
+    }
+    
+    fn capabilities(&self) -> FoundryCapabilities {
+        FoundryCapabilities {
+            name: "virtual-bmd-foundry".to_string(),
+            max_selection_rate: 1_000_000_000_000_000,
+            supports_context_selection: true,
+            remote: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    
+    /// Backend that refuses every selection, standing in for an unreachable remote foundry
+    #[derive(Debug)]
+    struct OfflineFoundry;
+    
+    #[async_trait]
+    impl FoundryBackend for OfflineFoundry {
+        async fn select_bmds_for_exploration(&self) -> Result<BMDSelection> {
+            Err(anyhow::anyhow!("foundry offline"))
+        }
+        
+        async fn select_bmds_with_context(&self, _context: BMDSelectionContext) -> Result<BMDSelection> {
+            Err(anyhow::anyhow!("foundry offline"))
+        }
+        
+        fn capabilities(&self) -> FoundryCapabilities {
+            FoundryCapabilities {
+                name: "offline".to_string(),
+                max_selection_rate: 0,
+                supports_context_selection: false,
+                remote: true,
+            }
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_custom_backend_as_trait_object() {
+        let backend: Arc<dyn FoundryBackend> = Arc::new(OfflineFoundry);
+        
+        assert_eq!(backend.capabilities().name, "offline");
+        assert!(backend.capabilities().remote);
+        assert!(backend.select_bmds_for_exploration().await.is_err());
+    }
+}
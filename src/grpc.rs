@@ -0,0 +1,294 @@
+//! # gRPC Service
+//! 
+//! Serves [`HugureSystem::handle_communication_request`] over tonic so that
+//! non-Rust systems can request BMD optimization from a running Hugure node. The
+//! wire format lives in `proto/hugure/v1/communication.proto`; this module converts
+//! between those messages and the communication types.
+//! 
+//! Enabled with the `grpc` feature.
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tokio_util::sync::CancellationToken;
+use tonic::{Request, Response, Status};
+use tracing::{info, warn};
+use anyhow::Result;
+
+use crate::bmd::{
+    BMD, CognitiveFramework, CommunicationGoal, CommunicationIntent, DecisionTimingProfile,
+    EmotionalTarget, IndividualModel, ReceptionHistory, TemporalPreferences,
+};
+use crate::communication::{CommunicationRequest, CommunicationResponse};
+use crate::correlation::{self, CorrelationId, CORRELATION_ID_HEADER};
+use crate::depth_breaker::ExplorationCancelled;
+use crate::util::flatten::flatten_numeric;
+use crate::util::ratelimit::RateLimited;
+use crate::HugureSystem;
+
+/// Generated protobuf messages and service stubs
+pub mod pb {
+    tonic::include_proto!("hugure.v1");
+}
+
+use pb::communication_optimizer_server::{CommunicationOptimizer, CommunicationOptimizerServer};
+
+/// gRPC front end for a [`HugureSystem`]
+#[derive(Debug, Clone)]
+pub struct HugureGrpcService {
+    system: Arc<HugureSystem>,
+}
+
+impl HugureGrpcService {
+    /// Wrap a running system
+    pub fn new(system: Arc<HugureSystem>) -> Self {
+        Self { system }
+    }
+    
+    /// Tonic server for this service, for composing with other services
+    pub fn into_server(self) -> CommunicationOptimizerServer<Self> {
+        CommunicationOptimizerServer::new(self)
+    }
+}
+
+#[tonic::async_trait]
+impl CommunicationOptimizer for HugureGrpcService {
+    async fn optimize_communication(
+        &self,
+        request: Request<pb::CommunicationRequest>,
+    ) -> std::result::Result<Response<pb::CommunicationResponse>, Status> {
+        if self.system.shutdown_handle().is_shutdown() {
+            return Err(Status::unavailable("Hugure is shutting down"));
+        }
+        
+        // Reuse the caller's correlation id so its logs and ours line up
+        let correlation_id = request.metadata()
+            .get(CORRELATION_ID_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse().ok())
+            .unwrap_or_else(CorrelationId::new);
+        
+        let request = request.into_inner();
+        let strategy = match request.optimization_strategy.as_str() {
+            "" => self.system.config().optimization_strategy,
+            name => name.parse()
+                .map_err(|e: anyhow::Error| Status::invalid_argument(e.to_string()))?,
+        };
+        
+        let request = CommunicationRequest::try_from(request)?;
+        
+        // Tonic drops this future when the client disconnects; stop exploring then
+        let cancel = CancellationToken::new();
+        let _cancel_on_disconnect = cancel.clone().drop_guard();
+        
+        let handled = self.system
+            .handle_communication_request_cancellable(request, strategy, cancel);
+        let response = correlation::scope(correlation_id, handled)
+            .await
+            .map_err(|e| {
+                if let Some(limited) = e.downcast_ref::<RateLimited>() {
+                    Status::resource_exhausted(limited.to_string())
+                } else if e.is::<ExplorationCancelled>() {
+                    Status::cancelled(e.to_string())
+                } else {
+                    warn!(
+                        target: "orchestration",
+                        error = %e,
+                        %correlation_id,
+                        "gRPC communication request failed"
+                    );
+                    Status::internal(e.to_string())
+                }
+            })?;
+        
+        let mut response = Response::new(pb::CommunicationResponse::from(response));
+        if let Ok(value) = correlation_id.to_string().parse() {
+            response.metadata_mut().insert(CORRELATION_ID_HEADER, value);
+        }
+        Ok(response)
+    }
+}
+
+/// Serve the communication optimizer on `addr` until the system shuts down
+pub async fn serve(system: Arc<HugureSystem>, addr: SocketAddr) -> Result<()> {
+    let shutdown = system.shutdown_handle();
+    info!(target: "orchestration", %addr, "serving communication optimizer over gRPC");
+    
+    tonic::transport::Server::builder()
+        .add_service(HugureGrpcService::new(system).into_server())
+        .serve_with_shutdown(addr, async move { shutdown.cancelled().await })
+        .await?;
+    
+    Ok(())
+}
+
+impl TryFrom<pb::CommunicationRequest> for CommunicationRequest {
+    type Error = Status;
+    
+    fn try_from(request: pb::CommunicationRequest) -> std::result::Result<Self, Status> {
+        let sender = request.sender_profile
+            .ok_or_else(|| Status::invalid_argument("sender_profile is required"))?;
+        let recipient = request.recipient_profile
+            .ok_or_else(|| Status::invalid_argument("recipient_profile is required"))?;
+        let intent = request.intent
+            .ok_or_else(|| Status::invalid_argument("intent is required"))?;
+        
+        let request_type = request.request_type.parse().map_err(|_| {
+            Status::invalid_argument(format!("unknown request_type '{}'", request.request_type))
+        })?;
+        
+        Ok(CommunicationRequest {
+            request_type,
+            sender_profile: sender.into(),
+            recipient_profile: recipient.into(),
+            intent: intent.try_into()?,
+        })
+    }
+}
+
+impl From<pb::IndividualProfile> for IndividualModel {
+    fn from(profile: pb::IndividualProfile) -> Self {
+        let timing = profile.decision_timing.unwrap_or_default();
+        
+        IndividualModel {
+            individual_id: profile.individual_id,
+            cognitive_frameworks: profile.cognitive_frameworks.into_iter()
+                .map(|framework| CognitiveFramework {
+                    category: framework.category.into(),
+                    strength: framework.strength,
+                    usage_frequency: framework.usage_frequency,
+                    emotional_valence: framework.emotional_valence,
+                })
+                .collect(),
+            emotional_patterns: Vec::new(),
+            temporal_preferences: TemporalPreferences {
+                preferred_rhythms: profile.preferred_rhythms,
+                attention_patterns: Vec::new(),
+                decision_timing: DecisionTimingProfile {
+                    deliberation_time: timing.deliberation_time,
+                    choice_expansion_preference: timing.choice_expansion_preference,
+                    temporal_binding_strength: timing.temporal_binding_strength,
+                    agency_attribution_timing: timing.agency_attribution_timing,
+                },
+            },
+            // Reception history is accumulated by Hugure, never supplied by callers
+            reception_history: ReceptionHistory::default(),
+        }
+    }
+}
+
+impl TryFrom<pb::CommunicationIntent> for CommunicationIntent {
+    type Error = Status;
+    
+    fn try_from(intent: pb::CommunicationIntent) -> std::result::Result<Self, Status> {
+        let primary_goal = intent.primary_goal
+            .ok_or_else(|| Status::invalid_argument("intent.primary_goal is required"))?;
+        let target = intent.emotional_target.unwrap_or_default();
+        
+        Ok(CommunicationIntent {
+            primary_goal: primary_goal.try_into()?,
+            secondary_objectives: intent.secondary_objectives.into_iter()
+                .map(CommunicationGoal::try_from)
+                .collect::<std::result::Result<_, _>>()?,
+            urgency: intent.urgency,
+            precision_requirement: intent.precision_requirement,
+            emotional_target: EmotionalTarget {
+                target_arousal: target.target_arousal,
+                target_valence: target.target_valence,
+                target_attention: target.target_attention,
+                target_memory_encoding: target.target_memory_encoding,
+                duration: target.duration,
+            },
+        })
+    }
+}
+
+impl TryFrom<pb::CommunicationGoal> for CommunicationGoal {
+    type Error = Status;
+    
+    fn try_from(goal: pb::CommunicationGoal) -> std::result::Result<Self, Status> {
+        use pb::communication_goal::Kind;
+        
+        let variant: fn(String) -> CommunicationGoal = match Kind::try_from(goal.kind) {
+            Ok(Kind::PatternTransmission) => CommunicationGoal::PatternTransmission,
+            Ok(Kind::EmotionalStateChange) => CommunicationGoal::EmotionalStateChange,
+            Ok(Kind::CognitiveFrameworkShift) => CommunicationGoal::CognitiveFrameworkShift,
+            Ok(Kind::MemoryInstallation) => CommunicationGoal::MemoryInstallation,
+            Ok(Kind::BehavioralInfluence) => CommunicationGoal::BehavioralInfluence,
+            Ok(Kind::ConsciousnessExpansion) => CommunicationGoal::ConsciousnessExpansion,
+            Ok(Kind::Unspecified) | Err(_) => {
+                return Err(Status::invalid_argument("communication goal kind is required"));
+            }
+        };
+        
+        Ok(variant(goal.description))
+    }
+}
+
+impl From<CommunicationResponse> for pb::CommunicationResponse {
+    fn from(response: CommunicationResponse) -> Self {
+        pb::CommunicationResponse {
+            optimized_bmds: response.optimized_bmds.iter().map(pb::OptimizedBmd::from).collect(),
+            injection_parameters: Some(pb::InjectionParameters {
+                parameters: flatten_numeric(&response.injection_parameters),
+            }),
+            fidelity_prediction: response.fidelity_prediction,
+            temporal_coordinates: flatten_numeric(&response.temporal_coordinates),
+            degradation: response.degradation.map(|degradation| pb::ExplorationDegradation {
+                requested_depth: degradation.requested_depth,
+                effective_depth: degradation.effective_depth,
+                reason: degradation.reason.as_str().to_string(),
+            }),
+        }
+    }
+}
+
+impl From<&BMD> for pb::OptimizedBmd {
+    fn from(bmd: &BMD) -> Self {
+        pb::OptimizedBmd {
+            id: bmd.id.to_string(),
+            core_vectors: bmd.pattern.core_vectors.clone(),
+            semantic_opacity: bmd.pattern.semantic_opacity,
+            arousal_level: bmd.emotional_substrate.arousal_level,
+            attention_intensity: bmd.emotional_substrate.attention_intensity,
+            memory_encoding: bmd.emotional_substrate.memory_encoding,
+            foundry_id: bmd.foundry_source.foundry_id.clone(),
+            transmission_fidelity: bmd.foundry_source.quality_metrics.transmission_fidelity,
+            selection_probability: bmd.frame_weights.selection_probability,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    
+    #[test]
+    fn test_flatten_numeric_paths() {
+        let flattened = flatten_numeric(&json!({
+            "timing": { "offset_fs": 10, "phases": [0.5, 0.25] },
+            "label": "ignored",
+            "synchronized": true,
+        }));
+        
+        assert_eq!(flattened["timing.offset_fs"], 10.0);
+        assert_eq!(flattened["timing.phases.1"], 0.25);
+        assert_eq!(flattened["synchronized"], 1.0);
+        assert!(!flattened.contains_key("label"));
+    }
+    
+    #[test]
+    fn test_goal_kind_required() {
+        let goal = pb::CommunicationGoal { kind: 0, description: "x".to_string() };
+        assert!(CommunicationGoal::try_from(goal).is_err());
+        
+        let goal = pb::CommunicationGoal {
+            kind: pb::communication_goal::Kind::PatternTransmission as i32,
+            description: "pattern".to_string(),
+        };
+        assert!(matches!(
+            CommunicationGoal::try_from(goal),
+            Ok(CommunicationGoal::PatternTransmission(d)) if d == "pattern"
+        ));
+    }
+}
@@ -0,0 +1,176 @@
+//! # Health Reporting
+//! 
+//! [`crate::HugureSystem::health`] gathers a [`HealthReport`] covering foundry
+//! connectivity, Kambuzuma transport liveness, the orchestration loop heartbeat,
+//! and optimizer backlog. The report serializes to JSON and [`HealthReport::is_ready`]
+//! gives the verdict for a readiness probe.
+
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+use serde::Serialize;
+
+/// Beat interval of a heartbeat created with [`Heartbeat::new`]
+const DEFAULT_BEAT_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Liveness marker updated by the orchestration loop on every tick
+/// 
+/// The loop beats once per iteration and, through [`Heartbeat::beat_while`], on
+/// every interval it spends waiting, so an idle loop stays as fresh as a busy one.
+#[derive(Debug)]
+pub struct Heartbeat {
+    origin: Instant,
+    /// Milliseconds since `origin` of the last beat, plus one; 0 before the first beat
+    last_beat_ms: AtomicU64,
+    interval: Duration,
+}
+
+impl Default for Heartbeat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Heartbeat {
+    /// Create a heartbeat that has not beaten yet
+    pub fn new() -> Self {
+        Self::with_interval(DEFAULT_BEAT_INTERVAL)
+    }
+    
+    /// Create a heartbeat that beats every `interval` while the loop waits
+    pub fn with_interval(interval: Duration) -> Self {
+        Self {
+            origin: Instant::now(),
+            last_beat_ms: AtomicU64::new(0),
+            interval: interval.max(Duration::from_millis(1)),
+        }
+    }
+    
+    /// Record that the loop is alive
+    pub fn beat(&self) {
+        let now = self.origin.elapsed().as_millis() as u64 + 1;
+        self.last_beat_ms.store(now, Ordering::Relaxed);
+    }
+    
+    /// Await `future`, beating now and on every interval until it completes
+    pub async fn beat_while<F: Future>(&self, future: F) -> F::Output {
+        tokio::pin!(future);
+        let mut ticks = tokio::time::interval(self.interval);
+        ticks.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            tokio::select! {
+                output = &mut future => return output,
+                _ = ticks.tick() => self.beat(),
+            }
+        }
+    }
+    
+    /// Time since the last beat, or `None` before the first
+    pub fn age(&self) -> Option<Duration> {
+        match self.last_beat_ms.load(Ordering::Relaxed) {
+            0 => None,
+            beat => Some(self.origin.elapsed().saturating_sub(Duration::from_millis(beat - 1))),
+        }
+    }
+}
+
+/// Health of one component or of the whole system
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HealthStatus {
+    /// Working normally
+    Healthy,
+    /// Serving, but with reduced capacity
+    Degraded,
+    /// Not able to serve
+    Unhealthy,
+}
+
+/// Health of a single dependency
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ComponentHealth {
+    /// Verdict for this component
+    pub status: HealthStatus,
+    /// Why the component is not healthy
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+}
+
+impl ComponentHealth {
+    /// Healthy component
+    pub fn healthy() -> Self {
+        Self { status: HealthStatus::Healthy, detail: None }
+    }
+    
+    /// Component in `status` for the given reason
+    pub fn with_status(status: HealthStatus, detail: impl Into<String>) -> Self {
+        Self { status, detail: Some(detail.into()) }
+    }
+}
+
+/// Health of the background orchestration loop
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LoopHealth {
+    /// Verdict for the loop
+    pub status: HealthStatus,
+    /// Whether the loop has been started and is still running
+    pub running: bool,
+    /// Milliseconds since the loop's last heartbeat
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub heartbeat_age_ms: Option<u64>,
+}
+
+/// Point-in-time health of a Hugure system
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct HealthReport {
+    /// Worst status of any component
+    pub status: HealthStatus,
+    /// Foundry backend connectivity
+    pub foundry: ComponentHealth,
+    /// Kambuzuma transport liveness
+    pub kambuzuma: ComponentHealth,
+    /// Orchestration loop heartbeat
+    pub orchestration_loop: LoopHealth,
+    /// Requests waiting in the optimizer mailbox
+    pub optimizer_backlog: usize,
+    /// Foundry selections waiting for exploration
+    pub exploration_queue_depth: usize,
+    /// Whether shutdown has begun
+    pub shutting_down: bool,
+}
+
+impl HealthReport {
+    /// Whether the system can accept communication requests
+    pub fn is_ready(&self) -> bool {
+        !self.shutting_down && self.status != HealthStatus::Unhealthy
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_heartbeat_age() {
+        let heartbeat = Heartbeat::new();
+        assert_eq!(heartbeat.age(), None);
+        
+        heartbeat.beat();
+        std::thread::sleep(Duration::from_millis(20));
+        let age = heartbeat.age().unwrap();
+        assert!(age >= Duration::from_millis(19) && age < Duration::from_secs(1), "{:?}", age);
+    }
+    
+    #[tokio::test]
+    async fn test_heartbeat_beats_while_waiting() {
+        let heartbeat = Heartbeat::with_interval(Duration::from_millis(10));
+        
+        let value = heartbeat.beat_while(async {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            heartbeat.age()
+        }).await;
+        
+        let age = value.unwrap();
+        assert!(age < Duration::from_millis(50), "{:?}", age);
+    }
+}
@@ -5,42 +5,155 @@
 //! Virtual BMD Foundries to select and optimize exotic BMD configurations for enhanced
 //! information transfer fidelity between conscious entities.
 
-use std::sync::Arc;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
 use tokio::sync::{RwLock, mpsc};
-use tracing::{info, debug, warn};
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+use tokio_util::task::TaskTracker;
+use tracing::{info, debug, warn, Instrument};
 use anyhow::Result;
+use hugure_core::units::Femtoseconds;
 
+pub mod actor;
 pub mod bmd;
 pub mod foundry;
+pub mod foundry_backend;
+#[cfg(feature = "grpc")]
+pub mod grpc;
 pub mod orchestration;
 pub mod optimization;
 pub mod kambuzuma;
 pub mod communication;
+pub mod correlation;
 pub mod temporal;
 pub mod emergence;
+pub mod events;
+pub mod detection;
+pub mod depth_breaker;
+pub mod exploration_budget;
+pub mod exploration_concurrency;
+pub mod exploration_queue;
+pub mod health;
+pub mod metrics;
+#[cfg(feature = "persistence")]
+pub mod persistence;
+pub mod plugins;
+pub mod replay;
+pub mod shutdown;
+pub mod simulation;
+pub mod snapshot;
+pub mod strategy;
+pub mod supervisor;
+pub mod transport;
+pub mod util;
 
 use bmd::{BMD, BMDConfiguration, BMDSelection};
 use foundry::{VirtualBMDFoundry, FoundryInterface};
+use health::{ComponentHealth, HealthReport, HealthStatus, Heartbeat, LoopHealth};
+use foundry_backend::{CachedFoundry, FoundryBackend, FoundryCapabilities, SelectionCacheStats};
 use orchestration::{OrchestrationEngine, ExplorationTask};
 use optimization::{OptimizationCoordinator, BiDirectionalOptimizer};
+use actor::HugureActors;
+use actor::priority::TaskPriority;
+use exploration_budget::{ExplorationBudget, ExplorationRateStats};
+use exploration_concurrency::{AutoTune, ConcurrencyStats, ExplorationConcurrency};
+use exploration_queue::{ExplorationQueue, QueueStats};
+use crate::metrics::{NoopMetrics, OrchestrationMetrics};
+use plugins::{PluginRegistry, PluginSelection, SelectedPlugins};
+use shutdown::{ShutdownHandle, ShutdownReport};
+use snapshot::{PatternRegistry, SystemSnapshot};
+use strategy::StrategyKind;
+use supervisor::LoopRestartPolicy;
+use correlation::CorrelationId;
+use events::{EventBus, LifecycleEvent};
+use transport::{InProcessTransport, KambuzumaTransport};
+use util::ratelimit::{RateLimiter, SlidingWindow, TokenBucket};
+
+/// Pause before retrying a failed foundry selection
+const SELECTION_RETRY_DELAY: Duration = Duration::from_millis(10);
+
+/// Time a health report waits for the foundry before marking it unhealthy
+const FOUNDRY_HEALTH_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Sinks the exploration loop reports each cycle to
+#[derive(Clone)]
+struct CycleObserver {
+    metrics: Arc<dyn OrchestrationMetrics>,
+    concurrency: Arc<ExplorationConcurrency>,
+    kambuzuma: Arc<dyn KambuzumaTransport>,
+    patterns: Arc<PatternRegistry>,
+    events: EventBus,
+    #[cfg(feature = "persistence")]
+    result_store: Option<Arc<persistence::ResultStore>>,
+}
 
 /// Core Hugure orchestration system for Virtual BMD communication optimization
 #[derive(Debug)]
 pub struct HugureSystem {
-    /// Foundry interface for BMD selection
-    foundry_interface: Arc<FoundryInterface>,
+    /// Supervised foundry client, orchestrator, optimizer, and emergence detector
+    actors: HugureActors,
+    
+    /// Transport to the Kambuzuma neural orchestrator
+    kambuzuma: Arc<dyn KambuzumaTransport>,
+    
+    /// Throttle for inbound communication requests
+    request_limiter: Arc<SlidingWindow>,
+    
+    /// Plugins selected for each extension point
+    plugins: SelectedPlugins,
+    
+    /// Source of BMD selections
+    foundry: Arc<dyn FoundryBackend>,
+    
+    /// Cache in front of the foundry for context selections, if enabled
+    selection_cache: Option<Arc<CachedFoundry>>,
+    
+    /// Foundry selections awaiting exploration
+    exploration_queue: Arc<ExplorationQueue<BMDSelection>>,
     
-    /// Orchestration engine for BMD exploration
-    orchestration_engine: Arc<OrchestrationEngine>,
+    /// Budget enforcing the exploration rate target
+    exploration_budget: Arc<ExplorationBudget>,
     
-    /// Bidirectional optimization coordinator
-    optimization_coordinator: Arc<OptimizationCoordinator>,
+    /// Limit on concurrently running orchestration cycles
+    exploration_concurrency: Arc<ExplorationConcurrency>,
     
-    /// Communication channel with Kambuzuma neural orchestrator
-    kambuzuma_channel: mpsc::Sender<communication::KambuzumaMessage>,
+    /// Hook receiving orchestration metrics
+    metrics: Arc<dyn OrchestrationMetrics>,
+    
+    /// Recently emerged patterns, carried across restarts by snapshots
+    patterns: Arc<PatternRegistry>,
+    
+    /// Broadcast of lifecycle events to subscribers
+    events: EventBus,
+    
+    /// Log of served communication requests, if recording
+    recorder: Option<Arc<replay::SessionRecorder>>,
+    
+    /// Journal of cycle results, if a store path is configured
+    #[cfg(feature = "persistence")]
+    result_store: Option<Arc<persistence::ResultStore>>,
+    
+    /// Shutdown signal shared with the orchestration loop and embedders
+    shutdown: ShutdownHandle,
+    
+    /// In-flight communication requests, drained on shutdown
+    in_flight: TaskTracker,
+    
+    /// Running orchestration loop, if started
+    orchestration_loop: Mutex<Option<JoinHandle<()>>>,
+    
+    /// Beaten by the orchestration loop as it makes progress
+    heartbeat: Arc<Heartbeat>,
     
     /// System configuration
     config: HugureConfig,
+    
+    /// Registry the plugins were selected from, owning any plugin libraries
+    /// 
+    /// Declared last so the libraries are unloaded only after the selected plugins and
+    /// the actors running them have been dropped.
+    _plugin_registry: PluginRegistry,
 }
 
 /// Hugure system configuration
@@ -49,6 +162,9 @@ pub struct HugureConfig {
     /// BMD exploration rate target (per second)
     pub exploration_rate_target: u64,
     
+    /// Exploration cycles admitted at once before the rate target applies
+    pub exploration_burst: u32,
+    
     /// Recursive amplification depth limit
     pub max_recursion_depth: u32,
     
@@ -58,22 +174,261 @@ pub struct HugureConfig {
     /// Optimization accuracy target
     pub optimization_accuracy_target: f64,
     
-    /// Temporal precision (femtoseconds)
-    pub temporal_precision_fs: u64,
+    /// Temporal precision
+    pub temporal_precision: Femtoseconds,
     
     /// Maximum concurrent BMD explorations
     pub max_concurrent_explorations: usize,
+    
+    /// Whether concurrency is tuned below `max_concurrent_explorations` at run time
+    pub auto_tune: AutoTune,
+    
+    /// Foundry selections buffered ahead of exploration before selection pauses
+    pub exploration_queue_capacity: usize,
+    
+    /// Foundry BMD selection requests allowed per second
+    pub foundry_requests_per_second: u32,
+    
+    /// Context BMD selections cached in front of the foundry (0 disables the cache)
+    pub bmd_selection_cache_capacity: usize,
+    
+    /// Time-to-live of cached context BMD selections (seconds)
+    pub bmd_selection_cache_ttl_secs: u64,
+    
+    /// Inbound communication requests accepted per second
+    pub communication_requests_per_second: u32,
+    
+    /// Mailbox capacity of each system actor
+    pub actor_mailbox_capacity: usize,
+    
+    /// Strategy weighting optimized BMDs, unless a request picks its own
+    pub optimization_strategy: StrategyKind,
+    
+    /// Intent urgency at or above which requests overtake other queued requests
+    pub urgent_request_threshold: f64,
+    
+    /// Consecutive recursion-depth exhaustions before explorations run shallower
+    pub depth_breaker_threshold: u32,
+    
+    /// Time explorations stay shallow after the depth breaker trips (milliseconds)
+    pub depth_breaker_cooldown_ms: u64,
+    
+    /// Orchestration loop heartbeat age at which health reports mark it unhealthy (milliseconds)
+    pub heartbeat_timeout_ms: u64,
+    
+    /// Recently emerged patterns kept for snapshots (0 keeps none)
+    pub emerged_pattern_registry_capacity: usize,
+    
+    /// Lifecycle events retained for slow subscribers
+    pub event_bus_capacity: usize,
+    
+    /// Registered plugin names to use for each extension point
+    pub plugins: PluginSelection,
+    
+    /// Time allowed for in-flight work to drain on shutdown (milliseconds)
+    pub shutdown_grace_period_ms: u64,
+    
+    /// Listen address of the communication gRPC service (`grpc` feature)
+    pub grpc_listen_addr: String,
+    
+    /// Listen address of the Prometheus `/metrics` endpoint (`prometheus` feature)
+    pub metrics_listen_addr: String,
+    
+    /// Directory of the cycle result journal (`persistence` feature); `None` disables it
+    pub result_store_path: Option<std::path::PathBuf>,
+    
+    /// Maximum age of journaled results in hours (0 keeps them forever)
+    pub max_record_age_hours: u64,
 }
 
 impl Default for HugureConfig {
     fn default() -> Self {
         Self {
             exploration_rate_target: 1_000_000_000_000_000, // 10^15 explorations/second
+            exploration_burst: 1024,
             max_recursion_depth: 1000,
             emergence_threshold: 0.9997, // 99.97% accuracy target
             optimization_accuracy_target: 0.9997,
-            temporal_precision_fs: 10, // 10 femtosecond precision
+            temporal_precision: Femtoseconds::new(10), // 10 femtosecond precision
             max_concurrent_explorations: 10_000,
+            auto_tune: AutoTune::default(),
+            exploration_queue_capacity: 256,
+            foundry_requests_per_second: 10_000,
+            bmd_selection_cache_capacity: 1024,
+            bmd_selection_cache_ttl_secs: 300,
+            communication_requests_per_second: 1_000,
+            actor_mailbox_capacity: 1024,
+            optimization_strategy: StrategyKind::default(),
+            urgent_request_threshold: 0.8,
+            depth_breaker_threshold: 3,
+            depth_breaker_cooldown_ms: 30_000,
+            heartbeat_timeout_ms: 30_000,
+            emerged_pattern_registry_capacity: 4096,
+            event_bus_capacity: 1024,
+            plugins: PluginSelection::default(),
+            shutdown_grace_period_ms: 30_000,
+            grpc_listen_addr: "0.0.0.0:50052".to_string(),
+            metrics_listen_addr: "0.0.0.0:9090".to_string(),
+            result_store_path: None,
+            max_record_age_hours: 0,
+        }
+    }
+}
+
+impl TryFrom<&hugure_core::settings::HugureSettings> for HugureConfig {
+    type Error = anyhow::Error;
+    
+    /// Build configuration from loaded settings
+    /// 
+    /// The settings are checked by `HugureSettings::validate`, the one rule set shared
+    /// with every other consumer of them; only values this conversion parses itself,
+    /// such as the optimization strategy, are checked here.
+    fn try_from(settings: &hugure_core::settings::HugureSettings) -> Result<Self> {
+        settings.validate()?;
+        let orchestration = &settings.orchestration;
+        Ok(Self {
+            exploration_rate_target: orchestration.exploration_rate_target,
+            exploration_burst: orchestration.exploration_burst,
+            max_recursion_depth: orchestration.max_recursion_depth,
+            emergence_threshold: orchestration.emergence_threshold,
+            optimization_accuracy_target: orchestration.optimization_accuracy_target,
+            temporal_precision: Femtoseconds::new(orchestration.temporal_precision_fs),
+            max_concurrent_explorations: orchestration.max_concurrent_explorations,
+            auto_tune: if orchestration.auto_tune {
+                AutoTune::Aimd {
+                    min_concurrency: orchestration.auto_tune_min_concurrency,
+                    target_cycle_latency_ms: orchestration.auto_tune_target_cycle_latency_ms,
+                }
+            } else {
+                AutoTune::Disabled
+            },
+            exploration_queue_capacity: orchestration.exploration_queue_capacity,
+            foundry_requests_per_second: orchestration.foundry_requests_per_second,
+            bmd_selection_cache_capacity: settings.cache.bmd_selection_capacity,
+            bmd_selection_cache_ttl_secs: settings.cache.ttl_secs,
+            communication_requests_per_second: orchestration.communication_requests_per_second,
+            actor_mailbox_capacity: orchestration.actor_mailbox_capacity,
+            optimization_strategy: orchestration.optimization_strategy.parse()?,
+            urgent_request_threshold: orchestration.urgent_request_threshold,
+            depth_breaker_threshold: orchestration.depth_breaker_threshold,
+            depth_breaker_cooldown_ms: orchestration.depth_breaker_cooldown_ms,
+            heartbeat_timeout_ms: orchestration.heartbeat_timeout_ms,
+            emerged_pattern_registry_capacity: orchestration.emerged_pattern_registry_capacity,
+            event_bus_capacity: orchestration.event_bus_capacity,
+            plugins: PluginSelection {
+                optimizer: settings.plugins.optimizer.clone(),
+                emergence_detector: settings.plugins.emergence_detector.clone(),
+                fidelity_predictor: settings.plugins.fidelity_predictor.clone(),
+                information_deficit_estimator: settings.plugins.information_deficit_estimator.clone(),
+            },
+            shutdown_grace_period_ms: orchestration.shutdown_grace_period_ms,
+            grpc_listen_addr: settings.transport.grpc_listen_addr.clone(),
+            metrics_listen_addr: settings.transport.metrics_listen_addr.clone(),
+            result_store_path: settings.retention.result_store_path.as_ref().map(Into::into),
+            max_record_age_hours: settings.retention.max_record_age_hours,
+        })
+    }
+}
+
+impl HugureConfig {
+    /// Load configuration from defaults, `hugure.toml`, and `HUGURE__*` environment variables
+    pub fn load() -> Result<Self> {
+        Self::load_with(&Default::default())
+    }
+    
+    /// Load configuration like [`load`](Self::load), reading `overrides.config_path` instead
+    /// of `hugure.toml` and applying `overrides` (typically command-line flags) last
+    pub fn load_with(overrides: &hugure_core::settings::SettingsOverrides) -> Result<Self> {
+        Self::from_settings(hugure_core::settings::HugureSettings::load(overrides)?)
+    }
+    
+    /// Load configuration from a TOML file layered over the defaults
+    pub fn from_file(path: impl AsRef<std::path::Path>) -> Result<Self> {
+        Self::from_settings(hugure_core::settings::HugureSettings::from_file(path)?)
+    }
+    
+    /// Load configuration from `HUGURE__*` environment variables layered over the defaults
+    pub fn from_env() -> Result<Self> {
+        Self::from_settings(hugure_core::settings::HugureSettings::from_env()?)
+    }
+    
+    /// Build configuration from already loaded settings, like `HugureConfig::try_from`
+    pub fn from_settings(settings: hugure_core::settings::HugureSettings) -> Result<Self> {
+        Self::try_from(&settings)
+    }
+    
+    /// Check that the configuration can drive an orchestration system
+    /// 
+    /// Applies the rules of `HugureSettings::validate` to configurations built in code.
+    /// Every problem is reported at once rather than stopping at the first.
+    pub fn validate(&self) -> Result<()> {
+        let mut issues = Vec::new();
+        
+        if self.max_recursion_depth == 0 {
+            issues.push("max_recursion_depth must be greater than 0".to_string());
+        }
+        if !(self.emergence_threshold > 0.0 && self.emergence_threshold <= 1.0) {
+            issues.push(format!(
+                "emergence_threshold must be in (0, 1], got {}", self.emergence_threshold
+            ));
+        }
+        if !(self.optimization_accuracy_target > 0.0 && self.optimization_accuracy_target <= 1.0) {
+            issues.push(format!(
+                "optimization_accuracy_target must be in (0, 1], got {}",
+                self.optimization_accuracy_target
+            ));
+        }
+        if !(0.0..=1.0).contains(&self.urgent_request_threshold) {
+            issues.push(format!(
+                "urgent_request_threshold must be in [0, 1], got {}", self.urgent_request_threshold
+            ));
+        }
+        
+        let positive = [
+            ("exploration_rate_target", self.exploration_rate_target),
+            ("exploration_burst", self.exploration_burst as u64),
+            ("temporal_precision", self.temporal_precision.get()),
+            ("max_concurrent_explorations", self.max_concurrent_explorations as u64),
+            ("exploration_queue_capacity", self.exploration_queue_capacity as u64),
+            ("foundry_requests_per_second", self.foundry_requests_per_second as u64),
+            ("communication_requests_per_second", self.communication_requests_per_second as u64),
+            ("actor_mailbox_capacity", self.actor_mailbox_capacity as u64),
+            ("depth_breaker_threshold", self.depth_breaker_threshold as u64),
+            ("heartbeat_timeout_ms", self.heartbeat_timeout_ms),
+            ("event_bus_capacity", self.event_bus_capacity as u64),
+        ];
+        for (field, value) in positive {
+            if value == 0 {
+                issues.push(format!("{} must be greater than 0", field));
+            }
+        }
+        
+        if let AutoTune::Aimd { min_concurrency, target_cycle_latency_ms } = self.auto_tune {
+            if min_concurrency == 0 || min_concurrency > self.max_concurrent_explorations {
+                issues.push(format!(
+                    "auto_tune min_concurrency must be in [1, max_concurrent_explorations], got {}",
+                    min_concurrency
+                ));
+            }
+            if target_cycle_latency_ms == 0 {
+                issues.push("auto_tune target_cycle_latency_ms must be greater than 0".to_string());
+            }
+        }
+        
+        let addresses = [
+            ("grpc_listen_addr", &self.grpc_listen_addr),
+            ("metrics_listen_addr", &self.metrics_listen_addr),
+        ];
+        for (field, addr) in addresses {
+            if addr.parse::<std::net::SocketAddr>().is_err() {
+                issues.push(format!("{} must be a socket address, got '{}'", field, addr));
+            }
+        }
+        
+        if issues.is_empty() {
+            Ok(())
+        } else {
+            Err(anyhow::anyhow!("invalid Hugure configuration: {}", issues.join("; ")))
         }
     }
 }
@@ -84,14 +439,64 @@ impl HugureSystem {
         config: HugureConfig,
         kambuzuma_channel: mpsc::Sender<communication::KambuzumaMessage>,
     ) -> Result<Self> {
-        info!("Initializing Hugure Virtual BMD Orchestration System");
-        
+        Self::with_plugins(config, kambuzuma_channel, PluginRegistry::new()).await
+    }
+    
+    /// Create a Hugure orchestration system using plugins from `registry`
+    /// 
+    /// Built-in implementations are registered as `"default"` unless the registry
+    /// already provides plugins under that name; `config.plugins` picks which
+    /// registered implementation each extension point uses.
+    pub async fn with_plugins(
+        config: HugureConfig,
+        kambuzuma_channel: mpsc::Sender<communication::KambuzumaMessage>,
+        registry: PluginRegistry,
+    ) -> Result<Self> {
+        let kambuzuma = Arc::new(InProcessTransport::new(kambuzuma_channel));
+        Self::with_transport(config, kambuzuma, registry).await
+    }
+    
+    /// Create a Hugure orchestration system that reaches Kambuzuma over `kambuzuma`
+    /// 
+    /// Use a [`transport::StreamTransport`] when Kambuzuma runs in another process
+    /// or on another host.
+    pub async fn with_transport(
+        config: HugureConfig,
+        kambuzuma: Arc<dyn KambuzumaTransport>,
+        registry: PluginRegistry,
+    ) -> Result<Self> {
         // Initialize foundry interface for BMD selection
         let foundry_interface = Arc::new(
             FoundryInterface::new().await
                 .map_err(|e| anyhow::anyhow!("Failed to initialize foundry interface: {}", e))?
         );
         
+        Self::with_foundry(config, kambuzuma, registry, foundry_interface).await
+    }
+    
+    /// Create a Hugure orchestration system that selects BMDs from `foundry`
+    /// 
+    /// Use this to drive Hugure from a local simulator or a remote foundry instead
+    /// of the bundled Virtual BMD Foundry interface.
+    pub async fn with_foundry(
+        config: HugureConfig,
+        kambuzuma: Arc<dyn KambuzumaTransport>,
+        mut registry: PluginRegistry,
+        foundry: Arc<dyn FoundryBackend>,
+    ) -> Result<Self> {
+        config.validate()?;
+        
+        let foundry_capabilities = foundry.capabilities();
+        info!(
+            target: "orchestration",
+            exploration_rate_target = config.exploration_rate_target,
+            max_concurrent_explorations = config.max_concurrent_explorations,
+            foundry = %foundry_capabilities.name,
+            remote_foundry = foundry_capabilities.remote,
+            kambuzuma_transport = kambuzuma.name(),
+            "initializing Hugure orchestration system"
+        );
+        
         // Initialize orchestration engine
         let orchestration_engine = Arc::new(
             OrchestrationEngine::new(config.clone()).await?
@@ -102,37 +507,385 @@ impl HugureSystem {
             OptimizationCoordinator::new(config.clone()).await?
         );
         
-        Ok(Self {
-            foundry_interface,
+        // Shared throttles for the foundry and communication ingress paths
+        let foundry_limiter = Arc::new(TokenBucket::new(
+            "foundry",
+            config.foundry_requests_per_second,
+            config.foundry_requests_per_second as f64,
+        ));
+        let request_limiter = Arc::new(SlidingWindow::new(
+            "communication_ingress",
+            config.communication_requests_per_second as usize,
+            std::time::Duration::from_secs(1),
+        ));
+        
+        // Open the cycle result journal and drop records past their retention age
+        #[cfg(feature = "persistence")]
+        let result_store = match &config.result_store_path {
+            Some(path) => {
+                let store = persistence::ResultStore::open(path)?;
+                if config.max_record_age_hours > 0 {
+                    let max_age = Duration::from_secs(config.max_record_age_hours * 3600);
+                    let pruned = store.prune_older_than(max_age)?;
+                    debug!(target: "orchestration", pruned, "pruned expired cycle records");
+                }
+                Some(Arc::new(store))
+            }
+            None => None,
+        };
+        
+        // Serve hot communication contexts without a foundry round trip
+        let (foundry, selection_cache) = if config.bmd_selection_cache_capacity > 0 {
+            let cache = Arc::new(CachedFoundry::new(
+                foundry,
+                config.bmd_selection_cache_capacity,
+                Duration::from_secs(config.bmd_selection_cache_ttl_secs),
+            ));
+            (Arc::clone(&cache) as Arc<dyn FoundryBackend>, Some(cache))
+        } else {
+            (foundry, None)
+        };
+        
+        // Resolve configured plugins, falling back to the built-in implementations
+        registry.register_defaults(Arc::clone(&optimization_coordinator));
+        let plugins = registry.select(&config.plugins)?;
+        
+        // Each component is owned by a supervised actor from here on
+        let actors = HugureActors::spawn(
+            Arc::clone(&foundry),
             orchestration_engine,
             optimization_coordinator,
-            kambuzuma_channel,
+            foundry_limiter,
+            &plugins,
+            &config,
+        );
+        
+        Ok(Self {
+            actors,
+            kambuzuma,
+            request_limiter,
+            plugins,
+            foundry,
+            selection_cache,
+            exploration_queue: Arc::new(ExplorationQueue::new(config.exploration_queue_capacity)),
+            exploration_budget: Arc::new(ExplorationBudget::new(
+                config.exploration_rate_target,
+                config.exploration_burst,
+            )),
+            exploration_concurrency: Arc::new(ExplorationConcurrency::new(
+                config.max_concurrent_explorations,
+                config.auto_tune,
+            )),
+            metrics: Arc::new(NoopMetrics),
+            patterns: Arc::new(PatternRegistry::new(config.emerged_pattern_registry_capacity)),
+            events: EventBus::new(config.event_bus_capacity),
+            recorder: None,
+            #[cfg(feature = "persistence")]
+            result_store,
+            shutdown: ShutdownHandle::new(),
+            in_flight: TaskTracker::new(),
+            orchestration_loop: Mutex::new(None),
+            // Beat several times per timeout, so only a stalled loop goes stale
+            heartbeat: Arc::new(Heartbeat::with_interval(
+                Duration::from_millis(config.heartbeat_timeout_ms) / 4,
+            )),
             config,
+            _plugin_registry: registry,
         })
     }
     
+    /// Report orchestration metrics to `metrics`; call before [`HugureSystem::start`]
+    pub fn with_metrics(mut self, metrics: Arc<dyn OrchestrationMetrics>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+    
+    /// Record every communication request to `recorder` for later [`replay::replay`]
+    pub fn with_session_recorder(mut self, recorder: Arc<replay::SessionRecorder>) -> Self {
+        self.recorder = Some(recorder);
+        self
+    }
+    
     /// Start the Hugure orchestration system
     pub async fn start(&self) -> Result<()> {
-        info!("Starting Hugure BMD orchestration system");
+        info!(target: "orchestration", "starting orchestration system");
         
         // Notify Kambuzuma that Hugure is ready for communication tasks
-        self.kambuzuma_channel.send(
+        self.kambuzuma.send(
             communication::KambuzumaMessage::HugureReady {
                 capabilities: self.get_capabilities(),
             }
         ).await.map_err(|e| anyhow::anyhow!("Failed to notify Kambuzuma: {}", e))?;
         
         // Start orchestration loops
-        self.start_orchestration_loops().await?;
+        let orchestration_loop = self.start_orchestration_loops().await?;
+        *self.orchestration_loop.lock().unwrap_or_else(PoisonError::into_inner) = Some(orchestration_loop);
         
         Ok(())
     }
     
+    /// Handle for requesting shutdown from outside the system
+    /// 
+    /// Triggering the handle stops new work; call [`HugureSystem::shutdown`] to
+    /// drain in-flight work and notify Kambuzuma.
+    pub fn shutdown_handle(&self) -> ShutdownHandle {
+        self.shutdown.clone()
+    }
+    
+    /// Gracefully stop the system
+    /// 
+    /// Stops the orchestration loop after its current cycle (so pending optimization
+    /// results are flushed through emergence detection), rejects new communication
+    /// requests, waits up to the configured grace period for in-flight requests, and
+    /// finally notifies Kambuzuma.
+    pub async fn shutdown(&self) -> Result<ShutdownReport> {
+        let started = Instant::now();
+        let drained_requests = self.in_flight.len();
+        info!(target: "orchestration", in_flight = drained_requests, "shutting down orchestration system");
+        
+        self.shutdown.trigger();
+        self.in_flight.close();
+        
+        let mut orchestration_loop = self.orchestration_loop
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .take();
+        let grace_period = Duration::from_millis(self.config.shutdown_grace_period_ms);
+        let completed_within_grace_period = tokio::time::timeout(grace_period, async {
+            if let Some(handle) = orchestration_loop.as_mut() {
+                if let Err(e) = handle.await {
+                    warn!(target: "orchestration", error = %e, "orchestration loop ended abnormally");
+                }
+            }
+            self.in_flight.wait().await;
+        }).await.is_ok();
+        
+        if !completed_within_grace_period {
+            warn!(
+                target: "orchestration",
+                grace_period_ms = self.config.shutdown_grace_period_ms,
+                remaining = self.in_flight.len(),
+                "shutdown grace period expired, abandoning in-flight work"
+            );
+            if let Some(handle) = orchestration_loop {
+                handle.abort();
+            }
+        }
+        
+        // Make sure every journaled cycle reaches disk
+        #[cfg(feature = "persistence")]
+        if let Some(store) = &self.result_store {
+            store.flush().await?;
+        }
+        
+        // Let Kambuzuma reallocate the neural task
+        self.kambuzuma.send(
+            communication::KambuzumaMessage::HugureShutdown {
+                graceful: completed_within_grace_period,
+            }
+        ).await.map_err(|e| anyhow::anyhow!("Failed to notify Kambuzuma of shutdown: {}", e))?;
+        
+        let report = ShutdownReport {
+            drained_requests,
+            completed_within_grace_period,
+            elapsed: started.elapsed(),
+        };
+        info!(
+            target: "orchestration",
+            drained_requests,
+            completed_within_grace_period,
+            elapsed_ms = report.elapsed.as_millis() as u64,
+            "orchestration system stopped"
+        );
+        Ok(report)
+    }
+    
+    /// Plugins in use for each extension point
+    pub fn plugins(&self) -> &SelectedPlugins {
+        &self.plugins
+    }
+    
+    /// Journal of orchestration cycle results, if configured
+    #[cfg(feature = "persistence")]
+    pub fn result_store(&self) -> Option<&persistence::ResultStore> {
+        self.result_store.as_deref()
+    }
+    
+    /// Depth and throughput of the exploration queue
+    pub fn exploration_queue_stats(&self) -> QueueStats {
+        self.exploration_queue.stats()
+    }
+    
+    /// Subscribe to lifecycle events published from now on
+    pub fn subscribe(&self) -> tokio::sync::broadcast::Receiver<LifecycleEvent> {
+        self.events.subscribe()
+    }
+    
+    /// Attained versus target exploration rate
+    pub fn exploration_rate_stats(&self) -> ExplorationRateStats {
+        self.exploration_budget.stats()
+    }
+    
+    /// Current exploration concurrency limit and, when auto-tuned, what drove it
+    pub fn exploration_concurrency_stats(&self) -> ConcurrencyStats {
+        self.exploration_concurrency.stats()
+    }
+    
+    /// Hit and miss counters of the BMD selection cache, if enabled
+    pub fn selection_cache_stats(&self) -> Option<SelectionCacheStats> {
+        self.selection_cache.as_ref().map(|cache| cache.stats())
+    }
+    
+    /// Drop the cached BMD selection for `context`, e.g. after a profile changes
+    /// 
+    /// Returns whether a selection was cached.
+    pub fn invalidate_selection_cache(
+        &self,
+        context: &foundry::BMDSelectionContext,
+    ) -> Result<bool> {
+        match &self.selection_cache {
+            Some(cache) => cache.invalidate(context),
+            None => Ok(false),
+        }
+    }
+    
+    /// Drop every cached BMD selection
+    pub fn clear_selection_cache(&self) {
+        if let Some(cache) = &self.selection_cache {
+            cache.clear();
+        }
+    }
+    
+    /// Configuration the system was built with
+    pub fn config(&self) -> &HugureConfig {
+        &self.config
+    }
+    
+    /// Current health of the system's dependencies and orchestration loop
+    /// 
+    /// Cheap enough to serve from a readiness probe: the only remote call is the
+    /// foundry health check, bounded by a short timeout.
+    pub async fn health(&self) -> HealthReport {
+        let foundry = match tokio::time::timeout(
+            FOUNDRY_HEALTH_TIMEOUT,
+            self.foundry.health_check(),
+        ).await {
+            Ok(Ok(())) => ComponentHealth::healthy(),
+            Ok(Err(e)) => ComponentHealth::with_status(HealthStatus::Unhealthy, e.to_string()),
+            Err(_) => ComponentHealth::with_status(
+                HealthStatus::Unhealthy,
+                format!("health check timed out after {:?}", FOUNDRY_HEALTH_TIMEOUT),
+            ),
+        };
+        
+        let kambuzuma = if self.kambuzuma.is_alive() {
+            ComponentHealth::healthy()
+        } else {
+            ComponentHealth::with_status(
+                HealthStatus::Unhealthy,
+                "transport cannot deliver messages",
+            )
+        };
+        
+        let running = self.orchestration_loop.lock().unwrap_or_else(PoisonError::into_inner)
+            .as_ref()
+            .is_some_and(|handle| !handle.is_finished());
+        let heartbeat_age = self.heartbeat.age();
+        let timeout = Duration::from_millis(self.config.heartbeat_timeout_ms);
+        let loop_status = match (running, heartbeat_age) {
+            (true, Some(age)) if age >= timeout => HealthStatus::Unhealthy,
+            (true, _) => HealthStatus::Healthy,
+            // Never started: requests are still served, but nothing explores in the background
+            (false, None) => HealthStatus::Degraded,
+            (false, Some(_)) if self.shutdown.is_shutdown() => HealthStatus::Degraded,
+            (false, Some(_)) => HealthStatus::Unhealthy,
+        };
+        let orchestration_loop = LoopHealth {
+            status: loop_status,
+            running,
+            heartbeat_age_ms: heartbeat_age.map(|age| age.as_millis() as u64),
+        };
+        
+        let optimizer_backlog = self.actors.optimizer.mailbox_depth();
+        let backlog_status = if optimizer_backlog >= self.config.actor_mailbox_capacity {
+            HealthStatus::Degraded
+        } else {
+            HealthStatus::Healthy
+        };
+        
+        let status = [foundry.status, kambuzuma.status, loop_status, backlog_status]
+            .into_iter()
+            .max()
+            .unwrap_or(HealthStatus::Healthy);
+        
+        HealthReport {
+            status,
+            foundry,
+            kambuzuma,
+            orchestration_loop,
+            optimizer_backlog,
+            exploration_queue_depth: self.exploration_queue.depth(),
+            shutting_down: self.shutdown.is_shutdown(),
+        }
+    }
+    
+    /// Recently emerged patterns, oldest first
+    pub fn emerged_patterns(&self) -> Vec<emergence::EmergedPattern> {
+        self.patterns.patterns()
+    }
+    
+    /// Write orchestration state, optimizer warm-start data, and the emerged-pattern
+    /// registry to `path`
+    /// 
+    /// Safe to call while the system is running; the snapshot reflects the state
+    /// between two actor messages.
+    pub async fn snapshot(&self, path: impl AsRef<std::path::Path>) -> Result<SystemSnapshot> {
+        let snapshot = SystemSnapshot::new(
+            self.actors.orchestration_state().await?,
+            self.actors.optimizer_warm_start().await?,
+            self.patterns.patterns(),
+        );
+        snapshot.write(path.as_ref())?;
+        info!(
+            target: "orchestration",
+            path = %path.as_ref().display(),
+            emerged_patterns = snapshot.emerged_patterns.len(),
+            "orchestration snapshot written"
+        );
+        Ok(snapshot)
+    }
+    
+    /// Resume from a snapshot written by [`HugureSystem::snapshot`]
+    /// 
+    /// Call before [`HugureSystem::start`] so the first cycles already benefit from
+    /// the restored state. The registry is replaced, not merged.
+    pub async fn restore(&self, path: impl AsRef<std::path::Path>) -> Result<SystemSnapshot> {
+        let snapshot = SystemSnapshot::read(path.as_ref())?;
+        self.actors.restore_orchestration_state(snapshot.orchestration.clone()).await?;
+        if let Some(warm_start) = snapshot.optimizer.clone() {
+            self.actors.restore_optimizer_warm_start(warm_start).await?;
+        }
+        self.patterns.replace(snapshot.emerged_patterns.clone());
+        info!(
+            target: "orchestration",
+            path = %path.as_ref().display(),
+            taken_at_ns = snapshot.taken_at_ns,
+            emerged_patterns = self.patterns.len(),
+            "orchestration snapshot restored"
+        );
+        Ok(snapshot)
+    }
+    
+    /// Capabilities of the foundry backend supplying BMD selections
+    pub fn foundry_capabilities(&self) -> FoundryCapabilities {
+        self.foundry.capabilities()
+    }
+    
     /// Get Hugure system capabilities for Kambuzuma
     fn get_capabilities(&self) -> communication::HugureCapabilities {
         communication::HugureCapabilities {
             max_exploration_rate: self.config.exploration_rate_target,
-            temporal_precision_fs: self.config.temporal_precision_fs,
+            temporal_precision_fs: self.config.temporal_precision.get(),
             optimization_accuracy: self.config.optimization_accuracy_target,
             supports_bidirectional: true,
             supports_recursive_amplification: true,
@@ -141,55 +894,215 @@ impl HugureSystem {
     }
     
     /// Start main orchestration loops
-    async fn start_orchestration_loops(&self) -> Result<()> {
-        let orchestration_engine = Arc::clone(&self.orchestration_engine);
-        let foundry_interface = Arc::clone(&self.foundry_interface);
-        let optimization_coordinator = Arc::clone(&self.optimization_coordinator);
-        
-        // BMD selection and exploration loop
-        tokio::spawn(async move {
-            loop {
-                match Self::orchestration_cycle(
-                    &orchestration_engine,
-                    &foundry_interface,
-                    &optimization_coordinator,
-                ).await {
-                    Ok(_) => debug!("Orchestration cycle completed"),
-                    Err(e) => warn!("Orchestration cycle error: {}", e),
+    async fn start_orchestration_loops(&self) -> Result<JoinHandle<()>> {
+        let actors = self.actors.clone();
+        let queue = Arc::clone(&self.exploration_queue);
+        let shutdown = self.shutdown.clone();
+        let heartbeat = Arc::clone(&self.heartbeat);
+        let budget = Arc::clone(&self.exploration_budget);
+        let observer = CycleObserver {
+            metrics: Arc::clone(&self.metrics),
+            concurrency: Arc::clone(&self.exploration_concurrency),
+            kambuzuma: Arc::clone(&self.kambuzuma),
+            patterns: Arc::clone(&self.patterns),
+            events: self.events.clone(),
+            #[cfg(feature = "persistence")]
+            result_store: self.result_store.clone(),
+        };
+        
+        // Foundry selection feeds the bounded exploration queue; exploration drains it.
+        // A panic in either restarts both, so the loop never dies silently.
+        let handle = tokio::spawn(async move {
+            let (actors, queue, budget, shutdown, observer, heartbeat) =
+                (&actors, &queue, &budget, &shutdown, &observer, &heartbeat);
+            supervisor::supervise_loop(
+                "orchestration",
+                LoopRestartPolicy::default(),
+                shutdown,
+                &observer.events,
+                move || async move {
+                    tokio::join!(
+                        Self::selection_loop(actors, queue, shutdown, &observer.events, heartbeat),
+                        Self::exploration_loop(actors, queue, budget, shutdown, observer, heartbeat),
+                    );
+                },
+            ).await;
+            debug!(target: "orchestration", "orchestration loop stopped");
+        });
+        
+        Ok(handle)
+    }
+    
+    /// Select BMDs from Virtual BMD Foundries into the exploration queue
+    /// 
+    /// A queue slot is reserved before the foundry is asked, so selection parks
+    /// while downstream optimization is saturated.
+    async fn selection_loop(
+        actors: &HugureActors,
+        queue: &ExplorationQueue<BMDSelection>,
+        shutdown: &ShutdownHandle,
+        events: &EventBus,
+        heartbeat: &Heartbeat,
+    ) {
+        while !shutdown.is_shutdown() {
+            heartbeat.beat();
+            let slot = tokio::select! {
+                slot = heartbeat.beat_while(queue.reserve()) => slot,
+                _ = shutdown.cancelled() => break,
+            };
+            let slot = match slot {
+                Ok(slot) => slot,
+                Err(e) => {
+                    warn!(target: "orchestration", error = %e, "exploration queue unavailable");
+                    break;
+                }
+            };
+            
+            // Throttled by the foundry client
+            match heartbeat.beat_while(actors.select_for_exploration()).await {
+                Ok(selection) => slot.push(selection),
+                Err(e) => {
+                    warn!(target: "orchestration", error = %e, "foundry selection failed");
+                    events.publish(LifecycleEvent::FoundryUnavailable { error: e.to_string() });
+                    tokio::time::sleep(SELECTION_RETRY_DELAY).await;
                 }
-                
-                // Femtosecond-precision timing for continuous operation
-                tokio::time::sleep(tokio::time::Duration::from_nanos(10)).await;
             }
-        });
+        }
+    }
+    
+    /// Run queued selections through exploration, optimization, and emergence
+    /// 
+    /// Each cycle waits for a slot under the exploration concurrency limit and
+    /// takes a token from the exploration budget, holding the loop to the
+    /// configured rate target. Running cycles finish, and selections still queued
+    /// at shutdown are processed, before returning so their optimization results
+    /// are not dropped.
+    async fn exploration_loop(
+        actors: &HugureActors,
+        queue: &Arc<ExplorationQueue<BMDSelection>>,
+        budget: &ExplorationBudget,
+        shutdown: &ShutdownHandle,
+        observer: &CycleObserver,
+        heartbeat: &Heartbeat,
+    ) {
+        observer.metrics.set_exploration_rate_target(budget.stats().target_per_second);
+        observer.metrics.set_exploration_concurrency_limit(observer.concurrency.limit());
+        let cycles = TaskTracker::new();
+        loop {
+            heartbeat.beat();
+            let permit = tokio::select! {
+                permit = heartbeat.beat_while(observer.concurrency.acquire()) => permit,
+                _ = shutdown.cancelled() => break,
+            };
+            let throttled = tokio::select! {
+                throttled = heartbeat.beat_while(budget.acquire()) => throttled,
+                _ = shutdown.cancelled() => break,
+            };
+            if throttled {
+                observer.metrics.record_exploration_throttled();
+            }
+            
+            let selection = tokio::select! {
+                selection = heartbeat.beat_while(queue.pop()) => selection,
+                _ = shutdown.cancelled() => break,
+            };
+            let Some(selection) = selection else { break };
+            
+            let (actors, queue, observer) = (actors.clone(), Arc::clone(queue), observer.clone());
+            cycles.spawn(async move {
+                let _permit = permit;
+                Self::run_exploration_cycle(&actors, &queue, &observer, selection).await;
+            });
+        }
         
-        Ok(())
+        cycles.close();
+        cycles.wait().await;
+        while let Some(selection) = queue.try_pop() {
+            Self::run_exploration_cycle(actors, queue, observer, selection).await;
+        }
     }
     
-    /// Single orchestration cycle: Select → Explore → Optimize
+    async fn run_exploration_cycle(
+        actors: &HugureActors,
+        queue: &ExplorationQueue<BMDSelection>,
+        observer: &CycleObserver,
+        selection: BMDSelection,
+    ) {
+        let started = Instant::now();
+        let result = Self::orchestration_cycle(actors, observer, selection).await;
+        
+        let emerged_patterns = *result.as_ref().unwrap_or(&0);
+        let adjusted = observer.concurrency.record_cycle(started.elapsed(), emerged_patterns);
+        if let Some(limit) = adjusted {
+            observer.metrics.set_exploration_concurrency_limit(limit);
+            debug!(target: "orchestration", limit, "exploration concurrency adjusted");
+        }
+        observer.metrics.record_exploration(result.is_ok());
+        observer.metrics.set_exploration_queue_depth(queue.depth());
+        observer.metrics.set_kambuzuma_channel_lag(observer.kambuzuma.pending());
+        
+        match result {
+            Ok(_) => debug!(
+                target: "orchestration",
+                queue_depth = queue.depth(),
+                "orchestration cycle completed"
+            ),
+            Err(e) => warn!(target: "orchestration", error = %e, "orchestration cycle failed"),
+        }
+    }
+    
+    /// Single orchestration cycle: Explore → Optimize → Detect emergence
+    /// 
+    /// Returns how many patterns emerged.
     async fn orchestration_cycle(
-        orchestration_engine: &OrchestrationEngine,
-        foundry_interface: &FoundryInterface,
-        optimization_coordinator: &OptimizationCoordinator,
-    ) -> Result<()> {
-        // Select BMDs from Virtual BMD Foundries
-        let bmd_selection = foundry_interface.select_bmds_for_exploration().await?;
+        actors: &HugureActors,
+        observer: &CycleObserver,
+        bmd_selection: BMDSelection,
+    ) -> Result<usize> {
+        let metrics = &observer.metrics;
+        let cycle_started = Instant::now();
         
         // Orchestrate exploration of selected BMDs
-        let exploration_results = orchestration_engine
-            .explore_bmd_combinations(bmd_selection).await?;
+        let exploration_results = actors.explore(bmd_selection).await?;
+        
+        // Capture exploration results for the journal before optimization consumes them
+        #[cfg(feature = "persistence")]
+        let journaled = match observer.result_store {
+            Some(_) => Some(serde_json::to_value(&exploration_results)?),
+            None => None,
+        };
         
         // Optimize patterns through bidirectional analysis
-        let optimization_results = optimization_coordinator
-            .optimize_bidirectional(exploration_results).await?;
+        let optimization_started = Instant::now();
+        let optimization_results = actors.optimize_bidirectional(exploration_results).await?;
+        metrics.observe_optimization_latency(optimization_started.elapsed());
         
         // Apply statistical emergence detection
-        let emerged_patterns = optimization_coordinator
-            .detect_statistical_emergence(optimization_results).await?;
+        let emerged_patterns = actors.detect_emergence(optimization_results).await?;
+        metrics.record_emerged_patterns(emerged_patterns.len());
         
-        debug!("Orchestration cycle: {} emerged patterns", emerged_patterns.len());
+        #[cfg(feature = "persistence")]
+        if let (Some(store), Some(exploration)) = (&observer.result_store, journaled) {
+            let emerged = serde_json::to_value(&emerged_patterns)?;
+            store.insert(&persistence::CycleRecord::new(exploration, emerged))?;
+        }
         
-        Ok(())
+        debug!(
+            target: "orchestration",
+            emerged_patterns = emerged_patterns.len(),
+            "orchestration cycle emergence"
+        );
+        observer.events.publish(LifecycleEvent::CycleCompleted {
+            emerged_patterns: emerged_patterns.len(),
+            duration: cycle_started.elapsed(),
+        });
+        for pattern in &emerged_patterns {
+            observer.events.publish(LifecycleEvent::PatternEmerged { pattern: pattern.clone() });
+        }
+        let emerged = emerged_patterns.len();
+        observer.patterns.extend(emerged_patterns);
+        
+        Ok(emerged)
     }
     
     /// Handle communication request from external systems
@@ -197,40 +1110,195 @@ impl HugureSystem {
         &self,
         request: communication::CommunicationRequest,
     ) -> Result<communication::CommunicationResponse> {
-        info!("Processing communication request: {:?}", request.request_type);
+        let strategy = self.config.optimization_strategy;
+        self.handle_communication_request_with_strategy(request, strategy).await
+    }
+    
+    /// Handle a communication request, weighting the optimized BMDs with `strategy`
+    /// 
+    /// The response's `degradation` reports whether exploration had to fall back to
+    /// a shallower recursion depth to produce a result.
+    pub async fn handle_communication_request_with_strategy(
+        &self,
+        request: communication::CommunicationRequest,
+        strategy: StrategyKind,
+    ) -> Result<communication::CommunicationResponse> {
+        self.process_communication_request(request, strategy, None).await
+    }
+    
+    /// Handle a communication request whose exploration `cancel` can abort
+    /// 
+    /// Cancel when the requester goes away; exploration stops at the next depth
+    /// step and the response is built from the deepest completed result, reported
+    /// as a [`depth_breaker::DegradationReason::Cancelled`] degradation.
+    pub async fn handle_communication_request_cancellable(
+        &self,
+        request: communication::CommunicationRequest,
+        strategy: StrategyKind,
+        cancel: CancellationToken,
+    ) -> Result<communication::CommunicationResponse> {
+        self.process_communication_request(request, strategy, Some(cancel)).await
+    }
+    
+    /// Handle a communication request in simulation mode
+    /// 
+    /// The request is optimized as usual, then its injection parameters are fed to
+    /// a [`simulation::RecipientSimulator`] built from the recipient profile to
+    /// predict reception quality. Nothing is transmitted.
+    pub async fn simulate_communication_request(
+        &self,
+        request: communication::CommunicationRequest,
+        strategy: StrategyKind,
+    ) -> Result<simulation::SimulatedCommunication> {
+        let simulator = simulation::RecipientSimulator::new(request.recipient_profile.clone());
+        let correlation_id = CorrelationId::current_or_new();
+        let processed = self.process_communication_request(request, strategy, None);
+        let response = correlation::scope(correlation_id, processed).await?;
+        let reception = simulator.simulate(&response);
+        debug!(
+            target: "orchestration",
+            %correlation_id,
+            reception_quality = reception.reception_quality,
+            "simulated communication reception"
+        );
+        Ok(simulation::SimulatedCommunication { response, reception })
+    }
+    
+    /// Run a communication request inside its correlation span
+    /// 
+    /// The id comes from an enclosing [`correlation::scope`] if there is one.
+    async fn process_communication_request(
+        &self,
+        request: communication::CommunicationRequest,
+        strategy: StrategyKind,
+        cancel: Option<CancellationToken>,
+    ) -> Result<communication::CommunicationResponse> {
+        let correlation_id = CorrelationId::current_or_new();
+        let span = correlation::request_span(correlation_id);
+        let response = self
+            .run_communication_request(request, strategy, cancel, correlation_id)
+            .instrument(span)
+            .await?;
+        if let Some(degradation) = response.degradation {
+            self.events.publish(LifecycleEvent::OptimizationDegraded {
+                correlation_id,
+                degradation,
+            });
+        }
+        Ok(response)
+    }
+    
+    async fn run_communication_request(
+        &self,
+        request: communication::CommunicationRequest,
+        strategy: StrategyKind,
+        cancel: Option<CancellationToken>,
+        correlation_id: CorrelationId,
+    ) -> Result<communication::CommunicationResponse> {
+        info!(
+            target: "orchestration",
+            request_type = ?request.request_type,
+            %strategy,
+            "processing communication request"
+        );
+        
+        // Refuse new work once shutdown has begun; otherwise count as in flight
+        if self.shutdown.is_shutdown() {
+            return Err(anyhow::anyhow!("Hugure is shutting down"));
+        }
+        let _in_flight = self.in_flight.token();
+        
+        // Reject ingress beyond the configured request rate
+        self.request_limiter.check()?;
         
         // Select appropriate BMDs for this communication scenario
         let context = foundry::BMDSelectionContext {
-            sender_profile: request.sender_profile,
-            recipient_profile: request.recipient_profile,
-            communication_intent: request.intent,
+            sender_profile: request.sender_profile.clone(),
+            recipient_profile: request.recipient_profile.clone(),
+            communication_intent: request.intent.clone(),
             optimization_target: self.config.optimization_accuracy_target,
         };
         
-        let selected_bmds = self.foundry_interface
-            .select_bmds_with_context(context).await?;
+        // Capture what replay needs before the request and context are consumed
+        let recording = match &self.recorder {
+            Some(_) => Some((
+                foundry_backend::context_key(&context)?,
+                serde_json::to_value(&request)?,
+            )),
+            None => None,
+        };
         
-        // Explore selected BMDs for optimal combinations
+        let selected_bmds = match self.actors.select_with_context(context)
+            .instrument(tracing::info_span!(target: "orchestration", "foundry_selection"))
+            .await
+        {
+            Ok(selection) => selection,
+            Err(e) => {
+                self.events.publish(LifecycleEvent::FoundryUnavailable { error: e.to_string() });
+                return Err(e);
+            }
+        };
+        let recorded_selection = match &recording {
+            Some(_) => Some(serde_json::to_value(&selected_bmds)?),
+            None => None,
+        };
+        
+        // Explore selected BMDs for optimal combinations, urgent requests first
+        let priority = TaskPriority::for_request(
+            request.intent.urgency,
+            self.config.urgent_request_threshold,
+        );
         let exploration_task = ExplorationTask {
             bmds: selected_bmds,
             target_accuracy: self.config.optimization_accuracy_target,
             max_recursion_depth: self.config.max_recursion_depth,
-            temporal_precision: self.config.temporal_precision_fs,
+            temporal_precision: self.config.temporal_precision.get(),
+            priority,
         };
         
-        let exploration_results = self.orchestration_engine
-            .execute_exploration_task(exploration_task).await?;
+        let exploration = async {
+            match cancel {
+                Some(cancel) => {
+                    self.actors.execute_cancellable(exploration_task, cancel).await
+                }
+                None => self.actors.execute(exploration_task).await,
+            }
+        };
+        let (exploration_results, degradation) = exploration
+            .instrument(tracing::info_span!(target: "orchestration", "exploration", ?priority))
+            .await?;
         
-        // Optimize for bidirectional communication
-        let optimized_patterns = self.optimization_coordinator
-            .optimize_for_communication(exploration_results, &request).await?;
+        // Optimize for bidirectional communication with the requested strategy
+        let optimized_patterns = self.actors
+            .optimize_for_communication(exploration_results, request, strategy)
+            .instrument(tracing::info_span!(target: "orchestration", "optimization"))
+            .await?;
         
-        Ok(communication::CommunicationResponse {
+        let response = communication::CommunicationResponse {
             optimized_bmds: optimized_patterns.bmds,
             injection_parameters: optimized_patterns.injection_params,
             fidelity_prediction: optimized_patterns.predicted_fidelity,
             temporal_coordinates: optimized_patterns.temporal_coords,
-        })
+            degradation,
+        };
+        
+        if let (Some(recorder), Some((context_key, request)), Some(selection)) =
+            (&self.recorder, recording, recorded_selection)
+        {
+            let record = replay::SessionRecord::new(
+                correlation_id,
+                strategy,
+                context_key,
+                request,
+                selection,
+                response.fidelity_prediction,
+                serde_json::to_value(&response)?,
+            );
+            if let Err(e) = recorder.record(&record) {
+                warn!(target: "orchestration", error = %e, "failed to record communication");
+            }
+        }
+        Ok(response)
     }
 }
 
@@ -256,7 +1324,153 @@ mod tests {
         
         let capabilities = system.get_capabilities();
         assert_eq!(capabilities.max_exploration_rate, config.exploration_rate_target);
-        assert_eq!(capabilities.temporal_precision_fs, config.temporal_precision_fs);
+        assert_eq!(capabilities.temporal_precision_fs, config.temporal_precision.get());
         assert!(capabilities.supports_bidirectional);
     }
+    
+    /// Optimizer whose only state is its warm-start data
+    #[derive(Debug, Default)]
+    struct WarmStartOptimizer {
+        state: Mutex<Option<serde_json::Value>>,
+    }
+    
+    #[async_trait::async_trait]
+    impl plugins::BidirectionalOptimizer for WarmStartOptimizer {
+        async fn optimize_bidirectional(
+            &self,
+            _results: orchestration::ExplorationResults,
+        ) -> Result<optimization::OptimizationResults> {
+            Err(anyhow::anyhow!("not used by the snapshot tests"))
+        }
+        
+        fn warm_start_state(&self) -> Result<Option<serde_json::Value>> {
+            Ok(self.state.lock().unwrap().clone())
+        }
+        
+        fn restore_warm_start(&self, state: serde_json::Value) -> Result<()> {
+            *self.state.lock().unwrap() = Some(state);
+            Ok(())
+        }
+    }
+    
+    async fn system_with_optimizer(optimizer: Arc<WarmStartOptimizer>) -> HugureSystem {
+        let (tx, _rx) = mpsc::channel(100);
+        let mut config = HugureConfig::default();
+        config.plugins.optimizer = "warm_start".to_string();
+        let mut registry = PluginRegistry::new();
+        registry.register_optimizer("warm_start", optimizer).unwrap();
+        HugureSystem::with_plugins(config, tx, registry).await.unwrap()
+    }
+    
+    #[tokio::test]
+    async fn test_snapshot_restores_optimizer_warm_start() {
+        let path = std::env::temp_dir().join(format!("hugure-warm-start-{}.json", std::process::id()));
+        let warm_start = serde_json::json!({ "step_size": 0.25, "iterations": 40 });
+        
+        let original = Arc::new(WarmStartOptimizer::default());
+        *original.state.lock().unwrap() = Some(warm_start.clone());
+        let snapshot = system_with_optimizer(original).await.snapshot(&path).await.unwrap();
+        assert_eq!(snapshot.optimizer, Some(warm_start.clone()));
+        
+        let restored = Arc::new(WarmStartOptimizer::default());
+        system_with_optimizer(Arc::clone(&restored)).await.restore(&path).await.unwrap();
+        let _ = std::fs::remove_file(&path);
+        
+        assert_eq!(*restored.state.lock().unwrap(), Some(warm_start));
+    }
+    
+    /// Foundry that never answers, leaving the orchestration loop with nothing to do
+    #[derive(Debug)]
+    struct IdleFoundry;
+    
+    #[async_trait::async_trait]
+    impl FoundryBackend for IdleFoundry {
+        async fn select_bmds_for_exploration(&self) -> Result<BMDSelection> {
+            std::future::pending().await
+        }
+        
+        async fn select_bmds_with_context(&self, _context: foundry::BMDSelectionContext) -> Result<BMDSelection> {
+            std::future::pending().await
+        }
+        
+        fn capabilities(&self) -> FoundryCapabilities {
+            FoundryCapabilities {
+                name: "idle".to_string(),
+                max_selection_rate: 0,
+                supports_context_selection: false,
+                remote: false,
+            }
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_idle_system_stays_healthy() {
+        let (tx, _rx) = mpsc::channel(100);
+        let config = HugureConfig { heartbeat_timeout_ms: 100, ..HugureConfig::default() };
+        let kambuzuma = Arc::new(InProcessTransport::new(tx));
+        let system = HugureSystem::with_foundry(config, kambuzuma, PluginRegistry::new(), Arc::new(IdleFoundry))
+            .await
+            .unwrap();
+        system.start().await.unwrap();
+        
+        // Several timeouts pass without a single exploration completing
+        tokio::time::sleep(Duration::from_millis(500)).await;
+        let health = system.health().await;
+        
+        assert!(health.orchestration_loop.running);
+        assert_eq!(health.orchestration_loop.status, HealthStatus::Healthy);
+        assert!(health.orchestration_loop.heartbeat_age_ms.unwrap() < 100);
+    }
+    
+    #[test]
+    fn test_config_validation() {
+        assert!(HugureConfig::default().validate().is_ok());
+        
+        let config = HugureConfig {
+            max_recursion_depth: 0,
+            emergence_threshold: 0.0,
+            ..HugureConfig::default()
+        };
+        let message = config.validate().unwrap_err().to_string();
+        assert!(message.contains("max_recursion_depth"));
+        assert!(message.contains("emergence_threshold"));
+        
+        let config = HugureConfig { emergence_threshold: 1.5, ..HugureConfig::default() };
+        assert!(config.validate().is_err());
+    }
+    
+    #[test]
+    fn test_config_from_settings_tree() {
+        let mut settings = hugure_core::settings::HugureSettings::default();
+        settings.orchestration.optimization_strategy = "annealing".to_string();
+        settings.cache.bmd_selection_capacity = 7;
+        settings.transport.metrics_listen_addr = "127.0.0.1:9191".to_string();
+        settings.plugins.emergence_detector = detection::CUSUM_DETECTOR.to_string();
+        
+        let config = HugureConfig::try_from(&settings).unwrap();
+        assert_eq!(config.optimization_strategy, StrategyKind::Annealing);
+        assert_eq!(config.bmd_selection_cache_capacity, 7);
+        assert_eq!(config.metrics_listen_addr, "127.0.0.1:9191");
+        assert_eq!(config.plugins.emergence_detector, detection::CUSUM_DETECTOR);
+        
+        settings.orchestration.optimization_strategy = "greedy".to_string();
+        assert!(HugureConfig::try_from(&settings).is_err());
+    }
+    
+    #[test]
+    fn test_config_from_file() {
+        let path = std::env::temp_dir().join(format!("hugure-config-{}.toml", std::process::id()));
+        std::fs::write(&path, "[orchestration]\nmax_recursion_depth = 12\n").unwrap();
+        
+        let config = HugureConfig::from_file(&path).unwrap();
+        assert_eq!(config.max_recursion_depth, 12);
+        assert_eq!(config.emergence_threshold, HugureConfig::default().emergence_threshold);
+        
+        std::fs::write(&path, "[orchestration]\nmax_recursion_depth = 0\n").unwrap();
+        assert!(HugureConfig::from_file(&path).is_err());
+        std::fs::write(&path, "[orchestration]\nemergence_threshold = 0.0\n").unwrap();
+        assert!(HugureConfig::from_file(&path).is_err());
+        
+        std::fs::remove_file(path).ok();
+    }
 } 
\ No newline at end of file
@@ -1,28 +1,103 @@
+use std::sync::Arc;
 use hugure::{Hugure, HugureConfig};
+use hugure_core::settings::{HugureSettings, SettingsOverrides};
 use anyhow::Result;
+use clap::{value_parser, Arg, Command};
 use tracing::{info, error};
 use tracing_subscriber;
 
+/// Build the command-line interface
+fn cli() -> Command {
+    Command::new("hugure")
+        .version(env!("CARGO_PKG_VERSION"))
+        .about("Virtual BMD orchestration framework")
+        .arg(
+            Arg::new("config")
+                .long("config")
+                .short('c')
+                .value_name("FILE")
+                .help("Configuration file (default: $HUGURE_CONFIG, then hugure.toml)")
+        )
+        .arg(
+            Arg::new("log-filter")
+                .long("log-filter")
+                .value_name("DIRECTIVES")
+                .help("Log filter directives (overrides logging.filter)")
+        )
+        .arg(
+            Arg::new("max-concurrent-explorations")
+                .long("max-concurrent-explorations")
+                .value_name("N")
+                .help("Maximum concurrent BMD explorations (overrides orchestration.max_concurrent_explorations)")
+                .value_parser(value_parser!(usize))
+        )
+        .arg(
+            Arg::new("grpc-listen-addr")
+                .long("grpc-listen-addr")
+                .value_name("ADDR")
+                .help("gRPC listen address (overrides transport.grpc_listen_addr)")
+        )
+        .arg(
+            Arg::new("metrics-listen-addr")
+                .long("metrics-listen-addr")
+                .value_name("ADDR")
+                .help("Metrics listen address (overrides transport.metrics_listen_addr)")
+        )
+}
+
+/// Settings overrides from command-line flags, applied over the file and environment
+fn settings_overrides(matches: &clap::ArgMatches) -> SettingsOverrides {
+    let mut overrides = SettingsOverrides {
+        config_path: matches.get_one::<String>("config").map(Into::into),
+        ..Default::default()
+    };
+    for (flag, key) in [
+        ("log-filter", "logging.filter"),
+        ("grpc-listen-addr", "transport.grpc_listen_addr"),
+        ("metrics-listen-addr", "transport.metrics_listen_addr"),
+    ] {
+        if let Some(value) = matches.get_one::<String>(flag) {
+            overrides.set(key, value.as_str());
+        }
+    }
+    if let Some(explorations) = matches.get_one::<usize>("max-concurrent-explorations") {
+        overrides.set("orchestration.max_concurrent_explorations", explorations.to_string());
+    }
+    overrides
+}
+
 #[tokio::main]
 async fn main() -> Result<()> {
+    let matches = cli().get_matches();
+    
+    // Load configuration: defaults < config file < HUGURE__* environment < flags
+    let settings = HugureSettings::load(&settings_overrides(&matches))?;
+    
     // Initialize tracing
     tracing_subscriber::fmt()
-        .with_env_filter("hugure=info")
+        .with_env_filter(settings.logging.filter.as_str())
         .init();
 
     info!("Initializing Hugure: Virtual BMD Orchestration Framework");
 
-    // Load configuration
-    let config = HugureConfig::default();
-    info!("Target exploration rate: {} BMDs/second", config.exploration_rate);
-    info!("Amplification depth: {}", config.amplification_depth);
+    let config = HugureConfig::from_settings(settings)?;
+    info!("Target exploration rate: {} BMDs/second", config.exploration_rate_target);
+    info!("Max recursion depth: {}", config.max_recursion_depth);
     info!("Emergence threshold: {}", config.emergence_threshold);
 
     // Initialize Hugure system
+    #[cfg(feature = "grpc")]
+    let grpc_addr: std::net::SocketAddr = config.grpc_listen_addr.parse()?;
+    #[cfg(feature = "prometheus")]
+    let metrics_addr: std::net::SocketAddr = config.metrics_listen_addr.parse()?;
+    #[cfg(feature = "prometheus")]
+    let metrics = Arc::new(hugure::metrics::prometheus::PrometheusMetrics::new()?);
     let hugure = match Hugure::new(config).await {
         Ok(system) => {
             info!("Hugure system initialized successfully");
-            system
+            #[cfg(feature = "prometheus")]
+            let system = system.with_metrics(metrics.clone());
+            Arc::new(system)
         },
         Err(e) => {
             error!("Failed to initialize Hugure: {}", e);
@@ -54,12 +129,39 @@ async fn main() -> Result<()> {
         }
     }
 
+    // Accept communication requests from non-Rust systems
+    #[cfg(feature = "grpc")]
+    {
+        let system = Arc::clone(&hugure);
+        tokio::spawn(async move {
+            if let Err(e) = hugure::grpc::serve(system, grpc_addr).await {
+                error!("gRPC service stopped: {}", e);
+            }
+        });
+        info!("Communication gRPC service listening on {}", grpc_addr);
+    }
+
+    // Expose orchestration metrics for Prometheus scraping
+    #[cfg(feature = "prometheus")]
+    {
+        let shutdown = hugure.shutdown_handle();
+        tokio::spawn(async move {
+            if let Err(e) = hugure::metrics::prometheus::serve(metrics, metrics_addr, shutdown).await {
+                error!("Metrics endpoint stopped: {}", e);
+            }
+        });
+        info!("Prometheus metrics available at http://{}/metrics", metrics_addr);
+    }
+
     info!("Hugure communication orchestrator ready for BMD exploration");
     info!("System now available for Virtual BMD Foundry coordination");
 
-    // Keep the system running for neural task coordination
+    // Keep the system running for neural task coordination until interrupted
     loop {
-        tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => break,
+            _ = tokio::time::sleep(tokio::time::Duration::from_secs(1)) => {}
+        }
         
         // Periodic health check
         if let Ok(metrics) = hugure.get_performance_metrics().await {
@@ -68,4 +170,43 @@ async fn main() -> Result<()> {
             }
         }
     }
-} 
\ No newline at end of file
+
+    info!("Shutdown requested, draining in-flight work");
+    let report = hugure.shutdown().await?;
+    info!(
+        "Hugure stopped in {:?} ({} requests drained, graceful: {})",
+        report.elapsed, report.drained_requests, report.completed_within_grace_period
+    );
+    
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_flags_override_settings() {
+        cli().debug_assert();
+        
+        let matches = cli().try_get_matches_from([
+            "hugure",
+            "--config",
+            "custom.toml",
+            "--max-concurrent-explorations",
+            "64",
+            "--log-filter",
+            "orchestration=debug",
+        ]).unwrap();
+        let overrides = settings_overrides(&matches);
+        assert_eq!(overrides.config_path, Some("custom.toml".into()));
+        assert!(overrides.values.contains(&(
+            "orchestration.max_concurrent_explorations".to_string(),
+            "64".to_string()
+        )));
+        assert!(overrides.values.contains(&(
+            "logging.filter".to_string(),
+            "orchestration=debug".to_string()
+        )));
+    }
+}
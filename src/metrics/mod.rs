@@ -0,0 +1,55 @@
+//! # Orchestration Metrics
+//! 
+//! [`OrchestrationMetrics`] is the hook through which the orchestration loop reports
+//! explorations, emerged patterns, optimization latency, channel lag, the
+//! exploration rate budget, and the exploration concurrency limit. The
+//! default [`NoopMetrics`] discards everything; with the `prometheus` feature,
+//! [`prometheus::PrometheusMetrics`] exports them for scraping on `/metrics`.
+
+use std::fmt;
+use std::time::Duration;
+
+#[cfg(feature = "prometheus")]
+pub mod prometheus;
+
+/// Hook notified of orchestration activity
+pub trait OrchestrationMetrics: Send + Sync + fmt::Debug {
+    /// An exploration cycle finished, successfully or not
+    fn record_exploration(&self, success: bool);
+    
+    /// Patterns emerged from one cycle
+    fn record_emerged_patterns(&self, count: usize);
+    
+    /// Time spent in bidirectional optimization for one cycle
+    fn observe_optimization_latency(&self, latency: Duration);
+    
+    /// Messages waiting in the Kambuzuma channel
+    fn set_kambuzuma_channel_lag(&self, pending: usize);
+    
+    /// Selections waiting in the exploration queue
+    fn set_exploration_queue_depth(&self, depth: usize);
+    
+    /// Configured exploration rate budget (per second)
+    fn set_exploration_rate_target(&self, per_second: f64);
+    
+    /// An exploration cycle waited for the rate budget to refill
+    fn record_exploration_throttled(&self);
+    
+    /// Orchestration cycles allowed to run at once
+    fn set_exploration_concurrency_limit(&self, limit: usize);
+}
+
+/// Metrics hook that discards all events
+#[derive(Debug, Default)]
+pub struct NoopMetrics;
+
+impl OrchestrationMetrics for NoopMetrics {
+    fn record_exploration(&self, _success: bool) {}
+    fn record_emerged_patterns(&self, _count: usize) {}
+    fn observe_optimization_latency(&self, _latency: Duration) {}
+    fn set_kambuzuma_channel_lag(&self, _pending: usize) {}
+    fn set_exploration_queue_depth(&self, _depth: usize) {}
+    fn set_exploration_rate_target(&self, _per_second: f64) {}
+    fn record_exploration_throttled(&self) {}
+    fn set_exploration_concurrency_limit(&self, _limit: usize) {}
+}
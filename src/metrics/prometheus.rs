@@ -0,0 +1,225 @@
+//! Prometheus exporter for orchestration metrics
+//! 
+//! Enabled with the `prometheus` feature. Mount [`router`] into an existing axum
+//! application or run [`serve`] for a standalone `/metrics` endpoint.
+
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use anyhow::Result;
+use axum::extract::State;
+use axum::http::{header, StatusCode};
+use axum::response::IntoResponse;
+use axum::routing::get;
+use axum::Router;
+use prometheus::{
+    Encoder, Gauge, Histogram, HistogramOpts, IntCounter, IntCounterVec, IntGauge, Opts,
+    Registry, TextEncoder,
+};
+use tracing::info;
+
+use crate::shutdown::ShutdownHandle;
+use super::OrchestrationMetrics;
+
+/// Window over which the exploration rate gauge is averaged
+const RATE_WINDOW: Duration = Duration::from_secs(1);
+
+/// Orchestration metrics registered in a Prometheus registry
+#[derive(Debug)]
+pub struct PrometheusMetrics {
+    registry: Registry,
+    explorations: IntCounterVec,
+    exploration_rate: Gauge,
+    exploration_rate_target: Gauge,
+    explorations_throttled: IntCounter,
+    emerged_patterns: IntCounter,
+    optimization_latency: Histogram,
+    kambuzuma_channel_lag: IntGauge,
+    exploration_queue_depth: IntGauge,
+    exploration_concurrency_limit: IntGauge,
+    rate_window: Mutex<(Instant, u64)>,
+}
+
+impl PrometheusMetrics {
+    /// Create metrics in a fresh registry
+    pub fn new() -> Result<Self> {
+        Self::with_registry(Registry::new_custom(Some("hugure".to_string()), None)?)
+    }
+    
+    /// Create metrics in an existing registry, e.g. one shared with other services
+    pub fn with_registry(registry: Registry) -> Result<Self> {
+        let explorations = IntCounterVec::new(
+            Opts::new("explorations_total", "Completed exploration cycles by outcome"),
+            &["outcome"],
+        )?;
+        let exploration_rate = Gauge::new(
+            "exploration_rate",
+            "Exploration cycles per second over the last window",
+        )?;
+        let exploration_rate_target = Gauge::new(
+            "exploration_rate_target",
+            "Exploration cycles per second allowed by the rate budget",
+        )?;
+        let explorations_throttled = IntCounter::new(
+            "explorations_throttled_total",
+            "Exploration cycles that waited for the rate budget to refill",
+        )?;
+        let emerged_patterns = IntCounter::new(
+            "emerged_patterns_total",
+            "Patterns detected by statistical emergence",
+        )?;
+        let optimization_latency = Histogram::with_opts(
+            HistogramOpts::new(
+                "optimization_latency_seconds",
+                "Time spent in bidirectional optimization per cycle",
+            )
+            .buckets(prometheus::exponential_buckets(0.0001, 2.0, 16)?),
+        )?;
+        let kambuzuma_channel_lag = IntGauge::new(
+            "kambuzuma_channel_lag",
+            "Messages waiting in the Kambuzuma channel",
+        )?;
+        let exploration_queue_depth = IntGauge::new(
+            "exploration_queue_depth",
+            "Foundry selections waiting for exploration",
+        )?;
+        let exploration_concurrency_limit = IntGauge::new(
+            "exploration_concurrency_limit",
+            "Orchestration cycles allowed to run at once",
+        )?;
+        
+        registry.register(Box::new(explorations.clone()))?;
+        registry.register(Box::new(exploration_rate.clone()))?;
+        registry.register(Box::new(exploration_rate_target.clone()))?;
+        registry.register(Box::new(explorations_throttled.clone()))?;
+        registry.register(Box::new(emerged_patterns.clone()))?;
+        registry.register(Box::new(optimization_latency.clone()))?;
+        registry.register(Box::new(kambuzuma_channel_lag.clone()))?;
+        registry.register(Box::new(exploration_queue_depth.clone()))?;
+        registry.register(Box::new(exploration_concurrency_limit.clone()))?;
+        
+        Ok(Self {
+            registry,
+            explorations,
+            exploration_rate,
+            exploration_rate_target,
+            explorations_throttled,
+            emerged_patterns,
+            optimization_latency,
+            kambuzuma_channel_lag,
+            exploration_queue_depth,
+            exploration_concurrency_limit,
+            rate_window: Mutex::new((Instant::now(), 0)),
+        })
+    }
+    
+    /// Registry holding these metrics
+    pub fn registry(&self) -> &Registry {
+        &self.registry
+    }
+    
+    /// Current metrics in the Prometheus text exposition format
+    pub fn render(&self) -> Result<String> {
+        let mut buffer = Vec::new();
+        TextEncoder::new().encode(&self.registry.gather(), &mut buffer)?;
+        Ok(String::from_utf8(buffer)?)
+    }
+}
+
+impl OrchestrationMetrics for PrometheusMetrics {
+    fn record_exploration(&self, success: bool) {
+        let outcome = if success { "success" } else { "failure" };
+        self.explorations.with_label_values(&[outcome]).inc();
+        
+        let mut window = self.rate_window.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        window.1 += 1;
+        let elapsed = window.0.elapsed();
+        if elapsed >= RATE_WINDOW {
+            self.exploration_rate.set(window.1 as f64 / elapsed.as_secs_f64());
+            *window = (Instant::now(), 0);
+        }
+    }
+    
+    fn record_emerged_patterns(&self, count: usize) {
+        self.emerged_patterns.inc_by(count as u64);
+    }
+    
+    fn observe_optimization_latency(&self, latency: Duration) {
+        self.optimization_latency.observe(latency.as_secs_f64());
+    }
+    
+    fn set_kambuzuma_channel_lag(&self, pending: usize) {
+        self.kambuzuma_channel_lag.set(pending as i64);
+    }
+    
+    fn set_exploration_queue_depth(&self, depth: usize) {
+        self.exploration_queue_depth.set(depth as i64);
+    }
+    
+    fn set_exploration_rate_target(&self, per_second: f64) {
+        self.exploration_rate_target.set(per_second);
+    }
+    
+    fn record_exploration_throttled(&self) {
+        self.explorations_throttled.inc();
+    }
+    
+    fn set_exploration_concurrency_limit(&self, limit: usize) {
+        self.exploration_concurrency_limit.set(limit as i64);
+    }
+}
+
+/// Router serving `GET /metrics`
+pub fn router(metrics: Arc<PrometheusMetrics>) -> Router {
+    Router::new()
+        .route("/metrics", get(render_metrics))
+        .with_state(metrics)
+}
+
+/// Serve `/metrics` on `addr` until shutdown is requested
+pub async fn serve(
+    metrics: Arc<PrometheusMetrics>,
+    addr: SocketAddr,
+    shutdown: ShutdownHandle,
+) -> Result<()> {
+    let listener = tokio::net::TcpListener::bind(addr).await?;
+    info!(target: "orchestration", %addr, "serving Prometheus metrics");
+    
+    axum::serve(listener, router(metrics))
+        .with_graceful_shutdown(async move { shutdown.cancelled().await })
+        .await?;
+    
+    Ok(())
+}
+
+async fn render_metrics(State(metrics): State<Arc<PrometheusMetrics>>) -> impl IntoResponse {
+    match metrics.render() {
+        Ok(body) => (
+            StatusCode::OK,
+            [(header::CONTENT_TYPE, prometheus::TEXT_FORMAT)],
+            body,
+        ).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_render_exposes_recorded_values() {
+        let metrics = PrometheusMetrics::new().unwrap();
+        metrics.record_exploration(true);
+        metrics.record_exploration(false);
+        metrics.record_emerged_patterns(3);
+        metrics.observe_optimization_latency(Duration::from_millis(2));
+        metrics.set_kambuzuma_channel_lag(7);
+        
+        let body = metrics.render().unwrap();
+        assert!(body.contains("hugure_explorations_total{outcome=\"success\"} 1"));
+        assert!(body.contains("hugure_emerged_patterns_total 3"));
+        assert!(body.contains("hugure_kambuzuma_channel_lag 7"));
+        assert!(body.contains("hugure_optimization_latency_seconds_count 1"));
+    }
+}
@@ -0,0 +1,235 @@
+//! # Result Persistence
+//! 
+//! [`ResultStore`] journals the exploration results and emerged patterns of every
+//! orchestration cycle to an embedded sled database, so results survive restarts
+//! and can be analyzed offline. Records are indexed by time and by the ids of the
+//! BMDs they involve.
+//! 
+//! Enabled with the `persistence` feature.
+
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+/// Journal entry for one orchestration cycle
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CycleRecord {
+    /// Unique cycle identifier
+    pub cycle_id: Uuid,
+    
+    /// Completion time, nanoseconds since the Unix epoch
+    pub recorded_at_ns: u64,
+    
+    /// BMDs that took part in the cycle
+    pub bmd_ids: Vec<Uuid>,
+    
+    /// Serialized exploration results
+    pub exploration_results: Value,
+    
+    /// Serialized emerged patterns
+    pub emerged_patterns: Value,
+}
+
+impl CycleRecord {
+    /// Build a record stamped with the current time
+    pub fn new(exploration_results: Value, emerged_patterns: Value) -> Self {
+        let mut bmd_ids = Vec::new();
+        collect_bmd_ids(&exploration_results, &mut bmd_ids);
+        bmd_ids.sort_unstable();
+        bmd_ids.dedup();
+        
+        Self {
+            cycle_id: Uuid::new_v4(),
+            recorded_at_ns: unix_nanos(SystemTime::now()),
+            bmd_ids,
+            exploration_results,
+            emerged_patterns,
+        }
+    }
+}
+
+/// Embedded store of orchestration cycle records
+#[derive(Debug, Clone)]
+pub struct ResultStore {
+    db: sled::Db,
+    /// `recorded_at_ns ‖ cycle_id` → record
+    cycles: sled::Tree,
+    /// `bmd_id ‖ recorded_at_ns ‖ cycle_id` → empty
+    bmd_index: sled::Tree,
+}
+
+impl ResultStore {
+    /// Open or create a store at `path`
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        Self::from_db(sled::open(path)?)
+    }
+    
+    /// Store backed by a temporary directory, deleted on drop
+    pub fn temporary() -> Result<Self> {
+        Self::from_db(sled::Config::new().temporary(true).open()?)
+    }
+    
+    fn from_db(db: sled::Db) -> Result<Self> {
+        Ok(Self {
+            cycles: db.open_tree("cycles")?,
+            bmd_index: db.open_tree("bmd_index")?,
+            db,
+        })
+    }
+    
+    /// Journal one cycle's results
+    pub fn record_cycle<E: Serialize, P: Serialize>(
+        &self,
+        exploration_results: &E,
+        emerged_patterns: &P,
+    ) -> Result<CycleRecord> {
+        let record = CycleRecord::new(
+            serde_json::to_value(exploration_results)?,
+            serde_json::to_value(emerged_patterns)?,
+        );
+        self.insert(&record)?;
+        Ok(record)
+    }
+    
+    /// Store a record and index it by its BMD ids
+    pub fn insert(&self, record: &CycleRecord) -> Result<()> {
+        let key = cycle_key(record.recorded_at_ns, record.cycle_id);
+        self.cycles.insert(&key, serde_json::to_vec(record)?)?;
+        
+        for bmd_id in &record.bmd_ids {
+            let mut index_key = bmd_id.as_bytes().to_vec();
+            index_key.extend_from_slice(&key);
+            self.bmd_index.insert(index_key, Vec::new())?;
+        }
+        Ok(())
+    }
+    
+    /// Records completed in `[from, to)`, oldest first
+    pub fn cycles_between(&self, from: SystemTime, to: SystemTime) -> Result<Vec<CycleRecord>> {
+        let start = unix_nanos(from).to_be_bytes();
+        let end = unix_nanos(to).to_be_bytes();
+        
+        self.cycles.range(start..end)
+            .map(|entry| Ok(serde_json::from_slice(&entry?.1)?))
+            .collect()
+    }
+    
+    /// Records involving `bmd_id`, oldest first
+    pub fn cycles_for_bmd(&self, bmd_id: Uuid) -> Result<Vec<CycleRecord>> {
+        let mut records = Vec::new();
+        for entry in self.bmd_index.scan_prefix(bmd_id.as_bytes()) {
+            let (index_key, _) = entry?;
+            if let Some(record) = self.cycles.get(&index_key[16..])? {
+                records.push(serde_json::from_slice(&record)?);
+            }
+        }
+        Ok(records)
+    }
+    
+    /// Delete records completed more than `max_age` ago; returns how many were removed
+    pub fn prune_older_than(&self, max_age: Duration) -> Result<usize> {
+        let cutoff = unix_nanos(SystemTime::now().checked_sub(max_age).unwrap_or(UNIX_EPOCH));
+        let mut removed = 0;
+        
+        for entry in self.cycles.range(..cutoff.to_be_bytes()) {
+            let (key, value) = entry?;
+            let record: CycleRecord = serde_json::from_slice(&value)?;
+            for bmd_id in &record.bmd_ids {
+                let mut index_key = bmd_id.as_bytes().to_vec();
+                index_key.extend_from_slice(&key);
+                self.bmd_index.remove(index_key)?;
+            }
+            self.cycles.remove(key)?;
+            removed += 1;
+        }
+        Ok(removed)
+    }
+    
+    /// Number of stored records
+    pub fn len(&self) -> usize {
+        self.cycles.len()
+    }
+    
+    /// Whether the store holds no records
+    pub fn is_empty(&self) -> bool {
+        self.cycles.is_empty()
+    }
+    
+    /// Persist buffered writes to disk
+    pub async fn flush(&self) -> Result<()> {
+        self.db.flush_async().await?;
+        Ok(())
+    }
+}
+
+fn cycle_key(recorded_at_ns: u64, cycle_id: Uuid) -> Vec<u8> {
+    let mut key = recorded_at_ns.to_be_bytes().to_vec();
+    key.extend_from_slice(cycle_id.as_bytes());
+    key
+}
+
+fn unix_nanos(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH).map(|d| d.as_nanos() as u64).unwrap_or(0)
+}
+
+/// Collect ids of serialized [`crate::bmd::BMD`]s, recognised by an `id` next to a `pattern`
+fn collect_bmd_ids(value: &Value, ids: &mut Vec<Uuid>) {
+    match value {
+        Value::Object(fields) => {
+            if fields.contains_key("pattern") {
+                let id = fields.get("id").and_then(Value::as_str).and_then(|id| id.parse::<Uuid>().ok());
+                ids.extend(id);
+            }
+            fields.values().for_each(|field| collect_bmd_ids(field, ids));
+        }
+        Value::Array(items) => items.iter().for_each(|item| collect_bmd_ids(item, ids)),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    
+    fn exploration(bmd_ids: &[Uuid]) -> Value {
+        let bmds: Vec<Value> = bmd_ids.iter()
+            .map(|id| json!({ "id": id, "pattern": { "semantic_opacity": 0.5 } }))
+            .collect();
+        json!({ "combinations": [{ "bmds": bmds, "fidelity": 0.99 }] })
+    }
+    
+    #[test]
+    fn test_query_by_bmd_id() {
+        let store = ResultStore::temporary().unwrap();
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+        
+        store.record_cycle(&exploration(&[a, b]), &json!([])).unwrap();
+        store.record_cycle(&exploration(&[b]), &json!([{ "significance": 0.9997 }])).unwrap();
+        
+        assert_eq!(store.len(), 2);
+        assert_eq!(store.cycles_for_bmd(a).unwrap().len(), 1);
+        assert_eq!(store.cycles_for_bmd(b).unwrap().len(), 2);
+        assert!(store.cycles_for_bmd(Uuid::new_v4()).unwrap().is_empty());
+    }
+    
+    #[test]
+    fn test_query_by_time_range_and_prune() {
+        let store = ResultStore::temporary().unwrap();
+        let mut old = CycleRecord::new(exploration(&[Uuid::new_v4()]), json!([]));
+        old.recorded_at_ns = unix_nanos(SystemTime::now() - Duration::from_secs(7200));
+        store.insert(&old).unwrap();
+        store.record_cycle(&exploration(&[]), &json!([])).unwrap();
+        
+        let hour_ago = SystemTime::now() - Duration::from_secs(3600);
+        let until = SystemTime::now() + Duration::from_secs(1);
+        assert_eq!(store.cycles_between(hour_ago, until).unwrap().len(), 1);
+        
+        assert_eq!(store.prune_older_than(Duration::from_secs(3600)).unwrap(), 1);
+        assert_eq!(store.len(), 1);
+        assert!(store.cycles_for_bmd(old.bmd_ids[0]).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,380 @@
+//! # Plugin Registry
+//! 
+//! Extension points for Hugure's pluggable algorithms. Implementations of
+//! [`BidirectionalOptimizer`], [`EmergenceDetector`], [`FidelityPredictor`], and
+//! [`InformationDeficitEstimator`] are registered by name at startup and selected
+//! through [`PluginSelection`] in the system configuration, so new algorithms can be
+//! added without patching the crate.
+//! 
+//! With the `dynamic-plugins` feature, additional implementations can be loaded
+//! from shared libraries exporting a `hugure_register_plugins` function (see
+//! [`declare_plugins!`]). Such libraries must be built with the same compiler and
+//! Hugure version as the host.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+use async_trait::async_trait;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::bmd::{BMD, BMDPattern};
+use crate::detection::{self, BayesianRule, CusumRule, RuleDetector, ZScoreRule};
+use crate::emergence::EmergedPattern;
+use crate::optimization::{OptimizationCoordinator, OptimizationResults};
+use crate::orchestration::ExplorationResults;
+
+/// Name under which the built-in implementations are registered
+pub const DEFAULT_PLUGIN: &str = "default";
+
+/// Bidirectional pattern optimization over exploration results
+#[async_trait]
+pub trait BidirectionalOptimizer: Send + Sync + fmt::Debug {
+    /// Optimize exploration results in both communication directions
+    async fn optimize_bidirectional(&self, results: ExplorationResults) -> Result<OptimizationResults>;
+    
+    /// Warm-start data worth carrying across restarts, if the optimizer keeps any
+    fn warm_start_state(&self) -> Result<Option<serde_json::Value>> {
+        Ok(None)
+    }
+    
+    /// Resume from data previously returned by [`BidirectionalOptimizer::warm_start_state`]
+    fn restore_warm_start(&self, state: serde_json::Value) -> Result<()> {
+        let _ = state;
+        Ok(())
+    }
+}
+
+/// Statistical emergence detection over optimization results
+#[async_trait]
+pub trait EmergenceDetector: Send + Sync + fmt::Debug {
+    /// Detect patterns that emerged from the optimization results
+    async fn detect_emergence(&self, results: OptimizationResults) -> Result<Vec<EmergedPattern>>;
+}
+
+/// Transmission fidelity prediction for a BMD combination
+pub trait FidelityPredictor: Send + Sync + fmt::Debug {
+    /// Predicted fidelity in [0, 1]
+    fn predict_fidelity(&self, bmds: &[BMD]) -> f64;
+}
+
+/// Information deficit estimation between sender and recipient patterns
+pub trait InformationDeficitEstimator: Send + Sync + fmt::Debug {
+    /// Estimated deficit, 0 for identical patterns
+    fn estimate_deficit(&self, sender: &BMDPattern, recipient: &BMDPattern) -> f64;
+}
+
+#[async_trait]
+impl BidirectionalOptimizer for OptimizationCoordinator {
+    async fn optimize_bidirectional(&self, results: ExplorationResults) -> Result<OptimizationResults> {
+        OptimizationCoordinator::optimize_bidirectional(self, results).await
+    }
+}
+
+#[async_trait]
+impl EmergenceDetector for OptimizationCoordinator {
+    async fn detect_emergence(&self, results: OptimizationResults) -> Result<Vec<EmergedPattern>> {
+        self.detect_statistical_emergence(results).await
+    }
+}
+
+/// Default predictor: mean foundry-reported transmission fidelity
+#[derive(Debug, Default)]
+pub struct FoundryQualityPredictor;
+
+impl FidelityPredictor for FoundryQualityPredictor {
+    fn predict_fidelity(&self, bmds: &[BMD]) -> f64 {
+        if bmds.is_empty() {
+            return 0.0;
+        }
+        let total: f64 = bmds.iter()
+            .map(|bmd| bmd.foundry_source.quality_metrics.transmission_fidelity)
+            .sum();
+        (total / bmds.len() as f64).clamp(0.0, 1.0)
+    }
+}
+
+/// Default estimator: normalized distance between core pattern vectors
+#[derive(Debug, Default)]
+pub struct PatternDistanceEstimator;
+
+impl InformationDeficitEstimator for PatternDistanceEstimator {
+    fn estimate_deficit(&self, sender: &BMDPattern, recipient: &BMDPattern) -> f64 {
+        let len = sender.core_vectors.len().max(recipient.core_vectors.len());
+        if len == 0 {
+            return 0.0;
+        }
+        let component = |v: &[f64], i: usize| v.get(i).copied().unwrap_or(0.0);
+        let squared: f64 = (0..len)
+            .map(|i| (component(&sender.core_vectors, i) - component(&recipient.core_vectors, i)).powi(2))
+            .sum();
+        (squared / len as f64).sqrt()
+    }
+}
+
+/// Names of the plugins to use for each extension point
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PluginSelection {
+    /// Bidirectional optimizer
+    pub optimizer: String,
+    /// Emergence detector
+    pub emergence_detector: String,
+    /// Fidelity predictor
+    pub fidelity_predictor: String,
+    /// Information deficit estimator
+    pub information_deficit_estimator: String,
+}
+
+impl Default for PluginSelection {
+    fn default() -> Self {
+        Self {
+            optimizer: DEFAULT_PLUGIN.to_string(),
+            emergence_detector: DEFAULT_PLUGIN.to_string(),
+            fidelity_predictor: DEFAULT_PLUGIN.to_string(),
+            information_deficit_estimator: DEFAULT_PLUGIN.to_string(),
+        }
+    }
+}
+
+/// The plugin chosen for each extension point
+#[derive(Debug, Clone)]
+pub struct SelectedPlugins {
+    /// Bidirectional optimizer
+    pub optimizer: Arc<dyn BidirectionalOptimizer>,
+    /// Emergence detector
+    pub emergence_detector: Arc<dyn EmergenceDetector>,
+    /// Fidelity predictor
+    pub fidelity_predictor: Arc<dyn FidelityPredictor>,
+    /// Information deficit estimator
+    pub information_deficit_estimator: Arc<dyn InformationDeficitEstimator>,
+}
+
+/// Named implementations of every extension point
+#[derive(Default)]
+pub struct PluginRegistry {
+    optimizers: HashMap<String, Arc<dyn BidirectionalOptimizer>>,
+    emergence_detectors: HashMap<String, Arc<dyn EmergenceDetector>>,
+    fidelity_predictors: HashMap<String, Arc<dyn FidelityPredictor>>,
+    deficit_estimators: HashMap<String, Arc<dyn InformationDeficitEstimator>>,
+    #[cfg(feature = "dynamic-plugins")]
+    libraries: Vec<libloading::Library>,
+}
+
+impl fmt::Debug for PluginRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PluginRegistry")
+            .field("optimizers", &sorted_names(&self.optimizers))
+            .field("emergence_detectors", &sorted_names(&self.emergence_detectors))
+            .field("fidelity_predictors", &sorted_names(&self.fidelity_predictors))
+            .field("deficit_estimators", &sorted_names(&self.deficit_estimators))
+            .finish()
+    }
+}
+
+fn sorted_names<T: ?Sized>(plugins: &HashMap<String, Arc<T>>) -> Vec<&str> {
+    let mut names: Vec<&str> = plugins.keys().map(String::as_str).collect();
+    names.sort_unstable();
+    names
+}
+
+fn insert_unique<T: ?Sized>(
+    plugins: &mut HashMap<String, Arc<T>>,
+    kind: &str,
+    name: String,
+    plugin: Arc<T>,
+) -> Result<()> {
+    if plugins.contains_key(&name) {
+        return Err(anyhow::anyhow!("{} plugin '{}' is already registered", kind, name));
+    }
+    plugins.insert(name, plugin);
+    Ok(())
+}
+
+fn lookup<T: ?Sized>(plugins: &HashMap<String, Arc<T>>, kind: &str, name: &str) -> Result<Arc<T>> {
+    plugins.get(name).cloned().ok_or_else(|| {
+        anyhow::anyhow!(
+            "unknown {} plugin '{}' (registered: {})",
+            kind,
+            name,
+            sorted_names(plugins).join(", ")
+        )
+    })
+}
+
+impl PluginRegistry {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Register a bidirectional optimizer under `name`
+    pub fn register_optimizer(&mut self, name: impl Into<String>, plugin: Arc<dyn BidirectionalOptimizer>) -> Result<()> {
+        insert_unique(&mut self.optimizers, "optimizer", name.into(), plugin)
+    }
+    
+    /// Register an emergence detector under `name`
+    pub fn register_emergence_detector(&mut self, name: impl Into<String>, plugin: Arc<dyn EmergenceDetector>) -> Result<()> {
+        insert_unique(&mut self.emergence_detectors, "emergence detector", name.into(), plugin)
+    }
+    
+    /// Register a fidelity predictor under `name`
+    pub fn register_fidelity_predictor(&mut self, name: impl Into<String>, plugin: Arc<dyn FidelityPredictor>) -> Result<()> {
+        insert_unique(&mut self.fidelity_predictors, "fidelity predictor", name.into(), plugin)
+    }
+    
+    /// Register an information deficit estimator under `name`
+    pub fn register_deficit_estimator(&mut self, name: impl Into<String>, plugin: Arc<dyn InformationDeficitEstimator>) -> Result<()> {
+        insert_unique(&mut self.deficit_estimators, "information deficit estimator", name.into(), plugin)
+    }
+    
+    /// Register the built-in implementations under [`DEFAULT_PLUGIN`] where no
+    /// plugin of that name exists yet
+    /// 
+    /// The alternative emergence detectors from [`crate::detection`] are registered
+    /// under their own names in the same way.
+    pub fn register_defaults(&mut self, coordinator: Arc<OptimizationCoordinator>) {
+        let optimizer: Arc<dyn BidirectionalOptimizer> = coordinator.clone();
+        let detector: Arc<dyn EmergenceDetector> = coordinator.clone();
+        
+        self.optimizers.entry(DEFAULT_PLUGIN.to_string()).or_insert(optimizer);
+        self.emergence_detectors.entry(DEFAULT_PLUGIN.to_string()).or_insert(detector);
+        self.emergence_detectors.entry(detection::ZSCORE_DETECTOR.to_string())
+            .or_insert_with(|| Arc::new(RuleDetector::new(coordinator.clone(), ZScoreRule::default())));
+        self.emergence_detectors.entry(detection::CUSUM_DETECTOR.to_string())
+            .or_insert_with(|| Arc::new(RuleDetector::new(coordinator.clone(), CusumRule::default())));
+        self.emergence_detectors.entry(detection::BAYESIAN_DETECTOR.to_string())
+            .or_insert_with(|| Arc::new(RuleDetector::new(coordinator, BayesianRule::default())));
+        self.fidelity_predictors.entry(DEFAULT_PLUGIN.to_string())
+            .or_insert_with(|| Arc::new(FoundryQualityPredictor));
+        self.deficit_estimators.entry(DEFAULT_PLUGIN.to_string())
+            .or_insert_with(|| Arc::new(PatternDistanceEstimator));
+    }
+    
+    /// Resolve the configured plugin for every extension point
+    pub fn select(&self, selection: &PluginSelection) -> Result<SelectedPlugins> {
+        Ok(SelectedPlugins {
+            optimizer: lookup(&self.optimizers, "optimizer", &selection.optimizer)?,
+            emergence_detector: lookup(&self.emergence_detectors, "emergence detector", &selection.emergence_detector)?,
+            fidelity_predictor: lookup(&self.fidelity_predictors, "fidelity predictor", &selection.fidelity_predictor)?,
+            information_deficit_estimator: lookup(
+                &self.deficit_estimators,
+                "information deficit estimator",
+                &selection.information_deficit_estimator,
+            )?,
+        })
+    }
+    
+    /// Load plugins from a shared library exporting `hugure_register_plugins`
+    /// 
+    /// # Safety
+    /// 
+    /// The library runs arbitrary code on load and must have been built with the same
+    /// compiler and Hugure version as this binary, typically via [`declare_plugins!`].
+    #[cfg(feature = "dynamic-plugins")]
+    pub unsafe fn load_library(&mut self, path: impl AsRef<std::ffi::OsStr>) -> Result<()> {
+        let library = libloading::Library::new(path.as_ref())
+            .map_err(|e| anyhow::anyhow!("failed to load plugin library {:?}: {}", path.as_ref(), e))?;
+        let register: PluginEntryPoint = *library.get::<PluginEntryPoint>(PLUGIN_ENTRY_POINT)
+            .map_err(|e| anyhow::anyhow!("plugin library {:?} has no entry point: {}", path.as_ref(), e))?;
+        // Kept even if registration fails, as plugins registered before the failure
+        // still point into the library
+        self.libraries.push(library);
+        register(self)
+    }
+}
+
+/// Signature of the entry point exported by dynamic plugin libraries
+#[cfg(feature = "dynamic-plugins")]
+pub type PluginEntryPoint = unsafe fn(&mut PluginRegistry) -> Result<()>;
+
+/// Symbol name of the dynamic plugin entry point
+#[cfg(feature = "dynamic-plugins")]
+pub const PLUGIN_ENTRY_POINT: &[u8] = b"hugure_register_plugins";
+
+/// Export a registration function from a dynamic plugin library
+/// 
+/// ```ignore
+/// hugure::declare_plugins!(|registry| {
+///     registry.register_optimizer("gradient", Arc::new(GradientOptimizer::default()))
+/// });
+/// ```
+#[cfg(feature = "dynamic-plugins")]
+#[macro_export]
+macro_rules! declare_plugins {
+    ($register:expr) => {
+        #[no_mangle]
+        pub unsafe fn hugure_register_plugins(
+            registry: &mut $crate::plugins::PluginRegistry,
+        ) -> ::anyhow::Result<()> {
+            let register: fn(&mut $crate::plugins::PluginRegistry) -> ::anyhow::Result<()> = $register;
+            register(registry)
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[derive(Debug)]
+    struct ConstantPredictor(f64);
+    
+    impl FidelityPredictor for ConstantPredictor {
+        fn predict_fidelity(&self, _bmds: &[BMD]) -> f64 {
+            self.0
+        }
+    }
+    
+    fn pattern(core_vectors: Vec<f64>) -> BMDPattern {
+        BMDPattern {
+            core_vectors,
+            cross_domain_compatibility: HashMap::new(),
+            frequency_ranges: Vec::new(),
+            semantic_opacity: 0.5,
+        }
+    }
+    
+    #[test]
+    fn test_duplicate_registration_rejected() {
+        let mut registry = PluginRegistry::new();
+        registry.register_fidelity_predictor("constant", Arc::new(ConstantPredictor(0.9))).unwrap();
+        
+        assert!(registry.register_fidelity_predictor("constant", Arc::new(ConstantPredictor(0.5))).is_err());
+    }
+    
+    #[test]
+    fn test_unknown_plugin_lists_registered_names() {
+        let mut registry = PluginRegistry::new();
+        registry.register_fidelity_predictor("constant", Arc::new(ConstantPredictor(0.9))).unwrap();
+        
+        let error = lookup(&registry.fidelity_predictors, "fidelity predictor", "missing").unwrap_err();
+        assert!(error.to_string().contains("constant"));
+    }
+    
+    #[test]
+    fn test_pattern_distance_estimator() {
+        let estimator = PatternDistanceEstimator;
+        
+        assert_eq!(estimator.estimate_deficit(&pattern(vec![1.0, 2.0]), &pattern(vec![1.0, 2.0])), 0.0);
+        assert!((estimator.estimate_deficit(&pattern(vec![0.0, 0.0]), &pattern(vec![3.0, 4.0])) - 12.5f64.sqrt()).abs() < 1e-12);
+    }
+    
+    #[cfg(feature = "dynamic-plugins")]
+    #[test]
+    fn test_load_library_registers_plugins() {
+        use std::env::consts::{DLL_PREFIX, DLL_SUFFIX};
+        
+        // `cargo test` builds examples next to the deps directory holding this binary
+        let exe = std::env::current_exe().unwrap();
+        let path = exe.parent().and_then(|deps| deps.parent()).unwrap()
+            .join("examples")
+            .join(format!("{}constant_plugin{}", DLL_PREFIX, DLL_SUFFIX));
+        
+        let mut registry = PluginRegistry::new();
+        unsafe { registry.load_library(&path) }.unwrap();
+        
+        let predictor = lookup(&registry.fidelity_predictors, "fidelity predictor", "constant").unwrap();
+        assert_eq!(predictor.predict_fidelity(&[]), 0.75);
+    }
+}
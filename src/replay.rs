@@ -0,0 +1,339 @@
+//! # Session Record and Replay
+//! 
+//! A [`SessionRecorder`] attached with [`crate::HugureSystem::with_session_recorder`]
+//! appends one [`SessionRecord`] per communication request to a JSON-lines log: the
+//! request, the foundry selection it was served, and the final response. [`replay`]
+//! re-runs a log against another build and diffs each `fidelity_prediction` against
+//! the recorded one, so optimizer changes can be regression tested on real traffic.
+//! 
+//! Build the replaying system over a [`ReplayFoundry`] to serve the recorded
+//! selections again, so differences come from exploration and optimization rather
+//! than from the foundry picking other BMDs.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{SystemTime, UNIX_EPOCH};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::bmd::BMDSelection;
+use crate::communication::CommunicationRequest;
+use crate::correlation::{self, CorrelationId};
+use crate::foundry::BMDSelectionContext;
+use crate::foundry_backend::{context_key, FoundryBackend, FoundryCapabilities};
+use crate::strategy::StrategyKind;
+use crate::HugureSystem;
+
+/// One recorded communication request
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SessionRecord {
+    /// Correlation id the request ran under
+    pub correlation_id: CorrelationId,
+    
+    /// Completion time, nanoseconds since the Unix epoch
+    pub recorded_at_ns: u64,
+    
+    /// Strategy that weighted the optimized BMDs
+    pub strategy: StrategyKind,
+    
+    /// Key of the selection context, matching the selection cache's
+    pub context_key: u64,
+    
+    /// Serialized request
+    pub request: Value,
+    
+    /// Serialized foundry selection
+    pub selection: Value,
+    
+    /// Fidelity predicted for the response
+    pub fidelity_prediction: f64,
+    
+    /// Serialized response
+    pub response: Value,
+}
+
+impl SessionRecord {
+    /// Record stamped with the current time
+    pub fn new(
+        correlation_id: CorrelationId,
+        strategy: StrategyKind,
+        context_key: u64,
+        request: Value,
+        selection: Value,
+        fidelity_prediction: f64,
+        response: Value,
+    ) -> Self {
+        Self {
+            correlation_id,
+            recorded_at_ns: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|elapsed| elapsed.as_nanos() as u64)
+                .unwrap_or(0),
+            strategy,
+            context_key,
+            request,
+            selection,
+            fidelity_prediction,
+            response,
+        }
+    }
+}
+
+/// Appends session records to a JSON-lines log
+#[derive(Debug)]
+pub struct SessionRecorder {
+    writer: Mutex<BufWriter<File>>,
+}
+
+impl SessionRecorder {
+    /// Append to the log at `path`, creating it if needed
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .with_context(|| format!("failed to open session log {}", path.display()))?;
+        Ok(Self { writer: Mutex::new(BufWriter::new(file)) })
+    }
+    
+    /// Append `record` and flush it to the log
+    pub fn record(&self, record: &SessionRecord) -> Result<()> {
+        let mut line = serde_json::to_vec(record)?;
+        line.push(b'\n');
+        
+        let mut writer = self.writer.lock().unwrap_or_else(PoisonError::into_inner);
+        writer.write_all(&line)?;
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+/// Read every record of the log at `path`
+pub fn read_session_log(path: impl AsRef<Path>) -> Result<Vec<SessionRecord>> {
+    let path = path.as_ref();
+    let file = File::open(path)
+        .with_context(|| format!("failed to open session log {}", path.display()))?;
+    
+    let mut records = Vec::new();
+    for (index, line) in BufReader::new(file).lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let record = serde_json::from_str(&line).with_context(|| {
+            format!("invalid record on line {} of {}", index + 1, path.display())
+        })?;
+        records.push(record);
+    }
+    Ok(records)
+}
+
+/// Foundry serving recorded selections for recorded contexts
+/// 
+/// Contexts missing from the log, and exploration selections, go to `inner`.
+#[derive(Debug)]
+pub struct ReplayFoundry {
+    inner: Arc<dyn FoundryBackend>,
+    selections: HashMap<u64, BMDSelection>,
+}
+
+impl ReplayFoundry {
+    /// Serve the selections of `records`, falling back to `inner`
+    pub fn new(inner: Arc<dyn FoundryBackend>, records: &[SessionRecord]) -> Result<Self> {
+        let selections = records.iter()
+            .map(|record| {
+                let selection = serde_json::from_value(record.selection.clone()).with_context(|| {
+                    format!("invalid selection in record {}", record.correlation_id)
+                })?;
+                Ok((record.context_key, selection))
+            })
+            .collect::<Result<_>>()?;
+        Ok(Self { inner, selections })
+    }
+}
+
+#[async_trait]
+impl FoundryBackend for ReplayFoundry {
+    async fn select_bmds_for_exploration(&self) -> Result<BMDSelection> {
+        self.inner.select_bmds_for_exploration().await
+    }
+    
+    async fn select_bmds_with_context(&self, context: BMDSelectionContext) -> Result<BMDSelection> {
+        match self.selections.get(&context_key(&context)?) {
+            Some(selection) => Ok(selection.clone()),
+            None => self.inner.select_bmds_with_context(context).await,
+        }
+    }
+    
+    fn capabilities(&self) -> FoundryCapabilities {
+        FoundryCapabilities {
+            name: format!("replay({})", self.inner.capabilities().name),
+            ..self.inner.capabilities()
+        }
+    }
+    
+    async fn health_check(&self) -> Result<()> {
+        self.inner.health_check().await
+    }
+}
+
+/// Recorded versus replayed fidelity of one request
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FidelityDiff {
+    /// Correlation id of the recorded request, reused by the replay
+    pub correlation_id: CorrelationId,
+    
+    /// Fidelity predicted when recorded
+    pub recorded: f64,
+    
+    /// Fidelity predicted on replay
+    pub replayed: f64,
+}
+
+impl FidelityDiff {
+    /// Replayed minus recorded fidelity
+    pub fn delta(&self) -> f64 {
+        self.replayed - self.recorded
+    }
+}
+
+/// Request that could not be replayed
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReplayFailure {
+    /// Correlation id of the recorded request
+    pub correlation_id: CorrelationId,
+    
+    /// Why the replay failed
+    pub error: String,
+}
+
+/// Result of replaying a session log
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReplayReport {
+    /// Fidelity of every replayed request
+    pub diffs: Vec<FidelityDiff>,
+    
+    /// Requests that failed on replay
+    pub failures: Vec<ReplayFailure>,
+}
+
+impl ReplayReport {
+    /// Requests whose fidelity dropped by more than `tolerance`
+    pub fn regressions(&self, tolerance: f64) -> impl Iterator<Item = &FidelityDiff> {
+        self.diffs.iter().filter(move |diff| diff.delta() < -tolerance)
+    }
+    
+    /// Largest absolute fidelity change
+    pub fn max_abs_delta(&self) -> f64 {
+        self.diffs.iter().map(|diff| diff.delta().abs()).fold(0.0, f64::max)
+    }
+    
+    /// Mean fidelity change
+    pub fn mean_delta(&self) -> f64 {
+        match self.diffs.len() {
+            0 => 0.0,
+            n => self.diffs.iter().map(FidelityDiff::delta).sum::<f64>() / n as f64,
+        }
+    }
+}
+
+impl fmt::Display for ReplayReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} replayed, {} failed, mean fidelity delta {:+.6}, max |delta| {:.6}",
+            self.diffs.len(),
+            self.failures.len(),
+            self.mean_delta(),
+            self.max_abs_delta(),
+        )
+    }
+}
+
+/// Re-run `records` against `system` and diff their fidelity predictions
+/// 
+/// Each request runs under its recorded correlation id and strategy, one at a time.
+pub async fn replay(system: &HugureSystem, records: &[SessionRecord]) -> ReplayReport {
+    let mut report = ReplayReport::default();
+    for record in records {
+        let correlation_id = record.correlation_id;
+        let replayed = async {
+            let request: CommunicationRequest = serde_json::from_value(record.request.clone())
+                .context("invalid recorded request")?;
+            let run = system.handle_communication_request_with_strategy(request, record.strategy);
+            correlation::scope(correlation_id, run).await
+        };
+        match replayed.await {
+            Ok(response) => report.diffs.push(FidelityDiff {
+                correlation_id,
+                recorded: record.fidelity_prediction,
+                replayed: response.fidelity_prediction,
+            }),
+            Err(e) => report.failures.push(ReplayFailure {
+                correlation_id,
+                error: format!("{:#}", e),
+            }),
+        }
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    
+    fn scratch_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("hugure-{}-{}.jsonl", name, std::process::id()))
+    }
+    
+    fn record(fidelity_prediction: f64) -> SessionRecord {
+        SessionRecord::new(
+            CorrelationId::new(),
+            StrategyKind::default(),
+            7,
+            json!({ "intent": "inform" }),
+            json!([]),
+            fidelity_prediction,
+            json!({}),
+        )
+    }
+    
+    #[test]
+    fn test_log_round_trip() {
+        let path = scratch_path("session-log");
+        let _ = std::fs::remove_file(&path);
+        let records = vec![record(0.9), record(0.8)];
+        
+        let recorder = SessionRecorder::open(&path).unwrap();
+        for record in &records {
+            recorder.record(record).unwrap();
+        }
+        
+        assert_eq!(read_session_log(&path).unwrap(), records);
+        std::fs::remove_file(&path).unwrap();
+    }
+    
+    #[test]
+    fn test_report_flags_regressions() {
+        let diff = |recorded, replayed| FidelityDiff {
+            correlation_id: CorrelationId::new(),
+            recorded,
+            replayed,
+        };
+        let report = ReplayReport {
+            diffs: vec![diff(0.9, 0.95), diff(0.9, 0.7), diff(0.8, 0.799)],
+            failures: vec![],
+        };
+        
+        assert_eq!(report.regressions(0.01).count(), 1);
+        assert!((report.max_abs_delta() - 0.2).abs() < 1e-9);
+    }
+}
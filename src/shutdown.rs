@@ -0,0 +1,70 @@
+//! # Graceful Shutdown
+//! 
+//! [`ShutdownHandle`] lets embedding services stop a running [`crate::HugureSystem`]
+//! from anywhere: the orchestration loop finishes its current cycle, in-flight
+//! communication requests drain, and Kambuzuma is notified before the system exits.
+
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
+
+/// Cloneable handle for requesting and observing shutdown
+#[derive(Debug, Clone, Default)]
+pub struct ShutdownHandle {
+    token: CancellationToken,
+}
+
+impl ShutdownHandle {
+    /// Create a handle that has not been triggered
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Request shutdown; idempotent
+    pub fn trigger(&self) {
+        self.token.cancel();
+    }
+    
+    /// Whether shutdown has been requested
+    pub fn is_shutdown(&self) -> bool {
+        self.token.is_cancelled()
+    }
+    
+    /// Resolve once shutdown has been requested
+    pub async fn cancelled(&self) {
+        self.token.cancelled().await
+    }
+    
+    /// Underlying cancellation token, for use with `tokio::select!` in embedding code
+    pub fn token(&self) -> CancellationToken {
+        self.token.clone()
+    }
+}
+
+/// Outcome of a graceful shutdown
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShutdownReport {
+    /// Communication requests that completed while draining
+    pub drained_requests: usize,
+    /// Whether everything drained before the grace period expired
+    pub completed_within_grace_period: bool,
+    /// Time spent shutting down
+    pub elapsed: Duration,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[tokio::test]
+    async fn test_shutdown_handle_trigger() {
+        let handle = ShutdownHandle::new();
+        let observer = handle.clone();
+        assert!(!observer.is_shutdown());
+        
+        handle.trigger();
+        handle.trigger();
+        
+        observer.cancelled().await;
+        assert!(observer.is_shutdown());
+    }
+}
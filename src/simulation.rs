@@ -0,0 +1,258 @@
+//! # Dry-Run Injection Simulation
+//! 
+//! Predicts how a recipient would receive an optimized communication without
+//! transmitting it. A [`RecipientSimulator`] is built from the recipient's
+//! [`IndividualModel`] and its [`crate::bmd::ReceptionHistory`], and turns a
+//! [`CommunicationResponse`] into the [`BMDReceptionEvent`] the recipient would
+//! most likely produce. Use [`crate::HugureSystem::simulate_communication_request`]
+//! to run a request end to end in this mode.
+//! 
+//! The predicted reception quality is the response's fidelity prediction scaled by
+//! three recipient factors, each in [0, 1]:
+//! 
+//! - receptivity: posterior success rate of past receptions times their mean quality
+//! - framework affinity: how well the BMDs' cross-domain compatibility matches the
+//!   recipient's cognitive frameworks
+//! - rhythm alignment: how close rhythm and frequency injection parameters are to
+//!   the recipient's preferred rhythms
+
+use std::time::{SystemTime, UNIX_EPOCH};
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::bmd::{BMDReceptionEvent, IndividualModel, BMD};
+use crate::communication::CommunicationResponse;
+use crate::util::flatten::flatten_numeric;
+
+/// Mean quality assumed for recipients without successful receptions
+const PRIOR_RECEPTION_QUALITY: f64 = 0.5;
+
+/// Integration time assumed for recipients without successful receptions
+const PRIOR_INTEGRATION_TIME: f64 = 1.0;
+
+/// Result of a communication request run in simulation mode
+#[derive(Debug)]
+pub struct SimulatedCommunication {
+    /// Response the request would have produced
+    pub response: CommunicationResponse,
+    
+    /// Reception predicted for the recipient; nothing was transmitted
+    pub reception: BMDReceptionEvent,
+}
+
+/// Recipient model predicting reception of injected BMDs
+#[derive(Debug, Clone)]
+pub struct RecipientSimulator {
+    recipient: IndividualModel,
+}
+
+impl RecipientSimulator {
+    /// Simulate receptions by `recipient`
+    pub fn new(recipient: IndividualModel) -> Self {
+        Self { recipient }
+    }
+    
+    /// Predict the reception of a communication response
+    pub fn simulate(&self, response: &CommunicationResponse) -> BMDReceptionEvent {
+        self.simulate_injection(
+            &response.optimized_bmds,
+            &response.injection_parameters,
+            response.fidelity_prediction,
+        )
+    }
+    
+    /// Predict the reception of `bmds` injected with `injection_parameters`
+    pub fn simulate_injection<P: Serialize>(
+        &self,
+        bmds: &[BMD],
+        injection_parameters: &P,
+        fidelity_prediction: f64,
+    ) -> BMDReceptionEvent {
+        let history = &self.recipient.reception_history;
+        let successes = &history.successful_receptions;
+        
+        let quality = (fidelity_prediction.clamp(0.0, 1.0)
+            * self.receptivity()
+            * self.framework_affinity(bmds)
+            * self.rhythm_alignment(injection_parameters))
+            .clamp(0.0, 1.0);
+        
+        let mean = |field: fn(&BMDReceptionEvent) -> f64, prior: f64| match successes.len() {
+            0 => prior,
+            n => successes.iter().map(field).sum::<f64>() / n as f64,
+        };
+        // Past successes were at their own quality; scale outcomes to the predicted one
+        let past_quality = mean(|event| event.reception_quality, PRIOR_RECEPTION_QUALITY);
+        let relative = quality / past_quality.max(f64::EPSILON);
+        
+        BMDReceptionEvent {
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|elapsed| elapsed.as_secs())
+                .unwrap_or(0),
+            bmd_id: primary_bmd(bmds).map(|bmd| bmd.id).unwrap_or_else(Uuid::nil),
+            reception_quality: quality,
+            // Weaker receptions take longer to integrate
+            integration_time: mean(|event| event.integration_time, PRIOR_INTEGRATION_TIME)
+                / relative.max(0.1),
+            emotional_impact: mean(|event| event.emotional_impact, 0.0) * relative,
+            behavioral_change: mean(|event| event.behavioral_change, 0.0) * relative,
+        }
+    }
+    
+    /// Posterior success rate of past receptions, times their mean quality
+    fn receptivity(&self) -> f64 {
+        let history = &self.recipient.reception_history;
+        let successes = history.successful_receptions.len() as f64;
+        let failures = history.failed_attempts.len() as f64;
+        // Beta(1, 1) prior, so a recipient without history is neither trusted nor written off
+        let success_rate = (successes + 1.0) / (successes + failures + 2.0);
+        
+        let quality = match history.successful_receptions.len() {
+            0 => PRIOR_RECEPTION_QUALITY,
+            n => {
+                history.successful_receptions.iter()
+                    .map(|event| event.reception_quality.clamp(0.0, 1.0))
+                    .sum::<f64>() / n as f64
+            }
+        };
+        success_rate * quality
+    }
+    
+    /// Strength-weighted compatibility of the BMDs with the recipient's frameworks
+    fn framework_affinity(&self, bmds: &[BMD]) -> f64 {
+        let frameworks = &self.recipient.cognitive_frameworks;
+        let total_strength: f64 = frameworks.iter()
+            .map(|framework| framework.strength.max(0.0))
+            .sum();
+        if bmds.is_empty() || total_strength <= 0.0 {
+            return 1.0;
+        }
+        
+        let affinity = |bmd: &BMD| {
+            frameworks.iter()
+                .map(|framework| {
+                    let compatibility = bmd.pattern.cross_domain_compatibility
+                        .get(framework.category.as_str())
+                        .copied()
+                        .unwrap_or(PRIOR_RECEPTION_QUALITY);
+                    framework.strength.max(0.0) * compatibility.clamp(0.0, 1.0)
+                })
+                .sum::<f64>() / total_strength
+        };
+        
+        let weights: Vec<f64> = bmds.iter()
+            .map(|bmd| bmd.frame_weights.selection_probability.unwrap_or(1.0).max(0.0))
+            .collect();
+        let total_weight: f64 = weights.iter().sum();
+        if total_weight <= 0.0 {
+            return bmds.iter().map(affinity).sum::<f64>() / bmds.len() as f64;
+        }
+        bmds.iter()
+            .zip(&weights)
+            .map(|(bmd, weight)| weight * affinity(bmd))
+            .sum::<f64>() / total_weight
+    }
+    
+    /// Closeness of rhythm-like injection parameters to the preferred rhythms
+    /// 
+    /// Parameters whose path ends in `rhythm` or `frequency` are compared on a log
+    /// scale with the nearest preferred rhythm; a factor-of-e mismatch scores 1/e.
+    fn rhythm_alignment<P: Serialize>(&self, injection_parameters: &P) -> f64 {
+        let preferred: Vec<f64> = self.recipient.temporal_preferences.preferred_rhythms.iter()
+            .copied()
+            .filter(|rhythm| *rhythm > 0.0)
+            .collect();
+        let rhythms: Vec<f64> = flatten_numeric(injection_parameters).into_iter()
+            .filter(|(path, value)| {
+                let name = path.rsplit('.').next().unwrap_or(path).to_ascii_lowercase();
+                *value > 0.0 && (name.ends_with("rhythm") || name.ends_with("frequency"))
+            })
+            .map(|(_, value)| value)
+            .collect();
+        if preferred.is_empty() || rhythms.is_empty() {
+            return 1.0;
+        }
+        
+        let alignment = |rhythm: f64| {
+            preferred.iter()
+                .map(|target| (-(rhythm / target).ln().abs()).exp())
+                .fold(0.0, f64::max)
+        };
+        rhythms.iter().map(|&rhythm| alignment(rhythm)).sum::<f64>() / rhythms.len() as f64
+    }
+}
+
+/// BMD most likely to be selected, or the first when none is weighted
+fn primary_bmd(bmds: &[BMD]) -> Option<&BMD> {
+    bmds.iter().max_by(|a, b| {
+        let probability = |bmd: &BMD| bmd.frame_weights.selection_probability.unwrap_or(0.0);
+        probability(a).total_cmp(&probability(b))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use crate::bmd::{DecisionTimingProfile, ReceptionHistory, TemporalPreferences};
+    
+    fn reception(quality: f64) -> BMDReceptionEvent {
+        BMDReceptionEvent {
+            timestamp: 0,
+            bmd_id: Uuid::nil(),
+            reception_quality: quality,
+            integration_time: 2.0,
+            emotional_impact: 0.4,
+            behavioral_change: 0.2,
+        }
+    }
+    
+    fn recipient(successes: usize, failures: usize, rhythms: Vec<f64>) -> IndividualModel {
+        IndividualModel {
+            individual_id: "recipient".to_string(),
+            cognitive_frameworks: vec![],
+            emotional_patterns: vec![],
+            temporal_preferences: TemporalPreferences {
+                preferred_rhythms: rhythms,
+                attention_patterns: vec![],
+                decision_timing: DecisionTimingProfile {
+                    deliberation_time: 1.0,
+                    choice_expansion_preference: 1.0,
+                    temporal_binding_strength: 1.0,
+                    agency_attribution_timing: 1.0,
+                },
+            },
+            reception_history: ReceptionHistory {
+                successful_receptions: (0..successes).map(|_| reception(0.9)).collect(),
+                failed_attempts: (0..failures).map(|_| reception(0.1)).collect(),
+                ..ReceptionHistory::default()
+            },
+        }
+    }
+    
+    #[test]
+    fn test_history_drives_predicted_quality() {
+        let parameters = json!({});
+        let receptive = RecipientSimulator::new(recipient(18, 0, vec![]))
+            .simulate_injection(&[], &parameters, 0.9);
+        let resistant = RecipientSimulator::new(recipient(2, 16, vec![]))
+            .simulate_injection(&[], &parameters, 0.9);
+        
+        assert!(receptive.reception_quality > resistant.reception_quality);
+        assert!(receptive.reception_quality <= 0.9);
+        assert!(resistant.integration_time > receptive.integration_time);
+    }
+    
+    #[test]
+    fn test_mismatched_rhythm_lowers_quality() {
+        let simulator = RecipientSimulator::new(recipient(10, 0, vec![10.0]));
+        let aligned = simulator
+            .simulate_injection(&[], &json!({ "timing": { "carrier_frequency": 10.0 } }), 0.9);
+        let detuned = simulator
+            .simulate_injection(&[], &json!({ "timing": { "carrier_frequency": 40.0 } }), 0.9);
+        
+        assert!(aligned.reception_quality > detuned.reception_quality);
+        assert_eq!(aligned.bmd_id, Uuid::nil());
+    }
+}
@@ -0,0 +1,187 @@
+//! # Orchestration State Snapshots
+//! 
+//! [`crate::HugureSystem::snapshot`] writes the knowledge a node accumulates while
+//! exploring to a single JSON file, and [`crate::HugureSystem::restore`] loads it
+//! back, so a node can be restarted or migrated without starting cold:
+//! 
+//! - orchestrator state, currently the recursion-depth breaker
+//! - optimizer warm-start data, as exported by the optimizer plugin
+//! - the registry of recently emerged patterns
+//! 
+//! Snapshots are written to a temporary file and renamed into place, so a crash
+//! mid-write never leaves a truncated snapshot behind.
+
+use std::collections::VecDeque;
+use std::fs;
+use std::path::Path;
+use std::sync::{Mutex, PoisonError};
+use std::time::{SystemTime, UNIX_EPOCH};
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::depth_breaker::BreakerState;
+use crate::emergence::EmergedPattern;
+
+/// Snapshot format written by this version
+pub const SNAPSHOT_FORMAT_VERSION: u32 = 1;
+
+/// Orchestrator state carried across restarts
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct OrchestrationState {
+    /// Recursion-depth circuit breaker
+    pub breaker: BreakerState,
+}
+
+/// Everything a node needs to resume exploration where it left off
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SystemSnapshot {
+    /// Format of this snapshot, see [`SNAPSHOT_FORMAT_VERSION`]
+    pub format_version: u32,
+    
+    /// Time the snapshot was taken, nanoseconds since the Unix epoch
+    pub taken_at_ns: u64,
+    
+    /// Orchestrator state
+    pub orchestration: OrchestrationState,
+    
+    /// Optimizer warm-start data, if the optimizer plugin exports any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub optimizer: Option<Value>,
+    
+    /// Recently emerged patterns, oldest first
+    #[serde(default)]
+    pub emerged_patterns: Vec<EmergedPattern>,
+}
+
+impl SystemSnapshot {
+    /// Snapshot stamped with the current time
+    pub fn new(
+        orchestration: OrchestrationState,
+        optimizer: Option<Value>,
+        emerged_patterns: Vec<EmergedPattern>,
+    ) -> Self {
+        let taken_at_ns = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_nanos() as u64)
+            .unwrap_or(0);
+        Self {
+            format_version: SNAPSHOT_FORMAT_VERSION,
+            taken_at_ns,
+            orchestration,
+            optimizer,
+            emerged_patterns,
+        }
+    }
+    
+    /// Write the snapshot to `path`, replacing any previous snapshot atomically
+    pub fn write(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let staging = path.with_extension("tmp");
+        let bytes = serde_json::to_vec(self)?;
+        fs::write(&staging, bytes)
+            .with_context(|| format!("failed to write snapshot to {}", staging.display()))?;
+        fs::rename(&staging, path)
+            .with_context(|| format!("failed to move snapshot into {}", path.display()))?;
+        Ok(())
+    }
+    
+    /// Read a snapshot written by [`SystemSnapshot::write`]
+    pub fn read(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let bytes = fs::read(path)
+            .with_context(|| format!("failed to read snapshot {}", path.display()))?;
+        let snapshot: Self = serde_json::from_slice(&bytes)
+            .with_context(|| format!("malformed snapshot {}", path.display()))?;
+        if snapshot.format_version > SNAPSHOT_FORMAT_VERSION {
+            anyhow::bail!(
+                "snapshot {} has format version {}, newest supported is {}",
+                path.display(),
+                snapshot.format_version,
+                SNAPSHOT_FORMAT_VERSION
+            );
+        }
+        Ok(snapshot)
+    }
+}
+
+/// Bounded registry of the most recently emerged patterns
+#[derive(Debug)]
+pub struct PatternRegistry {
+    capacity: usize,
+    patterns: Mutex<VecDeque<EmergedPattern>>,
+}
+
+impl PatternRegistry {
+    /// Registry keeping at most `capacity` patterns (0 keeps none)
+    pub fn new(capacity: usize) -> Self {
+        Self { capacity, patterns: Mutex::new(VecDeque::new()) }
+    }
+    
+    /// Record newly emerged patterns, evicting the oldest beyond capacity
+    pub fn extend(&self, emerged: impl IntoIterator<Item = EmergedPattern>) {
+        let mut patterns = self.patterns.lock().unwrap_or_else(PoisonError::into_inner);
+        patterns.extend(emerged);
+        let excess = patterns.len().saturating_sub(self.capacity);
+        patterns.drain(..excess);
+    }
+    
+    /// Registered patterns, oldest first
+    pub fn patterns(&self) -> Vec<EmergedPattern> {
+        self.patterns.lock().unwrap_or_else(PoisonError::into_inner).iter().cloned().collect()
+    }
+    
+    /// Replace the registry contents with `patterns`
+    pub fn replace(&self, patterns: Vec<EmergedPattern>) {
+        self.patterns.lock().unwrap_or_else(PoisonError::into_inner).clear();
+        self.extend(patterns);
+    }
+    
+    /// Number of registered patterns
+    pub fn len(&self) -> usize {
+        self.patterns.lock().unwrap_or_else(PoisonError::into_inner).len()
+    }
+    
+    /// Whether no patterns are registered
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn scratch_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("hugure-{}-{}.json", name, std::process::id()))
+    }
+    
+    #[test]
+    fn test_snapshot_round_trip() {
+        let path = scratch_path("snapshot-round-trip");
+        let orchestration = OrchestrationState {
+            breaker: BreakerState { consecutive_exhaustions: 2, open_remaining_ms: Some(1_500) },
+        };
+        let optimizer = Some(serde_json::json!({ "learning_rate": 0.1 }));
+        let snapshot = SystemSnapshot::new(orchestration, optimizer, Vec::new());
+        snapshot.write(&path).unwrap();
+        
+        let restored = SystemSnapshot::read(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(restored.orchestration, snapshot.orchestration);
+        assert_eq!(restored.optimizer, snapshot.optimizer);
+        assert_eq!(restored.taken_at_ns, snapshot.taken_at_ns);
+    }
+    
+    #[test]
+    fn test_newer_format_is_rejected() {
+        let path = scratch_path("snapshot-newer-format");
+        let mut snapshot = SystemSnapshot::new(OrchestrationState::default(), None, Vec::new());
+        snapshot.format_version = SNAPSHOT_FORMAT_VERSION + 1;
+        snapshot.write(&path).unwrap();
+        
+        let error = SystemSnapshot::read(&path).unwrap_err();
+        fs::remove_file(&path).unwrap();
+        assert!(error.to_string().contains("format version"), "{}", error);
+    }
+}
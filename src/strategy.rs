@@ -0,0 +1,324 @@
+//! # Optimization Strategies
+//! 
+//! Search algorithms for weighting the BMDs of an optimized communication.
+//! [`OptimizationStrategy`] maximizes an objective over the unit box; the built-in
+//! strategies are finite-difference gradient refinement, simulated annealing, and
+//! evolutionary search, chosen with [`StrategyKind`] in the configuration or per
+//! request via [`crate::HugureSystem::handle_communication_request_with_strategy`].
+//! 
+//! The optimizer actor runs the chosen strategy while optimizing a communication,
+//! trading predicted transmission fidelity against drift from the information
+//! content of the combination as explored, and records the result in each BMD's
+//! `selection_probability`.
+
+use std::fmt;
+use std::str::FromStr;
+use serde::{Deserialize, Serialize};
+
+/// Search algorithm maximizing an objective over `[0, 1]^n`
+pub trait OptimizationStrategy: Send + Sync + fmt::Debug {
+    /// Strategy name, used in logs
+    fn name(&self) -> &'static str;
+    
+    /// Best point found starting from `initial`
+    fn maximize(&self, objective: &dyn Fn(&[f64]) -> f64, initial: &[f64]) -> Vec<f64>;
+}
+
+/// Built-in strategies, selectable by name
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StrategyKind {
+    /// [`GradientRefinement`]
+    #[default]
+    Gradient,
+    /// [`SimulatedAnnealing`]
+    Annealing,
+    /// [`EvolutionarySearch`]
+    Evolutionary,
+}
+
+impl StrategyKind {
+    /// Strategy name as accepted by [`FromStr`]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StrategyKind::Gradient => "gradient",
+            StrategyKind::Annealing => "annealing",
+            StrategyKind::Evolutionary => "evolutionary",
+        }
+    }
+    
+    /// Strategy with default tuning
+    pub fn strategy(&self) -> Box<dyn OptimizationStrategy> {
+        match self {
+            StrategyKind::Gradient => Box::new(GradientRefinement::default()),
+            StrategyKind::Annealing => Box::new(SimulatedAnnealing::default()),
+            StrategyKind::Evolutionary => Box::new(EvolutionarySearch::default()),
+        }
+    }
+}
+
+impl fmt::Display for StrategyKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for StrategyKind {
+    type Err = anyhow::Error;
+    
+    fn from_str(name: &str) -> anyhow::Result<Self> {
+        match name {
+            "gradient" => Ok(StrategyKind::Gradient),
+            "annealing" => Ok(StrategyKind::Annealing),
+            "evolutionary" => Ok(StrategyKind::Evolutionary),
+            _ => Err(anyhow::anyhow!(
+                "unknown optimization strategy '{}' (expected gradient, annealing, evolutionary)",
+                name
+            )),
+        }
+    }
+}
+
+/// Projected gradient ascent with finite-difference gradients
+#[derive(Debug, Clone)]
+pub struct GradientRefinement {
+    /// Ascent steps
+    pub iterations: usize,
+    /// Step size along the gradient
+    pub learning_rate: f64,
+    /// Finite-difference step
+    pub epsilon: f64,
+}
+
+impl Default for GradientRefinement {
+    fn default() -> Self {
+        Self { iterations: 200, learning_rate: 0.05, epsilon: 1e-4 }
+    }
+}
+
+impl OptimizationStrategy for GradientRefinement {
+    fn name(&self) -> &'static str {
+        "gradient"
+    }
+    
+    fn maximize(&self, objective: &dyn Fn(&[f64]) -> f64, initial: &[f64]) -> Vec<f64> {
+        let mut point = clamped(initial);
+        let mut probe = point.clone();
+        
+        for _ in 0..self.iterations {
+            let base = objective(&point);
+            let mut gradient = vec![0.0; point.len()];
+            for (i, slope) in gradient.iter_mut().enumerate() {
+                probe[i] = (point[i] + self.epsilon).min(1.0);
+                let step = probe[i] - point[i];
+                if step > 0.0 {
+                    *slope = (objective(&probe) - base) / step;
+                }
+                probe[i] = point[i];
+            }
+            
+            let mut moved = false;
+            for (x, slope) in point.iter_mut().zip(&gradient) {
+                let next = (*x + self.learning_rate * slope).clamp(0.0, 1.0);
+                moved |= next != *x;
+                *x = next;
+            }
+            if !moved {
+                break;
+            }
+            probe.copy_from_slice(&point);
+        }
+        point
+    }
+}
+
+/// Simulated annealing with Gaussian-like perturbations and geometric cooling
+#[derive(Debug, Clone)]
+pub struct SimulatedAnnealing {
+    /// Proposals evaluated
+    pub iterations: usize,
+    /// Starting temperature
+    pub initial_temperature: f64,
+    /// Temperature multiplier per proposal
+    pub cooling_rate: f64,
+    /// Perturbation scale
+    pub step: f64,
+    /// Random seed, for reproducible runs
+    pub seed: u64,
+}
+
+impl Default for SimulatedAnnealing {
+    fn default() -> Self {
+        Self {
+            iterations: 2_000,
+            initial_temperature: 0.1,
+            cooling_rate: 0.995,
+            step: 0.1,
+            seed: 0x5eed,
+        }
+    }
+}
+
+impl OptimizationStrategy for SimulatedAnnealing {
+    fn name(&self) -> &'static str {
+        "annealing"
+    }
+    
+    fn maximize(&self, objective: &dyn Fn(&[f64]) -> f64, initial: &[f64]) -> Vec<f64> {
+        let mut rng = SplitMix64(self.seed);
+        let mut current = clamped(initial);
+        let mut current_score = objective(&current);
+        let mut best = (current.clone(), current_score);
+        let mut temperature = self.initial_temperature;
+        
+        for _ in 0..self.iterations {
+            let candidate: Vec<f64> = current.iter()
+                .map(|x| (x + self.step * rng.next_centered()).clamp(0.0, 1.0))
+                .collect();
+            let score = objective(&candidate);
+            
+            // Accept worse proposals with Boltzmann probability
+            let acceptance = ((score - current_score) / temperature.max(f64::MIN_POSITIVE)).exp();
+            let accept = score >= current_score || rng.next_unit() < acceptance;
+            if accept {
+                current = candidate;
+                current_score = score;
+                if current_score > best.1 {
+                    best = (current.clone(), current_score);
+                }
+            }
+            temperature *= self.cooling_rate;
+        }
+        best.0
+    }
+}
+
+/// Elitist evolutionary search with uniform crossover and mutation
+#[derive(Debug, Clone)]
+pub struct EvolutionarySearch {
+    /// Generations bred
+    pub generations: usize,
+    /// Individuals per generation
+    pub population: usize,
+    /// Fraction of each generation kept as parents
+    pub elite_fraction: f64,
+    /// Mutation scale
+    pub mutation: f64,
+    /// Random seed, for reproducible runs
+    pub seed: u64,
+}
+
+impl Default for EvolutionarySearch {
+    fn default() -> Self {
+        Self {
+            generations: 60,
+            population: 32,
+            elite_fraction: 0.25,
+            mutation: 0.1,
+            seed: 0x5eed,
+        }
+    }
+}
+
+impl OptimizationStrategy for EvolutionarySearch {
+    fn name(&self) -> &'static str {
+        "evolutionary"
+    }
+    
+    fn maximize(&self, objective: &dyn Fn(&[f64]) -> f64, initial: &[f64]) -> Vec<f64> {
+        let mut rng = SplitMix64(self.seed);
+        let population = self.population.max(2);
+        let elite = ((population as f64 * self.elite_fraction) as usize).clamp(1, population);
+        
+        // Seed with the starting point and random individuals around the box
+        let mut generation: Vec<(Vec<f64>, f64)> = std::iter::once(clamped(initial))
+            .chain((1..population).map(|_| initial.iter().map(|_| rng.next_unit()).collect()))
+            .map(|individual| {
+                let score = objective(&individual);
+                (individual, score)
+            })
+            .collect();
+        
+        for _ in 0..self.generations {
+            generation.sort_by(|a, b| b.1.total_cmp(&a.1));
+            generation.truncate(elite);
+            
+            while generation.len() < population {
+                let a = &generation[rng.next_index(elite)].0;
+                let b = &generation[rng.next_index(elite)].0;
+                let child: Vec<f64> = a.iter().zip(b)
+                    .map(|(&x, &y)| {
+                        let gene = if rng.next_unit() < 0.5 { x } else { y };
+                        (gene + self.mutation * rng.next_centered()).clamp(0.0, 1.0)
+                    })
+                    .collect();
+                let score = objective(&child);
+                generation.push((child, score));
+            }
+        }
+        
+        generation.into_iter()
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(individual, _)| individual)
+            .unwrap_or_else(|| clamped(initial))
+    }
+}
+
+fn clamped(point: &[f64]) -> Vec<f64> {
+    point.iter().map(|x| x.clamp(0.0, 1.0)).collect()
+}
+
+/// Small deterministic generator, so strategy runs are reproducible from a seed
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+    
+    /// Uniform in [0, 1)
+    fn next_unit(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+    
+    /// Roughly normal with mean 0 and unit variance (sum of uniforms)
+    fn next_centered(&mut self) -> f64 {
+        (0..12).map(|_| self.next_unit()).sum::<f64>() - 6.0
+    }
+    
+    fn next_index(&mut self, len: usize) -> usize {
+        (self.next_u64() % len as u64) as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    /// Concave objective peaking at (0.2, 0.7, 0.9)
+    fn peaked(point: &[f64]) -> f64 {
+        let target = [0.2, 0.7, 0.9];
+        -point.iter().zip(target).map(|(x, t)| (x - t).powi(2)).sum::<f64>()
+    }
+    
+    #[test]
+    fn test_strategies_find_interior_optimum() {
+        for kind in [StrategyKind::Gradient, StrategyKind::Annealing, StrategyKind::Evolutionary] {
+            let best = kind.strategy().maximize(&peaked, &[0.5, 0.5, 0.5]);
+            assert!(peaked(&best) > -1e-2, "{} ended at {:?}", kind, best);
+        }
+    }
+    
+    #[test]
+    fn test_strategy_names_round_trip() {
+        for kind in [StrategyKind::Gradient, StrategyKind::Annealing, StrategyKind::Evolutionary] {
+            assert_eq!(kind.as_str().parse::<StrategyKind>().unwrap(), kind);
+            assert_eq!(kind.strategy().name(), kind.as_str());
+        }
+        assert!("newton".parse::<StrategyKind>().is_err());
+    }
+}
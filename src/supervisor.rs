@@ -0,0 +1,159 @@
+//! # Loop Supervision
+//! 
+//! [`supervise_loop`] keeps a long-running loop alive across panics. A panic is
+//! caught at the loop boundary, published as [`LifecycleEvent::LoopCrashed`] with
+//! the panic message, and the loop is restarted after an exponential backoff.
+//! Without it, a panic in the spawned orchestration loop ends the task silently.
+
+use std::any::Any;
+use std::future::Future;
+use std::panic::AssertUnwindSafe;
+use std::time::{Duration, Instant};
+use futures::FutureExt;
+use tracing::{debug, error};
+
+use crate::events::{EventBus, LifecycleEvent};
+use crate::shutdown::ShutdownHandle;
+
+/// When a supervised loop is restarted after a panic
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LoopRestartPolicy {
+    /// Delay before the first restart
+    pub initial_backoff: Duration,
+    /// Upper bound on the delay between restarts
+    pub max_backoff: Duration,
+    /// A run lasting this long resets the backoff to `initial_backoff`
+    pub healthy_after: Duration,
+}
+
+impl Default for LoopRestartPolicy {
+    fn default() -> Self {
+        Self {
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(30),
+            healthy_after: Duration::from_secs(60),
+        }
+    }
+}
+
+impl LoopRestartPolicy {
+    /// Delay before restart number `restart` (0-based) in a run of crashes
+    pub fn backoff(&self, restart: u32) -> Duration {
+        self.initial_backoff
+            .saturating_mul(2u32.saturating_pow(restart))
+            .min(self.max_backoff)
+    }
+}
+
+/// Run the loop `run` builds until it returns or shutdown is requested,
+/// restarting it whenever it panics
+pub async fn supervise_loop<F, Fut>(
+    name: &'static str,
+    policy: LoopRestartPolicy,
+    shutdown: &ShutdownHandle,
+    events: &EventBus,
+    mut run: F,
+)
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = ()>,
+{
+    let mut crashes = 0u32;
+    let mut restarts = 0u64;
+    loop {
+        let started = Instant::now();
+        let Err(payload) = AssertUnwindSafe(run()).catch_unwind().await else {
+            debug!(target: "orchestration", loop_name = name, "supervised loop returned");
+            return;
+        };
+        
+        if started.elapsed() >= policy.healthy_after {
+            crashes = 0;
+        }
+        let panic = panic_message(payload.as_ref());
+        let restart_in = policy.backoff(crashes);
+        crashes = crashes.saturating_add(1);
+        restarts += 1;
+        error!(
+            target: "orchestration",
+            loop_name = name,
+            restarts,
+            restart_in_ms = restart_in.as_millis() as u64,
+            panic = %panic,
+            "supervised loop panicked"
+        );
+        events.publish(LifecycleEvent::LoopCrashed {
+            loop_name: name.to_string(),
+            panic,
+            restarts,
+            restart_in,
+        });
+        
+        tokio::select! {
+            _ = tokio::time::sleep(restart_in) => {}
+            _ = shutdown.cancelled() => return,
+        }
+    }
+}
+
+/// Message of a caught panic, for `panic!` with a string or format arguments
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "non-string panic payload".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    
+    #[test]
+    fn test_backoff_doubles_up_to_the_cap() {
+        let policy = LoopRestartPolicy {
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_millis(250),
+            healthy_after: Duration::from_secs(1),
+        };
+        assert_eq!(policy.backoff(0), Duration::from_millis(100));
+        assert_eq!(policy.backoff(1), Duration::from_millis(200));
+        assert_eq!(policy.backoff(2), Duration::from_millis(250));
+        assert_eq!(policy.backoff(40), Duration::from_millis(250));
+    }
+    
+    #[tokio::test]
+    async fn test_panicking_loop_is_restarted_and_reported() {
+        let policy = LoopRestartPolicy {
+            initial_backoff: Duration::from_millis(1),
+            max_backoff: Duration::from_millis(2),
+            healthy_after: Duration::from_secs(60),
+        };
+        let bus = EventBus::new(8);
+        let mut events = bus.subscribe();
+        let runs = AtomicU32::new(0);
+        
+        supervise_loop("test", policy, &ShutdownHandle::new(), &bus, || async {
+            let run = runs.fetch_add(1, Ordering::SeqCst);
+            if run < 2 {
+                panic!("cycle {} exploded", run);
+            }
+        })
+        .await;
+        assert_eq!(runs.load(Ordering::SeqCst), 3);
+        
+        for expected in 1..=2 {
+            match events.recv().await.unwrap() {
+                LifecycleEvent::LoopCrashed { loop_name, panic, restarts, .. } => {
+                    assert_eq!(loop_name, "test");
+                    assert_eq!(panic, format!("cycle {} exploded", expected - 1));
+                    assert_eq!(restarts, expected);
+                }
+                other => panic!("unexpected event {:?}", other),
+            }
+        }
+    }
+}
@@ -0,0 +1,150 @@
+//! Versioned wire envelope for Kambuzuma messages
+//! 
+//! Every message crossing a process boundary is wrapped in an [`Envelope`] of
+//! self-describing JSON:
+//! 
+//! ```json
+//! { "schema": { "major": 1, "minor": 0 }, "kind": "HugureReady", "payload": { ... } }
+//! ```
+//! 
+//! Peers sharing a major version interoperate: fields a peer does not know are
+//! ignored, envelope-level fields are carried through in `extensions`, and
+//! message kinds a peer does not know decode to `None` instead of failing, so
+//! Hugure and Kambuzuma can be upgraded independently. A major version bump marks
+//! an incompatible change and is rejected.
+
+use anyhow::Result;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// Wire schema version spoken by this build
+pub const WIRE_SCHEMA_VERSION: SchemaVersion = SchemaVersion { major: 1, minor: 0 };
+
+/// Wire schema version; minor versions only add fields or message kinds
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct SchemaVersion {
+    pub major: u16,
+    pub minor: u16,
+}
+
+impl SchemaVersion {
+    /// Whether messages of this version can be read by a peer speaking `other`
+    pub fn is_compatible_with(&self, other: SchemaVersion) -> bool {
+        self.major == other.major
+    }
+}
+
+/// Self-describing wrapper around one message
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Envelope {
+    /// Schema version of the sender
+    pub schema: SchemaVersion,
+    
+    /// Message variant name, readable even when the payload is not understood
+    pub kind: String,
+    
+    /// Variant fields
+    #[serde(default)]
+    pub payload: Value,
+    
+    /// Envelope fields added by newer schema versions, preserved for forwarding
+    #[serde(flatten)]
+    pub extensions: Map<String, Value>,
+}
+
+impl Envelope {
+    /// Wrap an externally tagged enum message (such as `KambuzumaMessage`)
+    pub fn seal<T: Serialize>(message: &T) -> Result<Self> {
+        let (kind, payload) = match serde_json::to_value(message)? {
+            Value::String(kind) => (kind, Value::Null),
+            Value::Object(map) if map.len() == 1 => map.into_iter().next().unwrap(),
+            other => return Err(anyhow::anyhow!("message is not an enum variant: {}", other)),
+        };
+        
+        Ok(Self {
+            schema: WIRE_SCHEMA_VERSION,
+            kind,
+            payload,
+            extensions: Map::new(),
+        })
+    }
+    
+    /// Unwrap the message, or `None` if its kind or shape is unknown to this build
+    pub fn open<T: DeserializeOwned>(&self) -> Result<Option<T>> {
+        if !self.schema.is_compatible_with(WIRE_SCHEMA_VERSION) {
+            return Err(anyhow::anyhow!(
+                "incompatible wire schema {}.{} (this build speaks {}.{})",
+                self.schema.major,
+                self.schema.minor,
+                WIRE_SCHEMA_VERSION.major,
+                WIRE_SCHEMA_VERSION.minor
+            ));
+        }
+        
+        let tagged = match &self.payload {
+            Value::Null => Value::String(self.kind.clone()),
+            payload => Value::Object(Map::from_iter([(self.kind.clone(), payload.clone())])),
+        };
+        match serde_json::from_value(tagged) {
+            Ok(message) => Ok(Some(message)),
+            // Newer peers may send message kinds this build has never heard of
+            Err(_) if self.schema > WIRE_SCHEMA_VERSION => Ok(None),
+            Err(e) => Err(anyhow::anyhow!("malformed '{}' message: {}", self.kind, e)),
+        }
+    }
+    
+    /// Encode as JSON bytes
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        Ok(serde_json::to_vec(self)?)
+    }
+    
+    /// Decode from JSON bytes
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        Ok(serde_json::from_slice(bytes)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    enum Message {
+        Ready { rate: u64 },
+        Shutdown,
+    }
+    
+    #[test]
+    fn test_round_trip() {
+        for message in [Message::Ready { rate: 10 }, Message::Shutdown] {
+            let bytes = Envelope::seal(&message).unwrap().to_bytes().unwrap();
+            let opened: Option<Message> = Envelope::from_bytes(&bytes).unwrap().open().unwrap();
+            assert_eq!(opened, Some(message));
+        }
+    }
+    
+    #[test]
+    fn test_newer_minor_version_is_tolerated() {
+        let bytes = json!({
+            "schema": { "major": 1, "minor": 3 },
+            "kind": "Ready",
+            "payload": { "rate": 10, "added_in_1_3": true },
+            "trace_id": "abc",
+        }).to_string();
+        let envelope = Envelope::from_bytes(bytes.as_bytes()).unwrap();
+        assert_eq!(envelope.extensions["trace_id"], "abc");
+        assert_eq!(envelope.open::<Message>().unwrap(), Some(Message::Ready { rate: 10 }));
+        
+        let unknown = Envelope { kind: "Rebalance".to_string(), ..envelope };
+        assert_eq!(unknown.open::<Message>().unwrap(), None);
+    }
+    
+    #[test]
+    fn test_major_version_mismatch_rejected() {
+        let mut envelope = Envelope::seal(&Message::Shutdown).unwrap();
+        envelope.schema.major += 1;
+        assert!(envelope.open::<Message>().is_err());
+    }
+}
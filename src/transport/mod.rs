@@ -0,0 +1,105 @@
+//! # Kambuzuma Transports
+//! 
+//! [`KambuzumaTransport`] carries [`KambuzumaMessage`]s from Hugure to the Kambuzuma
+//! neural orchestrator. [`InProcessTransport`] wraps the in-process channel used when
+//! both run in one binary; [`StreamTransport`] sends versioned [`Envelope`]s as
+//! length-delimited JSON over TCP or a Unix socket and reconnects with exponential
+//! backoff when the orchestrator runs in another process or on another host.
+
+use std::fmt;
+use std::sync::Arc;
+use async_trait::async_trait;
+use anyhow::Result;
+use tokio::sync::mpsc;
+
+use crate::communication::KambuzumaMessage;
+
+mod envelope;
+mod stream;
+
+pub use envelope::{Envelope, SchemaVersion, WIRE_SCHEMA_VERSION};
+pub use stream::{Connector, ReconnectPolicy, StreamTransport, TcpConnector};
+#[cfg(unix)]
+pub use stream::UnixConnector;
+
+/// Delivery of messages to the Kambuzuma orchestrator
+#[async_trait]
+pub trait KambuzumaTransport: Send + Sync + fmt::Debug {
+    /// Transport description, used in logs and errors
+    fn name(&self) -> &str;
+    
+    /// Deliver a message, waiting for capacity or reconnection as needed
+    async fn send(&self, message: KambuzumaMessage) -> Result<()>;
+    
+    /// Messages accepted but not yet handed to Kambuzuma
+    fn pending(&self) -> usize {
+        0
+    }
+    
+    /// Whether messages can currently reach Kambuzuma
+    fn is_alive(&self) -> bool {
+        true
+    }
+}
+
+/// Transport over an in-process channel
+#[derive(Debug, Clone)]
+pub struct InProcessTransport {
+    sender: mpsc::Sender<KambuzumaMessage>,
+}
+
+impl InProcessTransport {
+    /// Wrap the sending half of a Kambuzuma channel
+    pub fn new(sender: mpsc::Sender<KambuzumaMessage>) -> Self {
+        Self { sender }
+    }
+}
+
+#[async_trait]
+impl KambuzumaTransport for InProcessTransport {
+    fn name(&self) -> &str {
+        "in_process"
+    }
+    
+    async fn send(&self, message: KambuzumaMessage) -> Result<()> {
+        self.sender.send(message).await
+            .map_err(|_| anyhow::anyhow!("Kambuzuma channel closed"))
+    }
+    
+    fn pending(&self) -> usize {
+        self.sender.max_capacity() - self.sender.capacity()
+    }
+    
+    fn is_alive(&self) -> bool {
+        !self.sender.is_closed()
+    }
+}
+
+/// Open a transport for `endpoint`
+/// 
+/// Supported forms are `tcp://host:port` and, on Unix, `unix:///path/to/socket`.
+pub fn connect(endpoint: &str, policy: ReconnectPolicy) -> Result<Arc<dyn KambuzumaTransport>> {
+    if let Some(addr) = endpoint.strip_prefix("tcp://") {
+        return Ok(Arc::new(StreamTransport::tcp(addr, policy)));
+    }
+    
+    #[cfg(unix)]
+    if let Some(path) = endpoint.strip_prefix("unix://") {
+        return Ok(Arc::new(StreamTransport::unix(path, policy)));
+    }
+    
+    Err(anyhow::anyhow!("unsupported Kambuzuma endpoint '{}'", endpoint))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_connect_rejects_unknown_scheme() {
+        assert!(connect("http://127.0.0.1:50051", ReconnectPolicy::default()).is_err());
+        
+        let transport = connect("tcp://127.0.0.1:50051", ReconnectPolicy::default()).unwrap();
+        assert_eq!(transport.name(), "tcp://127.0.0.1:50051");
+    }
+}
@@ -0,0 +1,234 @@
+//! Reconnecting byte-stream transports (TCP and Unix sockets)
+
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use async_trait::async_trait;
+use anyhow::Result;
+use futures::SinkExt;
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio::sync::Mutex;
+use tokio_util::bytes::Bytes;
+use tokio_util::codec::{Framed, LengthDelimitedCodec};
+use tracing::{debug, warn};
+
+use crate::communication::KambuzumaMessage;
+use super::{Envelope, KambuzumaTransport};
+
+/// Retry behaviour for stream transports
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReconnectPolicy {
+    /// Delay before the first reconnection attempt
+    pub initial_backoff: Duration,
+    /// Upper bound on the delay between attempts
+    pub max_backoff: Duration,
+    /// Attempts per message before giving up
+    pub max_attempts: u32,
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            initial_backoff: Duration::from_millis(50),
+            max_backoff: Duration::from_secs(5),
+            max_attempts: 6,
+        }
+    }
+}
+
+impl ReconnectPolicy {
+    /// Delay before attempt number `attempt` (0-based)
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        self.initial_backoff
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_backoff)
+    }
+}
+
+/// Opens connections for a [`StreamTransport`]
+#[async_trait]
+pub trait Connector: Send + Sync + fmt::Debug {
+    /// Connected stream type
+    type Stream: AsyncRead + AsyncWrite + Unpin + Send;
+    
+    /// Open a new connection
+    async fn connect(&self) -> std::io::Result<Self::Stream>;
+}
+
+/// TCP connector
+#[derive(Debug, Clone)]
+pub struct TcpConnector {
+    addr: String,
+}
+
+#[async_trait]
+impl Connector for TcpConnector {
+    type Stream = tokio::net::TcpStream;
+    
+    async fn connect(&self) -> std::io::Result<Self::Stream> {
+        let stream = tokio::net::TcpStream::connect(&self.addr).await?;
+        stream.set_nodelay(true)?;
+        Ok(stream)
+    }
+}
+
+/// Unix domain socket connector
+#[cfg(unix)]
+#[derive(Debug, Clone)]
+pub struct UnixConnector {
+    path: std::path::PathBuf,
+}
+
+#[cfg(unix)]
+#[async_trait]
+impl Connector for UnixConnector {
+    type Stream = tokio::net::UnixStream;
+    
+    async fn connect(&self) -> std::io::Result<Self::Stream> {
+        tokio::net::UnixStream::connect(&self.path).await
+    }
+}
+
+/// Transport sending length-delimited [`Envelope`] frames over a reconnecting stream
+pub struct StreamTransport<C: Connector> {
+    name: String,
+    connector: C,
+    policy: ReconnectPolicy,
+    connection: Mutex<Option<Framed<C::Stream, LengthDelimitedCodec>>>,
+    /// Cleared when a message could not be delivered, set again on the next success
+    delivering: AtomicBool,
+}
+
+impl<C: Connector> fmt::Debug for StreamTransport<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StreamTransport")
+            .field("name", &self.name)
+            .field("policy", &self.policy)
+            .finish()
+    }
+}
+
+impl StreamTransport<TcpConnector> {
+    /// Transport to a Kambuzuma orchestrator listening on `addr` (`host:port`)
+    pub fn tcp(addr: impl Into<String>, policy: ReconnectPolicy) -> Self {
+        let addr = addr.into();
+        Self::with_connector(format!("tcp://{}", addr), TcpConnector { addr }, policy)
+    }
+}
+
+#[cfg(unix)]
+impl StreamTransport<UnixConnector> {
+    /// Transport to a Kambuzuma orchestrator listening on the Unix socket at `path`
+    pub fn unix(path: impl Into<std::path::PathBuf>, policy: ReconnectPolicy) -> Self {
+        let path = path.into();
+        Self::with_connector(format!("unix://{}", path.display()), UnixConnector { path }, policy)
+    }
+}
+
+impl<C: Connector> StreamTransport<C> {
+    /// Transport over connections opened by `connector`
+    /// 
+    /// Connections are opened lazily on the first send.
+    pub fn with_connector(name: impl Into<String>, connector: C, policy: ReconnectPolicy) -> Self {
+        Self {
+            name: name.into(),
+            connector,
+            policy,
+            connection: Mutex::new(None),
+            delivering: AtomicBool::new(true),
+        }
+    }
+}
+
+#[async_trait]
+impl<C: Connector> KambuzumaTransport for StreamTransport<C> {
+    fn name(&self) -> &str {
+        &self.name
+    }
+    
+    async fn send(&self, message: KambuzumaMessage) -> Result<()> {
+        let frame = Bytes::from(Envelope::seal(&message)?.to_bytes()?);
+        let mut connection = self.connection.lock().await;
+        let mut last_error = None;
+        
+        for attempt in 0..self.policy.max_attempts.max(1) {
+            if attempt > 0 {
+                tokio::time::sleep(self.policy.backoff(attempt - 1)).await;
+            }
+            
+            if connection.is_none() {
+                match self.connector.connect().await {
+                    Ok(stream) => {
+                        debug!(
+                            target: "orchestration",
+                            transport = %self.name,
+                            "connected to Kambuzuma"
+                        );
+                        *connection = Some(Framed::new(stream, LengthDelimitedCodec::new()));
+                    }
+                    Err(e) => {
+                        warn!(
+                            target: "orchestration",
+                            transport = %self.name,
+                            attempt,
+                            error = %e,
+                            "Kambuzuma connection failed"
+                        );
+                        last_error = Some(e);
+                        continue;
+                    }
+                }
+            }
+            
+            if let Some(framed) = connection.as_mut() {
+                match framed.send(frame.clone()).await {
+                    Ok(()) => {
+                        self.delivering.store(true, Ordering::Relaxed);
+                        return Ok(());
+                    }
+                    Err(e) => {
+                        warn!(
+                            target: "orchestration",
+                            transport = %self.name,
+                            attempt,
+                            error = %e,
+                            "Kambuzuma send failed, reconnecting"
+                        );
+                        *connection = None;
+                        last_error = Some(e);
+                    }
+                }
+            }
+        }
+        
+        self.delivering.store(false, Ordering::Relaxed);
+        Err(anyhow::anyhow!(
+            "failed to deliver message over {} after {} attempts: {}",
+            self.name,
+            self.policy.max_attempts.max(1),
+            last_error.map(|e| e.to_string()).unwrap_or_default()
+        ))
+    }
+    
+    fn is_alive(&self) -> bool {
+        self.delivering.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_backoff_is_capped() {
+        let policy = ReconnectPolicy {
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_millis(250),
+            max_attempts: 5,
+        };
+        assert_eq!(policy.backoff(0), Duration::from_millis(100));
+        assert_eq!(policy.backoff(1), Duration::from_millis(200));
+        assert_eq!(policy.backoff(2), Duration::from_millis(250));
+        assert_eq!(policy.backoff(40), Duration::from_millis(250));
+    }
+}
@@ -0,0 +1,44 @@
+//! Flattening of nested parameter structures into numeric key/value maps
+
+use std::collections::HashMap;
+
+/// Flatten every numeric field of `value` into a map keyed by dotted path
+/// 
+/// Lets wire formats and simulations consume the injection and temporal
+/// parameter structures without depending on their exact shape.
+pub fn flatten_numeric<T: serde::Serialize>(value: &T) -> HashMap<String, f64> {
+    fn walk(prefix: &str, value: &serde_json::Value, out: &mut HashMap<String, f64>) {
+        let key = |child: &str| match prefix {
+            "" => child.to_string(),
+            _ => format!("{}.{}", prefix, child),
+        };
+        
+        match value {
+            serde_json::Value::Number(n) => {
+                if let Some(n) = n.as_f64() {
+                    out.insert(prefix.to_string(), n);
+                }
+            }
+            serde_json::Value::Bool(b) => {
+                out.insert(prefix.to_string(), if *b { 1.0 } else { 0.0 });
+            }
+            serde_json::Value::Array(items) => {
+                for (index, item) in items.iter().enumerate() {
+                    walk(&key(&index.to_string()), item, out);
+                }
+            }
+            serde_json::Value::Object(fields) => {
+                for (name, field) in fields {
+                    walk(&key(name), field, out);
+                }
+            }
+            serde_json::Value::Null | serde_json::Value::String(_) => {}
+        }
+    }
+    
+    let mut out = HashMap::new();
+    if let Ok(value) = serde_json::to_value(value) {
+        walk("", &value, &mut out);
+    }
+    out
+}
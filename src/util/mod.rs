@@ -0,0 +1,6 @@
+//! # Shared Utilities
+//! 
+//! Cross-cutting building blocks used by several Hugure subsystems.
+
+pub mod flatten;
+pub mod ratelimit;
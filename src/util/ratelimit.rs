@@ -0,0 +1,340 @@
+//! # Rate Limiting and Throttling
+//! 
+//! Token-bucket and sliding-window limiters shared by the foundry interface,
+//! Kambuzuma sends, and communication ingress, so each subsystem does not grow
+//! its own implementation. Both limiters offer a non-blocking `try_acquire`, an
+//! async `acquire` that waits for capacity, and a `check` that reports how long
+//! to back off. Acquisitions and rejections are reported to a [`RateLimitMetrics`]
+//! hook.
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+use async_trait::async_trait;
+
+/// Common interface of all Hugure rate limiters
+#[async_trait]
+pub trait RateLimiter: Send + Sync + fmt::Debug {
+    /// Limiter name, used in metrics and errors
+    fn name(&self) -> &str;
+    
+    /// Take a permit if one is available right now
+    fn try_acquire(&self) -> bool;
+    
+    /// Time until a permit becomes available (zero if one is available now)
+    fn time_until_available(&self) -> Duration;
+    
+    /// Take a permit, or report how long the caller should back off
+    fn check(&self) -> Result<(), RateLimited> {
+        if self.try_acquire() {
+            Ok(())
+        } else {
+            Err(RateLimited {
+                limiter: self.name().to_string(),
+                retry_after: self.time_until_available(),
+            })
+        }
+    }
+    
+    /// Wait until a permit is available and take it
+    async fn acquire(&self) {
+        loop {
+            if self.try_acquire() {
+                return;
+            }
+            let wait = self.time_until_available().max(Duration::from_micros(50));
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+/// Error returned when a request is throttled
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RateLimited {
+    /// Name of the limiter that rejected the request
+    pub limiter: String,
+    /// Suggested back-off before retrying
+    pub retry_after: Duration,
+}
+
+impl fmt::Display for RateLimited {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "rate limit '{}' exceeded, retry after {:?}", self.limiter, self.retry_after)
+    }
+}
+
+impl std::error::Error for RateLimited {}
+
+/// Hook notified of limiter decisions
+pub trait RateLimitMetrics: Send + Sync + fmt::Debug {
+    /// A permit was granted
+    fn on_acquired(&self, limiter: &str);
+    
+    /// A permit was refused
+    fn on_rejected(&self, limiter: &str);
+}
+
+/// Metrics hook that discards all events
+#[derive(Debug, Default)]
+pub struct NoopMetrics;
+
+impl RateLimitMetrics for NoopMetrics {
+    fn on_acquired(&self, _limiter: &str) {}
+    fn on_rejected(&self, _limiter: &str) {}
+}
+
+/// Metrics hook that counts granted and refused permits
+#[derive(Debug, Default)]
+pub struct CountingMetrics {
+    acquired: AtomicU64,
+    rejected: AtomicU64,
+}
+
+impl CountingMetrics {
+    /// Permits granted so far
+    pub fn acquired(&self) -> u64 {
+        self.acquired.load(Ordering::Relaxed)
+    }
+    
+    /// Permits refused so far
+    pub fn rejected(&self) -> u64 {
+        self.rejected.load(Ordering::Relaxed)
+    }
+}
+
+impl RateLimitMetrics for CountingMetrics {
+    fn on_acquired(&self, _limiter: &str) {
+        self.acquired.fetch_add(1, Ordering::Relaxed);
+    }
+    
+    fn on_rejected(&self, _limiter: &str) {
+        self.rejected.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    // Limiter state stays consistent even if a holder panicked mid-update
+    mutex.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Token-bucket limiter: bursts up to `capacity`, refilled continuously
+#[derive(Debug)]
+pub struct TokenBucket {
+    name: String,
+    capacity: f64,
+    refill_per_second: f64,
+    state: Mutex<BucketState>,
+    metrics: Arc<dyn RateLimitMetrics>,
+}
+
+#[derive(Debug)]
+struct BucketState {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    /// Create a full bucket holding `capacity` tokens, refilled at `refill_per_second`
+    pub fn new(name: impl Into<String>, capacity: u32, refill_per_second: f64) -> Self {
+        let capacity = capacity.max(1) as f64;
+        Self {
+            name: name.into(),
+            capacity,
+            refill_per_second: refill_per_second.max(f64::MIN_POSITIVE),
+            state: Mutex::new(BucketState { tokens: capacity, last_refill: Instant::now() }),
+            metrics: Arc::new(NoopMetrics),
+        }
+    }
+    
+    /// Report decisions to `metrics`
+    pub fn with_metrics(mut self, metrics: Arc<dyn RateLimitMetrics>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+    
+    /// Tokens currently available
+    pub fn available(&self) -> f64 {
+        let mut state = lock(&self.state);
+        self.refill(&mut state);
+        state.tokens
+    }
+    
+    /// Take `n` tokens at once if available
+    pub fn try_acquire_n(&self, n: u32) -> bool {
+        let n = n as f64;
+        let granted = {
+            let mut state = lock(&self.state);
+            self.refill(&mut state);
+            if state.tokens >= n {
+                state.tokens -= n;
+                true
+            } else {
+                false
+            }
+        };
+        
+        if granted {
+            self.metrics.on_acquired(&self.name);
+        } else {
+            self.metrics.on_rejected(&self.name);
+        }
+        granted
+    }
+    
+    fn refill(&self, state: &mut BucketState) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(state.last_refill).as_secs_f64();
+        state.tokens = (state.tokens + elapsed * self.refill_per_second).min(self.capacity);
+        state.last_refill = now;
+    }
+}
+
+#[async_trait]
+impl RateLimiter for TokenBucket {
+    fn name(&self) -> &str {
+        &self.name
+    }
+    
+    fn try_acquire(&self) -> bool {
+        self.try_acquire_n(1)
+    }
+    
+    fn time_until_available(&self) -> Duration {
+        let mut state = lock(&self.state);
+        self.refill(&mut state);
+        if state.tokens >= 1.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64((1.0 - state.tokens) / self.refill_per_second)
+        }
+    }
+}
+
+/// Sliding-window limiter: at most `limit` permits in any `window`
+#[derive(Debug)]
+pub struct SlidingWindow {
+    name: String,
+    limit: usize,
+    window: Duration,
+    events: Mutex<VecDeque<Instant>>,
+    metrics: Arc<dyn RateLimitMetrics>,
+}
+
+impl SlidingWindow {
+    /// Allow `limit` permits per `window`
+    pub fn new(name: impl Into<String>, limit: usize, window: Duration) -> Self {
+        Self {
+            name: name.into(),
+            limit: limit.max(1),
+            window,
+            events: Mutex::new(VecDeque::with_capacity(limit.max(1))),
+            metrics: Arc::new(NoopMetrics),
+        }
+    }
+    
+    /// Report decisions to `metrics`
+    pub fn with_metrics(mut self, metrics: Arc<dyn RateLimitMetrics>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+    
+    /// Permits granted within the current window
+    pub fn current_count(&self) -> usize {
+        let mut events = lock(&self.events);
+        self.evict(&mut events, Instant::now());
+        events.len()
+    }
+    
+    fn evict(&self, events: &mut VecDeque<Instant>, now: Instant) {
+        while let Some(&oldest) = events.front() {
+            if now.duration_since(oldest) >= self.window {
+                events.pop_front();
+            } else {
+                break;
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl RateLimiter for SlidingWindow {
+    fn name(&self) -> &str {
+        &self.name
+    }
+    
+    fn try_acquire(&self) -> bool {
+        let granted = {
+            let now = Instant::now();
+            let mut events = lock(&self.events);
+            self.evict(&mut events, now);
+            if events.len() < self.limit {
+                events.push_back(now);
+                true
+            } else {
+                false
+            }
+        };
+        
+        if granted {
+            self.metrics.on_acquired(&self.name);
+        } else {
+            self.metrics.on_rejected(&self.name);
+        }
+        granted
+    }
+    
+    fn time_until_available(&self) -> Duration {
+        let now = Instant::now();
+        let mut events = lock(&self.events);
+        self.evict(&mut events, now);
+        if events.len() < self.limit {
+            return Duration::ZERO;
+        }
+        events
+            .front()
+            .map(|&oldest| self.window.saturating_sub(now.duration_since(oldest)))
+            .unwrap_or(Duration::ZERO)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_token_bucket_burst_then_reject() {
+        let metrics = Arc::new(CountingMetrics::default());
+        let bucket = TokenBucket::new("foundry", 3, 0.001).with_metrics(metrics.clone());
+        
+        assert!(bucket.try_acquire());
+        assert!(bucket.try_acquire());
+        assert!(bucket.try_acquire());
+        assert!(!bucket.try_acquire());
+        
+        assert_eq!(metrics.acquired(), 3);
+        assert_eq!(metrics.rejected(), 1);
+        assert!(bucket.check().unwrap_err().retry_after > Duration::ZERO);
+    }
+    
+    #[test]
+    fn test_sliding_window_limit() {
+        let window = SlidingWindow::new("ingress", 2, Duration::from_secs(60));
+        
+        assert!(window.try_acquire());
+        assert!(window.try_acquire());
+        assert!(!window.try_acquire());
+        assert_eq!(window.current_count(), 2);
+    }
+    
+    #[tokio::test]
+    async fn test_acquire_waits_for_refill() {
+        let bucket = TokenBucket::new("kambuzuma", 1, 1000.0);
+        assert!(bucket.try_acquire());
+        
+        let started = Instant::now();
+        bucket.acquire().await;
+        assert!(started.elapsed() < Duration::from_secs(1));
+    }
+}